@@ -0,0 +1,423 @@
+//! library api for embedding braid in other tools.
+//!
+//! these functions load, query and update issues the same way the `brd`
+//! commands do, but return data instead of printing. the `cmd_*` functions
+//! are thin wrappers that format what they get back from here.
+//!
+//! this module is the supported surface for library users: signatures here
+//! only change with a changelog entry. everything else in the crate is an
+//! implementation detail of the cli.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use time::OffsetDateTime;
+
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::graph::{compute_derived, get_ready_issues, rank_ready};
+use crate::issue::{Issue, IssueType, Priority, Status, resolve_issue_id};
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+/// which issues `list_issues` returns. every field that is set must match;
+/// the default filter matches everything.
+#[derive(Debug, Clone, Default)]
+pub struct IssueFilter {
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
+    pub owner: Option<String>,
+    /// issues carrying all of these tags.
+    pub tags: Vec<String>,
+    /// only issues that are ready under `ready_includes`.
+    pub ready: bool,
+    /// only issues with at least one unresolved dependency.
+    pub blocked: bool,
+}
+
+impl IssueFilter {
+    fn matches(
+        &self,
+        issue: &Issue,
+        issues: &HashMap<String, Issue>,
+        ready_includes: &[Status],
+    ) -> bool {
+        if self.status.is_some_and(|s| issue.status() != s)
+            || self.priority.is_some_and(|p| issue.priority() != p)
+        {
+            return false;
+        }
+        if let Some(owner) = &self.owner
+            && issue.frontmatter.owner.as_deref() != Some(owner.as_str())
+        {
+            return false;
+        }
+        if !self.tags.iter().all(|t| issue.frontmatter.tags.contains(t)) {
+            return false;
+        }
+        if self.ready || self.blocked {
+            let derived = compute_derived(issue, issues);
+            if self.ready && !derived.is_ready_for(issue.status(), ready_includes) {
+                return false;
+            }
+            if self.blocked && !derived.is_blocked {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// an issue file that failed to load, with the reason.
+pub type LoadFailure = (PathBuf, BrdError);
+
+/// load every issue in the repo, keyed by full id. files that fail to parse
+/// are skipped; `load_issues_with_failures` reports them.
+pub fn load_issues(paths: &RepoPaths, config: &Config) -> Result<HashMap<String, Issue>> {
+    load_issues_with_failures(paths, config).map(|(issues, _)| issues)
+}
+
+/// like `load_issues`, also returning each issue file that failed to parse
+/// with its error. archived issues are not included.
+pub fn load_issues_with_failures(
+    paths: &RepoPaths,
+    config: &Config,
+) -> Result<(HashMap<String, Issue>, Vec<LoadFailure>)> {
+    load_issues_from(&paths.issues_dir(config))
+}
+
+/// issues archived by `brd rm`, with the files that failed to parse.
+pub(crate) fn load_archived_issues_with_failures(
+    paths: &RepoPaths,
+    config: &Config,
+) -> Result<(HashMap<String, Issue>, Vec<LoadFailure>)> {
+    load_issues_from(&paths.archive_dir(config))
+}
+
+fn load_issues_from(issues_dir: &Path) -> Result<(HashMap<String, Issue>, Vec<LoadFailure>)> {
+    let mut issues = HashMap::new();
+    let mut failures = Vec::new();
+
+    if !issues_dir.exists() {
+        return Ok((issues, failures));
+    }
+
+    for entry in std::fs::read_dir(issues_dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "md") {
+            match Issue::load(&path) {
+                Ok(issue) => {
+                    issues.insert(issue.id().to_string(), issue);
+                }
+                Err(e) => failures.push((path, e)),
+            }
+        }
+    }
+
+    Ok((issues, failures))
+}
+
+/// the ready issues in `issues`, best first under the configured
+/// `next_strategy`.
+pub(crate) fn ranked_ready<'a>(
+    issues: &'a HashMap<String, Issue>,
+    config: &Config,
+    now: OffsetDateTime,
+) -> Vec<&'a Issue> {
+    let mut ready = get_ready_issues(issues, &config.ready_includes);
+    rank_ready(&mut ready, issues, config.next_strategy, now);
+    ready
+}
+
+/// issues matching `filter`, in `brd ls` order.
+pub fn list_issues(paths: &RepoPaths, config: &Config, filter: &IssueFilter) -> Result<Vec<Issue>> {
+    let issues = load_issues(paths, config)?;
+    let mut matched: Vec<&Issue> = issues
+        .values()
+        .filter(|issue| filter.matches(issue, &issues, &config.ready_includes))
+        .collect();
+    matched.sort_by(|a, b| a.cmp_for_listing(b));
+    Ok(matched.into_iter().cloned().collect())
+}
+
+/// look up one issue by full or partial id.
+pub fn get_issue(paths: &RepoPaths, config: &Config, id: &str) -> Result<Issue> {
+    let mut issues = load_issues(paths, config)?;
+    let full_id = resolve_issue_id(id, &issues)?;
+    issues
+        .remove(&full_id)
+        .ok_or(BrdError::IssueNotFound(full_id))
+}
+
+/// ready issues, best first under the configured `next_strategy`.
+pub fn ready_issues(paths: &RepoPaths, config: &Config) -> Result<Vec<Issue>> {
+    let issues = load_issues(paths, config)?;
+    let ready = ranked_ready(&issues, config, OffsetDateTime::now_utc());
+    Ok(ready.into_iter().cloned().collect())
+}
+
+/// refuse to mark `issue` done when `brd done` would without `--force`: a
+/// design issue without results, a meta issue with open children, or
+/// unchecked acceptance items under `require_acceptance_on_done`.
+pub(crate) fn check_done(
+    issue: &Issue,
+    issues: &HashMap<String, Issue>,
+    config: &Config,
+    has_results: bool,
+) -> Result<()> {
+    match issue.issue_type() {
+        Some(IssueType::Design) if !has_results => {
+            return Err(BrdError::InvalidInput(
+                "design issues require --result <issue-id> to specify resulting issues\n\
+                 use --force to close without results"
+                    .to_string(),
+            ));
+        }
+        Some(IssueType::Meta) => {
+            let derived = compute_derived(issue, issues);
+            if !derived.open_deps.is_empty() {
+                let total = issue.deps().len();
+                let done = total - derived.open_deps.len();
+                return Err(BrdError::IssueBlocked(format!(
+                    "meta issue has open children ({}/{})\n\
+                     use --force to close anyway",
+                    done, total
+                )));
+            }
+        }
+        _ => {}
+    }
+
+    let unchecked = issue.unchecked_acceptance();
+    if !unchecked.is_empty() && config.require_acceptance_on_done {
+        let items: Vec<String> = unchecked
+            .iter()
+            .map(|ac| format!("  - [ ] {}", ac))
+            .collect();
+        return Err(BrdError::IssueBlocked(format!(
+            "{} acceptance item(s) unchecked:\n{}\nuse --force to close anyway",
+            unchecked.len(),
+            items.join("\n")
+        )));
+    }
+    Ok(())
+}
+
+/// apply `status` to every issue in `ids` the way start/done/skip/reopen do,
+/// with `agent_id` as the owner for `doing`. ids are resolved and, unless
+/// `force`, checked before anything is written: `done` the way `brd done`
+/// checks, `doing` refusing issues another agent is working on the way
+/// `brd start` does. a bad id leaves every issue untouched. returns the
+/// updated issues in the order given, without duplicates.
+pub fn set_status(
+    paths: &RepoPaths,
+    config: &Config,
+    ids: &[String],
+    status: Status,
+    agent_id: &str,
+    force: bool,
+) -> Result<Vec<Issue>> {
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    let mut issues = load_issues(paths, config)?;
+
+    let mut full_ids: Vec<String> = Vec::new();
+    for id in ids {
+        let full_id = resolve_issue_id(id, &issues)?;
+        if !full_ids.contains(&full_id) {
+            full_ids.push(full_id);
+        }
+    }
+    if !force {
+        for full_id in &full_ids {
+            let issue = &issues[full_id];
+            match status {
+                Status::Done => check_done(issue, &issues, config, false)?,
+                Status::Doing if issue.status() == Status::Doing => {
+                    let owner = issue.frontmatter.owner.as_deref().unwrap_or("unknown");
+                    if owner != agent_id {
+                        return Err(BrdError::ClaimConflict(full_id.clone(), owner.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut updated = Vec::with_capacity(full_ids.len());
+    for full_id in full_ids {
+        let mut issue = issues
+            .remove(&full_id)
+            .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
+        apply_status(&mut issue, status, agent_id);
+        issue.save_in(&paths.issues_dir(config), config.filename_scheme)?;
+        updated.push(issue);
+    }
+
+    Ok(updated)
+}
+
+fn apply_status(issue: &mut Issue, status: Status, agent_id: &str) {
+    issue.frontmatter.status = status;
+    match status {
+        Status::Doing => {
+            issue.frontmatter.owner = Some(agent_id.to_string());
+            issue.mark_started();
+        }
+        Status::Done | Status::Skip => {
+            issue.frontmatter.owner = None;
+            issue.mark_completed();
+        }
+        Status::Open => {
+            issue.frontmatter.owner = None;
+            issue.frontmatter.started_at = None;
+            issue.frontmatter.completed_at = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestRepo;
+
+    #[test]
+    fn test_list_issues_applies_filter() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa")
+            .priority(Priority::P0)
+            .tags(&["ui"])
+            .create();
+        repo.issue("brd-bbbb")
+            .deps(&["brd-aaaa"])
+            .tags(&["ui"])
+            .create();
+        repo.issue("brd-cccc").status(Status::Done).create();
+
+        let all = list_issues(&repo.paths, &repo.config, &IssueFilter::default()).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let filter = IssueFilter {
+            tags: vec!["ui".to_string()],
+            ready: true,
+            ..Default::default()
+        };
+        let ids: Vec<_> = list_issues(&repo.paths, &repo.config, &filter)
+            .unwrap()
+            .iter()
+            .map(|i| i.id().to_string())
+            .collect();
+        assert_eq!(ids, vec!["brd-aaaa"]);
+
+        let filter = IssueFilter {
+            blocked: true,
+            ..Default::default()
+        };
+        let blocked = list_issues(&repo.paths, &repo.config, &filter).unwrap();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].id(), "brd-bbbb");
+    }
+
+    #[test]
+    fn test_get_issue_resolves_partial_id() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        assert_eq!(
+            get_issue(&repo.paths, &repo.config, "aaaa").unwrap().id(),
+            "brd-aaaa"
+        );
+        let err = get_issue(&repo.paths, &repo.config, "zzzz").unwrap_err();
+        assert!(matches!(err, BrdError::IssueNotFound(_)));
+    }
+
+    #[test]
+    fn test_set_status_returns_updated_issues() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        let ids = vec!["aaaa".to_string(), "brd-aaaa".to_string()];
+        let updated = set_status(
+            &repo.paths,
+            &repo.config,
+            &ids,
+            Status::Doing,
+            "agent-x",
+            false,
+        )
+        .unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(updated[0].frontmatter.owner.as_deref(), Some("agent-x"));
+
+        let stored = get_issue(&repo.paths, &repo.config, "aaaa").unwrap();
+        assert_eq!(stored.status(), Status::Doing);
+        assert!(stored.frontmatter.started_at.is_some());
+    }
+
+    #[test]
+    fn test_load_issues_with_failures_reports_broken_files() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        let broken = repo.paths.issues_dir(&repo.config).join("brd-bbbb.md");
+        std::fs::write(&broken, "---\nnot: [closed\n---\n").unwrap();
+
+        let (issues, failures) = load_issues_with_failures(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, broken);
+        assert_eq!(load_issues(&repo.paths, &repo.config).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_set_status_doing_refuses_claimed_issue() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        repo.issue("brd-bbbb")
+            .status(Status::Doing)
+            .owner("agent-y")
+            .create();
+
+        let ids = vec!["aaaa".to_string(), "bbbb".to_string()];
+        let err = set_status(
+            &repo.paths,
+            &repo.config,
+            &ids,
+            Status::Doing,
+            "agent-x",
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, BrdError::ClaimConflict(ref id, ref owner)
+            if id == "brd-bbbb" && owner == "agent-y"));
+        let issues = load_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].status(), Status::Open);
+        assert_eq!(
+            issues["brd-bbbb"].frontmatter.owner.as_deref(),
+            Some("agent-y")
+        );
+
+        // the owner itself may re-run it, and force reassigns
+        set_status(
+            &repo.paths,
+            &repo.config,
+            &ids[1..],
+            Status::Doing,
+            "agent-y",
+            false,
+        )
+        .unwrap();
+        set_status(
+            &repo.paths,
+            &repo.config,
+            &ids,
+            Status::Doing,
+            "agent-x",
+            true,
+        )
+        .unwrap();
+        let issues = load_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(
+            issues["brd-bbbb"].frontmatter.owner.as_deref(),
+            Some("agent-x")
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! crash-safe file writes.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::Result;
+
+/// write `contents` to `path` so readers see either the old file or the new
+/// one, never a truncated mix: the data goes to a temp file in the same
+/// directory, is synced, and is renamed over `path`. an existing file's
+/// permissions carry over.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    let tmp = temp_path(path);
+    let result = (|| -> Result<()> {
+        let mut file = File::create(&tmp)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        if let Ok(meta) = fs::metadata(path) {
+            fs::set_permissions(&tmp, meta.permissions())?;
+        }
+        fs::rename(&tmp, path)?;
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// `.<name>.<pid>.tmp` next to `path`, so the rename never crosses filesystems.
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.{}.tmp", name, std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic_replaces_contents_without_leftovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("brd-aaaa.md");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+
+        // only the target remains; the temp file was renamed away
+        let names: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(names, vec!["brd-aaaa.md"]);
+    }
+
+    #[test]
+    fn test_write_atomic_failure_cleans_up_temp_file() {
+        let dir = tempfile::tempdir().unwrap();
+        // a non-empty directory in the way makes the rename fail
+        let blocked = dir.path().join("sub");
+        fs::create_dir_all(blocked.join("x")).unwrap();
+
+        assert!(write_atomic(&blocked, "new").is_err());
+        assert!(!temp_path(&blocked).exists());
+        assert!(blocked.join("x").is_dir());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomic_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        write_atomic(&path, "{}").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        let before = fs::metadata(&path).unwrap().modified().unwrap();
+
+        write_atomic(&path, "{\"a\": 1}").unwrap();
+        let meta = fs::metadata(&path).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+        assert!(meta.modified().unwrap() >= before);
+    }
+}
@@ -1,6 +1,19 @@
 //! CLI command definitions and wiring.
 
-use clap::{Args, Parser, Subcommand};
+use std::io::IsTerminal;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// shells `brd completions` can generate scripts for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Elvish,
+    Fish,
+    Nushell,
+    Powershell,
+    Zsh,
+}
 
 /// Parse boolean from env var, accepting "1", "true", "yes" as truthy.
 fn parse_bool_env(s: &str) -> Result<bool, String> {
@@ -36,6 +49,39 @@ pub struct Cli {
     pub command: Command,
 }
 
+impl Cli {
+    /// whether the command was given `--ndjson`, which implies `--json`.
+    pub fn ndjson(&self) -> bool {
+        match &self.command {
+            Command::Ready { ndjson, .. } | Command::Recent { ndjson, .. } => *ndjson,
+            Command::Ls(args) => args.ndjson,
+            _ => false,
+        }
+    }
+}
+
+/// whether colored output should be emitted.
+///
+/// color is disabled by `--no-color`, a non-empty `NO_COLOR` env var, or stdout not being a tty.
+pub fn should_color(cli: &Cli) -> bool {
+    color_enabled(
+        cli.no_color,
+        std::env::var("NO_COLOR").ok().as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+fn color_enabled(no_color_flag: bool, no_color_env: Option<&str>, is_tty: bool) -> bool {
+    if no_color_flag {
+        return false;
+    }
+    // per no-color.org, any non-empty value disables color
+    if no_color_env.is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    is_tty
+}
+
 #[derive(Subcommand)]
 pub enum Command {
     /// initialize braid in the current repository
@@ -46,43 +92,27 @@ pub enum Command {
     Add(AddArgs),
 
     /// list issues
-    Ls {
-        /// filter by status
-        #[arg(long)]
-        status: Option<String>,
-
-        /// filter by priority
-        #[arg(long, short)]
-        priority: Option<String>,
-
-        /// show only ready issues
-        #[arg(long)]
-        ready: bool,
-
-        /// show only blocked issues
-        #[arg(long)]
-        blocked: bool,
-
-        /// show only issues scheduled for the future
-        #[arg(long)]
-        scheduled: bool,
-
-        /// filter by tag (can be repeated)
-        #[arg(long)]
-        tag: Vec<String>,
-
-        /// show all issues (no limit on done issues)
-        #[arg(long)]
-        all: bool,
-    },
+    Ls(LsArgs),
 
     /// show details of an issue
     Show {
-        /// issue ID (full or partial)
-        id: String,
+        /// issue IDs (full or partial; pick interactively if omitted)
+        ids: Vec<String>,
         /// include full content of dependencies and dependents
         #[arg(long)]
         context: bool,
+        /// list everything that depends on this issue, directly or transitively
+        #[arg(long, conflicts_with = "context")]
+        dependents: bool,
+        /// list the commits linked to this issue with `brd link`
+        #[arg(long, conflicts_with_all = ["context", "dependents"])]
+        log: bool,
+        /// page through issues interactively (n/p for next/previous, q to quit)
+        #[arg(long, conflicts_with_all = ["context", "dependents", "log"])]
+        pager: bool,
+        /// write output to this file instead of stdout (no colors)
+        #[arg(long, short = 'o', value_name = "PATH", conflicts_with = "pager")]
+        output: Option<std::path::PathBuf>,
     },
 
     /// open an issue in $EDITOR
@@ -108,12 +138,73 @@ pub enum Command {
     },
 
     /// list ready issues
-    Ready,
+    Ready {
+        /// explain which issue `brd start` would pick and why, with the runner-up
+        #[arg(long)]
+        explain: bool,
+
+        /// print JSON as one compact object per line (implies --json)
+        #[arg(long, conflicts_with = "explain")]
+        ndjson: bool,
+
+        /// write output to this file instead of stdout (no colors)
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// list recently changed issues, newest first
+    Recent {
+        /// how far back to look (e.g. 3h, 1d, 2w)
+        #[arg(long, default_value = "1d")]
+        since: String,
+        /// print JSON as one compact object per line (implies --json)
+        #[arg(long)]
+        ndjson: bool,
+        /// write output to this file instead of stdout (no colors)
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// raise the priority of open issues left untouched too long
+    Escalate {
+        /// days an open issue may wait before escalating (default: escalate_after_days)
+        #[arg(long, value_name = "DAYS")]
+        after: Option<u32>,
+        /// show what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// list open issues idle for too long, optionally bumping their priority
+    Groom {
+        /// days without activity before an open issue counts as stale
+        #[arg(long, value_name = "N")]
+        stale_days: u32,
+        /// raise each stale issue's priority one level (capped at P0)
+        #[arg(long)]
+        bump: bool,
+    },
+
+    /// field-level issue changes: replayed from git history, or live with --follow
+    Log {
+        /// keep watching the issues directory and print changes as they happen
+        #[arg(long, short)]
+        follow: bool,
+        /// how many commits of history to replay
+        #[arg(long, short = 'n', value_name = "N", default_value_t = 20)]
+        limit: usize,
+    },
+
+    /// reports over issue history
+    Report {
+        #[command(subcommand)]
+        action: ReportAction,
+    },
 
     /// show repo status summary
     Status,
 
-    /// add or remove dependencies
+    /// list, add, or remove dependencies
     Dep {
         #[command(subcommand)]
         action: DepAction,
@@ -142,22 +233,7 @@ pub enum Command {
     },
 
     /// mark an issue as done
-    Done {
-        /// issue ID (defaults to current "doing" issue if omitted)
-        id: Option<String>,
-
-        /// force completion even if not claimed by you, or close design issue without results
-        #[arg(long)]
-        force: bool,
-
-        /// issue IDs created as a result of this design issue (required for design issues)
-        #[arg(long, short)]
-        result: Vec<String>,
-
-        /// skip commit/push even when auto_push is enabled
-        #[arg(long)]
-        no_push: bool,
-    },
+    Done(DoneArgs),
 
     /// mark an issue as skipped (won't do)
     Skip {
@@ -171,6 +247,55 @@ pub enum Command {
         id: String,
     },
 
+    /// record a git commit on an issue
+    Link {
+        /// issue ID
+        #[arg(required_unless_present_any = ["from_message", "install_hook"])]
+        id: Option<String>,
+
+        /// commit to link (default: HEAD)
+        #[arg(long, value_name = "REV")]
+        commit: Option<String>,
+
+        /// link every issue whose ID appears in the commit message
+        #[arg(long, conflicts_with = "id")]
+        from_message: bool,
+
+        /// install a post-commit hook that runs `brd link --from-message`
+        #[arg(long, conflicts_with_all = ["id", "commit", "from_message"])]
+        install_hook: bool,
+    },
+
+    /// attach a file in the repo (design doc, screenshot) to an issue
+    Attach {
+        /// issue ID
+        id: String,
+
+        /// path to the file, relative to the current directory
+        path: String,
+    },
+
+    /// set the same status on several issues at once
+    #[command(name = "set-status")]
+    SetStatus {
+        /// status to apply (open/todo, doing, done, skip)
+        status: String,
+
+        /// issue IDs
+        #[arg(required = true)]
+        ids: Vec<String>,
+
+        /// with done, close design and meta issues and unchecked acceptance
+        /// items the way `brd done --force` does; with doing, take over
+        /// issues another agent is working on
+        #[arg(long)]
+        force: bool,
+
+        /// skip commit/push even when auto_push is enabled
+        #[arg(long)]
+        no_push: bool,
+    },
+
     /// print file path of an issue
     Path {
         /// issue ID (full or partial)
@@ -185,6 +310,16 @@ pub enum Command {
         /// force deletion even if issue is in progress
         #[arg(long)]
         force: bool,
+
+        /// delete the issue file instead of moving it to the archive
+        #[arg(long)]
+        purge: bool,
+    },
+
+    /// restore an issue archived by `brd rm`
+    Restore {
+        /// archived issue ID (full or partial)
+        id: String,
     },
 
     /// manage agent worktrees
@@ -198,6 +333,18 @@ pub enum Command {
         /// force interactive mode even without a TTY
         #[arg(long)]
         force: bool,
+
+        /// open with the issue list filtered by this query (id or title)
+        #[arg(long, value_name = "QUERY")]
+        filter: Option<String>,
+
+        /// open with the issue list filtered by status (can be repeated)
+        #[arg(long)]
+        status: Vec<String>,
+
+        /// open in this view (dashboard, issues, agents)
+        #[arg(long)]
+        view: Option<String>,
     },
 
     /// migrate issues to current schema version
@@ -207,17 +354,33 @@ pub enum Command {
         dry_run: bool,
     },
 
+    /// show the resolved agent identity used for claims
+    Whoami,
+
+    /// list issue templates in .braid/templates
+    Templates,
+
+    /// print the brd version, supported schema, and the repo's schema
+    Version,
+
+    /// list error codes and exit statuses for scripting against `--json` failures
+    Errors,
+
     /// validate repository state
-    Doctor,
+    Doctor {
+        /// refresh an outdated or mismatched braid block in the agent instructions file
+        #[arg(long)]
+        fix: bool,
+    },
 
     /// generate shell completions
     Completions {
         /// shell to generate completions for
-        shell: clap_complete::Shell,
+        shell: CompletionShell,
     },
 
-    /// search issues (prints instructions for using grep/rg)
-    Search,
+    /// search issue titles, tags, and bodies (without a query, prints grep/rg hints)
+    Search(SearchArgs),
 
     /// commit .braid changes
     Commit {
@@ -226,11 +389,19 @@ pub enum Command {
         message: Option<String>,
     },
 
-    /// sync issues with the sync branch (sync branch mode only)
+    /// sync issues with the remote (sync branch, or origin/main when issues live with code)
     Sync {
         /// push and set upstream if needed
-        #[arg(long)]
+        #[arg(long, conflicts_with = "pull_only")]
         push: bool,
+
+        /// only fetch and rebase, don't commit or push
+        #[arg(long, conflicts_with = "push_only")]
+        pull_only: bool,
+
+        /// only commit and push, don't fetch or rebase
+        #[arg(long)]
+        push_only: bool,
     },
 
     /// view or change braid configuration
@@ -249,6 +420,24 @@ fn parse_on_off(s: &str) -> Result<bool, String> {
     }
 }
 
+#[derive(Subcommand)]
+pub enum ReportAction {
+    /// lead time (created → done) and cycle time (started → done) of completed issues
+    CycleTime {
+        /// only include issues completed within this window (e.g. 7d, 4w)
+        #[arg(long, default_value = "30d")]
+        since: String,
+
+        /// group results by "priority" or "type"
+        #[arg(long)]
+        group_by: Option<String>,
+
+        /// write output to this file instead of stdout (no colors)
+        #[arg(long, short = 'o', value_name = "PATH")]
+        output: Option<std::path::PathBuf>,
+    },
+}
+
 #[derive(Subcommand)]
 pub enum ConfigAction {
     /// set or clear the issues branch (for shared worktree storage)
@@ -260,24 +449,17 @@ pub enum ConfigAction {
         #[arg(long)]
         clear: bool,
 
+        /// also link .braid/issues to the issues worktree so editors see the issues
+        #[arg(long, conflicts_with = "clear")]
+        symlink: bool,
+
         /// skip confirmation prompt
         #[arg(short = 'y', long)]
         yes: bool,
     },
 
     /// set or clear the external issues repository
-    ExternalRepo {
-        /// path to external issues repo (omit with --clear to disable)
-        path: Option<String>,
-
-        /// clear the external repo setting
-        #[arg(long)]
-        clear: bool,
-
-        /// skip confirmation prompt
-        #[arg(short = 'y', long)]
-        yes: bool,
-    },
+    ExternalRepo(ExternalRepoArgs),
 
     /// enable or disable auto-sync (pull on start, push on done)
     AutoSync {
@@ -297,6 +479,28 @@ pub enum AgentAction {
         /// base branch to create worktree from (default: current branch)
         #[arg(long)]
         base: Option<String>,
+
+        /// fetch origin and base the worktree on its default branch
+        /// (falls back to the current branch without a usable origin)
+        #[arg(long, conflicts_with = "base")]
+        from_origin: bool,
+    },
+
+    /// list agent worktrees: agent id, branch, clean or dirty, behind main
+    List,
+
+    /// remove an agent worktree, and its branch if fully merged
+    Remove {
+        /// agent name (as given to `brd agent init`)
+        name: String,
+
+        /// remove even with uncommitted changes
+        #[arg(long)]
+        force: bool,
+
+        /// delete the branch even if it isn't fully merged
+        #[arg(long)]
+        delete_branch: bool,
     },
 
     /// create feature branch for PR workflow
@@ -309,13 +513,20 @@ pub enum AgentAction {
     Pr,
 
     /// merge changes to main (rebase + fast-forward)
-    Merge,
+    Merge {
+        /// stash uncommitted changes during the merge, restore after
+        #[arg(long)]
+        stash: bool,
+    },
 
     /// inject/update the braid instructions block in AGENTS.md
     Inject {
         /// target file path (default: AGENTS.md)
         #[arg(long, short)]
         file: Option<String>,
+        /// manage the named block with content from .braid/agents/<name>.md
+        #[arg(long)]
+        name: Option<String>,
     },
 
     /// print the AGENTS.md instructions block to stdout
@@ -386,7 +597,12 @@ pub enum AgentAction {
     /// terminate a running agent
     Kill {
         /// session ID (e.g., agent-1)
-        session: String,
+        #[arg(required_unless_present = "all")]
+        session: Option<String>,
+
+        /// kill every running agent
+        #[arg(long, conflicts_with = "session")]
+        all: bool,
 
         /// use SIGKILL instead of SIGTERM
         #[arg(long)]
@@ -399,6 +615,10 @@ pub enum AgentAction {
         #[arg(long)]
         all: bool,
 
+        /// only remove sessions started longer ago than this (e.g. 3d, 2w)
+        #[arg(long, value_name = "DURATION")]
+        older_than: Option<String>,
+
         /// skip confirmation prompt
         #[arg(long, short)]
         force: bool,
@@ -409,17 +629,41 @@ pub enum AgentAction {
 pub enum DepAction {
     /// add a dependency (blocked depends on blocker)
     Add {
-        /// the issue that will be blocked
-        blocked: String,
-        /// the issue that blocks it
-        blocker: String,
-    },
-    /// remove a dependency
+        /// the issue that will be blocked (pick interactively if omitted)
+        blocked: Option<String>,
+        /// the issue that blocks it (pick interactively if omitted)
+        blocker: Option<String>,
+        /// add a soft (related) link that shows up in `brd show` but never blocks
+        #[arg(long, visible_alias = "related")]
+        soft: bool,
+    },
+    /// remove a dependency or related link
     Rm {
         /// the issue to remove dependency from
         blocked: String,
+        /// the blocker (or related issue) to remove
+        #[arg(required_unless_present = "all")]
+        blocker: Option<String>,
+        /// remove all of the issue's dependencies and related links
+        #[arg(long, conflicts_with = "blocker")]
+        all: bool,
+        /// skip confirmation prompt (with --all)
+        #[arg(short = 'y', long, requires = "all")]
+        yes: bool,
+    },
+    /// list an issue's direct dependencies and related links with their status
+    Ls {
+        /// the issue to list dependencies of
+        id: String,
+    },
+    /// replace one blocker with another in a single step
+    Swap {
+        /// the blocked issue
+        blocked: String,
         /// the blocker to remove
-        blocker: String,
+        old: String,
+        /// the blocker to add in its place
+        new: String,
     },
 }
 
@@ -435,15 +679,207 @@ pub struct InitArgs {
     pub non_interactive: bool,
 }
 
+/// arguments for `brd config external-repo`.
+#[derive(Args, Default)]
+pub struct ExternalRepoArgs {
+    /// path to external issues repo (omit with --clear to disable)
+    pub path: Option<String>,
+
+    /// clear the external repo setting
+    #[arg(long)]
+    pub clear: bool,
+
+    /// move local issues into the external repo and commit them there
+    #[arg(long, conflicts_with = "clear")]
+    pub migrate: bool,
+
+    /// with --migrate, overwrite issues that already exist in the external repo
+    #[arg(long, requires = "migrate")]
+    pub force: bool,
+
+    /// allow an external repo with the same id_prefix as this one
+    #[arg(long, conflicts_with = "clear")]
+    pub allow_same_prefix: bool,
+
+    /// skip confirmation prompt
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
+/// arguments for the done command.
+#[derive(Args, Default)]
+pub struct DoneArgs {
+    /// issue ID (defaults to current "doing" issue if omitted)
+    pub id: Option<String>,
+
+    /// force completion even if not claimed by you, or close design issue without results
+    /// or with unchecked acceptance items
+    #[arg(long)]
+    pub force: bool,
+
+    /// issue IDs created as a result of this design issue (required for design issues)
+    #[arg(long, short)]
+    pub result: Vec<String>,
+
+    /// skip fetch/rebase, trust local state
+    #[arg(long)]
+    pub no_sync: bool,
+
+    /// skip commit/push even when auto_push is enabled
+    #[arg(long)]
+    pub no_push: bool,
+
+    /// stash uncommitted changes before sync, restore after
+    #[arg(long)]
+    pub stash: bool,
+}
+
+/// parts of an issue `brd search` can look in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchField {
+    Title,
+    Tags,
+    Acceptance,
+    Body,
+}
+
+/// arguments for the search command.
+#[derive(Args, Default)]
+pub struct SearchArgs {
+    /// text to look for (case-insensitive)
+    pub query: Option<String>,
+
+    /// only search these fields (can be repeated; default: all)
+    #[arg(long, value_enum)]
+    pub field: Vec<SearchField>,
+
+    /// lines of context around body matches
+    #[arg(long, short = 'C', value_name = "N", default_value_t = 2)]
+    pub context: usize,
+
+    /// rebuild the cached search index from scratch
+    #[arg(long)]
+    pub reindex: bool,
+}
+
+/// arguments for the ls command.
+#[derive(Args, Default)]
+pub struct LsArgs {
+    /// filter by status
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// filter by priority
+    #[arg(long, short)]
+    pub priority: Option<String>,
+
+    /// filter by owner
+    #[arg(long)]
+    pub owner: Option<String>,
+
+    /// show only issues owned by you (the resolved agent ID)
+    #[arg(long, conflicts_with = "owner")]
+    pub mine: bool,
+
+    /// show only ready issues
+    #[arg(long)]
+    pub ready: bool,
+
+    /// show only blocked issues
+    #[arg(long)]
+    pub blocked: bool,
+
+    /// show only issues blocked by ID, directly or transitively
+    #[arg(long, value_name = "ID")]
+    pub blocked_by: Option<String>,
+
+    /// show only issues scheduled for the future
+    #[arg(long)]
+    pub scheduled: bool,
+
+    /// filter by tag (can be repeated)
+    #[arg(long)]
+    pub tag: Vec<String>,
+
+    /// show all issues (no limit on done issues)
+    #[arg(long)]
+    pub all: bool,
+
+    /// show at most N issues (disables the default open/done limits)
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
+
+    /// skip the first N issues (disables the default open/done limits)
+    #[arg(long, value_name = "N")]
+    pub offset: Option<usize>,
+
+    /// re-render the list every few seconds until interrupted
+    #[arg(long)]
+    pub watch: bool,
+
+    /// only issues created at or after this date (YYYY-MM-DD, or a duration like 7d)
+    #[arg(long, value_name = "DATE")]
+    pub created_after: Option<String>,
+
+    /// only issues created before this date (YYYY-MM-DD, or a duration like 7d)
+    #[arg(long, value_name = "DATE")]
+    pub created_before: Option<String>,
+
+    /// only issues last started/completed/created at or after this date
+    #[arg(long, value_name = "DATE")]
+    pub updated_after: Option<String>,
+
+    /// only issues last started/completed/created before this date
+    #[arg(long, value_name = "DATE")]
+    pub updated_before: Option<String>,
+    /// write output to this file instead of stdout (no colors)
+    #[arg(long, short = 'o', value_name = "PATH")]
+    pub output: Option<std::path::PathBuf>,
+
+    /// list archived (removed) issues instead of active ones
+    #[arg(long)]
+    pub archived: bool,
+
+    /// comma-separated columns to show, in order
+    /// (id, priority, status, owner, title, tags, updated, age, type, deps)
+    #[arg(long, value_name = "COLUMNS")]
+    pub columns: Option<String>,
+
+    /// end each row with resolved/total deps, e.g. [2/3 done] (! marks missing deps)
+    #[arg(long)]
+    pub deps: bool,
+
+    /// list every matching issue (like --all) in sections by status, priority,
+    /// owner, tag, or milestone (the meta issues they depend on), with a count
+    /// per section; with --json, an object keyed by section
+    #[arg(long, value_name = "FIELD", conflicts_with_all = ["limit", "offset", "ndjson"])]
+    pub group_by: Option<String>,
+
+    /// print JSON as one compact object per line (implies --json; paging
+    /// emits only the items)
+    #[arg(long)]
+    pub ndjson: bool,
+}
+
 /// arguments for the add command.
-#[derive(Args)]
+#[derive(Args, Clone, Debug)]
 pub struct AddArgs {
-    /// issue title
-    pub title: String,
-
-    /// priority (P0-P3, default P2)
-    #[arg(long, short, default_value = "P2")]
-    pub priority: String,
+    /// issue title (prompted for with --interactive), or `-` with --title to read the body from stdin
+    #[arg(required_unless_present_any = ["interactive", "title_flag"])]
+    pub title: Option<String>,
+
+    /// issue title, for when the positional argument is `-`
+    #[arg(
+        long = "title",
+        id = "title_flag",
+        value_name = "TITLE",
+        conflicts_with = "interactive"
+    )]
+    pub title_flag: Option<String>,
+
+    /// priority (P0-P3, default P2 or the template's)
+    #[arg(long, short)]
+    pub priority: Option<String>,
 
     /// issue type (design, meta)
     #[arg(long, short = 't')]
@@ -468,4 +904,55 @@ pub struct AddArgs {
     /// schedule issue for future date (YYYY-MM-DD, +Nd, +Nw, +Nmo, or "tomorrow")
     #[arg(long)]
     pub scheduled_for: Option<String>,
+
+    /// skip fetch/rebase, trust local state
+    #[arg(long)]
+    pub no_sync: bool,
+
+    /// skip commit/push even when auto_push is enabled
+    #[arg(long)]
+    pub no_push: bool,
+
+    /// stash uncommitted changes before sync, restore after
+    #[arg(long)]
+    pub stash: bool,
+
+    /// open the new issue in $EDITOR after creating it
+    #[arg(long)]
+    pub edit: bool,
+
+    /// seed the issue from .braid/templates/<name>.md (flags override its defaults)
+    #[arg(long)]
+    pub template: Option<String>,
+
+    /// prompt for title, priority, tags, deps, and body (terminal only)
+    #[arg(long, short)]
+    pub interactive: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_enabled_on_tty_by_default() {
+        assert!(color_enabled(false, None, true));
+    }
+
+    #[test]
+    fn test_color_disabled_by_flag() {
+        assert!(!color_enabled(true, None, true));
+    }
+
+    #[test]
+    fn test_color_disabled_by_no_color_env() {
+        assert!(!color_enabled(false, Some("1"), true));
+        // empty value is ignored
+        assert!(color_enabled(false, Some(""), true));
+    }
+
+    #[test]
+    fn test_color_disabled_when_not_tty() {
+        assert!(!color_enabled(false, None, false));
+    }
 }
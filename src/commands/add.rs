@@ -1,19 +1,225 @@
 //! brd add command.
 
+use std::collections::HashMap;
+use std::io::{BufRead, Read, Write};
+
 use crate::cli::{AddArgs, Cli};
 use crate::config::Config;
 use crate::date::parse_scheduled_date;
-use crate::error::Result;
+use crate::error::{BrdError, Result};
+use crate::is_interactive;
 use crate::issue::{Issue, IssueType, Priority};
 use crate::lock::LockGuard;
 use crate::repo::RepoPaths;
+use crate::template::Template;
 
+use super::edit::{editor_from_env, run_editor};
+use super::start::{auto_pull, auto_push};
 use super::{generate_issue_id, issue_to_json, load_all_issues, resolve_issue_id};
 
+/// read one trimmed line of input. a closed stdin aborts instead of looping.
+fn ask(input: &mut impl BufRead, out: &mut impl Write, prompt: &str) -> Result<String> {
+    write!(out, "{}", prompt)?;
+    out.flush()?;
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Err(BrdError::Other("input closed".to_string()));
+    }
+    Ok(line.trim().to_string())
+}
+
+/// split a comma/space separated answer into its non-empty parts.
+fn split_list(answer: &str) -> impl Iterator<Item = String> + '_ {
+    answer
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// ask for the fields of a new issue, merging the answers into a copy of
+/// `args`. flags already given act as defaults, then the template's;
+/// invalid answers are re-asked.
+fn prompt_add_args(
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+    args: &AddArgs,
+    template: Option<&Template>,
+    issues: &HashMap<String, Issue>,
+) -> Result<AddArgs> {
+    let mut args = args.clone();
+
+    while args.title.as_deref().is_none_or(str::is_empty) {
+        let title = ask(input, out, "title: ")?;
+        if !title.is_empty() {
+            args.title = Some(title);
+        }
+    }
+
+    let default_priority = args
+        .priority
+        .clone()
+        .or_else(|| {
+            template
+                .and_then(|t| t.frontmatter.priority)
+                .map(|p| p.to_string())
+        })
+        .unwrap_or_else(|| "P2".to_string());
+    loop {
+        let answer = ask(
+            input,
+            out,
+            &format!("priority (0 highest - 3 lowest) [{}]: ", default_priority),
+        )?;
+        if answer.is_empty() {
+            args.priority = Some(default_priority.clone());
+            break;
+        }
+        let answer = if answer.len() == 1 {
+            format!("P{}", answer)
+        } else {
+            answer
+        };
+        match answer.parse::<Priority>() {
+            Ok(p) => {
+                args.priority = Some(p.to_string());
+                break;
+            }
+            Err(e) => writeln!(out, "  {}", e)?,
+        }
+    }
+
+    let tags = ask(input, out, "tags (comma separated, optional): ")?;
+    for tag in split_list(&tags) {
+        if !args.tag.contains(&tag) {
+            args.tag.push(tag);
+        }
+    }
+
+    loop {
+        let answer = ask(input, out, "blocked by (issue ids, optional): ")?;
+        let deps: Vec<String> = split_list(&answer).collect();
+        match deps
+            .iter()
+            .map(|d| resolve_issue_id(d, issues))
+            .collect::<Result<Vec<_>>>()
+        {
+            Ok(_) => {
+                args.dep.extend(deps);
+                break;
+            }
+            Err(e) => writeln!(out, "  {}", e)?,
+        }
+    }
+
+    if args.body.is_none() && !args.edit {
+        let answer = ask(input, out, "write a body in $EDITOR? [y/N]: ")?;
+        args.edit = matches!(answer.to_lowercase().as_str(), "y" | "yes");
+    }
+
+    Ok(args)
+}
+
+/// fold `--title` into `args` and read the body from `input` when the title
+/// argument is `-`.
+fn apply_stdin_body(args: &AddArgs, input: &mut impl Read) -> Result<AddArgs> {
+    let mut args = args.clone();
+    let dash = args.title.as_deref() == Some("-");
+    match args.title_flag.take() {
+        Some(_) if args.title.is_some() && !dash => {
+            return Err(BrdError::InvalidInput(
+                "issue title given twice; pass it either as an argument or with --title"
+                    .to_string(),
+            ));
+        }
+        Some(title) => args.title = Some(title),
+        None if dash => {
+            return Err(BrdError::InvalidInput(
+                "`-` reads the body from stdin; pass the title with --title".to_string(),
+            ));
+        }
+        None => {}
+    }
+    if dash && args.body.is_some() {
+        return Err(BrdError::InvalidInput(
+            "--body and `-` both set the body; use one of them".to_string(),
+        ));
+    }
+
+    if dash {
+        let mut body = String::new();
+        input.read_to_string(&mut body)?;
+        let body = body.trim_end();
+        if !body.is_empty() {
+            args.body = Some(body.to_string());
+        }
+    }
+    Ok(args)
+}
+
 pub fn cmd_add(cli: &Cli, paths: &RepoPaths, args: &AddArgs) -> Result<()> {
+    let template = args
+        .template
+        .as_deref()
+        .map(|name| Template::load(&paths.templates_dir(), name))
+        .transpose()?;
+
+    let prompted;
+    let args = if args.interactive {
+        if !is_interactive() {
+            return Err(BrdError::NotInteractive(
+                "brd add --interactive requires an interactive terminal; pass the title and flags instead"
+                    .to_string(),
+            ));
+        }
+        let config = Config::load(&paths.config_path())?;
+        let issues = load_all_issues(paths, &config)?;
+        let stdin = std::io::stdin();
+        prompted = prompt_add_args(
+            &mut stdin.lock(),
+            &mut std::io::stdout(),
+            args,
+            template.as_ref(),
+            &issues,
+        )?;
+        &prompted
+    } else {
+        args
+    };
+
+    // check up front so we don't create an issue we then can't open
+    let editor = if args.edit {
+        if !is_interactive() {
+            return Err(BrdError::NotInteractive(
+                "brd add --edit requires an interactive terminal".to_string(),
+            ));
+        }
+        Some(editor_from_env()?)
+    } else {
+        None
+    };
+
+    let args = &apply_stdin_body(args, &mut std::io::stdin().lock())?;
+    let title = args
+        .title
+        .clone()
+        .ok_or_else(|| BrdError::InvalidInput("missing issue title".to_string()))?;
+
     let config = Config::load(&paths.config_path())?;
-    let priority: Priority = args.priority.parse()?;
-    let issue_type: Option<IssueType> = args.r#type.as_deref().map(|s| s.parse()).transpose()?;
+    let defaults = template.as_ref().map(|t| &t.frontmatter);
+
+    // flags win over template defaults
+    let priority: Priority = match &args.priority {
+        Some(p) => p.parse()?,
+        None => defaults.and_then(|d| d.priority).unwrap_or(Priority::P2),
+    };
+    let issue_type: Option<IssueType> = match &args.r#type {
+        Some(t) => Some(t.parse()?),
+        None => defaults.and_then(|d| d.issue_type),
+    };
+
+    // pull first so deps resolve against the latest remote state.
+    // unrelated changes block the pull unless --stash sets them aside.
+    auto_pull(paths, &config, cli, args.no_sync, args.stash)?;
 
     // resolve deps to full IDs
     let all_issues = load_all_issues(paths, &config)?;
@@ -35,22 +241,42 @@ pub fn cmd_add(cli: &Cli, paths: &RepoPaths, args: &AddArgs) -> Result<()> {
         .transpose()?;
 
     // create issue
-    let mut issue = Issue::new(id.clone(), args.title.clone(), priority, resolved_deps);
+    let mut issue = Issue::new(id.clone(), title, priority, resolved_deps);
     issue.frontmatter.issue_type = issue_type;
-    issue.frontmatter.acceptance = args.ac.clone();
-    issue.frontmatter.tags = args.tag.clone();
     issue.frontmatter.scheduled_for = scheduled_for;
+    // list fields combine: template entries first, then the ones from flags
+    if let Some(template) = &template {
+        issue.frontmatter.acceptance = template.frontmatter.acceptance.clone();
+        issue.frontmatter.tags = template.frontmatter.tags.clone();
+        issue.body = template.body.clone();
+    }
+    issue.frontmatter.acceptance.extend(args.ac.iter().cloned());
+    for tag in &args.tag {
+        if !issue.frontmatter.tags.contains(tag) {
+            issue.frontmatter.tags.push(tag.clone());
+        }
+    }
     if let Some(ref b) = args.body {
         issue.body = b.clone();
     }
 
     // save with lock
-    let _lock = LockGuard::acquire(&paths.lock_path())?;
-    let issue_path = issues_dir.join(format!("{}.md", id));
-    issue.save(&issue_path)?;
+    let mut issue_path = {
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        issue.save_in(&issues_dir, config.filename_scheme)?
+    };
+
+    // edit before pushing so the pushed issue includes the body
+    if let Some(editor) = editor {
+        run_editor(&editor, &issue_path)?;
+        issue = Issue::load(&issue_path)?;
+        issue_path = issue.save_in(&issues_dir, config.filename_scheme)?;
+    }
+
+    auto_push(paths, &config, &id, "add", cli, args.no_push)?;
 
     if cli.json {
-        let json = issue_to_json(&issue, &all_issues);
+        let json = issue_to_json(&issue, &all_issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         println!("Created issue: {}", id);
@@ -67,14 +293,21 @@ mod tests {
 
     fn make_args(title: &str) -> AddArgs {
         AddArgs {
-            title: title.to_string(),
-            priority: "P2".to_string(),
+            title: Some(title.to_string()),
+            title_flag: None,
+            priority: None,
             r#type: None,
             dep: vec![],
             ac: vec![],
             tag: vec![],
             body: None,
             scheduled_for: None,
+            no_sync: true,
+            no_push: true,
+            stash: false,
+            edit: false,
+            template: None,
+            interactive: false,
         }
     }
 
@@ -104,14 +337,21 @@ mod tests {
         repo.issue("brd-dep1").create();
 
         let args = AddArgs {
-            title: "Full issue".to_string(),
-            priority: "P0".to_string(),
+            title: Some("Full issue".to_string()),
+            title_flag: None,
+            priority: Some("P0".to_string()),
             r#type: Some("design".to_string()),
             dep: vec!["brd-dep1".to_string()],
             ac: vec!["criterion 1".to_string(), "criterion 2".to_string()],
             tag: vec!["testing".to_string(), "urgent".to_string()],
             body: Some("This is the body".to_string()),
             scheduled_for: None,
+            no_sync: true,
+            no_push: true,
+            stash: false,
+            edit: false,
+            template: None,
+            interactive: false,
         };
 
         let result = cmd_add(&test_cli(), &repo.paths, &args);
@@ -130,6 +370,152 @@ mod tests {
         assert_eq!(new_issue.body, "This is the body");
     }
 
+    // =========================================================================
+    // Interactive prompt tests
+    // =========================================================================
+
+    #[test]
+    fn test_prompt_add_args_collects_answers() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+
+        // empty title and bad priority/dep are asked again
+        let answers = "\nFix login\n7\n1\nauth, ui\nzzzz\naaaa\ny\n";
+        let mut out = Vec::new();
+        let mut args = make_args("");
+        args.title = None;
+        let args =
+            prompt_add_args(&mut answers.as_bytes(), &mut out, &args, None, &issues).unwrap();
+
+        assert_eq!(args.title.as_deref(), Some("Fix login"));
+        assert_eq!(args.priority.as_deref(), Some("P1"));
+        assert_eq!(args.tag, vec!["auth", "ui"]);
+        assert_eq!(args.dep, vec!["aaaa"]);
+        assert!(args.edit);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("invalid priority: P7"));
+        assert!(out.contains("zzzz"));
+    }
+
+    #[test]
+    fn test_prompt_add_args_keeps_flag_defaults() {
+        let repo = TestRepo::builder().build();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+
+        let mut args = make_args("Given title");
+        args.priority = Some("P0".to_string());
+        args.body = Some("body".to_string());
+        // priority, tags, deps all left empty; no body question since --body was given
+        let answers = "\n\n\n";
+        let args = prompt_add_args(
+            &mut answers.as_bytes(),
+            &mut Vec::new(),
+            &args,
+            None,
+            &issues,
+        )
+        .unwrap();
+        assert_eq!(args.title.as_deref(), Some("Given title"));
+        assert_eq!(args.priority.as_deref(), Some("P0"));
+        assert!(args.tag.is_empty() && args.dep.is_empty());
+        assert!(!args.edit);
+
+        // closed input aborts instead of spinning
+        let err = prompt_add_args(
+            &mut "".as_bytes(),
+            &mut Vec::new(),
+            &make_args(""),
+            None,
+            &issues,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("input closed"));
+    }
+
+    #[test]
+    fn test_prompt_add_args_defaults_to_template_priority() {
+        let repo = TestRepo::builder().build();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        write_template(&repo, "bug", "---\npriority: P1\n---\n");
+        let template = Template::load(&repo.paths.templates_dir(), "bug").unwrap();
+
+        let args = prompt_add_args(
+            &mut "\n\n\nn\n".as_bytes(),
+            &mut Vec::new(),
+            &make_args("Crash"),
+            Some(&template),
+            &issues,
+        )
+        .unwrap();
+        assert_eq!(args.priority.as_deref(), Some("P1"));
+    }
+
+    // =========================================================================
+    // Template tests
+    // =========================================================================
+
+    fn write_template(repo: &TestRepo, name: &str, content: &str) {
+        let dir = repo.paths.templates_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{}.md", name)), content).unwrap();
+    }
+
+    #[test]
+    fn test_add_from_template() {
+        let repo = TestRepo::builder().build();
+        write_template(
+            &repo,
+            "bug",
+            "---\npriority: P1\ntags: [bug]\nacceptance:\n  - regression test\n---\n## steps to reproduce\n",
+        );
+
+        let mut args = make_args("Login fails");
+        args.template = Some("bug".to_string());
+        args.tag = vec!["auth".to_string(), "bug".to_string()];
+        cmd_add(&test_cli(), &repo.paths, &args).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let issue = issues.values().next().unwrap();
+        assert_eq!(issue.title(), "Login fails");
+        assert_eq!(issue.priority(), Priority::P1);
+        assert_eq!(issue.tags(), ["bug", "auth"]);
+        assert_eq!(issue.frontmatter.acceptance, vec!["regression test"]);
+        assert_eq!(issue.body, "## steps to reproduce\n");
+    }
+
+    #[test]
+    fn test_add_flags_override_template() {
+        let repo = TestRepo::builder().build();
+        write_template(&repo, "bug", "---\npriority: P1\n---\nskeleton\n");
+
+        let mut args = make_args("Override");
+        args.template = Some("bug".to_string());
+        args.priority = Some("P3".to_string());
+        args.body = Some("custom body".to_string());
+        cmd_add(&test_cli(), &repo.paths, &args).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let issue = issues.values().next().unwrap();
+        assert_eq!(issue.priority(), Priority::P3);
+        assert_eq!(issue.body, "custom body");
+    }
+
+    #[test]
+    fn test_add_unknown_template() {
+        let repo = TestRepo::builder().build();
+        let mut args = make_args("Nope");
+        args.template = Some("missing".to_string());
+
+        let err = cmd_add(&test_cli(), &repo.paths, &args).unwrap_err();
+        assert!(err.to_string().contains("template 'missing' not found"));
+        assert!(
+            load_all_issues(&repo.paths, &repo.config)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     // =========================================================================
     // Priority parsing tests
     // =========================================================================
@@ -138,7 +524,7 @@ mod tests {
     fn test_add_priority_p0() {
         let repo = TestRepo::builder().build();
         let mut args = make_args("P0 issue");
-        args.priority = "P0".to_string();
+        args.priority = Some("P0".to_string());
 
         let result = cmd_add(&test_cli(), &repo.paths, &args);
         assert!(result.is_ok());
@@ -151,7 +537,7 @@ mod tests {
     fn test_add_priority_p3() {
         let repo = TestRepo::builder().build();
         let mut args = make_args("P3 issue");
-        args.priority = "P3".to_string();
+        args.priority = Some("P3".to_string());
 
         let result = cmd_add(&test_cli(), &repo.paths, &args);
         assert!(result.is_ok());
@@ -164,7 +550,7 @@ mod tests {
     fn test_add_invalid_priority() {
         let repo = TestRepo::builder().build();
         let mut args = make_args("Bad priority");
-        args.priority = "P5".to_string();
+        args.priority = Some("P5".to_string());
 
         let result = cmd_add(&test_cli(), &repo.paths, &args);
         assert!(result.is_err());
@@ -390,6 +776,41 @@ mod tests {
         assert!(issue.body.contains("multiple lines"));
     }
 
+    #[test]
+    fn test_apply_stdin_body() {
+        // `-` reads the body, with the title from --title
+        let mut args = make_args("-");
+        args.title_flag = Some("Scripted".to_string());
+        let args = apply_stdin_body(&args, &mut "body\n\n".as_bytes()).unwrap();
+        assert_eq!(args.title.as_deref(), Some("Scripted"));
+        assert_eq!(args.body.as_deref(), Some("body"));
+
+        // without `-`, stdin is never read
+        let args = apply_stdin_body(&make_args("T"), &mut "unread".as_bytes()).unwrap();
+        assert_eq!(args.body, None);
+
+        // empty input leaves the body unset
+        let mut args = make_args("-");
+        args.title_flag = Some("T".to_string());
+        let args = apply_stdin_body(&args, &mut "\n".as_bytes()).unwrap();
+        assert_eq!(args.body, None);
+    }
+
+    #[test]
+    fn test_apply_stdin_body_rejects_conflicts() {
+        // `-` without --title
+        assert!(apply_stdin_body(&make_args("-"), &mut "".as_bytes()).is_err());
+        // title given both ways
+        let mut args = make_args("T");
+        args.title_flag = Some("U".to_string());
+        assert!(apply_stdin_body(&args, &mut "".as_bytes()).is_err());
+        // `-` and --body
+        let mut args = make_args("-");
+        args.title_flag = Some("T".to_string());
+        args.body = Some("b".to_string());
+        assert!(apply_stdin_body(&args, &mut "".as_bytes()).is_err());
+    }
+
     #[test]
     fn test_add_without_body() {
         let repo = TestRepo::builder().build();
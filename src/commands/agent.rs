@@ -11,45 +11,27 @@ use crate::git;
 use crate::lock::LockGuard;
 use crate::repo::{self, RepoPaths};
 
+use super::config::{is_behind_main, list_agent_worktrees};
 use super::{
     claim_issue, commit_and_push_issues_branch, commit_and_push_main, load_all_issues,
     resolve_issue_id,
 };
 
-/// check if we're in a git worktree (not the main repo).
-/// compares git-dir with git-common-dir - they differ in a worktree.
-fn is_git_worktree(path: &std::path::Path) -> bool {
-    let git_dir = Command::new("git")
-        .args(["rev-parse", "--git-dir"])
-        .current_dir(path)
-        .output()
-        .ok()
-        .filter(|o| o.status.success())
-        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
-
-    let git_common_dir = Command::new("git")
-        .args(["rev-parse", "--git-common-dir"])
-        .current_dir(path)
-        .output()
-        .ok()
-        .filter(|o| o.status.success())
-        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
-
-    match (git_dir, git_common_dir) {
-        (Some(dir), Some(common)) => dir != common,
-        _ => false,
-    }
-}
-
-pub fn cmd_agent_init(cli: &Cli, paths: &RepoPaths, name: &str, base: Option<&str>) -> Result<()> {
+pub fn cmd_agent_init(
+    cli: &Cli,
+    paths: &RepoPaths,
+    name: &str,
+    base: Option<&str>,
+    from_origin: bool,
+) -> Result<()> {
     // refuse if already in an agent worktree
     // check both: agent.toml exists AND we're in a git worktree (not main)
     let agent_toml = paths.braid_dir().join("agent.toml");
-    let is_worktree = is_git_worktree(&paths.worktree_root);
+    let is_worktree = repo::is_git_worktree(&paths.worktree_root);
 
     if agent_toml.exists() {
         if is_worktree {
-            return Err(BrdError::Other(
+            return Err(BrdError::InvalidInput(
                 "already in an agent worktree. run `brd agent init` from main instead.".to_string(),
             ));
         } else {
@@ -58,7 +40,7 @@ pub fn cmd_agent_init(cli: &Cli, paths: &RepoPaths, name: &str, base: Option<&st
         }
     } else if is_worktree {
         // in a worktree but no agent.toml - still shouldn't init from here
-        return Err(BrdError::Other(
+        return Err(BrdError::InvalidInput(
             "cannot run `brd agent init` from a worktree. run from main instead.".to_string(),
         ));
     }
@@ -68,12 +50,17 @@ pub fn cmd_agent_init(cli: &Cli, paths: &RepoPaths, name: &str, base: Option<&st
         .chars()
         .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
     {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::InvalidInput(format!(
             "invalid agent name '{}': use only alphanumeric, hyphens, underscores",
             name
         )));
     }
 
+    // the agent branch is cut from a local commit unless it comes from origin
+    if base.is_none() && !from_origin {
+        git::require_commits(&paths.worktree_root)?;
+    }
+
     // determine worktree path (~/.braid/worktrees/<repo-name>/<agent-name>)
     let home_dir = std::env::var("HOME")
         .map(PathBuf::from)
@@ -91,43 +78,39 @@ pub fn cmd_agent_init(cli: &Cli, paths: &RepoPaths, name: &str, base: Option<&st
 
     // check if worktree already exists
     if worktree_path.exists() {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::AlreadyExists(format!(
             "directory already exists: {}",
             worktree_path.display()
         )));
     }
 
     // get base branch (default to current branch)
-    let base_branch = match base {
-        Some(b) => b.to_string(),
-        None => {
-            let output = std::process::Command::new("git")
-                .args(["rev-parse", "--abbrev-ref", "HEAD"])
-                .current_dir(&paths.worktree_root)
-                .output()?;
-            if !output.status.success() {
-                return Err(BrdError::Other("failed to get current branch".to_string()));
-            }
-            String::from_utf8_lossy(&output.stdout).trim().to_string()
-        }
+    let origin_base = match base {
+        Some(_) => None,
+        None if from_origin => fetch_origin_base(cli, &paths.worktree_root)?,
+        None => None,
+    };
+    let base_branch = match (base, &origin_base) {
+        (Some(b), _) => b.to_string(),
+        (None, Some(b)) => b.clone(),
+        (None, None) => git::current_branch(&paths.worktree_root)?,
     };
 
-    // create worktree with new branch
+    // create worktree with new branch. a remote base isn't tracked: the agent
+    // branch is pushed under its own name
+    let mut args = vec!["worktree", "add"];
+    if origin_base.is_some() {
+        args.push("--no-track");
+    }
+    args.extend(["-b", name, worktree_path.to_str().unwrap(), &base_branch]);
     let output = std::process::Command::new("git")
-        .args([
-            "worktree",
-            "add",
-            "-b",
-            name,
-            worktree_path.to_str().unwrap(),
-            &base_branch,
-        ])
+        .args(&args)
         .current_dir(&paths.worktree_root)
         .output()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Git(format!(
             "failed to create worktree: {}",
             stderr.trim()
         )));
@@ -183,6 +166,154 @@ pub fn cmd_agent_init(cli: &Cli, paths: &RepoPaths, name: &str, base: Option<&st
     Ok(())
 }
 
+/// list agent worktrees with their agent id, branch, and state.
+pub fn cmd_agent_list(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+    let worktrees: Vec<_> = list_agent_worktrees(&paths.worktree_root)
+        .into_iter()
+        .map(|wt| {
+            let agent_id = repo::agent_toml_id(&wt.path).unwrap_or_else(|| wt.branch.clone());
+            let clean = git::is_clean(&wt.path)?;
+            let behind = is_behind_main(&wt.path);
+            Ok((agent_id, wt, clean, behind))
+        })
+        .collect::<Result<_>>()?;
+
+    if cli.json {
+        let json: Vec<_> = worktrees
+            .iter()
+            .map(|(agent_id, wt, clean, behind)| {
+                serde_json::json!({
+                    "agent_id": agent_id,
+                    "branch": wt.branch,
+                    "path": wt.path.to_string_lossy(),
+                    "clean": clean,
+                    "behind_main": behind,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else if worktrees.is_empty() {
+        println!("no agent worktrees (create one with `brd agent init <name>`)");
+    } else {
+        println!(
+            "{:<16} {:<16} {:<6} {:<10}  PATH",
+            "AGENT", "BRANCH", "TREE", "MAIN"
+        );
+        for (agent_id, wt, clean, behind) in &worktrees {
+            println!(
+                "{:<16} {:<16} {:<6} {:<10}  {}",
+                agent_id,
+                wt.branch,
+                if *clean { "clean" } else { "dirty" },
+                if *behind { "behind" } else { "up to date" },
+                wt.path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// remove an agent worktree made by `brd agent init`, and its branch once merged.
+pub fn cmd_agent_remove(
+    cli: &Cli,
+    paths: &RepoPaths,
+    name: &str,
+    force: bool,
+    delete_branch: bool,
+) -> Result<()> {
+    let wt = list_agent_worktrees(&paths.worktree_root)
+        .into_iter()
+        .find(|wt| wt.branch == name || repo::agent_toml_id(&wt.path).as_deref() == Some(name))
+        .ok_or_else(|| {
+            BrdError::InvalidInput(format!(
+                "no agent worktree named '{}' (see `brd agent list`)",
+                name
+            ))
+        })?;
+
+    if wt.path == paths.worktree_root {
+        return Err(BrdError::InvalidInput(
+            "cannot remove the worktree you're in. run from main instead.".to_string(),
+        ));
+    }
+    if !force && !git::is_clean(&wt.path)? {
+        return Err(BrdError::DirtyWorkingTree(format!(
+            "agent worktree '{}' has uncommitted changes (use --force to remove anyway)",
+            name
+        )));
+    }
+
+    let path = wt.path.to_string_lossy();
+    let mut args = vec!["worktree", "remove", path.as_ref()];
+    if force {
+        args.push("--force");
+    }
+    let output = git::run_full(&args, &paths.worktree_root)?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(BrdError::Git(format!(
+            "failed to remove worktree: {}",
+            stderr.trim()
+        )));
+    }
+
+    // -d only deletes a branch merged into HEAD; -D deletes it regardless
+    let flag = if delete_branch { "-D" } else { "-d" };
+    let branch_deleted = git::run(&["branch", flag, &wt.branch], &paths.worktree_root)?;
+
+    if cli.json {
+        let json = serde_json::json!({
+            "ok": true,
+            "worktree": wt.path.to_string_lossy(),
+            "branch": wt.branch,
+            "branch_deleted": branch_deleted,
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else {
+        println!("Removed agent worktree: {}", name);
+        println!("  path:   {}", wt.path.display());
+        if branch_deleted {
+            println!("  branch: {} (deleted)", wt.branch);
+        } else {
+            println!(
+                "  branch: {} (kept: not fully merged; use --delete-branch to delete it)",
+                wt.branch
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// fetch origin and return `origin/<default branch>` as the base for a new
+/// agent worktree, or None (with a warning) when there is no usable origin.
+fn fetch_origin_base(cli: &Cli, cwd: &std::path::Path) -> Result<Option<String>> {
+    let warn = |msg: &str| {
+        if !cli.json {
+            eprintln!(
+                "warning: {}, basing the worktree on the current branch",
+                msg
+            );
+        }
+    };
+    if !git::has_remote(cwd, "origin") {
+        warn("no origin remote");
+        return Ok(None);
+    }
+    if !git::run(&["fetch", "origin"], cwd)? {
+        warn("failed to fetch origin");
+        return Ok(None);
+    }
+    match git::remote_default_branch(cwd, "origin") {
+        Some(branch) => Ok(Some(format!("origin/{}", branch))),
+        None => {
+            warn("origin has no default branch");
+            Ok(None)
+        }
+    }
+}
+
 /// create a feature branch for PR workflow.
 /// this command:
 /// 1. syncs with main and claims the issue (pushing to main so other agents see it)
@@ -194,7 +325,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
 
     // Step 1: Check for clean working tree
     if !git::is_clean(&paths.worktree_root)? {
-        return Err(BrdError::Other(
+        return Err(BrdError::DirtyWorkingTree(
             "working tree has uncommitted changes - commit or stash first".to_string(),
         ));
     }
@@ -209,7 +340,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
 
     // Check if branch already exists
     if git::branch_exists(&paths.worktree_root, &branch_name) {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::AlreadyExists(format!(
             "branch '{}' already exists",
             branch_name
         )));
@@ -241,7 +372,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create branch: {}",
                 stderr.trim()
             )));
@@ -267,7 +398,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
                 eprintln!("switching to main...");
             }
             if !git::run(&["checkout", "main"], &paths.worktree_root)? {
-                return Err(BrdError::Other("failed to checkout main".to_string()));
+                return Err(BrdError::Git("failed to checkout main".to_string()));
             }
         }
 
@@ -276,7 +407,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
             && !git::run(&["rebase", "origin/main"], &paths.worktree_root)?
         {
             let _ = git::run(&["rebase", "--abort"], &paths.worktree_root);
-            return Err(BrdError::Other(
+            return Err(BrdError::Conflict(
                 "rebase failed - resolve conflicts manually".to_string(),
             ));
         }
@@ -295,7 +426,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
         claim_issue(paths, &config, issue, &agent_id, false)?;
 
         // Step 4: Commit and push to main
-        commit_and_push_main(paths, &full_id, cli)?;
+        commit_and_push_main(paths, &config, &full_id, cli)?;
 
         // Step 5: Create feature branch from main
         if !cli.json {
@@ -309,7 +440,7 @@ pub fn cmd_agent_branch(cli: &Cli, paths: &RepoPaths, issue_id: &str) -> Result<
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create branch: {}",
                 stderr.trim()
             )));
@@ -357,10 +488,10 @@ fn extract_issue_id_from_branch(branch: &str) -> Option<&str> {
 fn check_gh_available() -> Result<()> {
     match Command::new("gh").arg("--version").output() {
         Ok(output) if output.status.success() => Ok(()),
-        Ok(_) => Err(BrdError::Other(
+        Ok(_) => Err(BrdError::ToolNotFound(
             "gh CLI found but returned an error. try running `gh auth login`".to_string(),
         )),
-        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(BrdError::Other(
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(BrdError::ToolNotFound(
             "gh CLI not found\n\n\
              install it to create PRs:\n  \
              macOS:   brew install gh\n  \
@@ -378,18 +509,19 @@ pub fn cmd_agent_pr(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
 
     // get current branch
+    git::require_branch(&paths.worktree_root)?;
     let branch = git::current_branch(&paths.worktree_root)?;
 
     // check if on main
     if branch == "main" {
-        return Err(BrdError::Other(
+        return Err(BrdError::InvalidInput(
             "PRs require a feature branch - use `brd agent init <name>` to create one".to_string(),
         ));
     }
 
     // extract issue ID from branch name
     let issue_id = extract_issue_id_from_branch(&branch).ok_or_else(|| {
-        BrdError::Other(format!(
+        BrdError::InvalidInput(format!(
             "branch '{}' doesn't match expected format 'pr/<agent>/<issue-id>'",
             branch
         ))
@@ -400,7 +532,7 @@ pub fn cmd_agent_pr(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     let full_id = resolve_issue_id(issue_id, &issues)?;
     let issue = issues
         .get(&full_id)
-        .ok_or_else(|| BrdError::Other(format!("issue '{}' not found", full_id)))?;
+        .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
 
     // generate PR title and body
     let title = format!("feat: {} ({})", issue.title(), full_id);
@@ -426,7 +558,7 @@ pub fn cmd_agent_pr(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         let stderr = String::from_utf8_lossy(&push_output.stderr);
         // ignore "already up to date" type messages
         if !stderr.contains("Everything up-to-date") && !stderr.contains("set up to track") {
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to push branch: {}",
                 stderr.trim()
             )));
@@ -446,7 +578,7 @@ pub fn cmd_agent_pr(cli: &Cli, paths: &RepoPaths) -> Result<()> {
 
     if !pr_output.status.success() {
         let stderr = String::from_utf8_lossy(&pr_output.stderr);
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Git(format!(
             "failed to create PR: {}",
             stderr.trim()
         )));
@@ -650,9 +782,14 @@ pub fn extract_mode(content: &str) -> Option<AgentsBlockMode> {
 }
 
 /// Check instruction files for a braid block and return the file name and version.
-/// Checks files in INSTRUCTION_FILES order: AGENTS.md, CLAUDE.md, CLAUDE.local.md.
-pub fn check_agents_block(paths: &RepoPaths) -> Option<(&'static str, u32)> {
-    for &file in INSTRUCTION_FILES {
+/// Checks the configured `agents_file` first, then INSTRUCTION_FILES in order:
+/// AGENTS.md, CLAUDE.md, CLAUDE.local.md.
+pub fn check_agents_block(paths: &RepoPaths, config: &Config) -> Option<(String, u32)> {
+    let configured = config.agents_file.as_deref();
+    for file in configured
+        .into_iter()
+        .chain(INSTRUCTION_FILES.iter().copied())
+    {
         let file_path = paths.worktree_root.join(file);
         if !file_path.exists() {
             continue;
@@ -660,7 +797,7 @@ pub fn check_agents_block(paths: &RepoPaths) -> Option<(&'static str, u32)> {
         if let Ok(content) = fs::read_to_string(&file_path)
             && let Some(version) = extract_version(&content)
         {
-            return Some((file, version));
+            return Some((file.to_string(), version));
         }
     }
     None
@@ -698,25 +835,99 @@ pub fn inject_agents_block(
     config: &Config,
     file_name: &str,
 ) -> Result<InjectResult> {
-    let agents_path = worktree_root.join(file_name);
     let block = generate_block(config);
+    write_block(worktree_root, file_name, BLOCK_START, BLOCK_END, &block)
+}
+
+/// current version of the named block markers
+const NAMED_BLOCK_VERSION: u32 = 1;
+
+/// start marker prefix and end marker for a named block, e.g.
+/// `<!-- braid:block:testing:start` and `<!-- braid:block:testing:end -->`.
+/// they live under their own `braid:block:` namespace, so no name (not even
+/// `startup`) can look like the unnamed block's `braid:agents:start` marker.
+fn named_block_markers(name: &str) -> (String, String) {
+    (
+        format!("<!-- braid:block:{name}:start"),
+        format!("<!-- braid:block:{name}:end -->"),
+    )
+}
+
+/// block names end up inside html comments and file names, so keep them simple.
+fn validate_block_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        return Err(BrdError::InvalidInput(format!(
+            "invalid block name '{}': use lowercase letters, digits, and '-'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// where the content of a named block is kept: `.braid/agents/<name>.md`.
+fn named_block_source(paths: &RepoPaths, name: &str) -> std::path::PathBuf {
+    paths.braid_dir().join("agents").join(format!("{name}.md"))
+}
+
+/// wrap `body` in the markers for block `name`.
+fn generate_named_block(name: &str, body: &str) -> String {
+    let (start, end) = named_block_markers(name);
+    format!(
+        "{start} v{NAMED_BLOCK_VERSION} -->\n{}\n{end}",
+        body.trim_end()
+    )
+}
+
+/// inject or update the named block `name` in a file, leaving any other
+/// blocks (named or not) untouched.
+fn inject_named_block(
+    worktree_root: &std::path::Path,
+    name: &str,
+    body: &str,
+    file_name: &str,
+) -> Result<InjectResult> {
+    validate_block_name(name)?;
+    let (start, end) = named_block_markers(name);
+    let block = generate_named_block(name, body);
+    write_block(worktree_root, file_name, &start, &end, &block)
+}
+
+/// replace the block delimited by `start`/`end` in `file_name`, appending it
+/// (or creating the file) when the block isn't there yet.
+fn write_block(
+    worktree_root: &std::path::Path,
+    file_name: &str,
+    start: &str,
+    end: &str,
+    block: &str,
+) -> Result<InjectResult> {
+    let agents_path = worktree_root.join(file_name);
+
+    // targets like .github/copilot-instructions.md may live in a missing directory
+    if let Some(parent) = agents_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
 
     if agents_path.exists() {
         let content = fs::read_to_string(&agents_path)?;
 
-        if let Some(start_idx) = content.find(BLOCK_START) {
+        if let Some(start_idx) = content.find(start) {
             // update existing block
-            if let Some(end_marker_start) = content[start_idx..].find(BLOCK_END) {
-                let end_idx = start_idx + end_marker_start + BLOCK_END.len();
+            if let Some(end_marker_start) = content[start_idx..].find(end) {
+                let end_idx = start_idx + end_marker_start + end.len();
                 let new_content =
                     format!("{}{}{}", &content[..start_idx], block, &content[end_idx..]);
                 fs::write(&agents_path, new_content)?;
                 Ok(InjectResult::Updated)
             } else {
-                Err(BrdError::Other(format!(
-                    "found start marker but no end marker in {}",
-                    file_name
-                )))
+                Err(BrdError::ParseError(
+                    file_name.to_string(),
+                    "found start marker but no end marker".to_string(),
+                ))
             }
         } else {
             // append to existing file
@@ -725,7 +936,7 @@ pub fn inject_agents_block(
                 content.push('\n');
             }
             content.push('\n');
-            content.push_str(&block);
+            content.push_str(block);
             content.push('\n');
             fs::write(&agents_path, content)?;
             Ok(InjectResult::Added)
@@ -740,10 +951,30 @@ pub fn inject_agents_block(
     }
 }
 
-/// inject or update the agents block in AGENTS.md (or custom file)
-pub fn cmd_agents_inject(paths: &RepoPaths, file: Option<&str>) -> Result<()> {
+/// inject or update the agents block in AGENTS.md (or custom file).
+/// with `name`, manages the named block sourced from `.braid/agents/<name>.md` instead.
+pub fn cmd_agents_inject(paths: &RepoPaths, file: Option<&str>, name: Option<&str>) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
-    let file_name = file.unwrap_or("AGENTS.md");
+    let file_name = file.unwrap_or(config.agents_file_name());
+
+    if let Some(name) = name {
+        validate_block_name(name)?;
+        let source = named_block_source(paths, name);
+        let body = fs::read_to_string(&source).map_err(|_| {
+            BrdError::InvalidInput(format!(
+                "no content for block '{}': create {}",
+                name,
+                source.display()
+            ))
+        })?;
+        let verb = match inject_named_block(&paths.worktree_root, name, &body, file_name)? {
+            InjectResult::Created => "created",
+            InjectResult::Added => "added",
+            InjectResult::Updated => "updated",
+        };
+        println!("{} braid block '{}' in {}", verb, name, file_name);
+        return Ok(());
+    }
 
     let mode_name = if config.issues_branch.is_some() {
         "local-sync"
@@ -840,7 +1071,7 @@ mod tests {
         let home_dir = tempdir().unwrap();
         let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
 
-        cmd_agent_init(&test_cli(), &paths, "agent-one", Some(&base_branch)).unwrap();
+        cmd_agent_init(&test_cli(), &paths, "agent-one", Some(&base_branch), false).unwrap();
 
         let worktree_path = home_dir
             .path()
@@ -858,6 +1089,114 @@ mod tests {
         assert!(branch_list.contains("agent-one"));
     }
 
+    #[test]
+    fn test_agent_init_from_origin_uses_fetched_default_branch() {
+        let _lock = AGENT_INIT_TEST_LOCK.lock().unwrap();
+        let (dir, repo_path, paths, base_branch) = create_repo();
+        let home_dir = tempdir().unwrap();
+        let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
+
+        // without an origin it falls back to the current branch
+        cmd_agent_init(&test_cli(), &paths, "agent-local", None, true).unwrap();
+
+        // origin gets a commit the local branch doesn't have
+        let origin = dir.path().join("origin.git");
+        git_ok(dir.path(), &["init", "--bare", origin.to_str().unwrap()]);
+        git_ok(
+            &repo_path,
+            &["remote", "add", "origin", origin.to_str().unwrap()],
+        );
+        git_ok(&repo_path, &["push", "origin", &base_branch]);
+        let other = dir.path().join("other");
+        git_ok(
+            dir.path(),
+            &["clone", origin.to_str().unwrap(), other.to_str().unwrap()],
+        );
+        git_ok(&other, &["config", "user.email", "test@test.com"]);
+        git_ok(&other, &["config", "user.name", "test user"]);
+        git_ok(&other, &["config", "commit.gpgsign", "false"]);
+        git_ok(&other, &["commit", "--allow-empty", "-m", "upstream"]);
+        git_ok(&other, &["push", "origin", &base_branch]);
+
+        cmd_agent_init(&test_cli(), &paths, "agent-fresh", None, true).unwrap();
+        let upstream = git_output(&other, &["rev-parse", "HEAD"]);
+        assert_eq!(
+            git_output(&repo_path, &["rev-parse", "agent-fresh"]),
+            upstream
+        );
+        assert_ne!(
+            git_output(&repo_path, &["rev-parse", "agent-local"]),
+            upstream
+        );
+    }
+
+    #[test]
+    fn test_agent_list_finds_agent_worktrees() {
+        let _lock = AGENT_INIT_TEST_LOCK.lock().unwrap();
+        let (_dir, repo_path, paths, base_branch) = create_repo();
+        let home_dir = tempdir().unwrap();
+        let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
+
+        // agent.toml on main (as brd init writes it) doesn't make main an agent
+        std::fs::write(repo_path.join(".git/info/exclude"), ".braid/agent.toml\n").unwrap();
+        std::fs::create_dir_all(repo_path.join(".braid")).unwrap();
+        std::fs::write(repo_path.join(".braid/agent.toml"), "agent_id = \"me\"\n").unwrap();
+        assert!(list_agent_worktrees(&repo_path).is_empty());
+
+        cmd_agent_init(&test_cli(), &paths, "agent-one", Some(&base_branch), false).unwrap();
+        cmd_agent_init(&test_cli(), &paths, "agent-two", Some(&base_branch), false).unwrap();
+
+        let mut worktrees = list_agent_worktrees(&repo_path);
+        worktrees.sort_by(|a, b| a.branch.cmp(&b.branch));
+        let branches: Vec<_> = worktrees.iter().map(|wt| wt.branch.as_str()).collect();
+        assert_eq!(branches, vec!["agent-one", "agent-two"]);
+        assert_eq!(
+            repo::agent_toml_id(&worktrees[0].path).as_deref(),
+            Some("agent-one")
+        );
+
+        std::fs::write(worktrees[1].path.join("scratch.txt"), "wip\n").unwrap();
+        assert!(git::is_clean(&worktrees[0].path).unwrap());
+        assert!(!git::is_clean(&worktrees[1].path).unwrap());
+
+        let mut cli = test_cli();
+        cli.json = true;
+        cmd_agent_list(&cli, &paths).unwrap();
+    }
+
+    #[test]
+    fn test_agent_remove_tears_down_worktree() {
+        let _lock = AGENT_INIT_TEST_LOCK.lock().unwrap();
+        let (_dir, repo_path, paths, base_branch) = create_repo();
+        let home_dir = tempdir().unwrap();
+        let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
+        std::fs::write(repo_path.join(".git/info/exclude"), ".braid/agent.toml\n").unwrap();
+
+        cmd_agent_init(&test_cli(), &paths, "agent-one", Some(&base_branch), false).unwrap();
+        cmd_agent_init(&test_cli(), &paths, "agent-two", Some(&base_branch), false).unwrap();
+
+        let err = cmd_agent_remove(&test_cli(), &paths, "agent-nope", false, false).unwrap_err();
+        assert!(matches!(err, BrdError::InvalidInput(_)));
+
+        // a merged branch goes with its worktree
+        cmd_agent_remove(&test_cli(), &paths, "agent-one", false, false).unwrap();
+        assert!(!git::run(&["rev-parse", "--verify", "agent-one"], &repo_path).unwrap());
+
+        // dirty trees need --force; unmerged branches need --delete-branch
+        let wt_path = list_agent_worktrees(&repo_path)[0].path.clone();
+        std::fs::write(wt_path.join("scratch.txt"), "wip\n").unwrap();
+        git_ok(&wt_path, &["add", "scratch.txt"]);
+        git_ok(&wt_path, &["commit", "-m", "wip"]);
+        std::fs::write(wt_path.join("scratch.txt"), "more wip\n").unwrap();
+        let err = cmd_agent_remove(&test_cli(), &paths, "agent-two", false, false).unwrap_err();
+        assert!(matches!(err, BrdError::DirtyWorkingTree(_)));
+
+        cmd_agent_remove(&test_cli(), &paths, "agent-two", true, false).unwrap();
+        assert!(!wt_path.exists());
+        assert!(git::run(&["rev-parse", "--verify", "agent-two"], &repo_path).unwrap());
+        assert!(list_agent_worktrees(&repo_path).is_empty());
+    }
+
     #[test]
     fn test_agent_init_rejects_invalid_name() {
         let _lock = AGENT_INIT_TEST_LOCK.lock().unwrap();
@@ -867,7 +1206,7 @@ mod tests {
         let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
 
         let cli = test_cli();
-        let err = cmd_agent_init(&cli, &paths, "bad name", Some(&base_branch)).unwrap_err();
+        let err = cmd_agent_init(&cli, &paths, "bad name", Some(&base_branch), false).unwrap_err();
         assert!(err.to_string().contains("invalid agent name"));
     }
 
@@ -888,7 +1227,7 @@ mod tests {
         std::fs::create_dir_all(&worktree_path).unwrap();
 
         let cli = test_cli();
-        let err = cmd_agent_init(&cli, &paths, "agent-one", Some(&base_branch)).unwrap_err();
+        let err = cmd_agent_init(&cli, &paths, "agent-one", Some(&base_branch), false).unwrap_err();
         assert!(err.to_string().contains("directory already exists"));
     }
 
@@ -909,7 +1248,7 @@ mod tests {
 
         // agent init should clean it up and succeed
         let cli = test_cli();
-        cmd_agent_init(&cli, &paths, "new-agent", Some(&base_branch)).unwrap();
+        cmd_agent_init(&cli, &paths, "new-agent", Some(&base_branch), false).unwrap();
 
         // stale file should be gone
         assert!(!agent_toml.exists(), "stale agent.toml should be removed");
@@ -925,7 +1264,7 @@ mod tests {
 
         // create a real agent worktree first
         let cli = test_cli();
-        cmd_agent_init(&cli, &paths, "first-agent", Some(&base_branch)).unwrap();
+        cmd_agent_init(&cli, &paths, "first-agent", Some(&base_branch), false).unwrap();
 
         // get the worktree path
         let worktree_path = home_dir
@@ -943,8 +1282,14 @@ mod tests {
         };
 
         // trying to init from within a worktree should fail
-        let err =
-            cmd_agent_init(&cli, &worktree_paths, "second-agent", Some(&base_branch)).unwrap_err();
+        let err = cmd_agent_init(
+            &cli,
+            &worktree_paths,
+            "second-agent",
+            Some(&base_branch),
+            false,
+        )
+        .unwrap_err();
         assert!(
             err.to_string().contains("worktree"),
             "expected error about worktree, got: {}",
@@ -1047,8 +1392,8 @@ mod tests {
         std::fs::write(&agents_path, generate_block(&config)).unwrap();
 
         assert_eq!(
-            check_agents_block(&paths),
-            Some(("AGENTS.md", AGENTS_BLOCK_VERSION))
+            check_agents_block(&paths, &config),
+            Some(("AGENTS.md".to_string(), AGENTS_BLOCK_VERSION))
         );
     }
 
@@ -1060,8 +1405,8 @@ mod tests {
         std::fs::write(&claude_path, generate_block(&config)).unwrap();
 
         assert_eq!(
-            check_agents_block(&paths),
-            Some(("CLAUDE.md", AGENTS_BLOCK_VERSION))
+            check_agents_block(&paths, &config),
+            Some(("CLAUDE.md".to_string(), AGENTS_BLOCK_VERSION))
         );
     }
 
@@ -1073,8 +1418,8 @@ mod tests {
         std::fs::write(&claude_local_path, generate_block(&config)).unwrap();
 
         assert_eq!(
-            check_agents_block(&paths),
-            Some(("CLAUDE.local.md", AGENTS_BLOCK_VERSION))
+            check_agents_block(&paths, &config),
+            Some(("CLAUDE.local.md".to_string(), AGENTS_BLOCK_VERSION))
         );
     }
 
@@ -1090,15 +1435,15 @@ mod tests {
 
         // AGENTS.md has highest priority, but doesn't exist, so CLAUDE.md should be found
         assert_eq!(
-            check_agents_block(&paths),
-            Some(("CLAUDE.md", AGENTS_BLOCK_VERSION))
+            check_agents_block(&paths, &config),
+            Some(("CLAUDE.md".to_string(), AGENTS_BLOCK_VERSION))
         );
 
         // Now create AGENTS.md - it should take priority
         std::fs::write(paths.worktree_root.join("AGENTS.md"), &block).unwrap();
         assert_eq!(
-            check_agents_block(&paths),
-            Some(("AGENTS.md", AGENTS_BLOCK_VERSION))
+            check_agents_block(&paths, &config),
+            Some(("AGENTS.md".to_string(), AGENTS_BLOCK_VERSION))
         );
     }
 
@@ -1106,13 +1451,13 @@ mod tests {
     fn test_check_agents_block_not_found() {
         let (_dir, paths) = create_paths();
         // No instruction files exist
-        assert_eq!(check_agents_block(&paths), None);
+        assert_eq!(check_agents_block(&paths, &Config::default()), None);
     }
 
     #[test]
     fn test_cmd_agents_inject_creates_file() {
         let (_dir, paths) = create_paths();
-        cmd_agents_inject(&paths, None).unwrap();
+        cmd_agents_inject(&paths, None, None).unwrap();
 
         let content = std::fs::read_to_string(paths.worktree_root.join("AGENTS.md")).unwrap();
         assert!(content.contains("Instructions for AI agents"));
@@ -1127,7 +1472,7 @@ mod tests {
         let agents_path = paths.worktree_root.join("AGENTS.md");
         std::fs::write(&agents_path, "custom header\n").unwrap();
 
-        cmd_agents_inject(&paths, None).unwrap();
+        cmd_agents_inject(&paths, None, None).unwrap();
 
         let content = std::fs::read_to_string(&agents_path).unwrap();
         assert!(content.starts_with("custom header"));
@@ -1142,7 +1487,7 @@ mod tests {
         let old_block = format!("{BLOCK_START} v1 -->\nold\n{BLOCK_END}");
         std::fs::write(&agents_path, format!("before\n{old_block}\nafter")).unwrap();
 
-        cmd_agents_inject(&paths, None).unwrap();
+        cmd_agents_inject(&paths, None, None).unwrap();
 
         let content = std::fs::read_to_string(&agents_path).unwrap();
         assert!(content.contains("before"));
@@ -1157,14 +1502,14 @@ mod tests {
         let agents_path = paths.worktree_root.join("AGENTS.md");
         std::fs::write(&agents_path, format!("{BLOCK_START} v1 -->\nno end")).unwrap();
 
-        let err = cmd_agents_inject(&paths, None).unwrap_err();
+        let err = cmd_agents_inject(&paths, None, None).unwrap_err();
         assert!(err.to_string().contains("no end marker"));
     }
 
     #[test]
     fn test_cmd_agents_inject_custom_file() {
         let (_dir, paths) = create_paths();
-        cmd_agents_inject(&paths, Some("CLAUDE.md")).unwrap();
+        cmd_agents_inject(&paths, Some("CLAUDE.md"), None).unwrap();
 
         // Custom file should be created
         let content = std::fs::read_to_string(paths.worktree_root.join("CLAUDE.md")).unwrap();
@@ -1179,15 +1524,97 @@ mod tests {
     #[test]
     fn test_cmd_agents_inject_custom_file_in_subdir() {
         let (_dir, paths) = create_paths();
-        std::fs::create_dir_all(paths.worktree_root.join(".github")).unwrap();
 
-        cmd_agents_inject(&paths, Some(".github/AGENTS.md")).unwrap();
+        cmd_agents_inject(&paths, Some(".github/AGENTS.md"), None).unwrap();
 
         let content =
             std::fs::read_to_string(paths.worktree_root.join(".github/AGENTS.md")).unwrap();
         assert!(content.contains(BLOCK_START));
     }
 
+    #[test]
+    fn test_cmd_agents_inject_uses_configured_file() {
+        let (_dir, paths) = create_paths();
+        let config = Config {
+            agents_file: Some(".github/copilot-instructions.md".to_string()),
+            ..Default::default()
+        };
+        config.save(&paths.config_path()).unwrap();
+
+        cmd_agents_inject(&paths, None, None).unwrap();
+
+        assert!(!paths.worktree_root.join("AGENTS.md").exists());
+        assert_eq!(
+            check_agents_block(&paths, &config),
+            Some((
+                ".github/copilot-instructions.md".to_string(),
+                AGENTS_BLOCK_VERSION
+            ))
+        );
+    }
+
+    #[test]
+    fn test_named_blocks_update_independently() {
+        let (_dir, paths) = create_paths();
+        let root = &paths.worktree_root;
+        let agents_dir = paths.braid_dir().join("agents");
+        std::fs::create_dir_all(&agents_dir).unwrap();
+        std::fs::write(agents_dir.join("testing.md"), "run cargo test\n").unwrap();
+        std::fs::write(agents_dir.join("workflow.md"), "small commits\n").unwrap();
+
+        cmd_agents_inject(&paths, None, None).unwrap();
+        cmd_agents_inject(&paths, None, Some("testing")).unwrap();
+        cmd_agents_inject(&paths, None, Some("workflow")).unwrap();
+
+        std::fs::write(agents_dir.join("testing.md"), "run cargo nextest\n").unwrap();
+        cmd_agents_inject(&paths, None, Some("testing")).unwrap();
+
+        let content = std::fs::read_to_string(root.join("AGENTS.md")).unwrap();
+        assert!(content.contains("run cargo nextest"));
+        assert!(!content.contains("run cargo test"));
+        assert!(content.contains("small commits"));
+        assert_eq!(content.matches("braid:block:testing:start").count(), 1);
+        // the unnamed block is still there and still detected
+        assert_eq!(extract_version(&content), Some(AGENTS_BLOCK_VERSION));
+
+        // refreshing the unnamed block leaves named blocks alone
+        cmd_agents_inject(&paths, None, None).unwrap();
+        let after = std::fs::read_to_string(root.join("AGENTS.md")).unwrap();
+        assert_eq!(after, content);
+    }
+
+    #[test]
+    fn test_named_block_starting_with_start() {
+        let (_dir, paths) = create_paths();
+        let root = &paths.worktree_root;
+        let agents_dir = paths.braid_dir().join("agents");
+        std::fs::create_dir_all(&agents_dir).unwrap();
+        std::fs::write(agents_dir.join("startup.md"), "boot the dev server\n").unwrap();
+
+        // the named block goes in first, so the unnamed block must not
+        // mistake it for itself
+        cmd_agents_inject(&paths, None, Some("startup")).unwrap();
+        cmd_agents_inject(&paths, None, None).unwrap();
+        cmd_agents_inject(&paths, None, None).unwrap();
+
+        let content = std::fs::read_to_string(root.join("AGENTS.md")).unwrap();
+        assert!(content.contains("boot the dev server"));
+        assert_eq!(content.matches(BLOCK_START).count(), 1);
+        assert_eq!(content.matches(BLOCK_END).count(), 1);
+        assert_eq!(extract_version(&content), Some(AGENTS_BLOCK_VERSION));
+    }
+
+    #[test]
+    fn test_named_block_errors() {
+        let (_dir, paths) = create_paths();
+        let err = cmd_agents_inject(&paths, None, Some("Bad Name")).unwrap_err();
+        assert!(err.to_string().contains("invalid block name"));
+
+        let err = cmd_agents_inject(&paths, None, Some("missing")).unwrap_err();
+        assert!(err.to_string().contains("no content for block 'missing'"));
+        assert!(!paths.worktree_root.join("AGENTS.md").exists());
+    }
+
     // ========================================================================
     // extract_issue_id_from_branch tests
     // ========================================================================
@@ -1239,7 +1666,7 @@ mod tests {
 
         let cli = test_cli();
         // Don't specify base - should use current branch (develop)
-        cmd_agent_init(&cli, &paths, "agent-dev", None).unwrap();
+        cmd_agent_init(&cli, &paths, "agent-dev", None, false).unwrap();
 
         let worktree_path = home_dir.path().join(".braid/worktrees/repo/agent-dev");
 
@@ -1264,11 +1691,11 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         };
 
         // This should succeed and produce JSON output (we can't easily capture stdout in unit tests)
-        let result = cmd_agent_init(&cli, &paths, "agent-json", Some(&base_branch));
+        let result = cmd_agent_init(&cli, &paths, "agent-json", Some(&base_branch), false);
         assert!(result.is_ok());
     }
 
@@ -1281,7 +1708,13 @@ mod tests {
         let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
 
         let cli = test_cli();
-        let result = cmd_agent_init(&cli, &paths, "agent_with_underscores", Some(&base_branch));
+        let result = cmd_agent_init(
+            &cli,
+            &paths,
+            "agent_with_underscores",
+            Some(&base_branch),
+            false,
+        );
         assert!(result.is_ok());
 
         let worktree_path = home_dir
@@ -1303,7 +1736,7 @@ mod tests {
         // Test various invalid names
         let invalid_names = ["agent@one", "agent/one", "agent.one", "agent:one"];
         for name in invalid_names {
-            let err = cmd_agent_init(&cli, &paths, name, Some(&base_branch)).unwrap_err();
+            let err = cmd_agent_init(&cli, &paths, name, Some(&base_branch), false).unwrap_err();
             assert!(
                 err.to_string().contains("invalid agent name"),
                 "expected error for name '{}', got: {}",
@@ -1334,7 +1767,7 @@ mod tests {
         let _env = EnvGuard::set("HOME", home_dir.path().to_str().unwrap());
 
         let cli = test_cli();
-        let result = cmd_agent_init(&cli, &paths, "sync-agent", Some(&base_branch));
+        let result = cmd_agent_init(&cli, &paths, "sync-agent", Some(&base_branch), false);
         assert!(result.is_ok());
 
         // Verify issues worktree was created
@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
 use std::process::{Command, Stdio};
 
 use time::OffsetDateTime;
@@ -35,6 +36,9 @@ pub fn cmd_agent_spawn(
 
     // ensure sessions directory exists
     let sessions_dir = paths.ensure_sessions_dir()?;
+    if let Some(retention) = config.session_retention()? {
+        reap_expired_sessions(&sessions_dir, retention)?;
+    }
 
     // generate session IDs
     let session_id = next_session_id(&sessions_dir);
@@ -98,7 +102,7 @@ pub fn cmd_agent_spawn(
         // run in background
         let child = cmd.spawn().map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
-                BrdError::Other(
+                BrdError::ToolNotFound(
                     "claude CLI not found - install from https://claude.ai/download".to_string(),
                 )
             } else {
@@ -202,7 +206,7 @@ pub fn cmd_agent_ps(cli: &Cli, paths: &RepoPaths, show_all: bool) -> Result<()>
 
 /// view agent session output.
 pub fn cmd_agent_logs(
-    _cli: &Cli,
+    cli: &Cli,
     paths: &RepoPaths,
     session_id: &str,
     follow: bool,
@@ -243,7 +247,7 @@ pub fn cmd_agent_logs(
     };
 
     // track tool_use events to correlate with tool_result
-    let mut tool_map: HashMap<String, ToolInfo> = HashMap::new();
+    let mut state = LogState::new(cli.verbose);
 
     for line in lines_to_show {
         if raw {
@@ -251,7 +255,7 @@ pub fn cmd_agent_logs(
         } else {
             // parse and pretty-print the JSON event
             if let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) {
-                print_event(&event, &mut tool_map);
+                print_event(&event, &mut state);
             } else {
                 println!("{}", line);
             }
@@ -277,13 +281,14 @@ pub fn cmd_agent_logs(
                     if !session.is_process_alive() {
                         break;
                     }
+                    std::io::stdout().flush().ok();
                     sleep(Duration::from_millis(100));
                 }
                 Ok(_) => {
                     if raw {
                         print!("{}", line);
                     } else if let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) {
-                        print_event(&event, &mut tool_map);
+                        print_event(&event, &mut state);
                     } else {
                         print!("{}", line);
                     }
@@ -294,6 +299,10 @@ pub fn cmd_agent_logs(
         }
     }
 
+    if let Some(line) = state.flush() {
+        print!("{}", line);
+    }
+
     Ok(())
 }
 
@@ -307,7 +316,7 @@ pub fn cmd_agent_send(cli: &Cli, paths: &RepoPaths, session_id: &str, message: &
 
     // agent must NOT be running to send a message via --resume
     if session.is_process_alive() {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Conflict(format!(
             "agent {} is still running (pid {}). use `brd agent kill {}` first.",
             session.session_id, session.pid, session.session_id
         )));
@@ -334,7 +343,7 @@ pub fn cmd_agent_send(cli: &Cli, paths: &RepoPaths, session_id: &str, message: &
         .status()
         .map_err(|e| {
             if e.kind() == std::io::ErrorKind::NotFound {
-                BrdError::Other(
+                BrdError::ToolNotFound(
                     "claude CLI not found - install from https://claude.ai/download".to_string(),
                 )
             } else {
@@ -368,7 +377,7 @@ pub fn cmd_agent_attach(_cli: &Cli, paths: &RepoPaths, session_id: &str) -> Resu
 
     // agent must NOT be running to attach via --resume
     if session.is_process_alive() {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Conflict(format!(
             "agent {} is still running (pid {}). use `brd agent kill {}` first.",
             session.session_id, session.pid, session.session_id
         )));
@@ -389,13 +398,26 @@ pub fn cmd_agent_attach(_cli: &Cli, paths: &RepoPaths, session_id: &str) -> Resu
 
 #[cfg(not(unix))]
 pub fn cmd_agent_attach(_cli: &Cli, _paths: &RepoPaths, _session_id: &str) -> Result<()> {
-    Err(BrdError::Other(
+    Err(BrdError::Unsupported(
         "agent attach is not supported on this platform".to_string(),
     ))
 }
 
-/// terminate a running agent.
-pub fn cmd_agent_kill(cli: &Cli, paths: &RepoPaths, session_id: &str, force: bool) -> Result<()> {
+/// terminate a running agent, or every live one with `all`.
+pub fn cmd_agent_kill(
+    cli: &Cli,
+    paths: &RepoPaths,
+    session_id: Option<&str>,
+    all: bool,
+    force: bool,
+) -> Result<()> {
+    if all {
+        return kill_all_sessions(cli, paths, force);
+    }
+    let session_id = session_id.ok_or_else(|| {
+        BrdError::InvalidInput("specify a session ID, or --all to kill every agent".to_string())
+    })?;
+
     let sessions_dir = paths.sessions_dir();
     let mut session = find_session(&sessions_dir, session_id)?;
 
@@ -414,7 +436,98 @@ pub fn cmd_agent_kill(cli: &Cli, paths: &RepoPaths, session_id: &str, force: boo
         return Ok(());
     }
 
-    // send signal
+    signal_session(&sessions_dir, &mut session, force)?;
+
+    if cli.json {
+        println!(
+            r#"{{"ok": true, "session_id": "{}", "signal": "{}"}}"#,
+            session.session_id,
+            signal_name(force)
+        );
+    } else {
+        println!(
+            "killed {} (pid {}) with {}",
+            session.session_id,
+            session.pid,
+            signal_name(force)
+        );
+    }
+
+    Ok(())
+}
+
+/// signal every live session, reporting failures without stopping. fails
+/// once all sessions were tried if any of them could not be signalled.
+fn kill_all_sessions(cli: &Cli, paths: &RepoPaths, force: bool) -> Result<()> {
+    let sessions_dir = paths.sessions_dir();
+    let mut killed = Vec::new();
+    let mut failed = Vec::new();
+
+    for mut session in load_all_sessions(&sessions_dir)? {
+        if !session.is_process_alive() {
+            continue;
+        }
+        match signal_session(&sessions_dir, &mut session, force) {
+            Ok(()) => {
+                if !cli.json {
+                    println!("killed {} (pid {})", session.session_id, session.pid);
+                }
+                killed.push(session.session_id);
+            }
+            Err(e) => {
+                if !cli.json {
+                    eprintln!("warning: {}: {}", session.session_id, e);
+                }
+                failed.push(session.session_id);
+            }
+        }
+    }
+
+    if cli.json {
+        let json = serde_json::json!({
+            "ok": failed.is_empty(),
+            "signal": signal_name(force),
+            "killed": killed,
+            "failed": failed,
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+    } else if killed.is_empty() && failed.is_empty() {
+        println!("no running agents");
+    } else {
+        println!(
+            "\nkilled {} agent(s) with {}{}",
+            killed.len(),
+            signal_name(force),
+            if failed.is_empty() {
+                String::new()
+            } else {
+                format!(", {} failed", failed.len())
+            }
+        );
+    }
+
+    if failed.is_empty() {
+        return Ok(());
+    }
+    let err = BrdError::Other(format!(
+        "failed to signal {} agent(s): {}",
+        failed.len(),
+        failed.join(", ")
+    ));
+    if cli.json {
+        // the json document above already carries the failures
+        return Err(BrdError::Reported(Box::new(err)));
+    }
+    Err(err)
+}
+
+fn signal_name(force: bool) -> &'static str {
+    if force { "SIGKILL" } else { "SIGTERM" }
+}
+
+/// send SIGTERM (or SIGKILL with `force`) to a session's process and mark it
+/// killed.
+fn signal_session(sessions_dir: &Path, session: &mut Session, force: bool) -> Result<()> {
     #[cfg(unix)]
     {
         let signal = if force { libc::SIGKILL } else { libc::SIGTERM };
@@ -431,57 +544,86 @@ pub fn cmd_agent_kill(cli: &Cli, paths: &RepoPaths, session_id: &str, force: boo
 
     #[cfg(not(unix))]
     {
-        return Err(BrdError::Other(
+        let _ = force;
+        return Err(BrdError::Unsupported(
             "kill not implemented on this platform".to_string(),
         ));
     }
 
     // update session state
     session.status = SessionStatus::Killed;
-    let state_path = Session::state_path(&sessions_dir, &session.session_id);
+    let state_path = Session::state_path(sessions_dir, &session.session_id);
     session.save(&state_path)?;
+    Ok(())
+}
 
-    if cli.json {
-        println!(
-            r#"{{"ok": true, "session_id": "{}", "signal": "{}"}}"#,
-            session.session_id,
-            if force { "SIGKILL" } else { "SIGTERM" }
-        );
-    } else {
-        println!(
-            "killed {} (pid {}) with {}",
-            session.session_id,
-            session.pid,
-            if force { "SIGKILL" } else { "SIGTERM" }
-        );
+/// stale = not running or waiting.
+fn is_stale(session: &Session) -> bool {
+    matches!(
+        session.status,
+        SessionStatus::Completed
+            | SessionStatus::Failed
+            | SessionStatus::Killed
+            | SessionStatus::Zombie
+    )
+}
+
+/// the sessions `brd agent clean` removes: stale ones (or all with `all`),
+/// limited to those started more than `older_than` before `now`.
+fn sessions_to_clean(
+    sessions: &[Session],
+    all: bool,
+    older_than: Option<time::Duration>,
+    now: OffsetDateTime,
+) -> Vec<&Session> {
+    sessions
+        .iter()
+        .filter(|s| all || is_stale(s))
+        .filter(|s| older_than.is_none_or(|age| now - s.started_at > age))
+        .collect()
+}
+
+/// remove a session's state, log, and stdin files, warning on failures.
+fn remove_session_files(sessions_dir: &Path, session_id: &str) {
+    let state_path = Session::state_path(sessions_dir, session_id);
+    let log_path = Session::log_path(sessions_dir, session_id);
+    let stdin_path = Session::stdin_path(sessions_dir, session_id);
+
+    // remove each file if it exists
+    for path in [&state_path, &log_path, &stdin_path] {
+        if path.exists()
+            && let Err(e) = std::fs::remove_file(path)
+        {
+            eprintln!("warning: failed to remove {}: {}", path.display(), e);
+        }
     }
+}
 
-    Ok(())
+/// silently remove stale sessions older than the configured
+/// `session_retention`. returns how many were removed.
+fn reap_expired_sessions(sessions_dir: &Path, retention: time::Duration) -> Result<usize> {
+    let sessions = load_all_sessions(sessions_dir)?;
+    let expired = sessions_to_clean(&sessions, false, Some(retention), OffsetDateTime::now_utc());
+    for s in &expired {
+        remove_session_files(sessions_dir, &s.session_id);
+    }
+    Ok(expired.len())
 }
 
 /// remove stale agent session files.
-pub fn cmd_agent_clean(cli: &Cli, paths: &RepoPaths, all: bool, force: bool) -> Result<()> {
+pub fn cmd_agent_clean(
+    cli: &Cli,
+    paths: &RepoPaths,
+    all: bool,
+    older_than: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let older_than = older_than.map(crate::date::parse_duration).transpose()?;
     let sessions_dir = paths.sessions_dir();
     let sessions = load_all_sessions(&sessions_dir)?;
 
     // find sessions to clean
-    let to_clean: Vec<_> = sessions
-        .iter()
-        .filter(|s| {
-            if all {
-                true
-            } else {
-                // stale = not running or waiting
-                matches!(
-                    s.status,
-                    SessionStatus::Completed
-                        | SessionStatus::Failed
-                        | SessionStatus::Killed
-                        | SessionStatus::Zombie
-                )
-            }
-        })
-        .collect();
+    let to_clean = sessions_to_clean(&sessions, all, older_than, OffsetDateTime::now_utc());
 
     if to_clean.is_empty() {
         if cli.json {
@@ -519,18 +661,7 @@ pub fn cmd_agent_clean(cli: &Cli, paths: &RepoPaths, all: bool, force: bool) ->
     // remove session files
     let mut cleaned = 0;
     for s in &to_clean {
-        let state_path = Session::state_path(&sessions_dir, &s.session_id);
-        let log_path = Session::log_path(&sessions_dir, &s.session_id);
-        let stdin_path = Session::stdin_path(&sessions_dir, &s.session_id);
-
-        // remove each file if it exists
-        for path in [&state_path, &log_path, &stdin_path] {
-            if path.exists()
-                && let Err(e) = std::fs::remove_file(path)
-            {
-                eprintln!("warning: failed to remove {}: {}", path.display(), e);
-            }
-        }
+        remove_session_files(&sessions_dir, &s.session_id);
         cleaned += 1;
 
         if !cli.json {
@@ -567,6 +698,103 @@ struct ToolInfo {
     summary: String,
 }
 
+/// consecutive results from the same tool with the same outcome.
+#[derive(Debug, Clone, PartialEq)]
+struct ResultRun {
+    name: String,
+    is_error: bool,
+    count: usize,
+    bytes: usize,
+}
+
+impl ResultRun {
+    /// `  → Read: ok ×12 (N bytes)`, without the count for a single result.
+    fn line(&self) -> String {
+        let outcome = if self.is_error { "error" } else { "ok" };
+        if self.count == 1 {
+            format!("  → {}: {} ({} bytes)", self.name, outcome, self.bytes)
+        } else {
+            format!(
+                "  → {}: {} ×{} ({} bytes)",
+                self.name, outcome, self.count, self.bytes
+            )
+        }
+    }
+}
+
+/// state carried across events while printing a log.
+///
+/// without `--verbose`, tool results are held back and folded into one line
+/// per run of the same tool, so an agent reading forty files doesn't bury its
+/// own text. the run ends when anything else is printed.
+struct LogState {
+    tool_map: HashMap<String, ToolInfo>,
+    run: Option<ResultRun>,
+    verbose: bool,
+}
+
+impl LogState {
+    fn new(verbose: bool) -> Self {
+        Self {
+            tool_map: HashMap::new(),
+            run: None,
+            verbose,
+        }
+    }
+
+    /// record a tool result. returns the output to print now: the result
+    /// itself when verbose, or the previous run when this one starts a new run.
+    fn push_result(&mut self, name: &str, is_error: bool, bytes: usize) -> Option<String> {
+        let result = ResultRun {
+            name: name.to_string(),
+            is_error,
+            count: 1,
+            bytes,
+        };
+        if self.verbose {
+            return Some(format!("{}\n", result.line()));
+        }
+        if let Some(run) = &mut self.run
+            && run.name == name
+            && run.is_error == is_error
+        {
+            run.count += 1;
+            run.bytes += bytes;
+            return None;
+        }
+        let flushed = self.flush();
+        self.run = Some(result);
+        flushed
+    }
+
+    /// end the pending run, if any. a tool call of the same kind doesn't end
+    /// it, so `[Read] a`, `[Read] b` lines still fold their results together.
+    fn flush_unless_tool(&mut self, tool: &str) -> Option<String> {
+        match &self.run {
+            Some(run) if run.name == tool => None,
+            _ => self.flush(),
+        }
+    }
+
+    /// end the pending run, returning its summary line and trailing blank line.
+    fn flush(&mut self) -> Option<String> {
+        self.run.take().map(|run| format!("{}\n\n", run.line()))
+    }
+
+    /// remember a tool call so its result can be named later.
+    fn track_tool(&mut self, id: &str, name: &str, summary: &str) {
+        if !id.is_empty() {
+            self.tool_map.insert(
+                id.to_string(),
+                ToolInfo {
+                    name: name.to_string(),
+                    summary: summary.to_string(),
+                },
+            );
+        }
+    }
+}
+
 /// extract a summary of what a tool is doing from its input.
 fn tool_summary(name: &str, input: &serde_json::Value) -> String {
     match name {
@@ -619,10 +847,11 @@ fn tool_summary(name: &str, input: &serde_json::Value) -> String {
 
 /// truncate a string to max length, adding "..." if truncated.
 fn truncate_str(s: &str, max: usize) -> String {
-    if s.len() <= max {
+    if s.chars().count() <= max {
         s.to_string()
     } else {
-        format!("{}...", &s[..max.saturating_sub(3)])
+        let kept: String = s.chars().take(max.saturating_sub(3)).collect();
+        format!("{}...", kept)
     }
 }
 
@@ -638,13 +867,21 @@ fn short_path(path: &str) -> String {
 
 /// pretty-print a claude stream-json event.
 ///
-/// `tool_map` tracks tool_use events by ID so we can correlate results.
-fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInfo>) {
+/// `state` tracks tool_use events by ID so we can correlate results, and
+/// holds back runs of tool results until they can be summarized.
+fn print_event(event: &serde_json::Value, state: &mut LogState) {
     let event_type = event
         .get("type")
         .and_then(|v| v.as_str())
         .unwrap_or("unknown");
 
+    // anything printed other than a tool call or result ends a run of results
+    let flush = |state: &mut LogState| {
+        if let Some(line) = state.flush() {
+            print!("{}", line);
+        }
+    };
+
     match event_type {
         "assistant" => {
             if let Some(message) = event.get("message")
@@ -655,6 +892,7 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
                     match item_type {
                         Some("text") => {
                             if let Some(text) = item.get("text").and_then(|t| t.as_str()) {
+                                flush(state);
                                 println!("{}", text);
                             }
                         }
@@ -663,25 +901,7 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
                             if let Some(name) = item.get("name").and_then(|n| n.as_str()) {
                                 let id = item.get("id").and_then(|i| i.as_str()).unwrap_or("");
                                 let input = item.get("input").unwrap_or(&serde_json::Value::Null);
-                                let summary = tool_summary(name, input);
-
-                                // store for later lookup
-                                if !id.is_empty() {
-                                    tool_map.insert(
-                                        id.to_string(),
-                                        ToolInfo {
-                                            name: name.to_string(),
-                                            summary: summary.clone(),
-                                        },
-                                    );
-                                }
-
-                                // print tool call with context
-                                if summary.is_empty() {
-                                    println!("[{}]", name);
-                                } else {
-                                    println!("[{}] {}", name, summary);
-                                }
+                                print_tool_use(state, id, name, input);
                             }
                         }
                         _ => {}
@@ -693,6 +913,7 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
             if let Some(delta) = event.get("delta")
                 && let Some(text) = delta.get("text").and_then(|t| t.as_str())
             {
+                flush(state);
                 print!("{}", text);
                 std::io::stdout().flush().ok();
             }
@@ -702,30 +923,13 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
             if let Some(name) = event.get("name").and_then(|n| n.as_str()) {
                 let id = event.get("id").and_then(|i| i.as_str()).unwrap_or("");
                 let input = event.get("input").unwrap_or(&serde_json::Value::Null);
-                let summary = tool_summary(name, input);
-
-                // store for later lookup
-                if !id.is_empty() {
-                    tool_map.insert(
-                        id.to_string(),
-                        ToolInfo {
-                            name: name.to_string(),
-                            summary: summary.clone(),
-                        },
-                    );
-                }
-
-                // print tool call with context
-                if summary.is_empty() {
-                    println!("[{}]", name);
-                } else {
-                    println!("[{}] {}", name, summary);
-                }
+                print_tool_use(state, id, name, input);
             }
         }
         "tool_result" => {
             // standalone tool_result events (rare, but handle them)
             if let Some(name) = event.get("name").and_then(|n| n.as_str()) {
+                flush(state);
                 println!("[{}]", name);
             }
         }
@@ -735,6 +939,7 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
                 .and_then(|e| e.get("message"))
                 .and_then(|m| m.as_str())
             {
+                flush(state);
                 eprintln!("error: {}", msg);
             }
         }
@@ -743,6 +948,7 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
         }
         "result" => {
             // end of turn - add blank line for readability
+            flush(state);
             println!();
         }
         "user" => {
@@ -767,20 +973,21 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
                             .unwrap_or(0);
 
                         // look up tool name from earlier tool_use event
-                        let tool_name = tool_map
+                        let tool_name = state
+                            .tool_map
                             .get(tool_use_id)
-                            .map(|info| info.name.as_str())
-                            .unwrap_or("?");
+                            .map(|info| info.name.clone())
+                            .unwrap_or_else(|| "?".to_string());
 
-                        if is_error {
-                            println!("  → {}: error ({} bytes)", tool_name, content_len);
-                        } else {
-                            println!("  → {}: ok ({} bytes)", tool_name, content_len);
+                        if let Some(line) = state.push_result(&tool_name, is_error, content_len) {
+                            print!("{}", line);
                         }
                     }
                 }
-                // blank line after tool results for visual separation
-                println!();
+                if state.verbose {
+                    // blank line after tool results for visual separation
+                    println!();
+                }
             }
         }
         "system" => {
@@ -788,11 +995,30 @@ fn print_event(event: &serde_json::Value, tool_map: &mut HashMap<String, ToolInf
         }
         _ => {
             // unknown event type, print type for debugging
+            flush(state);
             println!("[{}]", event_type);
         }
     }
 }
 
+/// track and print a tool call. a call to the same tool as the pending run of
+/// results keeps that run going.
+fn print_tool_use(state: &mut LogState, id: &str, name: &str, input: &serde_json::Value) {
+    let summary = tool_summary(name, input);
+    state.track_tool(id, name, &summary);
+
+    if let Some(line) = state.flush_unless_tool(name) {
+        print!("{}", line);
+    }
+
+    // print tool call with context
+    if summary.is_empty() {
+        println!("[{}]", name);
+    } else {
+        println!("[{}] {}", name, summary);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1170,6 +1396,28 @@ mod tests {
         assert_eq!(format_event(&event), "");
     }
 
+    // =========================================================================
+    // truncate_str tests
+    // =========================================================================
+
+    #[test]
+    fn test_truncate_str_ascii() {
+        assert_eq!(truncate_str("short", 10), "short");
+        assert_eq!(truncate_str("a longer command", 10), "a longe...");
+    }
+
+    #[test]
+    fn test_truncate_str_multibyte_boundaries() {
+        // every cut point lands inside or next to a multibyte char somewhere
+        let s = "grep 'café' — 🎉 done ✓";
+        for max in 0..=s.chars().count() + 1 {
+            let out = truncate_str(s, max);
+            assert!(out.chars().count() <= max.max(3), "max {max}: {out}");
+        }
+        assert_eq!(truncate_str(s, 11), "grep 'ca...");
+        assert_eq!(truncate_str(s, 18), "grep 'café' — 🎉...");
+    }
+
     // =========================================================================
     // cmd_agent_ps tests
     // =========================================================================
@@ -1211,6 +1459,60 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    // =========================================================================
+    // tool result collapsing tests
+    // =========================================================================
+
+    #[test]
+    fn test_log_state_collapses_consecutive_results() {
+        let mut state = LogState::new(false);
+        assert_eq!(state.push_result("Read", false, 100), None);
+        assert_eq!(state.flush_unless_tool("Read"), None);
+        assert_eq!(state.push_result("Read", false, 50), None);
+        assert_eq!(state.push_result("Read", false, 25), None);
+
+        // a different tool ends the run and starts a new one
+        assert_eq!(
+            state.flush_unless_tool("Bash").as_deref(),
+            Some("  → Read: ok ×3 (175 bytes)\n\n")
+        );
+        assert_eq!(state.push_result("Bash", false, 10), None);
+        assert_eq!(
+            state.flush().as_deref(),
+            Some("  → Bash: ok (10 bytes)\n\n")
+        );
+        assert_eq!(state.flush(), None);
+    }
+
+    #[test]
+    fn test_log_state_errors_break_runs() {
+        let mut state = LogState::new(false);
+        assert_eq!(state.push_result("Read", false, 10), None);
+        assert_eq!(
+            state.push_result("Read", true, 5).as_deref(),
+            Some("  → Read: ok (10 bytes)\n\n")
+        );
+        assert_eq!(state.push_result("Read", true, 5), None);
+        assert_eq!(
+            state.flush().as_deref(),
+            Some("  → Read: error ×2 (10 bytes)\n\n")
+        );
+    }
+
+    #[test]
+    fn test_log_state_verbose_prints_every_result() {
+        let mut state = LogState::new(true);
+        assert_eq!(
+            state.push_result("Read", false, 10).as_deref(),
+            Some("  → Read: ok (10 bytes)\n")
+        );
+        assert_eq!(
+            state.push_result("Read", false, 20).as_deref(),
+            Some("  → Read: ok (20 bytes)\n")
+        );
+        assert_eq!(state.flush(), None);
+    }
+
     // =========================================================================
     // cmd_agent_logs tests
     // =========================================================================
@@ -1267,7 +1569,7 @@ mod tests {
         let repo = TestRepo::default();
         let cli = test_cli();
 
-        let result = cmd_agent_kill(&cli, &repo.paths, "nonexistent", false);
+        let result = cmd_agent_kill(&cli, &repo.paths, Some("nonexistent"), false, false);
         assert!(result.is_err());
         assert!(
             result
@@ -1298,10 +1600,136 @@ mod tests {
             .unwrap();
 
         // should succeed (reports already dead)
-        let result = cmd_agent_kill(&cli, &repo.paths, "agent-1", false);
+        let result = cmd_agent_kill(&cli, &repo.paths, Some("agent-1"), false, false);
         assert!(result.is_ok());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_agent_kill_all_signals_live_sessions() {
+        let repo = TestRepo::default();
+        let cli = test_cli();
+        let sessions_dir = repo.paths.ensure_sessions_dir().unwrap();
+
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        for (id, pid) in [("agent-1", child.id()), ("agent-2", 99999)] {
+            Session::new(
+                id.to_string(),
+                format!("uuid-{}", id),
+                pid,
+                "brd-test".to_string(),
+                None,
+                1.0,
+                "claude-test".to_string(),
+            )
+            .save(&Session::state_path(&sessions_dir, id))
+            .unwrap();
+        }
+
+        cmd_agent_kill(&cli, &repo.paths, None, true, false).unwrap();
+        child.wait().unwrap();
+
+        // the live session is killed, the dead one is left alone
+        let live = find_session(&sessions_dir, "agent-1").unwrap();
+        assert_eq!(live.status, SessionStatus::Killed);
+        let dead = find_session(&sessions_dir, "agent-2").unwrap();
+        assert_ne!(dead.status, SessionStatus::Killed);
+    }
+
+    #[test]
+    fn test_agent_kill_requires_session_or_all() {
+        let repo = TestRepo::default();
+        let cli = test_cli();
+
+        let err = cmd_agent_kill(&cli, &repo.paths, None, false, false).unwrap_err();
+        assert!(err.to_string().contains("--all"));
+    }
+
+    // =========================================================================
+    // cmd_agent_clean tests
+    // =========================================================================
+
+    fn session_started(id: &str, status: SessionStatus, days_ago: i64) -> Session {
+        let mut session = Session::new(
+            id.to_string(),
+            format!("uuid-{}", id),
+            99999,
+            "brd-test".to_string(),
+            None,
+            1.0,
+            "claude-test".to_string(),
+        );
+        session.status = status;
+        session.started_at = OffsetDateTime::now_utc() - time::Duration::days(days_ago);
+        session
+    }
+
+    #[test]
+    fn test_sessions_to_clean_older_than() {
+        let sessions = vec![
+            session_started("agent-1", SessionStatus::Completed, 10),
+            session_started("agent-2", SessionStatus::Failed, 1),
+            session_started("agent-3", SessionStatus::Running, 10),
+        ];
+        let now = OffsetDateTime::now_utc();
+        let ids = |picked: Vec<&Session>| -> Vec<String> {
+            picked.iter().map(|s| s.session_id.clone()).collect()
+        };
+
+        assert_eq!(
+            ids(sessions_to_clean(&sessions, false, None, now)),
+            vec!["agent-1", "agent-2"]
+        );
+        let week = Some(time::Duration::days(7));
+        assert_eq!(
+            ids(sessions_to_clean(&sessions, false, week, now)),
+            vec!["agent-1"]
+        );
+        assert_eq!(
+            ids(sessions_to_clean(&sessions, true, week, now)),
+            vec!["agent-1", "agent-3"]
+        );
+    }
+
+    #[test]
+    fn test_reap_expired_sessions_keeps_recent_and_running() {
+        let repo = TestRepo::default();
+        let sessions_dir = repo.paths.ensure_sessions_dir().unwrap();
+        for session in [
+            session_started("agent-1", SessionStatus::Completed, 30),
+            session_started("agent-2", SessionStatus::Completed, 1),
+            session_started("agent-3", SessionStatus::Running, 30),
+        ] {
+            // a live pid, so the running session isn't downgraded to a zombie
+            let mut session = session;
+            session.pid = std::process::id();
+            let id = session.session_id.clone();
+            session
+                .save(&Session::state_path(&sessions_dir, &id))
+                .unwrap();
+            fs::write(Session::log_path(&sessions_dir, &id), "{}").unwrap();
+        }
+
+        let reaped = reap_expired_sessions(&sessions_dir, time::Duration::days(14)).unwrap();
+        assert_eq!(reaped, 1);
+        assert!(!Session::state_path(&sessions_dir, "agent-1").exists());
+        assert!(!Session::log_path(&sessions_dir, "agent-1").exists());
+        assert!(Session::state_path(&sessions_dir, "agent-2").exists());
+        assert!(Session::state_path(&sessions_dir, "agent-3").exists());
+    }
+
+    #[test]
+    fn test_agent_clean_rejects_bad_duration() {
+        let repo = TestRepo::default();
+        let cli = test_cli();
+
+        let err = cmd_agent_clean(&cli, &repo.paths, false, Some("soon"), true).unwrap_err();
+        assert!(err.to_string().contains("duration"));
+    }
+
     // =========================================================================
     // cmd_agent_spawn tests
     // =========================================================================
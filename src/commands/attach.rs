@@ -0,0 +1,161 @@
+//! brd attach command - reference repo files from an issue.
+
+use std::path::{Component, Path};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::issue::Issue;
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+use super::{issue_to_json, load_all_issues, resolve_issue_id};
+
+/// resolve `path` (relative to `cwd`) to an existing file inside `root`,
+/// returned relative to `root` with `/` separators.
+fn repo_relative(root: &Path, cwd: &Path, path: &str) -> Result<String> {
+    let full = cwd.join(path);
+    let full = full
+        .canonicalize()
+        .map_err(|_| BrdError::InvalidInput(format!("no such file: {}", path)))?;
+    let root = root.canonicalize()?;
+    let rel = full
+        .strip_prefix(&root)
+        .map_err(|_| BrdError::InvalidInput(format!("{} is outside the repository", path)))?;
+    let parts: Vec<String> = rel
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    if parts.is_empty() {
+        return Err(BrdError::InvalidInput(
+            "attach a file or directory inside the repository, not its root".to_string(),
+        ));
+    }
+    Ok(parts.join("/"))
+}
+
+/// add `path` to the issue's attachments. returns false if already attached.
+fn attach_path(issue: &mut Issue, path: &str) -> bool {
+    if issue.frontmatter.attachments.iter().any(|p| p == path) {
+        return false;
+    }
+    issue.frontmatter.attachments.push(path.to_string());
+    true
+}
+
+pub fn cmd_attach(cli: &Cli, paths: &RepoPaths, id: &str, path: &str) -> Result<()> {
+    let rel = repo_relative(&paths.worktree_root, &std::env::current_dir()?, path)?;
+
+    let config = Config::load(&paths.config_path())?;
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    let mut issues = load_all_issues(paths, &config)?;
+    let full_id = resolve_issue_id(id, &issues)?;
+
+    let issue = issues
+        .get_mut(&full_id)
+        .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
+    let attached = attach_path(issue, &rel);
+    if attached {
+        issue.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
+    }
+
+    if cli.json {
+        let json = serde_json::json!({
+            "path": rel,
+            "attached": attached,
+            "issue": issue_to_json(&issues[&full_id], &issues, &config.ready_includes),
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else if attached {
+        println!("attached {} to {}", rel, full_id);
+    } else {
+        println!("{} already attached to {}", rel, full_id);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    #[test]
+    fn test_repo_relative_paths() {
+        let repo = TestRepo::builder().build();
+        let root = &repo.paths.worktree_root;
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/design.md"), "design\n").unwrap();
+
+        assert_eq!(
+            repo_relative(root, root, "docs/design.md").unwrap(),
+            "docs/design.md"
+        );
+        // relative to a subdirectory, with `..` normalized away
+        let docs = root.join("docs");
+        assert_eq!(
+            repo_relative(root, &docs, "../docs/./design.md").unwrap(),
+            "docs/design.md"
+        );
+
+        let err = repo_relative(root, root, "docs/missing.png").unwrap_err();
+        assert!(err.to_string().contains("no such file"));
+        assert!(repo_relative(root, root, ".").is_err());
+
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::write(outside.path().join("x.png"), "").unwrap();
+        let err = repo_relative(root, outside.path(), "x.png").unwrap_err();
+        assert!(err.to_string().contains("outside the repository"));
+    }
+
+    #[test]
+    fn test_attach_records_path_once() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-abc1").create();
+        let root = &repo.paths.worktree_root;
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        let doc = root.join("docs/design.md");
+        std::fs::write(&doc, "design\n").unwrap();
+
+        let cli = test_cli();
+        let doc = doc.to_string_lossy();
+        cmd_attach(&cli, &repo.paths, "abc1", &doc).unwrap();
+        cmd_attach(&cli, &repo.paths, "abc1", &doc).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(
+            issues["brd-abc1"].frontmatter.attachments,
+            vec!["docs/design.md"]
+        );
+    }
+
+    #[test]
+    fn test_attach_missing_file_fails() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-abc1").create();
+        let missing = repo.paths.worktree_root.join("nope.png");
+
+        let result = cmd_attach(
+            &test_cli(),
+            &repo.paths,
+            "brd-abc1",
+            &missing.to_string_lossy(),
+        );
+        assert!(matches!(result, Err(BrdError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_issue_without_attachments_round_trips() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-abc1").create();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues["brd-abc1"].frontmatter.attachments.is_empty());
+        let issue_path = repo.paths.issues_dir(&repo.config).join("brd-abc1.md");
+        let content = std::fs::read_to_string(issue_path).unwrap();
+        assert!(!content.contains("attachments"));
+    }
+}
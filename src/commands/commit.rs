@@ -13,8 +13,9 @@ pub fn cmd_commit(cli: &Cli, paths: &RepoPaths, message: Option<&str>) -> Result
 
     // check if .braid directory exists
     if !braid_dir.exists() {
-        return Err(BrdError::Other("no .braid directory found".to_string()));
+        return Err(BrdError::Config("no .braid directory found".to_string()));
     }
+    crate::git::require_branch(&paths.worktree_root)?;
 
     // stage all .braid changes
     verbose!(cli, "staging .braid changes");
@@ -25,7 +26,7 @@ pub fn cmd_commit(cli: &Cli, paths: &RepoPaths, message: Option<&str>) -> Result
 
     if !add_output.status.success() {
         let stderr = String::from_utf8_lossy(&add_output.stderr);
-        return Err(BrdError::Other(format!("git add failed: {}", stderr)));
+        return Err(BrdError::Git(format!("git add failed: {}", stderr)));
     }
 
     // check if there are staged changes in .braid
@@ -53,14 +54,15 @@ pub fn cmd_commit(cli: &Cli, paths: &RepoPaths, message: Option<&str>) -> Result
     verbose!(cli, "committing with message: {}", commit_msg);
 
     // commit only .braid changes (not other staged files)
-    let commit_output = Command::new("git")
-        .args(["commit", "-m", &commit_msg, "--", ".braid"])
-        .current_dir(&paths.worktree_root)
-        .output()?;
+    let commit_output = crate::git::command(
+        &["commit", "-m", &commit_msg, "--", ".braid"],
+        &paths.worktree_root,
+    )
+    .output()?;
 
     if !commit_output.status.success() {
         let stderr = String::from_utf8_lossy(&commit_output.stderr);
-        return Err(BrdError::Other(format!("git commit failed: {}", stderr)));
+        return Err(BrdError::Git(format!("git commit failed: {}", stderr)));
     }
 
     if cli.json {
@@ -211,7 +213,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -1,34 +1,44 @@
 //! brd completions command.
 
-use crate::cli::Cli;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use clap_complete_nushell::Nushell;
+
+use crate::cli::{Cli, CompletionShell};
 use crate::error::Result;
 
-pub fn cmd_completions(shell: clap_complete::Shell) -> Result<()> {
-    use clap::CommandFactory;
-    clap_complete::generate(shell, &mut Cli::command(), "brd", &mut std::io::stdout());
-    Ok(())
+pub fn cmd_completions(shell: CompletionShell) -> Result<()> {
+    generate_completions_to(shell, &mut std::io::stdout())
 }
 
-/// Generate completions to a writer (for testing).
-#[cfg(test)]
+/// Generate completions to a writer (split out for testing).
 fn generate_completions_to<W: std::io::Write>(
-    shell: clap_complete::Shell,
+    shell: CompletionShell,
     writer: &mut W,
 ) -> Result<()> {
-    use clap::CommandFactory;
-    clap_complete::generate(shell, &mut Cli::command(), "brd", writer);
+    let mut cmd = Cli::command();
+    match shell {
+        CompletionShell::Bash => clap_complete::generate(Shell::Bash, &mut cmd, "brd", writer),
+        CompletionShell::Elvish => clap_complete::generate(Shell::Elvish, &mut cmd, "brd", writer),
+        CompletionShell::Fish => clap_complete::generate(Shell::Fish, &mut cmd, "brd", writer),
+        CompletionShell::Nushell => clap_complete::generate(Nushell, &mut cmd, "brd", writer),
+        CompletionShell::Powershell => {
+            clap_complete::generate(Shell::PowerShell, &mut cmd, "brd", writer)
+        }
+        CompletionShell::Zsh => clap_complete::generate(Shell::Zsh, &mut cmd, "brd", writer),
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use clap_complete::Shell;
+    use clap::ValueEnum;
 
     #[test]
     fn test_generate_bash_completions() {
         let mut output = Vec::new();
-        let result = generate_completions_to(Shell::Bash, &mut output);
+        let result = generate_completions_to(CompletionShell::Bash, &mut output);
         assert!(result.is_ok());
         assert!(!output.is_empty());
         let content = String::from_utf8_lossy(&output);
@@ -38,7 +48,7 @@ mod tests {
     #[test]
     fn test_generate_zsh_completions() {
         let mut output = Vec::new();
-        let result = generate_completions_to(Shell::Zsh, &mut output);
+        let result = generate_completions_to(CompletionShell::Zsh, &mut output);
         assert!(result.is_ok());
         assert!(!output.is_empty());
         let content = String::from_utf8_lossy(&output);
@@ -48,17 +58,31 @@ mod tests {
     #[test]
     fn test_generate_fish_completions() {
         let mut output = Vec::new();
-        let result = generate_completions_to(Shell::Fish, &mut output);
+        let result = generate_completions_to(CompletionShell::Fish, &mut output);
         assert!(result.is_ok());
         assert!(!output.is_empty());
         let content = String::from_utf8_lossy(&output);
         assert!(content.contains("brd"));
     }
 
+    #[test]
+    fn test_generate_every_supported_shell() {
+        for &shell in CompletionShell::value_variants() {
+            let mut output = Vec::new();
+            generate_completions_to(shell, &mut output).unwrap();
+            let content = String::from_utf8_lossy(&output);
+            assert!(
+                content.contains("brd"),
+                "{:?} completions mention brd",
+                shell
+            );
+        }
+    }
+
     #[test]
     fn test_completions_contain_subcommands() {
         let mut output = Vec::new();
-        generate_completions_to(Shell::Bash, &mut output).unwrap();
+        generate_completions_to(CompletionShell::Bash, &mut output).unwrap();
         let content = String::from_utf8_lossy(&output);
 
         // Check that common subcommands are in the completions
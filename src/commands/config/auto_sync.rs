@@ -8,7 +8,7 @@ use crate::repo::RepoPaths;
 
 /// Set auto-sync (auto_pull and auto_push) on or off.
 pub fn cmd_config_auto_sync(cli: &Cli, paths: &RepoPaths, enabled: bool) -> Result<()> {
-    let mut config = Config::load(&paths.config_path())?;
+    let mut config = Config::load_file(&paths.config_path())?;
 
     let already_set = config.auto_pull == enabled && config.auto_push == enabled;
     if already_set {
@@ -1,31 +1,46 @@
 //! brd config external-repo - set or clear the external-repo setting.
 
-use crate::cli::Cli;
+use std::path::Path;
+
+use crate::cli::{Cli, ExternalRepoArgs};
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::git;
 use crate::repo::RepoPaths;
 
-use super::{confirm, count_issues, find_agent_worktrees_needing_rebase, warn_agent_worktrees};
+use super::{
+    MovedIssues, confirm, count_issues, find_agent_worktrees_needing_rebase, move_issue_files,
+    warn_agent_worktrees,
+};
 
 /// Set or clear the external-repo setting.
+/// An external repo with the same id_prefix is refused unless
+/// `allow_same_prefix`. With `migrate`, local issues are moved into the
+/// external repo; ids it already has are skipped unless `force` overwrites them.
 pub fn cmd_config_external_repo(
     cli: &Cli,
     paths: &RepoPaths,
-    path: Option<&str>,
-    clear: bool,
-    yes: bool,
+    args: &ExternalRepoArgs,
 ) -> Result<()> {
+    let ExternalRepoArgs {
+        clear,
+        migrate,
+        force,
+        allow_same_prefix,
+        yes,
+        ..
+    } = *args;
+
     // Handle clear case
     if clear {
         return clear_external_repo(cli, paths, yes);
     }
 
     // Handle set case
-    let external_path = match path {
+    let external_path = match args.path.as_deref() {
         Some(p) => p,
         None => {
-            return Err(BrdError::Other(
+            return Err(BrdError::InvalidInput(
                 "must provide path or use --clear".to_string(),
             ));
         }
@@ -33,7 +48,7 @@ pub fn cmd_config_external_repo(
 
     use crate::repo::discover;
 
-    let mut config = Config::load(&paths.config_path())?;
+    let mut config = Config::load_file(&paths.config_path())?;
 
     // check if already set to this path
     if config.issues_repo.as_deref() == Some(external_path) {
@@ -45,7 +60,7 @@ pub fn cmd_config_external_repo(
 
     // check if already set to a different path
     if let Some(existing) = &config.issues_repo {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::AlreadyExists(format!(
             "external-repo already set to '{}'. run `brd config external-repo --clear` first.",
             existing
         )));
@@ -53,7 +68,7 @@ pub fn cmd_config_external_repo(
 
     // check if issues_branch is set
     if config.issues_branch.is_some() {
-        return Err(BrdError::Other(
+        return Err(BrdError::Config(
             "issues-branch is set. run `brd config issues-branch --clear` first.".to_string(),
         ));
     }
@@ -67,7 +82,7 @@ pub fn cmd_config_external_repo(
 
     // verify external repo exists
     let canonical = resolved.canonicalize().map_err(|_| {
-        BrdError::Other(format!(
+        BrdError::Config(format!(
             "external repo path does not exist: {}",
             resolved.display()
         ))
@@ -75,7 +90,7 @@ pub fn cmd_config_external_repo(
 
     // verify it's a braid repo (has .braid/config.toml)
     let external_paths = discover(Some(&canonical)).map_err(|_| {
-        BrdError::Other(format!(
+        BrdError::Git(format!(
             "path is not a git repository: {}",
             canonical.display()
         ))
@@ -83,7 +98,7 @@ pub fn cmd_config_external_repo(
 
     let external_config_path = external_paths.config_path();
     if !external_config_path.exists() {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Config(format!(
             "external repo is not initialized with braid. run `brd init` in {}",
             canonical.display()
         )));
@@ -91,12 +106,26 @@ pub fn cmd_config_external_repo(
 
     // load external config to verify it's valid and count issues
     let external_config = Config::load(&external_config_path)
-        .map_err(|e| BrdError::Other(format!("failed to load external repo config: {}", e)))?;
+        .map_err(|e| BrdError::Config(format!("failed to load external repo config: {}", e)))?;
+
+    // two repos minting ids with the same prefix can hand out the same id
+    let prefix_collision = external_config.id_prefix == config.id_prefix;
+    if prefix_collision && !allow_same_prefix {
+        return Err(BrdError::Config(format!(
+            "external repo uses the same id_prefix '{}' as this repo, so issue ids could collide\n\n\
+             give the repos distinct prefixes (id_prefix in .braid/config.toml), \
+             or pass --allow-same-prefix to use it anyway",
+            config.id_prefix
+        )));
+    }
+
+    let external_issues_dir = external_paths.issues_dir(&external_config);
+    let local_issues = paths.local_issues_dir();
 
     // confirmation prompt (unless -y or --json)
     if !yes && !cli.json {
-        let external_issues_dir = external_paths.issues_dir(&external_config);
         let issue_count = count_issues(&external_issues_dir);
+        let local_count = count_issues(&local_issues);
 
         println!("Setting external-repo to '{}'...", external_path);
         println!();
@@ -108,7 +137,21 @@ pub fn cmd_config_external_repo(
         if issue_count > 0 {
             println!("  • {} issue(s) available in external repo", issue_count);
         }
-        println!("  • Local .braid/issues/ will be ignored");
+        if prefix_collision {
+            println!(
+                "  • Warning: both repos use id_prefix '{}', so issue ids could collide",
+                config.id_prefix
+            );
+        }
+        if migrate && local_count > 0 {
+            let collisions = if force { "overwritten" } else { "skipped" };
+            println!(
+                "  • Move {} local issue(s) into the external repo and commit them there (existing ids {})",
+                local_count, collisions
+            );
+        } else {
+            println!("  • Local .braid/issues/ will be ignored");
+        }
         println!("  • Commit the config change");
         println!();
 
@@ -123,13 +166,29 @@ pub fn cmd_config_external_repo(
         println!("Setting external-repo...");
     }
 
+    // move local issues over first, so a failure leaves the config untouched
+    let migrated = if migrate {
+        let migrated = migrate_issues(cli, &local_issues, &external_issues_dir, force)?;
+        commit_migrated_issues(&external_issues_dir, &migrated, &paths.worktree_root)?;
+        migrated
+    } else {
+        MovedIssues::default()
+    };
+
     // update config
     config.issues_repo = Some(external_path.to_string());
     config.save(&paths.config_path())?;
 
-    // commit the config change
+    // commit the config change, plus the removal of migrated issues
     if !git::run(&["add", ".braid/config.toml"], &paths.worktree_root)? {
-        return Err(BrdError::Other("failed to stage config change".to_string()));
+        return Err(BrdError::Git("failed to stage config change".to_string()));
+    }
+    if !migrated.moved.is_empty()
+        && !git::run(&["add", "-A", ".braid/issues"], &paths.worktree_root)?
+    {
+        return Err(BrdError::Git(
+            "failed to stage removal of migrated issues".to_string(),
+        ));
     }
 
     let commit_msg = format!("chore(braid): set external-repo to '{}'", external_path);
@@ -153,6 +212,10 @@ pub fn cmd_config_external_repo(
             "ok": true,
             "external_repo": external_path,
             "resolved": canonical.to_string_lossy(),
+            "prefix_collision": prefix_collision,
+            "moved_issues": migrated.moved.len(),
+            "skipped_issues": migrated.skipped.len(),
+            "skipped_ids": migrated.skipped,
             "agent_worktrees_needing_rebase": worktrees_json,
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
@@ -160,6 +223,28 @@ pub fn cmd_config_external_repo(
         println!();
         println!("external-repo set to '{}'", external_path);
         println!("Issues now tracked in: {}", canonical.display());
+        if prefix_collision && yes {
+            println!(
+                "Warning: both repos use id_prefix '{}', so issue ids could collide",
+                config.id_prefix
+            );
+        }
+        if migrate {
+            println!(
+                "Moved {} issue(s), skipped {}",
+                migrated.moved.len(),
+                migrated.skipped.len()
+            );
+            if !migrated.skipped.is_empty() {
+                println!(
+                    "Skipped ids already exist in the external repo and were kept in .braid/issues/: {}",
+                    migrated.skipped.join(", ")
+                );
+                println!(
+                    "To overwrite them, clear external-repo and rerun with --migrate --force."
+                );
+            }
+        }
 
         warn_agent_worktrees(&agent_worktrees);
     }
@@ -167,9 +252,51 @@ pub fn cmd_config_external_repo(
     Ok(())
 }
 
+/// Move local issue files into the external repo's issues dir.
+fn migrate_issues(cli: &Cli, from: &Path, to: &Path, force: bool) -> Result<MovedIssues> {
+    let migrated = move_issue_files(from, to, force)?;
+    if !cli.json {
+        if !migrated.moved.is_empty() {
+            println!(
+                "  moved {} issue(s) to {}",
+                migrated.moved.len(),
+                to.display()
+            );
+        }
+        for id in &migrated.skipped {
+            println!("  skipped {} (already exists in external repo)", id);
+        }
+    }
+    Ok(migrated)
+}
+
+/// Commit migrated issues in the repo that holds `issues_dir`.
+fn commit_migrated_issues(issues_dir: &Path, migrated: &MovedIssues, source: &Path) -> Result<()> {
+    if migrated.moved.is_empty() {
+        return Ok(());
+    }
+    if !git::run(&["add", "-A", "--", "."], issues_dir)? {
+        return Err(BrdError::Git(
+            "failed to stage migrated issues in external repo".to_string(),
+        ));
+    }
+    let source_name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| source.display().to_string());
+    let commit_msg = format!(
+        "chore(braid): migrate {} issue(s) from {}",
+        migrated.moved.len(),
+        source_name
+    );
+    // commit might fail if nothing changed (e.g. overwritten with identical content)
+    let _ = git::run(&["commit", "-m", &commit_msg, "--", "."], issues_dir);
+    Ok(())
+}
+
 /// Clear the external-repo setting.
 fn clear_external_repo(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
-    let mut config = Config::load(&paths.config_path())?;
+    let mut config = Config::load_file(&paths.config_path())?;
 
     // check if external_repo is set
     let external_path = match &config.issues_repo {
@@ -260,11 +387,64 @@ mod tests {
     use std::process::Command;
     use tempfile::tempdir;
 
+    /// args for setting `path` without a confirmation prompt.
+    fn args(path: Option<&str>) -> ExternalRepoArgs {
+        ExternalRepoArgs {
+            path: path.map(String::from),
+            yes: true,
+            ..Default::default()
+        }
+    }
+
     fn setup_braid_config(dir: &tempfile::TempDir, content: &str) {
         fs::create_dir_all(dir.path().join(".braid")).unwrap();
         fs::write(dir.path().join(".braid/config.toml"), content).unwrap();
     }
 
+    /// a committed braid repo with prefix "ext" to point external-repo at.
+    fn setup_external_repo() -> tempfile::TempDir {
+        let external_dir = tempdir().unwrap();
+        Command::new("git")
+            .args(["init"])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["config", "user.email", "test@test.com"])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["config", "user.name", "Test"])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["config", "commit.gpgsign", "false"])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+
+        // initialize braid in external repo
+        fs::create_dir_all(external_dir.path().join(".braid/issues")).unwrap();
+        fs::write(
+            external_dir.path().join(".braid/config.toml"),
+            "schema_version = 6\nid_prefix = \"ext\"\nid_len = 4\n",
+        )
+        .unwrap();
+        Command::new("git")
+            .args(["add", "."])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "init braid"])
+            .current_dir(external_dir.path())
+            .output()
+            .unwrap();
+        external_dir
+    }
+
     #[test]
     fn test_config_external_repo_issues_branch_set() {
         let dir = setup_git_repo();
@@ -276,7 +456,7 @@ mod tests {
             "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_branch = \"issues\"\n",
         );
 
-        let result = cmd_config_external_repo(&cli, &paths, Some("../external"), false, true);
+        let result = cmd_config_external_repo(&cli, &paths, &args(Some("../external")));
         assert!(result.is_err());
         assert!(
             result
@@ -297,7 +477,7 @@ mod tests {
             "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_repo = \"../existing\"\n",
         );
 
-        let result = cmd_config_external_repo(&cli, &paths, Some("../new-external"), false, true);
+        let result = cmd_config_external_repo(&cli, &paths, &args(Some("../new-external")));
         assert!(result.is_err());
         assert!(
             result
@@ -318,7 +498,7 @@ mod tests {
             "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
         );
 
-        let result = cmd_config_external_repo(&cli, &paths, Some("/nonexistent/path"), false, true);
+        let result = cmd_config_external_repo(&cli, &paths, &args(Some("/nonexistent/path")));
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
@@ -335,7 +515,14 @@ mod tests {
         );
 
         // clearing when not set should succeed (no-op)
-        let result = cmd_config_external_repo(&cli, &paths, None, true, true);
+        let result = cmd_config_external_repo(
+            &cli,
+            &paths,
+            &ExternalRepoArgs {
+                clear: true,
+                ..args(None)
+            },
+        );
         assert!(result.is_ok());
     }
 
@@ -345,46 +532,7 @@ mod tests {
         let cli = make_cli();
         let paths = make_paths(&dir);
 
-        // create external repo
-        let external_dir = tempdir().unwrap();
-        Command::new("git")
-            .args(["init"])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
-        Command::new("git")
-            .args(["config", "user.email", "test@test.com"])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
-        Command::new("git")
-            .args(["config", "user.name", "Test"])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
-        Command::new("git")
-            .args(["config", "commit.gpgsign", "false"])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
-
-        // initialize braid in external repo
-        fs::create_dir_all(external_dir.path().join(".braid/issues")).unwrap();
-        fs::write(
-            external_dir.path().join(".braid/config.toml"),
-            "schema_version = 6\nid_prefix = \"ext\"\nid_len = 4\n",
-        )
-        .unwrap();
-        Command::new("git")
-            .args(["add", "."])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
-        Command::new("git")
-            .args(["commit", "-m", "init braid"])
-            .current_dir(external_dir.path())
-            .output()
-            .unwrap();
+        let external_dir = setup_external_repo();
 
         // set up main repo
         setup_braid_config(
@@ -404,7 +552,7 @@ mod tests {
 
         // set external-repo
         let external_path = external_dir.path().to_string_lossy().to_string();
-        let result = cmd_config_external_repo(&cli, &paths, Some(&external_path), false, true);
+        let result = cmd_config_external_repo(&cli, &paths, &args(Some(&external_path)));
         assert!(result.is_ok());
 
         // verify config was updated
@@ -435,11 +583,143 @@ mod tests {
             .unwrap();
 
         // clear external-repo
-        let result = cmd_config_external_repo(&cli, &paths, None, true, true);
+        let result = cmd_config_external_repo(
+            &cli,
+            &paths,
+            &ExternalRepoArgs {
+                clear: true,
+                ..args(None)
+            },
+        );
         assert!(result.is_ok());
 
         // verify config was updated
         let config = Config::load(&paths.config_path()).unwrap();
         assert!(config.issues_repo.is_none());
     }
+
+    fn write_issue(dir: &std::path::Path, id: &str, title: &str) {
+        fs::create_dir_all(dir).unwrap();
+        let issue = crate::issue::Issue::new(
+            id.to_string(),
+            title.to_string(),
+            crate::issue::Priority::P2,
+            vec![],
+        );
+        issue.save(&dir.join(format!("{}.md", id))).unwrap();
+    }
+
+    #[test]
+    fn test_config_external_repo_migrate_moves_issues() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+        let external_dir = setup_external_repo();
+        let external_issues = external_dir.path().join(".braid/issues");
+
+        setup_braid_config(
+            &dir,
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+        );
+        let local_issues = dir.path().join(".braid/issues");
+        write_issue(&local_issues, "tst-aaaa", "local only");
+        write_issue(&local_issues, "tst-bbbb", "local copy");
+        write_issue(&external_issues, "tst-bbbb", "external copy");
+        for cwd in [dir.path(), external_dir.path()] {
+            Command::new("git")
+                .args(["add", ".braid"])
+                .current_dir(cwd)
+                .output()
+                .unwrap();
+            Command::new("git")
+                .args(["commit", "-m", "add issues"])
+                .current_dir(cwd)
+                .output()
+                .unwrap();
+        }
+
+        let external_path = external_dir.path().to_string_lossy().to_string();
+        cmd_config_external_repo(
+            &cli,
+            &paths,
+            &ExternalRepoArgs {
+                migrate: true,
+                ..args(Some(&external_path))
+            },
+        )
+        .unwrap();
+
+        // the new issue moved, the colliding one stayed put on both sides
+        assert!(external_issues.join("tst-aaaa.md").exists());
+        assert!(!local_issues.join("tst-aaaa.md").exists());
+        assert!(local_issues.join("tst-bbbb.md").exists());
+        let kept = fs::read_to_string(external_issues.join("tst-bbbb.md")).unwrap();
+        assert!(kept.contains("external copy"));
+
+        // committed in the external repo, removal committed locally
+        assert!(git::is_clean(external_dir.path()).unwrap());
+        let log = git::output(&["log", "-1", "--format=%s"], external_dir.path()).unwrap();
+        assert!(log.contains("migrate 1 issue(s)"), "log: {}", log);
+        let status = git::output(&["status", "--porcelain"], dir.path()).unwrap();
+        assert!(!status.contains("tst-aaaa"), "status: {}", status);
+    }
+
+    #[test]
+    fn test_move_issue_files_force_overwrites() {
+        let from = tempdir().unwrap();
+        let to = tempdir().unwrap();
+        write_issue(from.path(), "tst-aaaa", "new");
+        write_issue(to.path(), "tst-aaaa", "old");
+
+        let moved = move_issue_files(from.path(), to.path(), false).unwrap();
+        assert!(moved.moved.is_empty());
+        assert_eq!(moved.skipped, vec!["tst-aaaa"]);
+
+        let moved = move_issue_files(from.path(), to.path(), true).unwrap();
+        assert_eq!(moved.moved, vec!["tst-aaaa"]);
+        assert!(!from.path().join("tst-aaaa.md").exists());
+        let content = fs::read_to_string(to.path().join("tst-aaaa.md")).unwrap();
+        assert!(content.contains("new"));
+    }
+
+    #[test]
+    fn test_config_external_repo_prefix_collision() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+        let external_dir = setup_external_repo();
+
+        // same prefix as the external repo
+        setup_braid_config(
+            &dir,
+            "schema_version = 6\nid_prefix = \"ext\"\nid_len = 4\n",
+        );
+        let external_path = external_dir.path().to_string_lossy().to_string();
+
+        let err = cmd_config_external_repo(&cli, &paths, &args(Some(&external_path))).unwrap_err();
+        assert!(matches!(err, BrdError::Config(_)));
+        assert!(err.to_string().contains("same id_prefix 'ext'"));
+        assert!(err.to_string().contains("--allow-same-prefix"));
+        let config = Config::load(&paths.config_path()).unwrap();
+        assert!(config.issues_repo.is_none());
+
+        // --force is only about overwriting migrated issues
+        let forced = ExternalRepoArgs {
+            force: true,
+            ..args(Some(&external_path))
+        };
+        assert!(cmd_config_external_repo(&cli, &paths, &forced).is_err());
+
+        cmd_config_external_repo(
+            &cli,
+            &paths,
+            &ExternalRepoArgs {
+                allow_same_prefix: true,
+                ..args(Some(&external_path))
+            },
+        )
+        .unwrap();
+        let config = Config::load(&paths.config_path()).unwrap();
+        assert!(config.issues_repo.is_some());
+    }
 }
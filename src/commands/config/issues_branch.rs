@@ -7,16 +7,22 @@ use crate::git;
 use crate::repo::RepoPaths;
 
 use super::{
-    ISSUES_SYMLINK_PATTERN, confirm, count_issues, find_agent_worktrees_needing_rebase,
-    remove_from_git_exclude, warn_agent_worktrees,
+    ISSUES_SYMLINK_PATTERN, add_to_git_exclude, confirm, count_issues,
+    find_agent_worktrees_needing_rebase, move_issue_files, remove_from_git_exclude,
+    warn_agent_worktrees,
 };
 
 /// Set or clear the issues-branch setting.
+///
+/// Setting it creates the branch if needed, sets up the shared issues
+/// worktree, moves `.braid/issues/*.md` into it, and commits on both sides.
+/// With `symlink`, `.braid/issues` then points at the worktree's issues.
 pub fn cmd_config_issues_branch(
     cli: &Cli,
     paths: &RepoPaths,
     name: Option<&str>,
     clear: bool,
+    symlink: bool,
     yes: bool,
 ) -> Result<()> {
     // Handle clear case
@@ -28,13 +34,13 @@ pub fn cmd_config_issues_branch(
     let branch = match name {
         Some(b) => b,
         None => {
-            return Err(BrdError::Other(
+            return Err(BrdError::InvalidInput(
                 "must provide branch name or use --clear".to_string(),
             ));
         }
     };
 
-    let mut config = Config::load(&paths.config_path())?;
+    let mut config = Config::load_file(&paths.config_path())?;
 
     // check if already set to this branch
     if config.issues_branch.as_deref() == Some(branch) {
@@ -46,7 +52,7 @@ pub fn cmd_config_issues_branch(
 
     // check if already set to a different branch
     if let Some(existing) = &config.issues_branch {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::AlreadyExists(format!(
             "issues-branch already set to '{}'. run `brd config issues-branch --clear` first.",
             existing
         )));
@@ -54,7 +60,7 @@ pub fn cmd_config_issues_branch(
 
     // check for uncommitted changes
     if !git::is_clean(&paths.worktree_root)? {
-        return Err(BrdError::Other(
+        return Err(BrdError::DirtyWorkingTree(
             "working tree has uncommitted changes - commit or stash first".to_string(),
         ));
     }
@@ -79,6 +85,9 @@ pub fn cmd_config_issues_branch(
             );
         }
         println!("  • Commit the changes");
+        if symlink {
+            println!("  • Link .braid/issues to the worktree's issues");
+        }
         println!();
 
         if !confirm("Continue?")? {
@@ -96,8 +105,9 @@ pub fn cmd_config_issues_branch(
     let branch_exists = git::run(&["rev-parse", "--verify", branch], &paths.worktree_root)?;
 
     if !branch_exists {
+        git::require_commits(&paths.worktree_root)?;
         if !git::run(&["branch", branch], &paths.worktree_root)? {
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create sync branch '{}'",
                 branch
             )));
@@ -116,21 +126,9 @@ pub fn cmd_config_issues_branch(
     // 3. move existing issues to sync branch worktree
     let local_issues = paths.worktree_root.join(".braid/issues");
     let wt_issues = issues_wt.join(".braid/issues");
-    std::fs::create_dir_all(&wt_issues)?;
-
-    let mut moved_count = 0;
-    if local_issues.exists() {
-        for entry in std::fs::read_dir(&local_issues)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().is_some_and(|e| e == "md") {
-                let dest = wt_issues.join(path.file_name().unwrap());
-                std::fs::copy(&path, &dest)?;
-                std::fs::remove_file(&path)?;
-                moved_count += 1;
-            }
-        }
-    }
+    let moved_count = move_issue_files(&local_issues, &wt_issues, true)?
+        .moved
+        .len();
 
     if moved_count > 0 && !cli.json {
         println!("  moved {} issue(s) to sync branch", moved_count);
@@ -142,9 +140,7 @@ pub fn cmd_config_issues_branch(
 
     // 5. commit the changes
     if !git::run(&["add", ".braid"], &paths.worktree_root)? {
-        return Err(BrdError::Other(
-            "failed to stage .braid changes".to_string(),
-        ));
+        return Err(BrdError::Git("failed to stage .braid changes".to_string()));
     }
 
     let commit_msg = format!("chore(braid): set issues-branch to '{}'", branch);
@@ -153,7 +149,7 @@ pub fn cmd_config_issues_branch(
 
     // also commit in the issues worktree
     if !git::run(&["add", ".braid"], &issues_wt)? {
-        return Err(BrdError::Other(
+        return Err(BrdError::Git(
             "failed to stage .braid in issues worktree".to_string(),
         ));
     }
@@ -162,6 +158,17 @@ pub fn cmd_config_issues_branch(
         &issues_wt,
     );
 
+    // 6. optionally link .braid/issues to the worktree, after committing so
+    // the link itself is never staged
+    let linked = symlink && create_issues_symlink(paths, &wt_issues)?;
+    if symlink && !cli.json {
+        if linked {
+            println!("  linked .braid/issues -> {}", wt_issues.display());
+        } else {
+            eprintln!("  warning: .braid/issues still has files in it, not linking");
+        }
+    }
+
     // check for agent worktrees needing rebase
     let agent_worktrees = find_agent_worktrees_needing_rebase(&paths.worktree_root);
 
@@ -181,6 +188,7 @@ pub fn cmd_config_issues_branch(
             "issues_branch": branch,
             "issues_worktree": issues_wt.to_string_lossy(),
             "moved_issues": moved_count,
+            "symlink": linked,
             "agent_worktrees_needing_rebase": worktrees_json,
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
@@ -197,7 +205,7 @@ pub fn cmd_config_issues_branch(
 
 /// Clear the issues-branch setting (move issues back to .braid/issues/).
 fn clear_issues_branch(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
-    let mut config = Config::load(&paths.config_path())?;
+    let mut config = Config::load_file(&paths.config_path())?;
 
     // check if issues_branch is set
     let branch = match &config.issues_branch {
@@ -212,7 +220,7 @@ fn clear_issues_branch(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
 
     // check for uncommitted changes
     if !git::is_clean(&paths.worktree_root)? {
-        return Err(BrdError::Other(
+        return Err(BrdError::DirtyWorkingTree(
             "working tree has uncommitted changes - commit or stash first".to_string(),
         ));
     }
@@ -224,7 +232,7 @@ fn clear_issues_branch(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
 
     // check for uncommitted changes in issues worktree
     if issues_wt.exists() && !git::is_clean(&issues_wt)? {
-        return Err(BrdError::Other(
+        return Err(BrdError::DirtyWorkingTree(
             "issues worktree has uncommitted changes - commit them first with `brd sync`"
                 .to_string(),
         ));
@@ -295,9 +303,7 @@ fn clear_issues_branch(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
 
     // commit the changes
     if !git::run(&["add", ".braid"], &paths.worktree_root)? {
-        return Err(BrdError::Other(
-            "failed to stage .braid changes".to_string(),
-        ));
+        return Err(BrdError::Git("failed to stage .braid changes".to_string()));
     }
 
     let commit_msg = format!("chore(braid): clear issues-branch (was '{}')", branch);
@@ -346,6 +352,34 @@ fn clear_issues_branch(cli: &Cli, paths: &RepoPaths, yes: bool) -> Result<()> {
     Ok(())
 }
 
+/// Replace the (now empty) local .braid/issues dir with a symlink to `target`
+/// and keep it out of git. Returns false if the dir still holds files.
+fn create_issues_symlink(paths: &RepoPaths, target: &std::path::Path) -> Result<bool> {
+    let symlink_path = paths.worktree_root.join(".braid/issues");
+
+    if symlink_path.is_symlink() {
+        std::fs::remove_file(&symlink_path)?;
+    } else if symlink_path.is_dir() {
+        if std::fs::read_dir(&symlink_path)?.next().is_some() {
+            return Ok(false);
+        }
+        std::fs::remove_dir(&symlink_path)?;
+    }
+
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(target, &symlink_path)?;
+    #[cfg(not(unix))]
+    {
+        let _ = target;
+        return Err(BrdError::Unsupported(
+            "linking .braid/issues is only supported on unix".to_string(),
+        ));
+    }
+
+    add_to_git_exclude(paths, ISSUES_SYMLINK_PATTERN)?;
+    Ok(true)
+}
+
 /// Remove the issues symlink if it exists.
 pub fn remove_issues_symlink(paths: &RepoPaths) -> Result<()> {
     let symlink_path = paths.worktree_root.join(".braid/issues");
@@ -383,7 +417,7 @@ mod tests {
             "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_branch = \"existing-branch\"\n",
         );
 
-        let result = cmd_config_issues_branch(&cli, &paths, Some("new-branch"), false, true);
+        let result = cmd_config_issues_branch(&cli, &paths, Some("new-branch"), false, false, true);
         assert!(result.is_err());
         assert!(
             result
@@ -407,7 +441,8 @@ mod tests {
         // create uncommitted changes
         fs::write(dir.path().join("uncommitted.txt"), "content").unwrap();
 
-        let result = cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true);
+        let result =
+            cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true);
         assert!(result.is_err());
         assert!(
             result
@@ -441,7 +476,8 @@ mod tests {
             .output()
             .unwrap();
 
-        let result = cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true);
+        let result =
+            cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true);
         assert!(result.is_ok());
 
         // verify config was updated
@@ -461,7 +497,7 @@ mod tests {
         );
 
         // clearing when not set should succeed (no-op)
-        let result = cmd_config_issues_branch(&cli, &paths, None, true, true);
+        let result = cmd_config_issues_branch(&cli, &paths, None, true, false, true);
         assert!(result.is_ok());
     }
 
@@ -496,7 +532,8 @@ mod tests {
             .unwrap();
 
         // set issues-branch (creates worktree)
-        let result = cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true);
+        let result =
+            cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true);
         assert!(result.is_ok());
 
         // verify issues_branch is set
@@ -526,7 +563,7 @@ mod tests {
             .unwrap();
 
         // now clear issues-branch
-        let result = cmd_config_issues_branch(&cli, &paths, None, true, true);
+        let result = cmd_config_issues_branch(&cli, &paths, None, true, false, true);
         assert!(result.is_ok());
 
         // verify issues_branch is cleared
@@ -569,7 +606,8 @@ mod tests {
             .unwrap();
 
         // set issues-branch (creates worktree)
-        let result = cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true);
+        let result =
+            cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true);
         assert!(result.is_ok());
 
         // create uncommitted changes in the issues worktree
@@ -577,7 +615,7 @@ mod tests {
         fs::write(issues_wt.join("uncommitted.txt"), "dirty").unwrap();
 
         // try to clear - should fail
-        let result = cmd_config_issues_branch(&cli, &paths, None, true, true);
+        let result = cmd_config_issues_branch(&cli, &paths, None, true, false, true);
         assert!(result.is_err());
         assert!(
             result
@@ -618,15 +656,109 @@ mod tests {
             .unwrap();
 
         // set issues-branch (creates worktree)
-        let result = cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true);
+        let result =
+            cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true);
         assert!(result.is_ok());
 
         // don't create any issues - just clear immediately
-        let result = cmd_config_issues_branch(&cli, &paths, None, true, true);
+        let result = cmd_config_issues_branch(&cli, &paths, None, true, false, true);
         assert!(result.is_ok());
 
         // verify issues_branch is cleared
         let config = Config::load(&paths.config_path()).unwrap();
         assert!(config.issues_branch.is_none());
     }
+
+    fn commit_braid(dir: &tempfile::TempDir) {
+        Command::new("git")
+            .args(["add", ".braid"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["commit", "-m", "add braid"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+    }
+
+    fn write_issue(dir: &tempfile::TempDir, id: &str) {
+        let issues_dir = dir.path().join(".braid/issues");
+        fs::create_dir_all(&issues_dir).unwrap();
+        let issue = crate::issue::Issue::new(
+            id.to_string(),
+            format!("issue {}", id),
+            crate::issue::Priority::P2,
+            vec![],
+        );
+        issue.save(&issues_dir.join(format!("{}.md", id))).unwrap();
+    }
+
+    #[test]
+    fn test_config_issues_branch_moves_issues_to_worktree() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+
+        fs::create_dir_all(&paths.brd_common_dir).unwrap();
+        setup_braid_config(
+            &dir,
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+        );
+        write_issue(&dir, "tst-aaaa");
+        write_issue(&dir, "tst-bbbb");
+        commit_braid(&dir);
+
+        cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, false, true).unwrap();
+
+        let wt_issues = paths.issues_worktree_dir().join(".braid/issues");
+        assert!(wt_issues.join("tst-aaaa.md").exists());
+        assert!(wt_issues.join("tst-bbbb.md").exists());
+        let local_issues = dir.path().join(".braid/issues");
+        assert!(!local_issues.join("tst-aaaa.md").exists());
+        assert!(!local_issues.is_symlink());
+
+        // committed on the issues branch, and the removal committed on main
+        let tree = git::output(
+            &["ls-tree", "-r", "--name-only", "braid-issues"],
+            dir.path(),
+        )
+        .unwrap();
+        assert!(tree.contains(".braid/issues/tst-aaaa.md"), "tree: {}", tree);
+        assert!(git::is_clean(dir.path()).unwrap());
+
+        // issues load from the worktree under the new config
+        let config = Config::load(&paths.config_path()).unwrap();
+        let issues = crate::commands::load_all_issues(&paths, &config).unwrap();
+        assert_eq!(issues.len(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_config_issues_branch_symlink() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+
+        fs::create_dir_all(&paths.brd_common_dir).unwrap();
+        setup_braid_config(
+            &dir,
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+        );
+        write_issue(&dir, "tst-aaaa");
+        commit_braid(&dir);
+
+        cmd_config_issues_branch(&cli, &paths, Some("braid-issues"), false, true, true).unwrap();
+
+        let local_issues = dir.path().join(".braid/issues");
+        assert!(local_issues.is_symlink());
+        assert!(local_issues.join("tst-aaaa.md").exists());
+        // the link is excluded, so main stays clean
+        assert!(git::is_clean(dir.path()).unwrap());
+
+        // clearing removes the link and brings the issues back
+        cmd_config_issues_branch(&cli, &paths, None, true, false, true).unwrap();
+        assert!(!local_issues.is_symlink());
+        assert!(local_issues.join("tst-aaaa.md").exists());
+    }
 }
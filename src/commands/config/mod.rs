@@ -46,6 +46,51 @@ pub(crate) fn count_issues(dir: &Path) -> usize {
         .unwrap_or(0)
 }
 
+/// Issue files moved between issues directories, by id.
+#[derive(Debug, Default)]
+pub(crate) struct MovedIssues {
+    pub moved: Vec<String>,
+    /// ids already present at the destination, left in place at the source
+    pub skipped: Vec<String>,
+}
+
+/// Move the .md issue files in `from` into `to`, removing each source once copied.
+/// An issue that already exists in `to` is skipped unless `overwrite` is set.
+pub(crate) fn move_issue_files(from: &Path, to: &Path, overwrite: bool) -> Result<MovedIssues> {
+    let mut result = MovedIssues::default();
+    if !from.exists() {
+        return Ok(result);
+    }
+    std::fs::create_dir_all(to)?;
+
+    let mut files: Vec<_> = std::fs::read_dir(from)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    files.sort();
+
+    for path in files {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let id = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let dest = to.join(name);
+        if dest.exists() && !overwrite {
+            result.skipped.push(id);
+            continue;
+        }
+        std::fs::copy(&path, &dest)?;
+        std::fs::remove_file(&path)?;
+        result.moved.push(id);
+    }
+
+    Ok(result)
+}
+
 /// Check if a branch has an upstream tracking branch.
 pub(crate) fn has_upstream(branch: &str, cwd: &Path) -> bool {
     git::run(
@@ -65,6 +110,52 @@ pub(crate) fn get_upstream(branch: &str, cwd: &Path) -> Option<String> {
     .filter(|s| !s.is_empty())
 }
 
+/// The ref `branch` syncs with: its upstream, or `origin/<branch>` when no
+/// upstream is set (brd pushes without `--set-upstream` when the remote branch exists).
+pub(crate) fn tracking_ref(branch: &str, cwd: &Path) -> Option<String> {
+    get_upstream(branch, cwd).or_else(|| {
+        let remote = format!("origin/{}", branch);
+        git::run(
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("refs/remotes/{}", remote),
+            ],
+            cwd,
+        )
+        .unwrap_or(false)
+        .then_some(remote)
+    })
+}
+
+/// Count commits `branch` is ahead of and behind the ref it syncs with.
+/// None when there is no such ref or the counts can't be read.
+pub(crate) fn ahead_behind(branch: &str, cwd: &Path) -> Option<(usize, usize)> {
+    let upstream = tracking_ref(branch, cwd)?;
+    let counts = git::output(
+        &[
+            "rev-list",
+            "--left-right",
+            "--count",
+            &format!("{}...{}", upstream, branch),
+        ],
+        cwd,
+    )
+    .ok()?;
+    let mut parts = counts.split_whitespace();
+    let behind = parts.next()?.parse().ok()?;
+    let ahead = parts.next()?.parse().ok()?;
+    Some((ahead, behind))
+}
+
+/// Check for uncommitted changes under `pathspec`, relative to `cwd`.
+pub(crate) fn has_uncommitted(cwd: &Path, pathspec: &str) -> bool {
+    git::output(&["status", "--porcelain", "--", pathspec], cwd)
+        .map(|out| !out.is_empty())
+        .unwrap_or(false)
+}
+
 /// Agent worktree info for rebase warnings.
 pub(crate) struct AgentWorktree {
     pub branch: String,
@@ -74,6 +165,15 @@ pub(crate) struct AgentWorktree {
 /// Find agent worktrees that need to rebase on main.
 /// Returns worktrees that have .braid/agent.toml and are behind main.
 pub(crate) fn find_agent_worktrees_needing_rebase(cwd: &Path) -> Vec<AgentWorktree> {
+    list_agent_worktrees(cwd)
+        .into_iter()
+        .filter(|wt| is_behind_main(&wt.path))
+        .collect()
+}
+
+/// List the agent worktrees of the repo at `cwd`: linked worktrees on a
+/// branch that have .braid/agent.toml.
+pub(crate) fn list_agent_worktrees(cwd: &Path) -> Vec<AgentWorktree> {
     let mut result = Vec::new();
 
     // get worktree list in porcelain format
@@ -100,15 +200,10 @@ pub(crate) fn find_agent_worktrees_needing_rebase(cwd: &Path) -> Vec<AgentWorktr
             current_branch = branch_ref.strip_prefix("refs/heads/").map(String::from);
         } else if line.is_empty() {
             // end of entry, check if it's an agent worktree
-            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take()) {
-                // check if this is an agent worktree (has .braid/agent.toml)
-                let agent_toml = path.join(".braid/agent.toml");
-                if agent_toml.exists() {
-                    // check if behind main
-                    if is_behind_main(&path) {
-                        result.push(AgentWorktree { branch, path });
-                    }
-                }
+            if let (Some(path), Some(branch)) = (current_path.take(), current_branch.take())
+                && crate::repo::is_agent_worktree(&path)
+            {
+                result.push(AgentWorktree { branch, path });
             }
         }
     }
@@ -151,6 +246,35 @@ pub(crate) fn warn_agent_worktrees(worktrees: &[AgentWorktree]) {
     println!("Run `git rebase main` in each worktree to pick up the new config.");
 }
 
+/// Add a pattern to .git/info/exclude unless it is already there.
+/// Returns whether the pattern was added.
+pub(crate) fn add_to_git_exclude(paths: &crate::repo::RepoPaths, pattern: &str) -> Result<bool> {
+    let exclude_path = paths.git_common_dir.join("info/exclude");
+
+    let content = if exclude_path.exists() {
+        std::fs::read_to_string(&exclude_path)?
+    } else {
+        std::fs::create_dir_all(paths.git_common_dir.join("info"))?;
+        String::new()
+    };
+
+    if content.lines().any(|line| line.trim() == pattern) {
+        return Ok(false);
+    }
+
+    let separator = if content.is_empty() || content.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    std::fs::write(
+        &exclude_path,
+        format!("{}{}{}\n", content, separator, pattern),
+    )?;
+
+    Ok(true)
+}
+
 /// Remove a pattern from .git/info/exclude.
 pub(crate) fn remove_from_git_exclude(paths: &crate::repo::RepoPaths, pattern: &str) -> Result<()> {
     let exclude_path = paths.git_common_dir.join("info/exclude");
@@ -239,7 +363,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -251,6 +375,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ahead_behind_and_uncommitted() {
+        let dir = setup_git_repo();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .output()
+                .unwrap()
+        };
+        let main = git::current_branch(dir.path()).unwrap();
+
+        // no upstream yet
+        assert_eq!(ahead_behind(&main, dir.path()), None);
+
+        run(&["checkout", "-b", "feature", "--track", &main]);
+        run(&["commit", "--allow-empty", "-m", "feature work"]);
+        assert_eq!(ahead_behind("feature", dir.path()), Some((1, 0)));
+
+        run(&["checkout", &main]);
+        run(&["commit", "--allow-empty", "-m", "main work 1"]);
+        run(&["commit", "--allow-empty", "-m", "main work 2"]);
+        assert_eq!(ahead_behind("feature", dir.path()), Some((1, 2)));
+
+        assert!(!has_uncommitted(dir.path(), ".braid/issues"));
+        fs::create_dir_all(dir.path().join(".braid/issues")).unwrap();
+        fs::write(dir.path().join(".braid/issues/tst-aaaa.md"), "x").unwrap();
+        assert!(has_uncommitted(dir.path(), ".braid/issues"));
+        assert!(!has_uncommitted(dir.path(), "src"));
+    }
+
     #[test]
     fn test_count_issues_empty_dir() {
         let dir = tempdir().unwrap();
@@ -342,4 +497,33 @@ mod tests {
 
         assert!(is_behind_main(dir.path()));
     }
+
+    #[test]
+    fn test_add_to_git_exclude_is_idempotent() {
+        let dir = tempdir().unwrap();
+        let paths = make_paths(&dir);
+        fs::create_dir_all(dir.path().join(".git/info")).unwrap();
+        // no trailing newline on the existing content
+        fs::write(dir.path().join(".git/info/exclude"), "# comment").unwrap();
+
+        assert!(add_to_git_exclude(&paths, ".braid/runtime/").unwrap());
+        assert!(!add_to_git_exclude(&paths, ".braid/runtime/").unwrap());
+
+        let content = fs::read_to_string(dir.path().join(".git/info/exclude")).unwrap();
+        assert_eq!(content, "# comment\n.braid/runtime/\n");
+
+        remove_from_git_exclude(&paths, ".braid/runtime/").unwrap();
+        let content = fs::read_to_string(dir.path().join(".git/info/exclude")).unwrap();
+        assert_eq!(content, "# comment\n");
+    }
+
+    #[test]
+    fn test_add_to_git_exclude_creates_file() {
+        let dir = tempdir().unwrap();
+        let paths = make_paths(&dir);
+
+        assert!(add_to_git_exclude(&paths, ".braid/agent.toml").unwrap());
+        let content = fs::read_to_string(dir.path().join(".git/info/exclude")).unwrap();
+        assert_eq!(content, ".braid/agent.toml\n");
+    }
 }
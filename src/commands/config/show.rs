@@ -5,26 +5,116 @@ use crate::config::Config;
 use crate::error::Result;
 use crate::repo::RepoPaths;
 
-use super::{get_upstream, has_upstream};
+use super::{ahead_behind, get_upstream, has_uncommitted, has_upstream};
+
+/// Where issues are stored and whether they need a `brd sync`.
+#[derive(Debug, PartialEq, Eq)]
+struct SyncStatus {
+    mode: &'static str,
+    /// commits the issues branch is ahead of / behind its upstream (local-sync only)
+    ahead: Option<usize>,
+    behind: Option<usize>,
+    /// uncommitted changes to issue files
+    dirty: bool,
+}
+
+fn sync_status(paths: &RepoPaths, config: &Config) -> SyncStatus {
+    if let Some(branch) = &config.issues_branch {
+        let issues_wt = paths.issues_worktree_dir();
+        let (ahead, behind) = if issues_wt.exists() {
+            ahead_behind(branch, &issues_wt).unzip()
+        } else {
+            (None, None)
+        };
+        return SyncStatus {
+            mode: "local-sync",
+            ahead,
+            behind,
+            dirty: issues_wt.exists() && has_uncommitted(&issues_wt, "."),
+        };
+    }
+
+    let (mode, dirty) = if config.is_external_repo_mode() {
+        let issues_dir = paths.issues_dir(config);
+        (
+            "external-repo",
+            issues_dir.exists() && has_uncommitted(&issues_dir, "."),
+        )
+    } else {
+        (
+            "git-native",
+            has_uncommitted(&paths.worktree_root, ".braid/issues"),
+        )
+    };
+    SyncStatus {
+        mode,
+        ahead: None,
+        behind: None,
+        dirty,
+    }
+}
+
+/// One-line sync summary, with a `brd sync` hint when there is something to sync.
+fn format_sync_line(status: &SyncStatus, has_upstream: bool) -> String {
+    let mut parts = Vec::new();
+    match (status.ahead, status.behind) {
+        (Some(0), Some(0)) => parts.push("up to date".to_string()),
+        (Some(ahead), Some(behind)) => {
+            if ahead > 0 {
+                parts.push(format!("{} ahead", ahead));
+            }
+            if behind > 0 {
+                parts.push(format!("{} behind", behind));
+            }
+        }
+        _ if status.mode == "local-sync" && !has_upstream => parts.push("no upstream".to_string()),
+        _ => {}
+    }
+    if status.dirty {
+        parts.push("uncommitted issue changes".to_string());
+    } else if status.mode != "local-sync" {
+        parts.push("issues committed".to_string());
+    }
+
+    let needs_sync =
+        status.dirty || status.ahead.unwrap_or(0) > 0 || status.behind.unwrap_or(0) > 0;
+    let mut line = parts.join(", ");
+    if needs_sync {
+        line.push_str(" - run `brd sync`");
+    }
+    line
+}
 
 /// Show current configuration.
 pub fn cmd_config_show(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
 
     let auto_sync = config.auto_pull && config.auto_push;
+    let sync = sync_status(paths, &config);
 
     if cli.json {
         let json = serde_json::json!({
+            "mode": sync.mode,
+            "ahead": sync.ahead,
+            "behind": sync.behind,
+            "dirty": sync.dirty,
             "issues_branch": config.issues_branch,
             "external_repo": config.issues_repo,
             "auto_sync": auto_sync,
             "auto_pull": config.auto_pull,
             "auto_push": config.auto_push,
+            "commit_mode": config.commit_mode.to_string(),
+            "session_retention": config.session_retention,
+            "next_strategy": config.next_strategy.to_string(),
+            "ready_includes": config.ready_includes,
+            "filename_scheme": config.filename_scheme.to_string(),
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
         return Ok(());
     }
 
+    println!("mode:          {}", sync.mode);
+
     // issues-branch setting
     if let Some(ref branch) = config.issues_branch {
         print!("issues-branch: {}", branch);
@@ -59,6 +149,12 @@ pub fn cmd_config_show(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         println!("external-repo: (not set)");
     }
 
+    let upstream = config
+        .issues_branch
+        .as_deref()
+        .is_some_and(|branch| has_upstream(branch, &paths.issues_worktree_dir()));
+    println!("sync:          {}", format_sync_line(&sync, upstream));
+
     // auto-sync setting
     if auto_sync {
         println!("auto-sync:     enabled");
@@ -71,6 +167,19 @@ pub fn cmd_config_show(cli: &Cli, paths: &RepoPaths) -> Result<()> {
             config.auto_pull, config.auto_push
         );
     }
+    println!("commit-mode:   {}", config.commit_mode);
+    println!(
+        "session-retention: {}",
+        config.session_retention.as_deref().unwrap_or("(not set)")
+    );
+    println!("next-strategy: {}", config.next_strategy);
+    let ready_includes: Vec<_> = config
+        .ready_includes
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    println!("ready-includes: {}", ready_includes.join(", "));
+    println!("filename-scheme: {}", config.filename_scheme);
 
     Ok(())
 }
@@ -130,4 +239,55 @@ mod tests {
         let result = cmd_config_show(&cli, &paths);
         assert!(result.is_ok());
     }
+
+    fn status(mode: &'static str, counts: Option<(usize, usize)>, dirty: bool) -> SyncStatus {
+        let (ahead, behind) = counts.unzip();
+        SyncStatus {
+            mode,
+            ahead,
+            behind,
+            dirty,
+        }
+    }
+
+    #[test]
+    fn test_format_sync_line() {
+        let up_to_date = status("local-sync", Some((0, 0)), false);
+        assert_eq!(format_sync_line(&up_to_date, true), "up to date");
+
+        let diverged = status("local-sync", Some((2, 1)), false);
+        assert_eq!(
+            format_sync_line(&diverged, true),
+            "2 ahead, 1 behind - run `brd sync`"
+        );
+
+        let local_only = status("local-sync", None, true);
+        assert_eq!(
+            format_sync_line(&local_only, false),
+            "no upstream, uncommitted issue changes - run `brd sync`"
+        );
+
+        let clean = status("git-native", None, false);
+        assert_eq!(format_sync_line(&clean, false), "issues committed");
+    }
+
+    #[test]
+    fn test_sync_status_git_native_dirty() {
+        let dir = setup_git_repo();
+        let paths = make_paths(&dir);
+        setup_braid_config(
+            &dir,
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+        );
+        let config = Config::load(&paths.config_path()).unwrap();
+
+        assert_eq!(
+            sync_status(&paths, &config),
+            status("git-native", None, false)
+        );
+
+        fs::create_dir_all(dir.path().join(".braid/issues")).unwrap();
+        fs::write(dir.path().join(".braid/issues/tst-aaaa.md"), "x").unwrap();
+        assert!(sync_status(&paths, &config).dirty);
+    }
 }
@@ -1,31 +1,75 @@
-//! brd dep add/rm commands.
+//! brd dep add/rm/swap/ls commands.
+
+use std::collections::HashMap;
 
 use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
-use crate::graph::would_create_cycle;
+use crate::graph::{compute_derived, would_create_cycle};
+use crate::issue::{DepKind, Issue, Status};
 use crate::lock::LockGuard;
 use crate::repo::RepoPaths;
 
+use super::config::confirm;
+use super::pick::pick_issue;
 use super::{load_all_issues, resolve_issue_id};
 
-pub fn cmd_dep_add(cli: &Cli, paths: &RepoPaths, blocked_id: &str, blocker_id: &str) -> Result<()> {
+/// add a dep of the given kind. adding an existing link with the other kind
+/// switches it, so an issue is never both blocked by and related to another.
+pub fn cmd_dep_add(
+    cli: &Cli,
+    paths: &RepoPaths,
+    blocked_id: Option<&str>,
+    blocker_id: Option<&str>,
+    kind: DepKind,
+) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
-    let _lock = LockGuard::acquire(&paths.lock_path())?;
 
-    let mut issues = load_all_issues(paths, &config)?;
-    let blocked_full = resolve_issue_id(blocked_id, &issues)?;
-    let blocker_full = resolve_issue_id(blocker_id, &issues)?;
+    // resolve (and maybe pick) before taking the lock, so an open picker
+    // doesn't hold up other brd processes
+    let issues = load_all_issues(paths, &config)?;
+    let blocked_full = match blocked_id {
+        Some(partial) => resolve_issue_id(partial, &issues)?,
+        None => {
+            let active = issues
+                .values()
+                .filter(|i| matches!(i.status(), Status::Open | Status::Doing))
+                .collect();
+            pick_issue(cli, "blocked issue", active)?
+        }
+    };
+    let blocker_full = match blocker_id {
+        Some(partial) => resolve_issue_id(partial, &issues)?,
+        None => {
+            let others = issues.values().filter(|i| i.id() != blocked_full).collect();
+            let prompt = match kind {
+                DepKind::Blocks => format!("{blocked_full} is blocked by"),
+                DepKind::Related => format!("{blocked_full} is related to"),
+            };
+            pick_issue(cli, &prompt, others)?
+        }
+    };
 
     // check not self-dep
     if blocked_full == blocker_full {
-        return Err(BrdError::Other("cannot add self-dependency".to_string()));
+        return Err(BrdError::InvalidInput(
+            "cannot add self-dependency".to_string(),
+        ));
     }
 
-    // check for cycles
-    if let Some(cycle_path) = would_create_cycle(&blocked_full, &blocker_full, &issues) {
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    // reload under the lock; either issue may have changed meanwhile
+    let mut issues = load_all_issues(paths, &config)?;
+    if !issues.contains_key(&blocker_full) {
+        return Err(BrdError::IssueNotFound(blocker_full));
+    }
+
+    // check for cycles (related links aren't part of the graph)
+    if kind == DepKind::Blocks
+        && let Some(cycle_path) = would_create_cycle(&blocked_full, &blocker_full, &issues)
+    {
         let cycle_str = cycle_path.join(" -> ");
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::DependencyCycle(format!(
             "cannot add dependency: would create cycle: {}",
             cycle_str
         )));
@@ -33,18 +77,23 @@ pub fn cmd_dep_add(cli: &Cli, paths: &RepoPaths, blocked_id: &str, blocker_id: &
 
     let blocked = issues
         .get_mut(&blocked_full)
-        .ok_or_else(|| BrdError::IssueNotFound(blocked_id.to_string()))?;
-
-    if !blocked.frontmatter.deps.contains(&blocker_full) {
-        blocked.frontmatter.deps.push(blocker_full.clone());
-        let issue_path = paths
-            .issues_dir(&config)
-            .join(format!("{}.md", blocked_full));
-        blocked.save(&issue_path)?;
+        .ok_or_else(|| BrdError::IssueNotFound(blocked_full.clone()))?;
+
+    let deps = &mut blocked.frontmatter.deps;
+    let (list, other) = match kind {
+        DepKind::Blocks => (&mut deps.blocks, &mut deps.related),
+        DepKind::Related => (&mut deps.related, &mut deps.blocks),
+    };
+    if !list.contains(&blocker_full) {
+        list.push(blocker_full.clone());
+        other.retain(|d| d != &blocker_full);
+        blocked.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
     }
 
     if cli.json {
         println!(r#"{{"ok": true}}"#);
+    } else if kind == DepKind::Related {
+        println!("{} is now related to {}", blocked_full, blocker_full);
     } else {
         println!("{} is now blocked by {}", blocked_full, blocker_full);
     }
@@ -64,14 +113,23 @@ pub fn cmd_dep_rm(cli: &Cli, paths: &RepoPaths, blocked_id: &str, blocker_id: &s
         .get_mut(&blocked_full)
         .ok_or_else(|| BrdError::IssueNotFound(blocked_id.to_string()))?;
 
-    blocked.frontmatter.deps.retain(|d| d != &blocker_full);
-    let issue_path = paths
-        .issues_dir(&config)
-        .join(format!("{}.md", blocked_full));
-    blocked.save(&issue_path)?;
+    let was_related = blocked.related().contains(&blocker_full);
+    blocked
+        .frontmatter
+        .deps
+        .blocks
+        .retain(|d| d != &blocker_full);
+    blocked
+        .frontmatter
+        .deps
+        .related
+        .retain(|d| d != &blocker_full);
+    blocked.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
 
     if cli.json {
         println!(r#"{{"ok": true}}"#);
+    } else if was_related {
+        println!("{} is no longer related to {}", blocked_full, blocker_full);
     } else {
         println!("{} is no longer blocked by {}", blocked_full, blocker_full);
     }
@@ -79,6 +137,253 @@ pub fn cmd_dep_rm(cli: &Cli, paths: &RepoPaths, blocked_id: &str, blocker_id: &s
     Ok(())
 }
 
+/// remove every dependency and related link from an issue.
+/// every dependency link of `issue`, blocking ones first.
+fn dep_links(issue: &Issue) -> Vec<String> {
+    issue
+        .deps()
+        .iter()
+        .chain(issue.related())
+        .cloned()
+        .collect()
+}
+
+pub fn cmd_dep_rm_all(cli: &Cli, paths: &RepoPaths, blocked_id: &str, yes: bool) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+
+    // confirm before taking the lock, so a waiting prompt doesn't hold it
+    let issues = load_all_issues(paths, &config)?;
+    let blocked_full = resolve_issue_id(blocked_id, &issues)?;
+    let removed = dep_links(&issues[&blocked_full]);
+    if removed.is_empty() {
+        if cli.json {
+            println!("{}", serde_json::json!({ "ok": true, "removed": removed }));
+        } else {
+            println!("{} has no dependencies", blocked_full);
+        }
+        return Ok(());
+    }
+
+    if !yes && !cli.json {
+        println!(
+            "This will remove {} dependency link(s) from {}: {}",
+            removed.len(),
+            blocked_full,
+            removed.join(", ")
+        );
+        if !confirm("Continue?")? {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    // reload under the lock and drop whatever links are there now
+    let mut issues = load_all_issues(paths, &config)?;
+    let blocked = issues
+        .get_mut(&blocked_full)
+        .ok_or_else(|| BrdError::IssueNotFound(blocked_full.clone()))?;
+    let removed = dep_links(blocked);
+    blocked.frontmatter.deps.blocks.clear();
+    blocked.frontmatter.deps.related.clear();
+    blocked.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
+
+    if cli.json {
+        println!("{}", serde_json::json!({ "ok": true, "removed": removed }));
+    } else {
+        println!(
+            "removed {} dependency link(s) from {}",
+            removed.len(),
+            blocked_full
+        );
+    }
+
+    Ok(())
+}
+
+/// a direct dependency edge as shown by `brd dep ls`.
+struct DepEdge<'a> {
+    id: &'a str,
+    kind: DepKind,
+    issue: Option<&'a Issue>,
+    /// "resolved", "open", or "missing"; related links are never open
+    state: &'static str,
+}
+
+fn dep_edges<'a>(issue: &'a Issue, issues: &'a HashMap<String, Issue>) -> Vec<DepEdge<'a>> {
+    let derived = compute_derived(issue, issues);
+    let blocks = issue.deps().iter().map(|id| {
+        let state = if derived.missing_deps.contains(id) {
+            "missing"
+        } else if derived.open_deps.contains(id) {
+            "open"
+        } else {
+            "resolved"
+        };
+        (id, DepKind::Blocks, state)
+    });
+    let related = issue.related().iter().map(|id| {
+        let state = if issues.contains_key(id) {
+            "resolved"
+        } else {
+            "missing"
+        };
+        (id, DepKind::Related, state)
+    });
+    blocks
+        .chain(related)
+        .map(|(id, kind, state)| DepEdge {
+            id,
+            kind,
+            issue: issues.get(id),
+            state,
+        })
+        .collect()
+}
+
+/// list an issue's direct dependencies and related links.
+pub fn cmd_dep_ls(cli: &Cli, paths: &RepoPaths, id: &str) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+    let issues = load_all_issues(paths, &config)?;
+    let full_id = resolve_issue_id(id, &issues)?;
+    let issue = &issues[&full_id];
+    let edges = dep_edges(issue, &issues);
+
+    if cli.json {
+        let deps: Vec<_> = edges
+            .iter()
+            .map(|edge| {
+                serde_json::json!({
+                    "id": edge.id,
+                    "kind": edge.kind,
+                    "state": edge.state,
+                    "status": edge.issue.map(|i| i.status().to_string()),
+                    "title": edge.issue.map(|i| i.title()),
+                })
+            })
+            .collect();
+        let json = serde_json::json!({ "id": full_id, "deps": deps });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return Ok(());
+    }
+
+    if edges.is_empty() {
+        println!("{} has no dependencies", full_id);
+        return Ok(());
+    }
+    for (kind, heading) in [
+        (DepKind::Blocks, "blocked by:"),
+        (DepKind::Related, "related to:"),
+    ] {
+        let group: Vec<_> = edges.iter().filter(|e| e.kind == kind).collect();
+        if group.is_empty() {
+            continue;
+        }
+        println!("{} {}", full_id, heading);
+        for edge in group {
+            match edge.issue {
+                Some(dep) if kind == DepKind::Blocks => println!(
+                    "  {}  {:<5}  {:<8}  {}",
+                    edge.id,
+                    dep.status(),
+                    edge.state,
+                    dep.title()
+                ),
+                Some(dep) => println!("  {}  {:<5}  {}", edge.id, dep.status(), dep.title()),
+                None => println!("  {}  (missing)", edge.id),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// replace `old_blocker` with `new_blocker` in one write, so the issue is never
+/// left with both or neither edge.
+pub fn cmd_dep_swap(
+    cli: &Cli,
+    paths: &RepoPaths,
+    blocked_id: &str,
+    old_blocker_id: &str,
+    new_blocker_id: &str,
+) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+
+    let mut issues = load_all_issues(paths, &config)?;
+    let blocked_full = resolve_issue_id(blocked_id, &issues)?;
+    let new_full = resolve_issue_id(new_blocker_id, &issues)?;
+
+    // the old blocker may have been deleted, so also accept an exact dep id
+    let old_full = match resolve_issue_id(old_blocker_id, &issues) {
+        Ok(id) => id,
+        Err(_)
+            if issues[&blocked_full]
+                .deps()
+                .iter()
+                .any(|d| d == old_blocker_id) =>
+        {
+            old_blocker_id.to_string()
+        }
+        Err(e) => return Err(e),
+    };
+
+    if !issues[&blocked_full].deps().contains(&old_full) {
+        return Err(BrdError::InvalidInput(format!(
+            "{} is not blocked by {}",
+            blocked_full, old_full
+        )));
+    }
+    if blocked_full == new_full {
+        return Err(BrdError::InvalidInput(
+            "cannot add self-dependency".to_string(),
+        ));
+    }
+    if old_full == new_full {
+        return Err(BrdError::InvalidInput(format!(
+            "{} is already blocked by {}; nothing to swap",
+            blocked_full, new_full
+        )));
+    }
+
+    let blocked = issues
+        .get_mut(&blocked_full)
+        .ok_or_else(|| BrdError::IssueNotFound(blocked_full.clone()))?;
+    let original_deps = blocked.frontmatter.deps.blocks.clone();
+    blocked.frontmatter.deps.blocks.retain(|d| d != &old_full);
+
+    // check for cycles against the graph without the old edge
+    if let Some(cycle_path) = would_create_cycle(&blocked_full, &new_full, &issues) {
+        let cycle_str = cycle_path.join(" -> ");
+        return Err(BrdError::DependencyCycle(format!(
+            "cannot swap dependency: would create cycle: {}",
+            cycle_str
+        )));
+    }
+
+    let blocked = issues
+        .get_mut(&blocked_full)
+        .ok_or_else(|| BrdError::IssueNotFound(blocked_full.clone()))?;
+    // keep the new blocker where the old one was
+    blocked.frontmatter.deps.blocks = original_deps
+        .into_iter()
+        .filter(|d| d != &new_full)
+        .map(|d| if d == old_full { new_full.clone() } else { d })
+        .collect();
+    blocked.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
+
+    if cli.json {
+        println!(r#"{{"ok": true}}"#);
+    } else {
+        println!(
+            "{} is now blocked by {} instead of {}",
+            blocked_full, new_full, old_full
+        );
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,7 +398,13 @@ mod tests {
         let repo = TestRepo::builder().build();
         repo.issue("issue-a").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-a", "issue-a");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-a"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("self-dependency"));
@@ -106,7 +417,13 @@ mod tests {
         repo.issue("issue-a").deps(&["issue-b"]).create();
         repo.issue("issue-b").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-b", "issue-a");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-b"),
+            Some("issue-a"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
@@ -122,7 +439,13 @@ mod tests {
         repo.issue("issue-b").deps(&["issue-c"]).create();
         repo.issue("issue-c").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-c", "issue-a");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-c"),
+            Some("issue-a"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("cycle"));
@@ -134,7 +457,13 @@ mod tests {
         repo.issue("issue-a").create();
         repo.issue("issue-b").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-a", "issue-b");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-b"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_ok());
         let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
@@ -148,7 +477,13 @@ mod tests {
         repo.issue("issue-b").create();
 
         // Add same dep again
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-a", "issue-b");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-b"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_ok());
         // Verify no duplicate
@@ -168,7 +503,13 @@ mod tests {
         repo.issue("brd-xyz9").create();
 
         // Use partial IDs
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "abc1", "xyz9");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("abc1"),
+            Some("xyz9"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_ok());
         let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
@@ -180,7 +521,13 @@ mod tests {
         let repo = TestRepo::builder().build();
         repo.issue("issue-b").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "nonexistent", "issue-b");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("nonexistent"),
+            Some("issue-b"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_err());
     }
@@ -190,7 +537,13 @@ mod tests {
         let repo = TestRepo::builder().build();
         repo.issue("issue-a").create();
 
-        let result = cmd_dep_add(&test_cli(), &repo.paths, "issue-a", "nonexistent");
+        let result = cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("nonexistent"),
+            DepKind::Blocks,
+        );
 
         assert!(result.is_err());
     }
@@ -262,4 +615,206 @@ mod tests {
         assert!(!issues["issue-a"].deps().contains(&"issue-b".to_string()));
         assert!(issues["issue-a"].deps().contains(&"issue-c".to_string()));
     }
+
+    #[test]
+    fn test_dep_rm_all_clears_deps_and_related() {
+        let repo = TestRepo::builder().build();
+        let mut issue = repo.issue("issue-a").deps(&["issue-b", "issue-c"]).create();
+        issue.frontmatter.deps.related = vec!["issue-d".to_string()];
+        issue
+            .save(&repo.paths.issues_dir(&repo.config).join("issue-a.md"))
+            .unwrap();
+        repo.issue("issue-b").create();
+        repo.issue("issue-c").create();
+        repo.issue("issue-d").deps(&["issue-b"]).create();
+
+        cmd_dep_rm_all(&test_cli(), &repo.paths, "issue-a", true).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues["issue-a"].deps().is_empty());
+        assert!(issues["issue-a"].related().is_empty());
+        // other issues keep their deps
+        assert_eq!(issues["issue-d"].deps(), ["issue-b"]);
+    }
+
+    #[test]
+    fn test_dep_rm_all_without_deps_is_noop() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").create();
+
+        assert!(cmd_dep_rm_all(&test_cli(), &repo.paths, "issue-a", true).is_ok());
+    }
+
+    // =========================================================================
+    // cmd_dep_ls tests
+    // =========================================================================
+
+    #[test]
+    fn test_dep_edges_annotate_state() {
+        let repo = TestRepo::builder().build();
+        let mut issue = repo
+            .issue("issue-a")
+            .deps(&["issue-done", "issue-open", "issue-gone"])
+            .create();
+        issue.frontmatter.deps.related = vec!["issue-rel".to_string()];
+        issue
+            .save(&repo.paths.issues_dir(&repo.config).join("issue-a.md"))
+            .unwrap();
+        repo.issue("issue-done").status(Status::Done).create();
+        repo.issue("issue-open").create();
+        repo.issue("issue-rel").status(Status::Doing).create();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let edges = dep_edges(&issues["issue-a"], &issues);
+        let summary: Vec<_> = edges.iter().map(|e| (e.id, e.kind, e.state)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("issue-done", DepKind::Blocks, "resolved"),
+                ("issue-open", DepKind::Blocks, "open"),
+                ("issue-gone", DepKind::Blocks, "missing"),
+                ("issue-rel", DepKind::Related, "resolved"),
+            ]
+        );
+        assert!(edges[2].issue.is_none());
+        assert_eq!(edges[3].issue.unwrap().status(), Status::Doing);
+    }
+
+    #[test]
+    fn test_dep_ls_partial_id() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-abc1").deps(&["brd-xyz9"]).create();
+        repo.issue("brd-xyz9").create();
+
+        assert!(cmd_dep_ls(&test_cli(), &repo.paths, "abc1").is_ok());
+        assert!(cmd_dep_ls(&test_cli(), &repo.paths, "nope").is_err());
+    }
+
+    // =========================================================================
+    // cmd_dep_swap tests
+    // =========================================================================
+
+    #[test]
+    fn test_dep_swap_replaces_edge_in_place() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").deps(&["issue-b", "issue-c"]).create();
+        repo.issue("issue-b").create();
+        repo.issue("issue-c").create();
+        repo.issue("issue-d").create();
+
+        cmd_dep_swap(&test_cli(), &repo.paths, "issue-a", "issue-b", "issue-d").unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].deps(), &["issue-d", "issue-c"]);
+    }
+
+    #[test]
+    fn test_dep_swap_rejects_cycle_without_writing() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").deps(&["issue-b"]).create();
+        repo.issue("issue-b").create();
+        repo.issue("issue-c").deps(&["issue-a"]).create();
+
+        let err = cmd_dep_swap(&test_cli(), &repo.paths, "issue-a", "issue-b", "issue-c")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("cycle"));
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].deps(), &["issue-b"]);
+    }
+
+    #[test]
+    fn test_dep_swap_requires_existing_edge() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").create();
+        repo.issue("issue-b").create();
+        repo.issue("issue-c").create();
+
+        let err = cmd_dep_swap(&test_cli(), &repo.paths, "issue-a", "issue-b", "issue-c")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not blocked by"));
+    }
+
+    #[test]
+    fn test_dep_swap_same_blocker_keeps_edge() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").deps(&["issue-b"]).create();
+        repo.issue("issue-b").create();
+
+        let err =
+            cmd_dep_swap(&test_cli(), &repo.paths, "issue-a", "issue-b", "issue-b").unwrap_err();
+        assert!(matches!(err, BrdError::InvalidInput(_)));
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].deps(), &["issue-b"]);
+    }
+
+    #[test]
+    fn test_dep_swap_accepts_deleted_old_blocker() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").deps(&["issue-gone"]).create();
+        repo.issue("issue-b").create();
+
+        cmd_dep_swap(&test_cli(), &repo.paths, "issue-a", "issue-gone", "issue-b").unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].deps(), &["issue-b"]);
+    }
+
+    #[test]
+    fn test_dep_add_related_does_not_block() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").create();
+        repo.issue("issue-b").deps(&["issue-a"]).create();
+
+        // a related link may point against a hard dep: no cycle, no block
+        cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-b"),
+            DepKind::Related,
+        )
+        .unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].related(), ["issue-b"]);
+        assert!(issues["issue-a"].deps().is_empty());
+
+        cmd_dep_rm(&test_cli(), &repo.paths, "issue-a", "issue-b").unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues["issue-a"].related().is_empty());
+    }
+
+    #[test]
+    fn test_dep_add_switches_kind() {
+        let repo = TestRepo::builder().build();
+        repo.issue("issue-a").deps(&["issue-b"]).create();
+        repo.issue("issue-b").create();
+
+        cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-b"),
+            DepKind::Related,
+        )
+        .unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues["issue-a"].deps().is_empty());
+        assert_eq!(issues["issue-a"].related(), ["issue-b"]);
+
+        cmd_dep_add(
+            &test_cli(),
+            &repo.paths,
+            Some("issue-a"),
+            Some("issue-b"),
+            DepKind::Blocks,
+        )
+        .unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["issue-a"].deps(), ["issue-b"]);
+        assert!(issues["issue-a"].related().is_empty());
+    }
 }
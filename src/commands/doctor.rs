@@ -1,15 +1,21 @@
 //! brd doctor command.
 
+use std::collections::HashMap;
 use std::time::Instant;
 
 use crate::cli::Cli;
+use crate::config::Config;
 use crate::error::{BrdError, Result};
+use crate::git;
+use crate::issue::{Issue, find_issue_file};
+use crate::lock::LockGuard;
 use crate::migrate::{self, CURRENT_SCHEMA};
-use crate::repo::RepoPaths;
+use crate::repo::{self, RepoPaths};
 
+use super::config::{ahead_behind, has_upstream, tracking_ref};
 use super::{
     AGENTS_BLOCK_VERSION, AgentsBlockMode, INSTRUCTION_FILES, check_agents_block, extract_mode,
-    load_all_issues,
+    generate_issue_id, inject_agents_block, load_all_issues,
 };
 
 /// Parse frontmatter from markdown content.
@@ -33,7 +39,7 @@ fn parse_frontmatter(content: &str) -> Result<(String, String)> {
     Ok((frontmatter, body))
 }
 
-pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths, fix: bool) -> Result<()> {
     let start = Instant::now();
     let mut checks: Vec<serde_json::Value> = Vec::new();
     let mut errors: Vec<serde_json::Value> = Vec::new();
@@ -78,6 +84,53 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     // load config for issue operations
     let config = crate::config::Config::load(&paths.config_path()).unwrap_or_default();
 
+    // check 2b: repo schema is one this brd understands.
+    // main.rs skips its early schema validation for doctor so this can report it.
+    if config_valid {
+        if config.schema_version > CURRENT_SCHEMA {
+            record_check(
+                "config_schema",
+                &format!(
+                    "repo uses schema v{}, this brd supports up to v{}",
+                    config.schema_version, CURRENT_SCHEMA
+                ),
+                false,
+            );
+            errors.push(serde_json::json!({
+                "code": "schema_unsupported",
+                "message": format!(
+                    "this brd is older than the repo (schema v{} < v{}); upgrade brd: cargo install braid",
+                    CURRENT_SCHEMA, config.schema_version
+                )
+            }));
+        } else {
+            record_check(
+                "config_schema",
+                &format!(
+                    "repo schema v{} supported (brd supports up to v{})",
+                    config.schema_version, CURRENT_SCHEMA
+                ),
+                true,
+            );
+        }
+    }
+
+    // check 2c: HEAD is on a branch. commits made on a detached HEAD
+    // (after checking out a tag, mid-bisect) are orphaned on switch.
+    if crate::git::is_detached_head(&paths.worktree_root) {
+        record_check(
+            "head_attached",
+            "HEAD is detached (not on any branch)",
+            false,
+        );
+        errors.push(serde_json::json!({
+            "code": "detached_head",
+            "message": "HEAD is detached; check out a branch before running brd commands that commit, e.g. `git switch main`"
+        }));
+    } else {
+        record_check("head_attached", "HEAD is on a branch", true);
+    }
+
     // check 3: external repo config version (if in external-repo mode)
     if let Some(ref external_path) = config.issues_repo {
         match check_external_config(paths, external_path) {
@@ -168,41 +221,49 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         }
     }
 
-    // check 5: all issue files parse correctly
-    let issues = load_all_issues(paths, &config)?;
-    record_check(
-        "issues_parse",
-        "all issue files parse correctly",
-        true, // load_all_issues already warns on parse errors
-    );
-
-    // check 6: all issues at current schema version (check raw files, not migrated structs)
-    let mut needs_migration = Vec::new();
+    // check 5: all issue files parse correctly. with --fix, title, tag and
+    // acceptance values that yaml misreads because of an unquoted ": " are quoted.
     let issues_dir = paths.issues_dir(&config);
-    if issues_dir.exists() {
-        for entry in std::fs::read_dir(&issues_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.extension().is_none_or(|e| e != "md") {
-                continue;
-            }
-
-            let content = std::fs::read_to_string(&path)?;
-            if let Ok((frontmatter_str, _)) = parse_frontmatter(&content)
-                && let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&frontmatter_str)
-            {
-                let version = migrate::get_schema_version(&yaml).unwrap_or(0);
-                if migrate::needs_migration(version) {
-                    let id = path
-                        .file_stem()
-                        .and_then(|s| s.to_str())
-                        .unwrap_or("unknown");
-                    needs_migration.push(id.to_string());
+    let mut broken = unparseable_issue_files(&issues_dir)?;
+    let mut quoted = 0;
+    if fix && broken.iter().any(|file| file.fixed.is_some()) {
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        let mut still_broken = Vec::new();
+        for file in broken {
+            match &file.fixed {
+                Some(fixed) => {
+                    crate::atomic::write_atomic(&file.path, fixed)?;
+                    quoted += 1;
                 }
+                None => still_broken.push(file),
             }
         }
+        broken = still_broken;
     }
+    if broken.is_empty() {
+        let desc = if quoted == 0 {
+            "all issue files parse correctly".to_string()
+        } else {
+            format!("quoted colon values in {} issue file(s)", quoted)
+        };
+        record_check("issues_parse", &desc, true);
+    } else {
+        record_check("issues_parse", "all issue files parse correctly", false);
+        for file in &broken {
+            errors.push(serde_json::json!({
+                "code": "parse_error",
+                "file": file.path.display().to_string(),
+                "message": format!("{}: {}", file.path.display(), file.error)
+            }));
+        }
+        if !fix && !cli.json && broken.iter().any(|file| file.fixed.is_some()) {
+            eprintln!("  hint: run `brd doctor --fix` to quote values containing ': '");
+        }
+    }
+    let mut issues = load_all_issues(paths, &config)?;
+
+    // check 6: all issues at current schema version (check raw files, not migrated structs)
+    let needs_migration = issues_needing_migration(&paths.issues_dir(&config))?;
     let schema_ok = needs_migration.is_empty();
     record_check(
         "schema_current",
@@ -212,13 +273,101 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     if !schema_ok {
         // This is a warning, not an error - issues still work
         if !cli.json {
+            // migrating in an agent worktree would conflict with main
+            let how = if repo::is_agent_worktree(&paths.worktree_root) {
+                "run `brd migrate` on main, then rebase this worktree"
+            } else {
+                "run `brd migrate`"
+            };
             eprintln!(
-                "  warning: {} issue(s) need migration, run `brd migrate`",
-                needs_migration.len()
+                "  warning: {} issue(s) need migration to schema v{}, {}",
+                needs_migration.len(),
+                CURRENT_SCHEMA,
+                how
             );
         }
     }
 
+    // check 6b: issue ids use the configured prefix (a warning; old ids keep
+    // working after id_prefix changes). with --fix, they are renumbered.
+    let mut off_prefix: Vec<String> = issues
+        .keys()
+        .filter(|id| id.rsplit_once('-').map(|(prefix, _)| prefix) != Some(&config.id_prefix))
+        .cloned()
+        .collect();
+    off_prefix.sort();
+    let prefix_desc = format!("all issue ids use prefix '{}'", config.id_prefix);
+    if off_prefix.is_empty() {
+        record_check("id_prefix", &prefix_desc, true);
+    } else if fix {
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        let mut renamed = Vec::new();
+        for old_id in &off_prefix {
+            let new_id = renumber_issue(paths, &config, &mut issues, old_id)?;
+            renamed.push((old_id, new_id));
+        }
+        record_check(
+            "id_prefix",
+            &format!(
+                "renumbered {} issue(s) to prefix '{}'",
+                renamed.len(),
+                config.id_prefix
+            ),
+            true,
+        );
+        if !cli.json {
+            for (old_id, new_id) in &renamed {
+                println!("  {} -> {}", old_id, new_id);
+            }
+        }
+    } else {
+        record_check("id_prefix", &prefix_desc, false);
+        if !cli.json {
+            eprintln!(
+                "  warning: {} issue(s) use another prefix: {}",
+                off_prefix.len(),
+                off_prefix.join(", ")
+            );
+            eprintln!("  hint: run `brd doctor --fix` to renumber them");
+        }
+    }
+
+    // check 6c: timestamps are ordered and not in the future (a warning;
+    // they only skew reports). with --fix, they are clamped.
+    let now = time::OffsetDateTime::now_utc();
+    let mut bad_timestamps: Vec<(String, Vec<String>)> = issues
+        .iter()
+        .map(|(id, issue)| (id.clone(), timestamp_problems(issue, now)))
+        .filter(|(_, problems)| !problems.is_empty())
+        .collect();
+    bad_timestamps.sort();
+    if bad_timestamps.is_empty() {
+        record_check("timestamps", "all issue timestamps are consistent", true);
+    } else if fix {
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        let issues_dir = paths.issues_dir(&config);
+        for (id, _) in &bad_timestamps {
+            let issue = issues
+                .get_mut(id)
+                .ok_or_else(|| BrdError::IssueNotFound(id.clone()))?;
+            clamp_timestamps(issue, now);
+            issue.save_in(&issues_dir, config.filename_scheme)?;
+        }
+        record_check(
+            "timestamps",
+            &format!("clamped timestamps on {} issue(s)", bad_timestamps.len()),
+            true,
+        );
+    } else {
+        record_check("timestamps", "all issue timestamps are consistent", false);
+        if !cli.json {
+            for (id, problems) in &bad_timestamps {
+                eprintln!("  warning: {}: {}", id, problems.join(", "));
+            }
+            eprintln!("  hint: run `brd doctor --fix` to clamp them");
+        }
+    }
+
     // check 7: no missing dependencies
     let mut missing_deps = Vec::new();
     for (id, issue) in &issues {
@@ -244,6 +393,23 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         }
     }
 
+    // check 7b: attached files still exist. a moved doc is a warning, not an error
+    let missing_attachments = missing_attachments(&paths.worktree_root, &issues);
+    if missing_attachments.is_empty() {
+        record_check("attachments", "all attached files exist", true);
+    } else {
+        record_check(
+            "attachments",
+            &format!("{} attached file(s) missing", missing_attachments.len()),
+            false,
+        );
+        if !cli.json {
+            for (id, path) in &missing_attachments {
+                eprintln!("  warning: {}: attachment {} not found", id, path);
+            }
+        }
+    }
+
     // check 8: no dependency cycles
     let cycles = crate::graph::find_cycles(&issues);
     for cycle in &cycles {
@@ -260,9 +426,18 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     }
 
     // check 9: instruction file block version (informational)
-    // checks AGENTS.md, CLAUDE.md, and CLAUDE.local.md in order
-    let agents_block_result = check_agents_block(paths);
-    let block_file = agents_block_result.map(|(file, _)| file);
+    // checks the configured agents_file, then AGENTS.md, CLAUDE.md, and CLAUDE.local.md.
+    // with --fix, an existing block is re-injected; a missing one is left alone.
+    let agents_block_result = check_agents_block(paths, &config);
+    let block_file = agents_block_result.as_ref().map(|(file, _)| file.clone());
+    // `brd agent inject` without --file targets the configured agents file
+    let inject_cmd = |file: &str| {
+        if file == config.agents_file_name() {
+            "brd agent inject".to_string()
+        } else {
+            format!("brd agent inject --file {}", file)
+        }
+    };
     match agents_block_result {
         Some((file, version)) if version >= AGENTS_BLOCK_VERSION => {
             record_check(
@@ -271,6 +446,17 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
                 true,
             );
         }
+        Some((file, version)) if fix => {
+            inject_agents_block(&paths.worktree_root, &config, &file)?;
+            record_check(
+                "agents_block",
+                &format!(
+                    "{} braid block updated (v{} -> v{})",
+                    file, version, AGENTS_BLOCK_VERSION
+                ),
+                true,
+            );
+        }
         Some((file, version)) => {
             record_check(
                 "agents_block",
@@ -281,15 +467,16 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
                 false,
             );
             if !cli.json {
-                if file == "AGENTS.md" {
-                    eprintln!("  hint: run `brd agent inject` to update");
-                } else {
-                    eprintln!("  hint: run `brd agent inject --file {}` to update", file);
-                }
+                eprintln!(
+                    "  hint: run `{}` or `brd doctor --fix` to update",
+                    inject_cmd(&file)
+                );
             }
         }
         None => {
-            let files = INSTRUCTION_FILES.join(", ");
+            let mut files: Vec<&str> = config.agents_file.as_deref().into_iter().collect();
+            files.extend(INSTRUCTION_FILES);
+            let files = files.join(", ");
             record_check(
                 "agents_block",
                 &format!("braid block not found in {}", files),
@@ -303,7 +490,7 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
 
     // check 10: instruction file block mode matches config mode (informational)
     if let Some(file) = block_file {
-        let file_path = paths.worktree_root.join(file);
+        let file_path = paths.worktree_root.join(&file);
         if let Ok(content) = std::fs::read_to_string(&file_path) {
             let block_mode = extract_mode(&content);
             let config_mode = if config.is_issues_branch_mode() {
@@ -320,6 +507,14 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
                         true,
                     );
                 }
+                Some(mode) if fix => {
+                    inject_agents_block(&paths.worktree_root, &config, &file)?;
+                    record_check(
+                        "agents_block_mode",
+                        &format!("{} block mode updated ({} -> {})", file, mode, config_mode),
+                        true,
+                    );
+                }
                 Some(mode) => {
                     record_check(
                         "agents_block_mode",
@@ -329,11 +524,7 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
                     if !cli.json {
                         eprintln!("  current mode: {}", config_mode);
                         eprintln!("  {} block: {}", file, mode);
-                        if file == "AGENTS.md" {
-                            eprintln!("  run `brd agent inject` to update");
-                        } else {
-                            eprintln!("  run `brd agent inject --file {}` to update", file);
-                        }
+                        eprintln!("  run `{}` to update", inject_cmd(&file));
                     }
                 }
                 None => {
@@ -343,6 +534,45 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         }
     }
 
+    // check 11: divergence from upstream (informational, as of the last fetch).
+    // issues on main only need `brd sync` in git-native mode.
+    let issues_branch = match &config.issues_branch {
+        Some(branch) if paths.issues_worktree_dir().exists() => {
+            divergence(branch, &paths.issues_worktree_dir())
+        }
+        _ => None,
+    };
+    let main_branch = main_branch_name(&paths.worktree_root)
+        .and_then(|branch| divergence(&branch, &paths.worktree_root));
+    let git_native = !config.is_issues_branch_mode() && !config.is_external_repo_mode();
+    let sync_recommended = issues_branch.as_ref().is_some_and(Divergence::diverged)
+        || (git_native && main_branch.as_ref().is_some_and(Divergence::diverged));
+    for (name, div) in [
+        ("issues_branch_sync", &issues_branch),
+        ("main_sync", &main_branch),
+    ] {
+        if let Some(div) = div {
+            record_check(name, &div.describe(), !div.diverged());
+        }
+    }
+    // without an upstream, plain `brd sync` doesn't push
+    let sync_command = match &issues_branch {
+        Some(div) if !has_upstream(&div.branch, &paths.issues_worktree_dir()) => "brd sync --push",
+        _ => "brd sync",
+    };
+    if sync_recommended && !cli.json {
+        eprintln!(
+            "  hint: run `{}` to reconcile issues with upstream",
+            sync_command
+        );
+    }
+    let divergence_json = serde_json::json!({
+        "issues_branch": issues_branch.as_ref().map(Divergence::to_json),
+        "main": main_branch.as_ref().map(Divergence::to_json),
+        "sync_recommended": sync_recommended,
+        "sync_command": sync_recommended.then_some(sync_command),
+    });
+
     let ok = errors.is_empty();
     let elapsed_ms = start.elapsed().as_millis();
 
@@ -351,6 +581,7 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
             "ok": ok,
             "checks": checks,
             "errors": errors,
+            "divergence": divergence_json,
             "took_ms": elapsed_ms
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
@@ -384,6 +615,289 @@ pub fn cmd_doctor(cli: &Cli, paths: &RepoPaths) -> Result<()> {
     }
 }
 
+/// how far a branch has drifted from its upstream tracking branch.
+#[derive(Debug, PartialEq, Eq)]
+struct Divergence {
+    branch: String,
+    upstream: String,
+    ahead: usize,
+    behind: usize,
+}
+
+impl Divergence {
+    fn diverged(&self) -> bool {
+        self.ahead > 0 || self.behind > 0
+    }
+
+    fn describe(&self) -> String {
+        if !self.diverged() {
+            return format!("{} is up to date with {}", self.branch, self.upstream);
+        }
+        let mut parts = Vec::new();
+        if self.ahead > 0 {
+            parts.push(format!("{} ahead", self.ahead));
+        }
+        if self.behind > 0 {
+            parts.push(format!("{} behind", self.behind));
+        }
+        format!("{} is {} {}", self.branch, parts.join(", "), self.upstream)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "branch": self.branch,
+            "upstream": self.upstream,
+            "ahead": self.ahead,
+            "behind": self.behind,
+        })
+    }
+}
+
+/// ahead/behind counts for `branch`, or None if it has nothing to sync with.
+fn divergence(branch: &str, cwd: &std::path::Path) -> Option<Divergence> {
+    let upstream = tracking_ref(branch, cwd)?;
+    let (ahead, behind) = ahead_behind(branch, cwd)?;
+    Some(Divergence {
+        branch: branch.to_string(),
+        upstream,
+        ahead,
+        behind,
+    })
+}
+
+/// the repo's main line: origin's default branch, or the checked out branch
+/// when there is no origin to ask.
+fn main_branch_name(cwd: &std::path::Path) -> Option<String> {
+    git::remote_default_branch(cwd, "origin").or_else(|| git::current_branch(cwd).ok())
+}
+
+/// `(issue id, path)` for attachments that no longer exist under `root`, sorted.
+fn missing_attachments(
+    root: &std::path::Path,
+    issues: &HashMap<String, Issue>,
+) -> Vec<(String, String)> {
+    let mut missing: Vec<(String, String)> = issues
+        .iter()
+        .flat_map(|(id, issue)| {
+            issue
+                .frontmatter
+                .attachments
+                .iter()
+                .map(move |path| (id.clone(), path.clone()))
+        })
+        .filter(|(_, path)| !root.join(path).exists())
+        .collect();
+    missing.sort();
+    missing
+}
+
+/// how far ahead of the local clock a timestamp may be before it counts as in
+/// the future, to tolerate skew between machines.
+const FUTURE_SLACK: time::Duration = time::Duration::minutes(5);
+
+/// the lifecycle timestamps doctor checks, by frontmatter name.
+/// `scheduled_for` is left out: it is meant to be in the future.
+fn lifecycle_timestamps(issue: &Issue) -> [(&'static str, Option<time::OffsetDateTime>); 4] {
+    let fm = &issue.frontmatter;
+    [
+        ("created_at", Some(fm.created_at)),
+        ("started_at", fm.started_at),
+        ("completed_at", fm.completed_at),
+        ("escalated_at", fm.escalated_at),
+    ]
+}
+
+/// what's wrong with an issue's timestamps, e.g. `completed_at before started_at`.
+fn timestamp_problems(issue: &Issue, now: time::OffsetDateTime) -> Vec<String> {
+    let fm = &issue.frontmatter;
+    let mut problems: Vec<String> = lifecycle_timestamps(issue)
+        .into_iter()
+        .filter(|(_, at)| at.is_some_and(|at| at > now + FUTURE_SLACK))
+        .map(|(name, _)| format!("{} in the future", name))
+        .collect();
+    let created = fm.created_at.min(now);
+    if fm.started_at.is_some_and(|at| at < created) {
+        problems.push("started_at before created_at".to_string());
+    }
+    if let Some(completed) = fm.completed_at {
+        match fm.started_at {
+            Some(started) if completed < started => {
+                problems.push("completed_at before started_at".to_string())
+            }
+            _ if completed < created => problems.push("completed_at before created_at".to_string()),
+            _ => {}
+        }
+    }
+    if fm.escalated_at.is_some_and(|at| at < created) {
+        problems.push("escalated_at before created_at".to_string());
+    }
+    problems
+}
+
+/// pull future timestamps back to `now`, then raise each one to at least the
+/// one it must follow (created <= started <= completed, created <= escalated).
+fn clamp_timestamps(issue: &mut Issue, now: time::OffsetDateTime) {
+    let fm = &mut issue.frontmatter;
+    let clamp = |at: time::OffsetDateTime| if at > now + FUTURE_SLACK { now } else { at };
+    fm.created_at = clamp(fm.created_at);
+    let created = fm.created_at;
+    fm.started_at = fm.started_at.map(|at| clamp(at).max(created));
+    let started = fm.started_at.unwrap_or(created);
+    fm.completed_at = fm.completed_at.map(|at| clamp(at).max(started));
+    fm.escalated_at = fm.escalated_at.map(|at| clamp(at).max(created));
+}
+
+/// an issue file that fails to load.
+struct BrokenIssueFile {
+    path: std::path::PathBuf,
+    error: String,
+    /// the file with colon values quoted, if that makes it load.
+    fixed: Option<String>,
+}
+
+/// issue files in `issues_dir` that fail to load.
+fn unparseable_issue_files(issues_dir: &std::path::Path) -> Result<Vec<BrokenIssueFile>> {
+    let mut broken = Vec::new();
+    if !issues_dir.exists() {
+        return Ok(broken);
+    }
+    for entry in std::fs::read_dir(issues_dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+        let raw = std::fs::read_to_string(&path)?;
+        if let Err(e) = Issue::parse(&raw) {
+            let fixed = quote_colon_values(&raw).filter(|fixed| Issue::parse(fixed).is_ok());
+            broken.push(BrokenIssueFile {
+                path,
+                error: e.to_string(),
+                fixed,
+            });
+        }
+    }
+    broken.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(broken)
+}
+
+/// quote `title`, `tags` and `acceptance` values containing ": ", which yaml
+/// otherwise reads as a nested map (e.g. `- foo: bar`).
+/// returns None when nothing needed quoting.
+fn quote_colon_values(content: &str) -> Option<String> {
+    let mut out = String::with_capacity(content.len() + 16);
+    let mut changed = false;
+    let mut fences = 0;
+    let mut section = "";
+    for line in content.split_inclusive('\n') {
+        let text = line.trim_end_matches(['\n', '\r']);
+        if text.trim_end() == "---" {
+            fences += 1;
+        }
+        let mut fixed = None;
+        if fences == 1 && !text.trim().is_empty() && text.trim_end() != "---" {
+            if text.starts_with([' ', '-']) {
+                let item = text.trim_start().strip_prefix("- ");
+                if let Some(value) = item.filter(|_| matches!(section, "tags" | "acceptance")) {
+                    let prefix = &text[..text.len() - value.len()];
+                    fixed = quote_yaml_value(value).map(|v| format!("{prefix}{v}"));
+                }
+            } else {
+                // a top-level key starts a new section
+                let (key, value) = text.split_once(':').unwrap_or((text, ""));
+                section = key;
+                if key == "title" {
+                    fixed = quote_yaml_value(value.trim_start()).map(|v| format!("title: {v}"));
+                }
+            }
+        }
+        match fixed {
+            Some(fixed) => {
+                out.push_str(&fixed);
+                out.push_str(&line[text.len()..]);
+                changed = true;
+            }
+            None => out.push_str(line),
+        }
+    }
+    changed.then_some(out)
+}
+
+/// double-quote a plain scalar that contains ": " or ends with ":".
+fn quote_yaml_value(value: &str) -> Option<String> {
+    let value = value.trim_end();
+    let plain = !value.starts_with(['"', '\'', '[', '{', '|', '>']);
+    (plain && (value.contains(": ") || value.ends_with(':')))
+        .then(|| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")))
+}
+
+/// move an issue to a fresh id under the configured prefix, rewriting deps
+/// that point at it. `issues` is kept in sync so renumbers can be chained.
+fn renumber_issue(
+    paths: &RepoPaths,
+    config: &Config,
+    issues: &mut HashMap<String, Issue>,
+    old_id: &str,
+) -> Result<String> {
+    let issues_dir = paths.issues_dir(config);
+    let new_id = generate_issue_id(config, &issues_dir)?;
+
+    let mut issue = issues
+        .remove(old_id)
+        .ok_or_else(|| BrdError::IssueNotFound(old_id.to_string()))?;
+    let old_path = find_issue_file(&issues_dir, old_id)
+        .ok_or_else(|| BrdError::IssueNotFound(old_id.to_string()))?;
+    issue.frontmatter.id = new_id.clone();
+    issue.save_in(&issues_dir, config.filename_scheme)?;
+    std::fs::remove_file(old_path)?;
+    issues.insert(new_id.clone(), issue);
+
+    for other in issues.values_mut() {
+        let deps = &mut other.frontmatter.deps;
+        if deps.blocks.iter().chain(&deps.related).any(|d| d == old_id) {
+            for dep in deps.blocks.iter_mut().chain(&mut deps.related) {
+                if dep == old_id {
+                    *dep = new_id.clone();
+                }
+            }
+            other.save_in(&issues_dir, config.filename_scheme)?;
+        }
+    }
+
+    Ok(new_id)
+}
+
+/// ids of issues whose files are below the current schema, read from the raw
+/// frontmatter (loading would migrate them in memory). unparseable files are skipped.
+pub(super) fn issues_needing_migration(issues_dir: &std::path::Path) -> Result<Vec<String>> {
+    let mut needs_migration = Vec::new();
+    if !issues_dir.exists() {
+        return Ok(needs_migration);
+    }
+    for entry in std::fs::read_dir(issues_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        if let Ok((frontmatter_str, _)) = parse_frontmatter(&content)
+            && let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&frontmatter_str)
+        {
+            let version = migrate::get_schema_version(&yaml).unwrap_or(0);
+            if migrate::needs_migration(version) {
+                let id = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("unknown");
+                needs_migration.push(id.to_string());
+            }
+        }
+    }
+    Ok(needs_migration)
+}
+
 /// Check external repo config and return (schema_version, is_supported).
 fn check_external_config(
     paths: &RepoPaths,
@@ -580,7 +1094,7 @@ Test body."#,
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -589,7 +1103,7 @@ Test body."#,
         let (_dir, paths) = create_test_repo();
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
 
@@ -601,7 +1115,7 @@ Test body."#,
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
 
@@ -614,7 +1128,7 @@ Test body."#,
         let cli = make_cli();
 
         // Should pass (only AGENTS.md check fails, which is not an error)
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
@@ -628,7 +1142,7 @@ Test body."#,
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
 
@@ -642,10 +1156,34 @@ Test body."#,
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_missing_attachments() {
+        let (dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+        create_issue(&paths, "doc", &[]);
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+        fs::write(dir.path().join("docs/design.md"), "design\n").unwrap();
+
+        let config = crate::config::Config::default();
+        let mut issues = load_all_issues(&paths, &config).unwrap();
+        issues.get_mut("doc").unwrap().frontmatter.attachments =
+            vec!["docs/design.md".to_string(), "shots/gone.png".to_string()];
+
+        assert_eq!(
+            missing_attachments(&paths.worktree_root, &issues),
+            vec![("doc".to_string(), "shots/gone.png".to_string())]
+        );
+        // a moved attachment is a warning, doctor still passes
+        let issue_path = paths.issues_dir(&config).join("doc.md");
+        issues["doc"].save(&issue_path).unwrap();
+        assert!(cmd_doctor(&make_cli(), &paths, false).is_ok());
+    }
+
     #[test]
     fn test_doctor_dependency_cycle() {
         let (_dir, paths) = create_test_repo();
@@ -657,10 +1195,24 @@ Test body."#,
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_doctor_reports_newer_repo_schema() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        let config = crate::config::Config {
+            schema_version: CURRENT_SCHEMA + 1,
+            ..Default::default()
+        };
+        config.save(&paths.config_path()).unwrap();
+
+        let err = cmd_doctor(&make_cli(), &paths, false).unwrap_err();
+        assert!(err.to_string().contains("doctor found errors"));
+    }
+
     #[test]
     fn test_doctor_schema_needs_migration() {
         let (_dir, paths) = create_test_repo();
@@ -687,7 +1239,7 @@ Old issue."#;
         let cli = make_cli();
 
         // Schema migration warning doesn't cause failure
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
@@ -700,7 +1252,7 @@ Old issue."#;
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
 
@@ -721,7 +1273,7 @@ Old issue."#;
 
         let cli = make_cli();
 
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
@@ -740,10 +1292,166 @@ Old content
         let cli = make_cli();
 
         // Outdated agents block is not an error
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_doctor_fix_updates_outdated_block() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+
+        let old_block =
+            "# Agents\n\n<!-- braid:agents:start v1 -->\nOld content\n<!-- braid:agents:end -->\n";
+        fs::write(paths.worktree_root.join("AGENTS.md"), old_block).unwrap();
+
+        cmd_doctor(&make_cli(), &paths, true).unwrap();
+
+        assert_eq!(
+            check_agents_block(&paths, &crate::config::Config::default()),
+            Some(("AGENTS.md".to_string(), AGENTS_BLOCK_VERSION))
+        );
+        let content = fs::read_to_string(paths.worktree_root.join("AGENTS.md")).unwrap();
+        assert!(content.starts_with("# Agents"));
+        assert!(!content.contains("Old content"));
+    }
+
+    #[test]
+    fn test_doctor_fix_renumbers_issues_with_old_prefix() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+        create_issue(&paths, "brd-aaaa", &["old-bbbb"]);
+        create_issue(&paths, "old-bbbb", &["old-cccc"]);
+        create_issue(&paths, "old-cccc", &[]);
+
+        // without --fix it's only a warning
+        cmd_doctor(&make_cli(), &paths, false).unwrap();
+        let config = crate::config::Config::default();
+        assert!(
+            load_all_issues(&paths, &config)
+                .unwrap()
+                .contains_key("old-bbbb")
+        );
+
+        cmd_doctor(&make_cli(), &paths, true).unwrap();
+        let issues = load_all_issues(&paths, &config).unwrap();
+        assert_eq!(issues.len(), 3);
+        assert!(issues.keys().all(|id| id.starts_with("brd-")));
+        // deps follow the renumbered issues
+        let bbbb = &issues[&issues["brd-aaaa"].deps()[0]];
+        let cccc = &issues[&bbbb.deps()[0]];
+        assert!(cccc.deps().is_empty());
+    }
+
+    fn inverted_issue(id: &str) -> Issue {
+        let now = time::OffsetDateTime::now_utc();
+        let mut issue = Issue::new(
+            id.to_string(),
+            "inverted".to_string(),
+            crate::issue::Priority::P2,
+            vec![],
+        );
+        issue.frontmatter.created_at = now - time::Duration::days(2);
+        issue.frontmatter.started_at = Some(now - time::Duration::days(3));
+        issue.frontmatter.completed_at = Some(now + time::Duration::days(1));
+        issue
+    }
+
+    #[test]
+    fn test_timestamp_problems_and_clamp() {
+        let now = time::OffsetDateTime::now_utc();
+        let mut issue = inverted_issue("brd-aaaa");
+        assert_eq!(
+            timestamp_problems(&issue, now),
+            vec!["completed_at in the future", "started_at before created_at"]
+        );
+
+        clamp_timestamps(&mut issue, now);
+        assert!(timestamp_problems(&issue, now).is_empty());
+        let fm = &issue.frontmatter;
+        assert_eq!(fm.started_at, Some(fm.created_at));
+        assert_eq!(fm.completed_at, Some(now));
+
+        // completed before started is raised to started
+        let mut issue = inverted_issue("brd-bbbb");
+        issue.frontmatter.started_at = Some(now - time::Duration::hours(1));
+        issue.frontmatter.completed_at = Some(now - time::Duration::hours(2));
+        assert_eq!(
+            timestamp_problems(&issue, now),
+            vec!["completed_at before started_at"]
+        );
+        clamp_timestamps(&mut issue, now);
+        assert_eq!(issue.frontmatter.completed_at, issue.frontmatter.started_at);
+    }
+
+    #[test]
+    fn test_doctor_fix_clamps_inverted_timestamps() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+        let config = crate::config::Config::default();
+        inverted_issue("brd-aaaa")
+            .save(&paths.issues_dir(&config).join("brd-aaaa.md"))
+            .unwrap();
+
+        // without --fix it's only a warning, and nothing is written
+        cmd_doctor(&make_cli(), &paths, false).unwrap();
+        let now = time::OffsetDateTime::now_utc();
+        let issues = load_all_issues(&paths, &config).unwrap();
+        assert!(!timestamp_problems(&issues["brd-aaaa"], now).is_empty());
+
+        cmd_doctor(&make_cli(), &paths, true).unwrap();
+        let issues = load_all_issues(&paths, &config).unwrap();
+        assert!(timestamp_problems(&issues["brd-aaaa"], now).is_empty());
+    }
+
+    #[test]
+    fn test_quote_colon_values() {
+        let raw = "---\ntitle: fix: the parser\ntags:\n- a: b\n- plain\nacceptance:\n  - foo: bar\n  - \"already: quoted\"\ndeps:\n- id: brd-x\n  kind: related\n---\nbody: text\n";
+        let fixed = quote_colon_values(raw).unwrap();
+        assert_eq!(
+            fixed,
+            "---\ntitle: \"fix: the parser\"\ntags:\n- \"a: b\"\n- plain\nacceptance:\n  - \"foo: bar\"\n  - \"already: quoted\"\ndeps:\n- id: brd-x\n  kind: related\n---\nbody: text\n"
+        );
+        assert!(quote_colon_values(&fixed).is_none());
+    }
+
+    #[test]
+    fn test_doctor_fix_quotes_colon_values() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+        create_issue(&paths, "brd-aaaa", &[]);
+        let config = crate::config::Config::default();
+        let path = paths.issues_dir(&config).join("brd-aaaa.md");
+        let raw = fs::read_to_string(&path).unwrap();
+        fs::write(
+            &path,
+            raw.replace("acceptance: []", "acceptance:\n- foo: bar"),
+        )
+        .unwrap();
+
+        // without --fix the file is reported and left as is
+        assert!(cmd_doctor(&make_cli(), &paths, false).is_err());
+        assert!(load_all_issues(&paths, &config).unwrap().is_empty());
+
+        cmd_doctor(&make_cli(), &paths, true).unwrap();
+        let issues = load_all_issues(&paths, &config).unwrap();
+        assert_eq!(issues["brd-aaaa"].frontmatter.acceptance, vec!["foo: bar"]);
+    }
+
+    #[test]
+    fn test_doctor_fix_leaves_missing_block_alone() {
+        let (_dir, paths) = create_test_repo();
+        create_braid_dir(&paths);
+        create_valid_config(&paths);
+
+        cmd_doctor(&make_cli(), &paths, true).unwrap();
+        assert!(!paths.worktree_root.join("AGENTS.md").exists());
+    }
+
     #[test]
     fn test_doctor_agents_block_mode_match() {
         let (_dir, paths) = create_test_repo();
@@ -762,7 +1470,7 @@ Old content
         let cli = make_cli();
 
         // Mode matches, should pass
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
@@ -790,7 +1498,7 @@ Old content
         let cli = make_cli();
 
         // Mode mismatch is informational, not an error
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_ok());
     }
 
@@ -806,7 +1514,55 @@ Old content
         let cli = make_cli();
 
         // Should fail and aggregate multiple errors
-        let result = cmd_doctor(&cli, &paths);
+        let result = cmd_doctor(&cli, &paths, false);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_main_branch_name() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        git::test::run_ok(root, &["init", "-b", "trunk"]);
+        git::test::run_ok(root, &["config", "user.email", "test@test.com"]);
+        git::test::run_ok(root, &["config", "user.name", "tester"]);
+        git::test::run_ok(root, &["config", "commit.gpgsign", "false"]);
+        git::test::run_ok(root, &["commit", "--allow-empty", "-m", "init"]);
+        git::test::run_ok(root, &["checkout", "-b", "feature"]);
+
+        // no origin: whatever is checked out
+        assert_eq!(main_branch_name(root).as_deref(), Some("feature"));
+
+        // origin's default branch wins
+        let origin = tempdir().unwrap();
+        git::test::run_ok(origin.path(), &["init", "--bare", "-b", "trunk"]);
+        let url = origin.path().to_string_lossy().to_string();
+        git::test::run_ok(root, &["remote", "add", "origin", &url]);
+        git::test::run_ok(root, &["push", "origin", "trunk"]);
+        git::test::run_ok(root, &["remote", "set-head", "origin", "trunk"]);
+        assert_eq!(main_branch_name(root).as_deref(), Some("trunk"));
+    }
+
+    #[test]
+    fn test_divergence_describe() {
+        let mut div = Divergence {
+            branch: "braid-issues".to_string(),
+            upstream: "origin/braid-issues".to_string(),
+            ahead: 0,
+            behind: 0,
+        };
+        assert!(!div.diverged());
+        assert_eq!(
+            div.describe(),
+            "braid-issues is up to date with origin/braid-issues"
+        );
+
+        div.ahead = 2;
+        div.behind = 1;
+        assert!(div.diverged());
+        assert_eq!(
+            div.describe(),
+            "braid-issues is 2 ahead, 1 behind origin/braid-issues"
+        );
+        assert_eq!(div.to_json()["behind"], 1);
+    }
 }
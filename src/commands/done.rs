@@ -1,30 +1,42 @@
 //! brd done command.
 
-use crate::cli::Cli;
+use crate::api;
+use crate::cli::{Cli, DoneArgs};
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::graph::would_create_cycle;
-use crate::issue::{Issue, IssueType, Status};
+use crate::issue::{Issue, Status};
 use crate::lock::LockGuard;
 use crate::repo::{self, RepoPaths};
 
-use super::start::{commit_and_push_issues_branch_with_action, commit_and_push_main_with_action};
+use super::pick::pick_issue;
+use super::start::{auto_pull, auto_push};
 use super::{issue_to_json, load_all_issues, resolve_issue_id};
 use std::collections::{HashMap, HashSet};
 
-pub fn cmd_done(
-    cli: &Cli,
-    paths: &RepoPaths,
-    id: Option<&str>,
-    force: bool,
-    result_ids: &[String],
-    no_push: bool,
-) -> Result<()> {
+/// why picking among the in-progress `ids` failed: outside a terminal, ask
+/// for an explicit id; anything else, like a cancelled picker, passes through.
+fn pick_error(err: BrdError, ids: &[String]) -> BrdError {
+    match err {
+        BrdError::NotInteractive(_) => BrdError::InvalidInput(format!(
+            "multiple issues in progress: {}. specify which to complete",
+            ids.join(", ")
+        )),
+        err => err,
+    }
+}
+
+pub fn cmd_done(cli: &Cli, paths: &RepoPaths, args: &DoneArgs) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
-    let _lock = LockGuard::acquire(&paths.lock_path())?;
 
-    let mut issues = load_all_issues(paths, &config)?;
-    let full_id = match id {
+    // pull first so we complete against the latest remote state.
+    // unrelated changes block the pull unless --stash sets them aside.
+    auto_pull(paths, &config, cli, args.no_sync, args.stash)?;
+
+    // resolve (and maybe pick) before taking the lock, so an open picker
+    // doesn't hold up other brd processes
+    let issues = load_all_issues(paths, &config)?;
+    let full_id = match args.id.as_deref() {
         Some(partial) => resolve_issue_id(partial, &issues)?,
         None => {
             let agent_id = repo::get_agent_id(&paths.worktree_root);
@@ -37,65 +49,43 @@ pub fn cmd_done(
 
             match doing.len() {
                 0 => {
-                    return Err(BrdError::Other(
+                    return Err(BrdError::InvalidInput(
                         "no issue in progress. specify an issue ID or run `brd start` first"
                             .to_string(),
                     ));
                 }
                 1 => doing[0].id().to_string(),
                 _ => {
-                    let ids: Vec<_> = doing.iter().map(|i| i.id()).collect();
-                    return Err(BrdError::Other(format!(
-                        "multiple issues in progress: {}. specify which to complete",
-                        ids.join(", ")
-                    )));
+                    let ids: Vec<_> = doing.iter().map(|i| i.id().to_string()).collect();
+                    pick_issue(cli, "done", doing).map_err(|e| pick_error(e, &ids))?
                 }
             }
         }
     };
-    let mut changed_ids = HashSet::new();
 
-    // check if this is a design issue
-    let is_design = issues
-        .get(&full_id)
-        .map(|i| i.issue_type() == Some(IssueType::Design))
-        .unwrap_or(false);
-
-    // design issues require --result or --force
-    if is_design && result_ids.is_empty() && !force {
-        return Err(BrdError::Other(
-            "design issues require --result <issue-id> to specify resulting issues\n\
-             use --force to close without results"
-                .to_string(),
-        ));
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    // reload under the lock; the issue may have changed meanwhile
+    let mut issues = load_all_issues(paths, &config)?;
+    if !issues.contains_key(&full_id) {
+        return Err(BrdError::IssueNotFound(full_id));
     }
+    let mut changed_ids = HashSet::new();
 
-    // meta issues require all children to be done/skip, or --force
-    let is_meta = issues
-        .get(&full_id)
-        .map(|i| i.issue_type() == Some(IssueType::Meta))
-        .unwrap_or(false);
-
-    if is_meta && !force {
-        let derived = crate::graph::compute_derived(issues.get(&full_id).unwrap(), &issues);
-        if !derived.open_deps.is_empty() {
-            let total = issues.get(&full_id).unwrap().deps().len();
-            let done = total - derived.open_deps.len();
-            return Err(BrdError::Other(format!(
-                "meta issue has open children ({}/{})\n\
-                 use --force to close anyway",
-                done, total
-            )));
+    if !args.force {
+        let has_results = !args.result.is_empty();
+        api::check_done(&issues[&full_id], &issues, &config, has_results)?;
+        if !cli.json {
+            warn_unchecked(&issues[&full_id]);
         }
     }
 
     // resolve and validate result issue IDs
     let mut resolved_results = Vec::new();
     let mut seen_results = HashSet::new();
-    for result_id in result_ids {
+    for result_id in &args.result {
         let resolved = resolve_issue_id(result_id, &issues)?;
         if resolved == full_id {
-            return Err(BrdError::Other(
+            return Err(BrdError::InvalidInput(
                 "design issue cannot list itself as a result".to_string(),
             ));
         }
@@ -137,9 +127,9 @@ pub fn cmd_done(
                 let dependent = issues
                     .get_mut(dependent_id)
                     .ok_or_else(|| BrdError::IssueNotFound(dependent_id.clone()))?;
-                let before_len = dependent.frontmatter.deps.len();
-                dependent.frontmatter.deps.retain(|d| d != &full_id);
-                if dependent.frontmatter.deps.len() != before_len {
+                let before_len = dependent.frontmatter.deps.blocks.len();
+                dependent.frontmatter.deps.blocks.retain(|d| d != &full_id);
+                if dependent.frontmatter.deps.blocks.len() != before_len {
                     changed_ids.insert(dependent_id.clone());
                 }
             }
@@ -186,37 +176,66 @@ pub fn cmd_done(
         let issue = issues
             .get(&issue_id)
             .ok_or_else(|| BrdError::IssueNotFound(issue_id.clone()))?;
-        let issue_path = paths.issues_dir(&config).join(format!("{}.md", issue_id));
-        issue.save(&issue_path)?;
+        issue.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
     }
 
     // Commit and push if auto_push is enabled (unless --no-push)
-    if !no_push && config.auto_push {
-        if config.is_issues_branch_mode() {
-            commit_and_push_issues_branch_with_action(paths, &config, &full_id, "done", cli)?;
-        } else {
-            commit_and_push_main_with_action(paths, &full_id, "done", cli)?;
-        }
-    }
+    auto_push(paths, &config, &full_id, "done", cli, args.no_push)?;
 
     if cli.json {
         let issue = issues.get(&full_id).unwrap();
-        let json = issue_to_json(issue, &issues);
+        let json = issue_to_json(issue, &issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         println!("Done: {}", full_id);
+        let pushed = !args.no_push && config.auto_push;
+        let in_worktree = repo::is_agent_worktree(&paths.worktree_root);
+        if let Some(hint) = done_hint(in_worktree, config.is_issues_branch_mode(), pushed) {
+            eprintln!("{}", hint);
+        }
     }
 
     Ok(())
 }
 
+/// warn about acceptance items still unchecked on an issue being closed.
+pub(crate) fn warn_unchecked(issue: &Issue) {
+    let unchecked = issue.unchecked_acceptance();
+    if unchecked.is_empty() {
+        return;
+    }
+    let items: Vec<String> = unchecked
+        .iter()
+        .map(|ac| format!("  - [ ] {}", ac))
+        .collect();
+    eprintln!(
+        "warning: {} acceptance item(s) unchecked:\n{}",
+        unchecked.len(),
+        items.join("\n")
+    );
+}
+
+/// the next step after `brd done`: land the code from an agent worktree, or
+/// share the issue change when nothing pushed it.
+fn done_hint(in_agent_worktree: bool, issues_branch: bool, pushed: bool) -> Option<&'static str> {
+    if in_agent_worktree {
+        Some("next: `brd agent merge` to land this on main, or `brd agent pr` to open a PR")
+    } else if issues_branch && !pushed {
+        Some("next: `brd sync` to share it")
+    } else {
+        None
+    }
+}
+
 fn add_dep_checked(
     issues: &mut HashMap<String, Issue>,
     child_id: &str,
     parent_id: &str,
 ) -> Result<bool> {
     if child_id == parent_id {
-        return Err(BrdError::Other("cannot add self-dependency".to_string()));
+        return Err(BrdError::InvalidInput(
+            "cannot add self-dependency".to_string(),
+        ));
     }
 
     let parent = parent_id.to_string();
@@ -224,13 +243,13 @@ fn add_dep_checked(
         .get(child_id)
         .ok_or_else(|| BrdError::IssueNotFound(child_id.to_string()))?;
 
-    if child.frontmatter.deps.contains(&parent) {
+    if child.frontmatter.deps.blocks.contains(&parent) {
         return Ok(false);
     }
 
     if let Some(cycle_path) = would_create_cycle(child_id, &parent, issues) {
         let cycle_str = cycle_path.join(" -> ");
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::DependencyCycle(format!(
             "cannot add dependency: would create cycle: {}",
             cycle_str
         )));
@@ -239,7 +258,7 @@ fn add_dep_checked(
     let child = issues
         .get_mut(child_id)
         .ok_or_else(|| BrdError::IssueNotFound(child_id.to_string()))?;
-    child.frontmatter.deps.push(parent);
+    child.frontmatter.deps.blocks.push(parent);
 
     Ok(true)
 }
@@ -247,8 +266,19 @@ fn add_dep_checked(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::issue::IssueType;
     use crate::test_utils::{TestRepo, test_cli};
 
+    /// done without syncing or pushing.
+    fn done_args(id: &str) -> DoneArgs {
+        DoneArgs {
+            id: Some(id.to_string()),
+            no_sync: true,
+            no_push: true,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_done_sets_status_and_clears_owner() {
         let repo = TestRepo::builder().build();
@@ -257,7 +287,7 @@ mod tests {
             .owner("tester")
             .create();
 
-        cmd_done(&test_cli(), &repo.paths, Some("brd-aaaa"), false, &[], true).unwrap();
+        cmd_done(&test_cli(), &repo.paths, &done_args("brd-aaaa")).unwrap();
 
         let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
         let issue = issues.get("brd-aaaa").unwrap();
@@ -268,15 +298,7 @@ mod tests {
     #[test]
     fn test_done_issue_not_found() {
         let repo = TestRepo::builder().build();
-        let err = cmd_done(
-            &test_cli(),
-            &repo.paths,
-            Some("brd-missing"),
-            false,
-            &[],
-            true,
-        )
-        .unwrap_err();
+        let err = cmd_done(&test_cli(), &repo.paths, &done_args("brd-missing")).unwrap_err();
         assert!(matches!(err, BrdError::IssueNotFound(_)));
     }
 
@@ -286,7 +308,7 @@ mod tests {
         repo.issue("brd-aaaa").create();
         repo.issue("brd-aaab").create();
 
-        let err = cmd_done(&test_cli(), &repo.paths, Some("aaa"), false, &[], true).unwrap_err();
+        let err = cmd_done(&test_cli(), &repo.paths, &done_args("aaa")).unwrap_err();
         assert!(matches!(err, BrdError::AmbiguousId(_, _)));
     }
 
@@ -297,15 +319,7 @@ mod tests {
             .issue_type(IssueType::Design)
             .create();
 
-        let err = cmd_done(
-            &test_cli(),
-            &repo.paths,
-            Some("brd-design"),
-            false,
-            &[],
-            true,
-        )
-        .unwrap_err();
+        let err = cmd_done(&test_cli(), &repo.paths, &done_args("brd-design")).unwrap_err();
         assert!(err.to_string().contains("design issues require --result"));
     }
 
@@ -319,10 +333,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            true,
-            &[],
-            true,
+            &DoneArgs {
+                force: true,
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -341,10 +355,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -362,10 +376,10 @@ mod tests {
         let err = cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-missing".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-missing".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap_err();
         assert!(matches!(err, BrdError::IssueNotFound(_)));
@@ -383,10 +397,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -410,10 +424,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -438,10 +452,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl1".to_string(), "brd-impl2".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl1".to_string(), "brd-impl2".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -480,10 +494,10 @@ mod tests {
         cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl1".to_string(), "brd-impl2".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl1".to_string(), "brd-impl2".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap();
 
@@ -521,8 +535,7 @@ mod tests {
         repo.issue("brd-child1").create();
         repo.issue("brd-child2").status(Status::Done).create();
 
-        let err =
-            cmd_done(&test_cli(), &repo.paths, Some("brd-meta"), false, &[], true).unwrap_err();
+        let err = cmd_done(&test_cli(), &repo.paths, &done_args("brd-meta")).unwrap_err();
         assert!(err.to_string().contains("open children"));
     }
 
@@ -535,7 +548,15 @@ mod tests {
             .create();
         repo.issue("brd-child1").create();
 
-        cmd_done(&test_cli(), &repo.paths, Some("brd-meta"), true, &[], true).unwrap();
+        cmd_done(
+            &test_cli(),
+            &repo.paths,
+            &DoneArgs {
+                force: true,
+                ..done_args("brd-meta")
+            },
+        )
+        .unwrap();
 
         let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
         assert_eq!(issues.get("brd-meta").unwrap().status(), Status::Done);
@@ -551,7 +572,7 @@ mod tests {
         repo.issue("brd-child1").status(Status::Done).create();
         repo.issue("brd-child2").status(Status::Done).create();
 
-        cmd_done(&test_cli(), &repo.paths, Some("brd-meta"), false, &[], true).unwrap();
+        cmd_done(&test_cli(), &repo.paths, &done_args("brd-meta")).unwrap();
 
         let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
         assert_eq!(issues.get("brd-meta").unwrap().status(), Status::Done);
@@ -569,10 +590,10 @@ mod tests {
         let err = cmd_done(
             &test_cli(),
             &repo.paths,
-            Some("brd-design"),
-            false,
-            &["brd-impl".to_string()],
-            true,
+            &DoneArgs {
+                result: vec!["brd-impl".to_string()],
+                ..done_args("brd-design")
+            },
         )
         .unwrap_err();
         assert!(err.to_string().contains("cycle"));
@@ -582,4 +603,128 @@ mod tests {
         assert!(dependent.deps().contains(&"brd-design".to_string()));
         assert_eq!(issues.get("brd-design").unwrap().status(), Status::Open);
     }
+
+    #[test]
+    fn test_done_all_acceptance_checked() {
+        let repo = TestRepo::builder().with_required_acceptance().build();
+        repo.issue("brd-aaaa")
+            .acceptance(&["[x] tests pass", "[X] docs updated", "reviewed"])
+            .create();
+
+        cmd_done(&test_cli(), &repo.paths, &done_args("brd-aaaa")).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues.get("brd-aaaa").unwrap().status(), Status::Done);
+    }
+
+    #[test]
+    fn test_done_unchecked_acceptance_warns_by_default() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa")
+            .acceptance(&["[x] tests pass", "[ ] docs updated"])
+            .create();
+
+        cmd_done(&test_cli(), &repo.paths, &done_args("brd-aaaa")).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues.get("brd-aaaa").unwrap().status(), Status::Done);
+    }
+
+    #[test]
+    fn test_done_unchecked_acceptance_refused_when_required() {
+        let repo = TestRepo::builder().with_required_acceptance().build();
+        repo.issue("brd-aaaa")
+            .acceptance(&["[x] tests pass", "[ ] docs updated"])
+            .create();
+
+        let err = cmd_done(&test_cli(), &repo.paths, &done_args("brd-aaaa")).unwrap_err();
+        assert!(matches!(err, BrdError::IssueBlocked(_)));
+        assert!(err.to_string().contains("[ ] docs updated"));
+        assert!(!err.to_string().contains("tests pass"));
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues.get("brd-aaaa").unwrap().status(), Status::Open);
+    }
+
+    #[test]
+    fn test_done_unchecked_acceptance_with_force() {
+        let repo = TestRepo::builder().with_required_acceptance().build();
+        repo.issue("brd-aaaa")
+            .acceptance(&["[ ] docs updated"])
+            .create();
+
+        cmd_done(
+            &test_cli(),
+            &repo.paths,
+            &DoneArgs {
+                force: true,
+                ..done_args("brd-aaaa")
+            },
+        )
+        .unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues.get("brd-aaaa").unwrap().status(), Status::Done);
+    }
+
+    #[test]
+    fn test_done_hint_depends_on_context() {
+        assert!(
+            done_hint(true, false, true)
+                .unwrap()
+                .contains("brd agent merge")
+        );
+        assert!(
+            done_hint(true, true, false)
+                .unwrap()
+                .contains("brd agent merge")
+        );
+        assert!(done_hint(false, true, false).unwrap().contains("brd sync"));
+        assert_eq!(done_hint(false, true, true), None);
+        assert_eq!(done_hint(false, false, false), None);
+    }
+
+    #[test]
+    fn test_done_sync_refuses_dirty_tree_without_stash() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").status(Status::Doing).create();
+        let root = &repo.paths.worktree_root;
+        crate::git::test::run_ok(root, &["init"]);
+        crate::git::test::run_ok(root, &["config", "user.email", "test@test.com"]);
+        crate::git::test::run_ok(root, &["config", "user.name", "tester"]);
+        crate::git::test::run_ok(root, &["config", "commit.gpgsign", "false"]);
+        crate::git::test::run_ok(root, &["commit", "--allow-empty", "-m", "init"]);
+        let origin = ["remote", "add", "origin", "https://example.com/repo.git"];
+        crate::git::test::run_ok(root, &origin);
+        std::fs::write(root.join("dirty.txt"), "uncommitted").unwrap();
+
+        let syncing = DoneArgs {
+            no_sync: false,
+            ..done_args("brd-aaaa")
+        };
+        let err = cmd_done(&test_cli(), &repo.paths, &syncing).unwrap_err();
+        assert!(matches!(err, BrdError::DirtyWorkingTree(_)));
+
+        // --stash sets the change aside for the (failing) pull and restores it
+        let stashing = DoneArgs {
+            stash: true,
+            ..syncing
+        };
+        cmd_done(&test_cli(), &repo.paths, &stashing).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.join("dirty.txt")).unwrap(),
+            "uncommitted"
+        );
+    }
+
+    #[test]
+    fn test_pick_error_keeps_cancel() {
+        let ids = vec!["brd-aaaa".to_string(), "brd-bbbb".to_string()];
+        assert!(matches!(
+            pick_error(BrdError::Cancelled, &ids),
+            BrdError::Cancelled
+        ));
+        let err = pick_error(BrdError::NotInteractive("x".into()), &ids);
+        assert!(err.to_string().contains("brd-aaaa, brd-bbbb"));
+    }
 }
@@ -1,10 +1,12 @@
 //! brd edit command - open an issue in $EDITOR.
 
+use std::path::Path;
+
 use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::is_interactive;
-use crate::issue::Status;
+use crate::issue::{Issue, Status, find_issue_file};
 use crate::repo::{self, RepoPaths};
 
 use super::{load_all_issues, resolve_issue_id};
@@ -13,7 +15,7 @@ use super::{load_all_issues, resolve_issue_id};
 pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) -> Result<()> {
     // check for interactive terminal
     if !force && !is_interactive() {
-        return Err(BrdError::Other(
+        return Err(BrdError::NotInteractive(
             "brd edit requires an interactive terminal.\n\
              hint: read the issue file directly at .braid/issues/<id>.md\n\
              hint: use --force to override this check"
@@ -38,7 +40,7 @@ pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) ->
 
             match doing.len() {
                 0 => {
-                    return Err(BrdError::Other(
+                    return Err(BrdError::InvalidInput(
                         "no issue in progress. specify an issue ID or run `brd start` first"
                             .to_string(),
                     ));
@@ -46,7 +48,7 @@ pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) ->
                 1 => doing[0].id().to_string(),
                 _ => {
                     let ids: Vec<_> = doing.iter().map(|i| i.id()).collect();
-                    return Err(BrdError::Other(format!(
+                    return Err(BrdError::InvalidInput(format!(
                         "multiple issues in progress: {}. specify which to edit",
                         ids.join(", ")
                     )));
@@ -56,21 +58,11 @@ pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) ->
     };
 
     // get issue path
-    let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
+    let issues_dir = paths.issues_dir(&config);
+    let issue_path = find_issue_file(&issues_dir, &full_id)
+        .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
 
-    if !issue_path.exists() {
-        return Err(BrdError::Other(format!(
-            "issue file not found: {}",
-            issue_path.display()
-        )));
-    }
-
-    // get editor from $EDITOR or $VISUAL
-    let editor = std::env::var("EDITOR")
-        .or_else(|_| std::env::var("VISUAL"))
-        .map_err(|_| {
-            BrdError::Other("$EDITOR or $VISUAL not set. set one to use `brd edit`".to_string())
-        })?;
+    let editor = editor_from_env()?;
 
     if cli.json {
         let json = serde_json::json!({
@@ -82,9 +74,29 @@ pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) ->
         return Ok(());
     }
 
-    // open editor
-    let status = std::process::Command::new(&editor)
-        .arg(&issue_path)
+    run_editor(&editor, &issue_path)?;
+
+    // a new title means a new file name under the id-slug scheme
+    let issue = Issue::load(&issue_path)?;
+    if issue_path.file_name() != Some(issue.file_name(config.filename_scheme).as_ref()) {
+        issue.save_in(&issues_dir, config.filename_scheme)?;
+    }
+    Ok(())
+}
+
+/// get the editor from $EDITOR or $VISUAL.
+pub(super) fn editor_from_env() -> Result<String> {
+    std::env::var("EDITOR")
+        .or_else(|_| std::env::var("VISUAL"))
+        .map_err(|_| {
+            BrdError::Config("$EDITOR or $VISUAL not set. set one to use `brd edit`".to_string())
+        })
+}
+
+/// open `issue_path` in `editor`, then check the saved file still parses.
+pub(super) fn run_editor(editor: &str, issue_path: &Path) -> Result<()> {
+    let status = std::process::Command::new(editor)
+        .arg(issue_path)
         .status()?;
 
     if !status.success() {
@@ -95,5 +107,51 @@ pub fn cmd_edit(cli: &Cli, paths: &RepoPaths, id: Option<&str>, force: bool) ->
         )));
     }
 
+    Issue::load(issue_path).map_err(|e| {
+        BrdError::Other(format!(
+            "{} is no longer a valid issue after editing: {}\n\
+             hint: run `brd edit` again to fix it",
+            issue_path.display(),
+            e
+        ))
+    })?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestRepo;
+
+    #[test]
+    fn test_run_editor_accepts_valid_issue() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        let path = repo.paths.issues_dir(&repo.config).join("brd-aaaa.md");
+
+        // `true` leaves the file as it is
+        run_editor("true", &path).unwrap();
+    }
+
+    #[test]
+    fn test_run_editor_rejects_broken_issue() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        let path = repo.paths.issues_dir(&repo.config).join("brd-aaaa.md");
+        std::fs::write(&path, "---\nnot: [valid\n---\n").unwrap();
+
+        let err = run_editor("true", &path).unwrap_err().to_string();
+        assert!(err.contains("no longer a valid issue"));
+    }
+
+    #[test]
+    fn test_run_editor_reports_failing_editor() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        let path = repo.paths.issues_dir(&repo.config).join("brd-aaaa.md");
+
+        let err = run_editor("false", &path).unwrap_err().to_string();
+        assert!(err.contains("exited with status"));
+    }
+}
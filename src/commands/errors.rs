@@ -0,0 +1,33 @@
+//! brd errors command - list the stable error codes for scripting.
+
+use crate::cli::Cli;
+use crate::error::{ERROR_CODES, Result};
+
+pub fn cmd_errors(cli: &Cli) -> Result<()> {
+    if cli.json {
+        let codes: Vec<_> = ERROR_CODES
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "code": e.code,
+                    "exit": i32::from(e.exit),
+                    "meaning": e.meaning,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&codes).unwrap());
+        return Ok(());
+    }
+
+    let width = ERROR_CODES.iter().map(|e| e.code.len()).max().unwrap_or(0);
+    println!("{:<width$}  exit  meaning", "code");
+    for e in ERROR_CODES {
+        println!(
+            "{:<width$}  {:>4}  {}",
+            e.code,
+            i32::from(e.exit),
+            e.meaning
+        );
+    }
+    Ok(())
+}
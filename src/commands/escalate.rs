@@ -0,0 +1,269 @@
+//! brd escalate command - raise the priority of open issues left untouched
+//! for too long.
+
+use std::collections::HashMap;
+
+use time::{Duration, OffsetDateTime};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::issue::{Issue, Priority, Status};
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+use super::load_all_issues;
+
+/// one priority bump.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Escalation {
+    pub id: String,
+    pub from: Priority,
+    pub to: Priority,
+    /// how long the issue had been waiting
+    pub waited: Duration,
+}
+
+/// when an issue's idle clock started.
+pub(crate) type IdleSince = fn(&Issue) -> OffsetDateTime;
+
+/// when an issue's wait started: its creation, or its last escalation.
+fn waiting_since(issue: &Issue) -> OffsetDateTime {
+    issue
+        .frontmatter
+        .escalated_at
+        .map_or(issue.frontmatter.created_at, |at| {
+            at.max(issue.frontmatter.created_at)
+        })
+}
+
+/// when an issue was last touched: its latest timestamp, or its last
+/// escalation if that came later.
+pub(crate) fn last_touched(issue: &Issue) -> OffsetDateTime {
+    issue
+        .frontmatter
+        .escalated_at
+        .map_or(issue.updated_at(), |at| at.max(issue.updated_at()))
+}
+
+/// open issues below P0 that have been idle longer than `after`, counting
+/// from `since`, by id.
+pub(crate) fn plan_escalations(
+    issues: &HashMap<String, Issue>,
+    after: Duration,
+    now: OffsetDateTime,
+    since: IdleSince,
+) -> Vec<Escalation> {
+    let mut plan: Vec<_> = issues
+        .values()
+        .filter(|issue| issue.status() == Status::Open)
+        .filter_map(|issue| {
+            let waited = now - since(issue);
+            let to = issue.priority().raised()?;
+            (waited > after).then(|| Escalation {
+                id: issue.id().to_string(),
+                from: issue.priority(),
+                to,
+                waited,
+            })
+        })
+        .collect();
+    plan.sort_by(|a, b| a.id.cmp(&b.id));
+    plan
+}
+
+/// raise the priority of every issue that has waited longer than
+/// `after_days`, stamping `escalated_at` so the clock restarts. the caller
+/// holds the lock. with `dry_run`, nothing is written.
+pub(crate) fn escalate_issues(
+    paths: &RepoPaths,
+    config: &Config,
+    after_days: u32,
+    dry_run: bool,
+) -> Result<Vec<Escalation>> {
+    let mut issues = load_all_issues(paths, config)?;
+    let now = OffsetDateTime::now_utc();
+    let after = Duration::days(after_days.into());
+    let plan = plan_escalations(&issues, after, now, waiting_since);
+    if !dry_run {
+        apply_escalations(paths, config, &mut issues, &plan, now)?;
+    }
+    Ok(plan)
+}
+
+/// write each planned bump, stamping `escalated_at` with `now`. the caller
+/// holds the lock.
+pub(crate) fn apply_escalations(
+    paths: &RepoPaths,
+    config: &Config,
+    issues: &mut HashMap<String, Issue>,
+    plan: &[Escalation],
+    now: OffsetDateTime,
+) -> Result<()> {
+    let issues_dir = paths.issues_dir(config);
+    for escalation in plan {
+        let issue = issues
+            .get_mut(&escalation.id)
+            .ok_or_else(|| BrdError::IssueNotFound(escalation.id.clone()))?;
+        issue.frontmatter.priority = escalation.to;
+        issue.frontmatter.escalated_at = Some(now);
+        issue.save_in(&issues_dir, config.filename_scheme)?;
+    }
+    Ok(())
+}
+
+/// one line per escalation, e.g. `brd-abc1: P2 -> P1 (waiting 21d)`.
+pub(crate) fn format_escalation(escalation: &Escalation) -> String {
+    format!(
+        "{}: {} -> {} (waiting {}d)",
+        escalation.id,
+        escalation.from,
+        escalation.to,
+        escalation.waited.whole_days()
+    )
+}
+
+pub fn cmd_escalate(
+    cli: &Cli,
+    paths: &RepoPaths,
+    after_days: Option<u32>,
+    dry_run: bool,
+) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+    let after_days = after_days.or(config.escalate_after_days).ok_or_else(|| {
+        BrdError::Config(
+            "no threshold: pass --after <days> or set escalate_after_days in .braid/config.toml"
+                .to_string(),
+        )
+    })?;
+    if after_days == 0 {
+        return Err(BrdError::InvalidInput(
+            "--after must be at least 1".to_string(),
+        ));
+    }
+
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    let escalated = escalate_issues(paths, &config, after_days, dry_run)?;
+
+    if cli.json {
+        let items: Vec<_> = escalated
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "id": e.id,
+                    "from": e.from.to_string(),
+                    "to": e.to.to_string(),
+                    "waited_days": e.waited.whole_days(),
+                })
+            })
+            .collect();
+        let json = serde_json::json!({
+            "dry_run": dry_run,
+            "after_days": after_days,
+            "escalated": items,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return Ok(());
+    }
+
+    if escalated.is_empty() {
+        println!(
+            "no open issues below P0 waiting longer than {}d",
+            after_days
+        );
+        return Ok(());
+    }
+    let verb = if dry_run {
+        "would escalate"
+    } else {
+        "escalated"
+    };
+    for escalation in &escalated {
+        println!("{} {}", verb, format_escalation(escalation));
+    }
+    println!("\n{} {} issue(s)", verb, escalated.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    fn aged(id: &str, priority: Priority, days: i64) -> Issue {
+        let mut issue = Issue::new(id.to_string(), id.to_string(), priority, vec![]);
+        issue.frontmatter.created_at = OffsetDateTime::now_utc() - Duration::days(days);
+        issue
+    }
+
+    fn save(repo: &TestRepo, issue: &Issue) {
+        let path = repo
+            .paths
+            .issues_dir(&repo.config)
+            .join(format!("{}.md", issue.id()));
+        issue.save(&path).unwrap();
+    }
+
+    #[test]
+    fn test_plan_escalations_picks_stale_open_issues() {
+        let mut doing = aged("brd-dddd", Priority::P2, 30);
+        doing.frontmatter.status = Status::Doing;
+        let issues: HashMap<String, Issue> = [
+            aged("brd-aaaa", Priority::P2, 30),
+            aged("brd-bbbb", Priority::P2, 3),
+            aged("brd-cccc", Priority::P0, 30),
+            doing,
+        ]
+        .into_iter()
+        .map(|issue| (issue.id().to_string(), issue))
+        .collect();
+
+        let now = OffsetDateTime::now_utc();
+        let plan = plan_escalations(&issues, Duration::days(14), now, waiting_since);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].id, "brd-aaaa");
+        assert_eq!((plan[0].from, plan[0].to), (Priority::P2, Priority::P1));
+        assert_eq!(
+            format_escalation(&plan[0]),
+            "brd-aaaa: P2 -> P1 (waiting 30d)"
+        );
+    }
+
+    #[test]
+    fn test_escalate_restarts_the_clock() {
+        let repo = TestRepo::default();
+        save(&repo, &aged("brd-aaaa", Priority::P3, 30));
+
+        let escalated = escalate_issues(&repo.paths, &repo.config, 14, false).unwrap();
+        assert_eq!(escalated.len(), 1);
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].priority(), Priority::P2);
+        assert!(issues["brd-aaaa"].frontmatter.escalated_at.is_some());
+
+        // just escalated, so a second run leaves it alone
+        assert!(
+            escalate_issues(&repo.paths, &repo.config, 14, false)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_escalate_dry_run_writes_nothing() {
+        let repo = TestRepo::default();
+        save(&repo, &aged("brd-aaaa", Priority::P1, 30));
+
+        cmd_escalate(&test_cli(), &repo.paths, Some(7), true).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].priority(), Priority::P1);
+        assert!(issues["brd-aaaa"].frontmatter.escalated_at.is_none());
+    }
+
+    #[test]
+    fn test_escalate_requires_threshold() {
+        let repo = TestRepo::default();
+        let err = cmd_escalate(&test_cli(), &repo.paths, None, false).unwrap_err();
+        assert!(err.to_string().contains("escalate_after_days"));
+    }
+}
@@ -0,0 +1,247 @@
+//! brd groom command - find open issues nobody has touched in a while and
+//! optionally bump their priority.
+
+use std::collections::HashMap;
+
+use time::{Duration, OffsetDateTime};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::issue::{Issue, Priority, Status};
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+use super::escalate::{Escalation, apply_escalations, last_touched, plan_escalations};
+use super::load_all_issues;
+
+/// an open issue that has been idle longer than the threshold.
+#[derive(Debug, Clone, PartialEq)]
+struct StaleIssue {
+    id: String,
+    title: String,
+    priority: Priority,
+    /// the priority one level up, `None` at P0
+    bump_to: Option<Priority>,
+    idle: Duration,
+}
+
+/// the issues `plan` would bump, plus open P0 issues idle just as long,
+/// longest idle first.
+fn find_stale(
+    issues: &HashMap<String, Issue>,
+    plan: &[Escalation],
+    stale_after: Duration,
+    now: OffsetDateTime,
+) -> Vec<StaleIssue> {
+    let bumps = plan.iter().map(|e| StaleIssue {
+        id: e.id.clone(),
+        title: issues[&e.id].title().to_string(),
+        priority: e.from,
+        bump_to: Some(e.to),
+        idle: e.waited,
+    });
+    // P0 has nowhere to go, so escalation skips it, but it's still stale
+    let top = issues
+        .values()
+        .filter(|issue| issue.status() == Status::Open && issue.priority() == Priority::P0)
+        .filter_map(|issue| {
+            let idle = now - last_touched(issue);
+            (idle > stale_after).then(|| StaleIssue {
+                id: issue.id().to_string(),
+                title: issue.title().to_string(),
+                priority: issue.priority(),
+                bump_to: None,
+                idle,
+            })
+        });
+    let mut stale: Vec<_> = bumps.chain(top).collect();
+    stale.sort_by(|a, b| b.idle.cmp(&a.idle).then_with(|| a.id.cmp(&b.id)));
+    stale
+}
+
+/// what happened (or would happen) to a stale issue.
+fn action(stale: &StaleIssue, bump: bool) -> &'static str {
+    match (stale.bump_to, bump) {
+        (None, _) => "none",
+        (Some(_), true) => "bumped",
+        (Some(_), false) => "would_bump",
+    }
+}
+
+pub fn cmd_groom(cli: &Cli, paths: &RepoPaths, stale_days: u32, bump: bool) -> Result<()> {
+    if stale_days == 0 {
+        return Err(BrdError::InvalidInput(
+            "--stale-days must be at least 1".to_string(),
+        ));
+    }
+
+    let config = Config::load(&paths.config_path())?;
+    // a report only reads, so only --bump waits for the lock
+    let _lock = if bump {
+        Some(LockGuard::acquire(&paths.lock_path())?)
+    } else {
+        None
+    };
+    let mut issues = load_all_issues(paths, &config)?;
+    let now = OffsetDateTime::now_utc();
+    let stale_after = Duration::days(stale_days.into());
+    let plan = plan_escalations(&issues, stale_after, now, last_touched);
+    let stale = find_stale(&issues, &plan, stale_after, now);
+
+    if bump {
+        // restarts the idle clock, shared with `brd escalate`
+        apply_escalations(paths, &config, &mut issues, &plan, now)?;
+    }
+
+    if cli.json {
+        let candidates: Vec<_> = stale
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "id": s.id,
+                    "title": s.title,
+                    "priority": s.priority.to_string(),
+                    "bump_to": s.bump_to.map(|p| p.to_string()),
+                    "idle_days": s.idle.whole_days(),
+                    "action": action(s, bump),
+                })
+            })
+            .collect();
+        let bumped = stale.iter().filter(|s| bump && s.bump_to.is_some()).count();
+        let json = serde_json::json!({
+            "stale_days": stale_days,
+            "bump": bump,
+            "bumped": bumped,
+            "candidates": candidates,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return Ok(());
+    }
+
+    if stale.is_empty() {
+        println!("no open issues idle for more than {}d", stale_days);
+        return Ok(());
+    }
+    for s in &stale {
+        let change = match (s.bump_to, bump) {
+            (Some(to), true) => format!("{} -> {}", s.priority, to),
+            (Some(to), false) => format!("{} -> {} (not applied)", s.priority, to),
+            (None, _) => format!("{} (already highest)", s.priority),
+        };
+        println!(
+            "{}  idle {}d  {}  {}",
+            s.id,
+            s.idle.whole_days(),
+            change,
+            s.title
+        );
+    }
+    let bumpable = stale.iter().filter(|s| s.bump_to.is_some()).count();
+    if bump {
+        println!("\nbumped {} of {} stale issue(s)", bumpable, stale.len());
+    } else {
+        println!(
+            "\n{} stale issue(s); run with --bump to raise their priority",
+            stale.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    fn idle(id: &str, priority: Priority, days: i64) -> Issue {
+        let mut issue = Issue::new(id.to_string(), id.to_string(), priority, vec![]);
+        issue.frontmatter.created_at = OffsetDateTime::now_utc() - Duration::days(days);
+        issue
+    }
+
+    fn save(repo: &TestRepo, issue: &Issue) {
+        let path = repo
+            .paths
+            .issues_dir(&repo.config)
+            .join(format!("{}.md", issue.id()));
+        issue.save(&path).unwrap();
+    }
+
+    #[test]
+    fn test_find_stale_uses_latest_activity() {
+        let now = OffsetDateTime::now_utc();
+        // old, but reopened recently
+        let mut reopened = idle("brd-bbbb", Priority::P2, 60);
+        reopened.frontmatter.started_at = Some(now - Duration::days(2));
+        // old, but bumped recently
+        let mut bumped = idle("brd-cccc", Priority::P2, 60);
+        bumped.frontmatter.escalated_at = Some(now - Duration::days(1));
+        let mut done = idle("brd-dddd", Priority::P2, 60);
+        done.frontmatter.status = Status::Done;
+        let issues: HashMap<String, Issue> = [
+            idle("brd-aaaa", Priority::P3, 20),
+            reopened,
+            bumped,
+            done,
+            idle("brd-eeee", Priority::P0, 40),
+            idle("brd-ffff", Priority::P1, 5),
+        ]
+        .into_iter()
+        .map(|issue| (issue.id().to_string(), issue))
+        .collect();
+
+        let after = Duration::days(14);
+        let plan = plan_escalations(&issues, after, now, last_touched);
+        let stale = find_stale(&issues, &plan, after, now);
+        let ids: Vec<_> = stale.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["brd-eeee", "brd-aaaa"]);
+        assert_eq!(stale[0].bump_to, None);
+        assert_eq!(stale[1].bump_to, Some(Priority::P2));
+        assert_eq!(action(&stale[0], true), "none");
+        assert_eq!(action(&stale[1], false), "would_bump");
+    }
+
+    #[test]
+    fn test_groom_reports_without_bump() {
+        let repo = TestRepo::builder().build();
+        save(&repo, &idle("brd-aaaa", Priority::P2, 30));
+
+        cmd_groom(&test_cli(), &repo.paths, 14, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].priority(), Priority::P2);
+    }
+
+    #[test]
+    fn test_groom_bump_raises_priority_once() {
+        let repo = TestRepo::builder().build();
+        save(&repo, &idle("brd-aaaa", Priority::P2, 30));
+        save(&repo, &idle("brd-bbbb", Priority::P2, 3));
+
+        cmd_groom(&test_cli(), &repo.paths, 14, true).unwrap();
+        // the bump restarts the idle clock, so a second run is a no-op
+        cmd_groom(&test_cli(), &repo.paths, 14, true).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].priority(), Priority::P1);
+        assert!(issues["brd-aaaa"].frontmatter.escalated_at.is_some());
+        assert_eq!(issues["brd-bbbb"].priority(), Priority::P2);
+    }
+
+    #[test]
+    fn test_groom_report_does_not_wait_for_the_lock() {
+        let repo = TestRepo::builder().build();
+        save(&repo, &idle("brd-aaaa", Priority::P2, 30));
+
+        let _held = LockGuard::acquire(&repo.paths.lock_path()).unwrap();
+        cmd_groom(&test_cli(), &repo.paths, 14, false).unwrap();
+    }
+
+    #[test]
+    fn test_groom_rejects_zero_days() {
+        let repo = TestRepo::builder().build();
+        let result = cmd_groom(&test_cli(), &repo.paths, 0, false);
+        assert!(matches!(result, Err(BrdError::InvalidInput(_))));
+    }
+}
@@ -4,6 +4,7 @@ use std::io::{self, BufRead, Write};
 
 use crate::cli::{Cli, InitArgs};
 use crate::commands::agent::inject_agents_block;
+use crate::commands::config::add_to_git_exclude;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::is_interactive;
@@ -16,6 +17,9 @@ struct WorkflowConfig {
     auto_push: bool,
 }
 
+/// local-only paths kept out of commits even if `.braid/.gitignore` is edited away.
+const LOCAL_EXCLUDE_PATTERNS: &[&str] = &[".braid/agent.toml", ".braid/runtime/"];
+
 /// Result of injection prompt.
 enum InjectChoice {
     /// inject into AGENTS.md (default)
@@ -59,6 +63,17 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
     // determine workflow config: from args, interactive prompt, or defaults
     let workflow = determine_workflow_config(cli, args, &worktree_root)?;
 
+    // the issues branch is cut from HEAD; check before writing anything so a
+    // failed init leaves no half-configured .braid behind
+    if workflow.issues_branch.is_some() && !crate::git::has_commits(&worktree_root) {
+        return Err(BrdError::Git(
+            "cannot set up local-sync mode in a repo with no commits\n\n\
+             make an initial commit first, e.g. `git commit --allow-empty -m \"initial commit\"`, \
+             then run `brd init` again"
+                .to_string(),
+        ));
+    }
+
     // create directories
     std::fs::create_dir_all(&issues_dir)?;
     std::fs::create_dir_all(&brd_common_dir)?;
@@ -77,7 +92,7 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
         config.save(&config_path)?;
     } else {
         // update existing config with workflow settings
-        let mut config = Config::load(&config_path)?;
+        let mut config = Config::load_file(&config_path)?;
         config.issues_branch = workflow.issues_branch.clone();
         config.auto_pull = workflow.auto_pull;
         config.auto_push = workflow.auto_push;
@@ -120,11 +135,20 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
         brd_common_dir,
     };
 
+    // keep per-machine files out of commits. `.braid/issues` must never go here:
+    // info/exclude is shared with the issues worktree, which commits that path.
+    let mut excluded = Vec::new();
+    for &pattern in LOCAL_EXCLUDE_PATTERNS {
+        if add_to_git_exclude(&paths, pattern)? {
+            excluded.push(pattern);
+        }
+    }
+
     // determine injection choice
     let inject_choice = determine_inject_choice(cli, args)?;
 
     // load config for injection
-    let config = Config::load(&config_path)?;
+    let mut config = Config::load_file(&config_path)?;
 
     // perform injection if requested
     let injected_file = match &inject_choice {
@@ -134,6 +158,11 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
         }
         InjectChoice::Custom(file) => {
             inject_agents_block(&paths.worktree_root, &config, file)?;
+            // remember the target so later `brd agent inject` and doctor use it
+            if file != config.agents_file_name() {
+                config.agents_file = Some(file.clone());
+                config.save(&config_path)?;
+            }
             Some(file.clone())
         }
         InjectChoice::Skip => None,
@@ -148,6 +177,7 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
             "auto_pull": workflow.auto_pull,
             "auto_push": workflow.auto_push,
             "injected_file": injected_file,
+            "excluded": excluded,
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
@@ -170,6 +200,12 @@ pub fn cmd_init(cli: &Cli, args: &InitArgs) -> Result<()> {
         println!("  auto-sync:  {}", sync_desc);
 
         println!("  location:   .braid/");
+        if !excluded.is_empty() {
+            println!(
+                "  excluded:   {} (in .git/info/exclude)",
+                excluded.join(", ")
+            );
+        }
 
         // injection status
         if let Some(file) = &injected_file {
@@ -348,22 +384,6 @@ fn setup_issues_branch(
         .unwrap_or(false);
 
     if !branch_exists {
-        // check if repo has any commits (HEAD must exist to create a branch)
-        let has_commits = std::process::Command::new("git")
-            .args(["rev-parse", "HEAD"])
-            .current_dir(worktree_root)
-            .output()
-            .map(|o| o.status.success())
-            .unwrap_or(false);
-
-        if !has_commits {
-            return Err(BrdError::Other(
-                "cannot set up local-sync mode in a repo with no commits. \
-                 make an initial commit first, then run 'brd init --issues-branch <name>'."
-                    .to_string(),
-            ));
-        }
-
         // create the branch from current HEAD
         let output = std::process::Command::new("git")
             .args(["branch", branch_name])
@@ -372,7 +392,7 @@ fn setup_issues_branch(
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create sync branch '{}': {}",
                 branch_name, stderr
             )));
@@ -398,7 +418,7 @@ fn setup_issues_branch(
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create issues worktree: {}",
                 stderr
             )));
@@ -522,7 +542,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -554,6 +574,11 @@ mod tests {
 
             let agent_toml = std::fs::read_to_string(braid_dir.join("agent.toml")).unwrap();
             assert!(agent_toml.contains("agent_id = \"tester\""));
+
+            let exclude = std::fs::read_to_string(repo_path.join(".git/info/exclude")).unwrap();
+            assert!(exclude.lines().any(|l| l == ".braid/agent.toml"));
+            assert!(exclude.lines().any(|l| l == ".braid/runtime/"));
+            assert!(!exclude.lines().any(|l| l.starts_with(".braid/issues")));
         });
     }
 
@@ -598,7 +623,7 @@ mod tests {
     #[test]
     fn test_init_local_sync_fails_without_commits() {
         // fresh repo with no commits should fail gracefully when local-sync mode requested
-        with_empty_repo("no-commits", |_repo_path| {
+        with_empty_repo("no-commits", |repo_path| {
             let _env = EnvGuard::set("USER", Some("tester"));
             let cli = make_cli(false);
             let args = InitArgs {
@@ -614,6 +639,8 @@ mod tests {
                 "expected 'no commits' in error, got: {}",
                 err
             );
+            // nothing is left behind, so a retry after committing works
+            assert!(!repo_path.join(".braid").exists());
         });
     }
 
@@ -0,0 +1,301 @@
+//! brd link command - record git commits on issues.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::git;
+use crate::issue::Issue;
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+use super::start::BRAID_COMMIT_PREFIX;
+use super::{issue_to_json, load_all_issues, resolve_issue_id};
+
+/// first line of the hook `--install-hook` writes, used to recognize it later.
+const HOOK_MARKER: &str = "# installed by brd link --install-hook";
+
+/// the shortest word `--from-message` tries as an issue id: `id_len`'s minimum.
+const MIN_SHORT_ID_LEN: usize = 4;
+
+/// resolve a revision to a full commit sha.
+fn resolve_commit(cwd: &Path, rev: &str) -> Result<String> {
+    let spec = format!("{}^{{commit}}", rev);
+    let out = git::run_full(&["rev-parse", "--verify", "--quiet", &spec], cwd)?;
+    if !out.status.success() {
+        return Err(BrdError::Git(format!("not a commit: {}", rev)));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// ids of the issues a commit message mentions, in order of first mention.
+///
+/// full ids (`brd-abc1`) and short ids (`abc1`) both count. words shorter
+/// than the shortest id suffix are skipped, and so are words matching more
+/// than one issue, so ordinary words rarely link anything by accident.
+fn ids_in_message(message: &str, issues: &HashMap<String, Issue>) -> Vec<String> {
+    let mut ids = Vec::new();
+    let words = message.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'));
+    for word in words.filter(|w| w.len() >= MIN_SHORT_ID_LEN) {
+        if let Ok(id) = resolve_issue_id(word, issues)
+            && !ids.contains(&id)
+        {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// add `sha` to the issue's linked commits. returns false if already linked.
+fn link_commit(issue: &mut Issue, sha: &str) -> bool {
+    if issue.frontmatter.commits.iter().any(|c| c == sha) {
+        return false;
+    }
+    issue.frontmatter.commits.push(sha.to_string());
+    true
+}
+
+/// where git looks for hooks, honoring `core.hooksPath`.
+fn hooks_dir(cwd: &Path) -> Result<PathBuf> {
+    let dir = git::output(&["rev-parse", "--git-path", "hooks"], cwd)?;
+    if dir.is_empty() {
+        return Err(BrdError::NotGitRepo);
+    }
+    Ok(cwd.join(dir))
+}
+
+/// write a post-commit hook that runs `brd link --from-message`. an existing
+/// hook that brd didn't write is left alone.
+fn install_hook(cwd: &Path) -> Result<PathBuf> {
+    let dir = hooks_dir(cwd)?;
+    let path = dir.join("post-commit");
+    if let Ok(existing) = std::fs::read_to_string(&path)
+        && !existing.contains(HOOK_MARKER)
+    {
+        return Err(BrdError::AlreadyExists(format!(
+            "{} already exists; add `brd link --from-message` to it yourself",
+            path.display()
+        )));
+    }
+
+    std::fs::create_dir_all(&dir)?;
+    // brd's own commits are made while it holds the lock, so skip them
+    let script = format!(
+        "#!/bin/sh\n{}\n[ -n \"${}\" ] && exit 0\nbrd link --from-message >/dev/null 2>&1 || true\n",
+        HOOK_MARKER,
+        git::INTERNAL_ENV
+    );
+    crate::atomic::write_atomic(&path, script)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(path)
+}
+
+pub fn cmd_link(
+    cli: &Cli,
+    paths: &RepoPaths,
+    id: Option<&str>,
+    commit: Option<&str>,
+    from_message: bool,
+    install: bool,
+) -> Result<()> {
+    let cwd = &paths.worktree_root;
+
+    if install {
+        let path = install_hook(cwd)?;
+        if cli.json {
+            let json = serde_json::json!({ "ok": true, "hook": path });
+            println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        } else {
+            println!("installed post-commit hook: {}", path.display());
+        }
+        return Ok(());
+    }
+
+    let sha = resolve_commit(cwd, commit.unwrap_or("HEAD"))?;
+    let message = if from_message {
+        git::output(&["log", "-1", "--format=%B", &sha], cwd)?
+    } else {
+        String::new()
+    };
+
+    let config = Config::load(&paths.config_path())?;
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    let mut issues = load_all_issues(paths, &config)?;
+
+    let ids = if from_message {
+        // brd's own bookkeeping commits never refer to work on an issue
+        if message.starts_with(BRAID_COMMIT_PREFIX) {
+            Vec::new()
+        } else {
+            ids_in_message(&message, &issues)
+        }
+    } else {
+        let id = id.ok_or_else(|| {
+            BrdError::InvalidInput("specify an issue ID, or --from-message".to_string())
+        })?;
+        vec![resolve_issue_id(id, &issues)?]
+    };
+
+    let issues_dir = paths.issues_dir(&config);
+    let mut linked = Vec::new();
+    for id in &ids {
+        let issue = issues
+            .get_mut(id)
+            .ok_or_else(|| BrdError::IssueNotFound(id.clone()))?;
+        if link_commit(issue, &sha) {
+            issue.save_in(&issues_dir, config.filename_scheme)?;
+            linked.push(id.clone());
+        }
+    }
+
+    if cli.json {
+        let json = serde_json::json!({
+            "commit": sha,
+            "linked": linked,
+            "issues": ids
+                .iter()
+                .filter_map(|id| issues.get(id))
+                .map(|issue| issue_to_json(issue, &issues, &config.ready_includes))
+                .collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else if ids.is_empty() {
+        println!("no issue ids in commit {}", &sha[..12.min(sha.len())]);
+    } else {
+        let short = &sha[..12.min(sha.len())];
+        for id in &ids {
+            if linked.contains(id) {
+                println!("linked {} to {}", short, id);
+            } else {
+                println!("{} already linked to {}", short, id);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    fn git_repo() -> TestRepo {
+        let repo = TestRepo::default();
+        let root = &repo.paths.worktree_root;
+        git::test::run_ok(root, &["init"]);
+        git::test::run_ok(root, &["config", "user.email", "test@test.com"]);
+        git::test::run_ok(root, &["config", "user.name", "tester"]);
+        git::test::run_ok(root, &["config", "commit.gpgsign", "false"]);
+        repo
+    }
+
+    fn commit(repo: &TestRepo, message: &str) -> String {
+        let root = &repo.paths.worktree_root;
+        git::test::run_ok(root, &["commit", "--allow-empty", "-m", message]);
+        resolve_commit(root, "HEAD").unwrap()
+    }
+
+    #[test]
+    fn test_link_records_head_once() {
+        let repo = git_repo();
+        repo.issue("brd-abc1").create();
+        let sha = commit(&repo, "unrelated");
+
+        let cli = test_cli();
+        cmd_link(&cli, &repo.paths, Some("abc1"), None, false, false).unwrap();
+        cmd_link(&cli, &repo.paths, Some("abc1"), Some("HEAD"), false, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-abc1"].frontmatter.commits, vec![sha]);
+    }
+
+    #[test]
+    fn test_link_rejects_unknown_commit() {
+        let repo = git_repo();
+        repo.issue("brd-abc1").create();
+        commit(&repo, "first");
+
+        let err = cmd_link(
+            &test_cli(),
+            &repo.paths,
+            Some("brd-abc1"),
+            Some("nope"),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not a commit"));
+    }
+
+    #[test]
+    fn test_link_from_message_finds_ids() {
+        let repo = git_repo();
+        repo.issue("brd-abc1").create();
+        repo.issue("brd-def2").create();
+        repo.issue("brd-ghi3").create();
+        let sha = commit(&repo, "brd-abc1: fix parser\n\nalso touches BRD-DEF2");
+
+        cmd_link(&test_cli(), &repo.paths, None, None, true, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-abc1"].frontmatter.commits, vec![sha.clone()]);
+        assert_eq!(issues["brd-def2"].frontmatter.commits, vec![sha]);
+        assert!(issues["brd-ghi3"].frontmatter.commits.is_empty());
+    }
+
+    #[test]
+    fn test_link_from_message_resolves_short_ids() {
+        let repo = git_repo();
+        repo.issue("brd-abc1").create();
+        repo.issue("brd-def2").create();
+        repo.issue("brd-def3").create();
+        // `def` matches two issues, so it links neither
+        let sha = commit(&repo, "abc1: fix parser\n\nsee brd-def too");
+
+        cmd_link(&test_cli(), &repo.paths, None, None, true, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-abc1"].frontmatter.commits, vec![sha]);
+        assert!(issues["brd-def2"].frontmatter.commits.is_empty());
+        assert!(issues["brd-def3"].frontmatter.commits.is_empty());
+    }
+
+    #[test]
+    fn test_link_from_message_skips_braid_commits() {
+        let repo = git_repo();
+        repo.issue("brd-abc1").create();
+        commit(&repo, "chore(braid): start brd-abc1");
+
+        cmd_link(&test_cli(), &repo.paths, None, None, true, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues["brd-abc1"].frontmatter.commits.is_empty());
+    }
+
+    #[test]
+    fn test_install_hook_keeps_foreign_hooks() {
+        let repo = git_repo();
+        let root = &repo.paths.worktree_root;
+
+        let path = install_hook(root).unwrap();
+        let script = std::fs::read_to_string(&path).unwrap();
+        assert!(script.contains("--from-message"));
+        assert!(script.contains(git::INTERNAL_ENV));
+        // reinstalling over our own hook is fine
+        install_hook(root).unwrap();
+
+        std::fs::write(&path, "#!/bin/sh\necho mine\n").unwrap();
+        assert!(install_hook(root).is_err());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "#!/bin/sh\necho mine\n"
+        );
+    }
+}
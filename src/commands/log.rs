@@ -0,0 +1,383 @@
+//! brd log command - field-level issue changes, replayed from git history or
+//! followed live.
+
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::Result;
+use crate::git;
+use crate::issue::Issue;
+use crate::repo::RepoPaths;
+
+use super::load_all_issues;
+
+/// how often `brd log --follow` re-reads the issues directory.
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(2);
+
+/// frontmatter fields left out of the log: the schema version is bookkeeping,
+/// and the timestamps only ever move together with `status` or `priority`.
+const SKIPPED_FIELDS: &[&str] = &[
+    "schema_version",
+    "created_at",
+    "started_at",
+    "completed_at",
+    "escalated_at",
+];
+
+/// one field of one issue changing.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ChangeEvent {
+    #[serde(with = "time::serde::rfc3339")]
+    ts: OffsetDateTime,
+    id: String,
+    field: String,
+    old: Value,
+    new: Value,
+    agent: Option<String>,
+}
+
+/// an issue's loggable fields as JSON, including the body.
+fn issue_fields(issue: &Issue) -> serde_json::Map<String, Value> {
+    let mut fields = match serde_json::to_value(&issue.frontmatter) {
+        Ok(Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    for skipped in SKIPPED_FIELDS {
+        fields.remove(*skipped);
+    }
+    fields.insert("body".to_string(), Value::String(issue.body.clone()));
+    fields
+}
+
+/// the changes between two versions of an issue. a missing side is reported
+/// as a single `created` or `removed` event carrying the title.
+fn diff_issue(
+    id: &str,
+    before: Option<&Issue>,
+    after: Option<&Issue>,
+    ts: OffsetDateTime,
+    agent: Option<&str>,
+) -> Vec<ChangeEvent> {
+    let event = |field: &str, old: Value, new: Value| ChangeEvent {
+        ts,
+        id: id.to_string(),
+        field: field.to_string(),
+        old,
+        new,
+        agent: agent.map(String::from),
+    };
+
+    match (before, after) {
+        (None, None) => Vec::new(),
+        (None, Some(after)) => vec![event("created", Value::Null, after.title().into())],
+        (Some(before), None) => vec![event("removed", before.title().into(), Value::Null)],
+        (Some(before), Some(after)) => {
+            let old = issue_fields(before);
+            let new = issue_fields(after);
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            keys.into_iter()
+                .filter_map(|key| {
+                    let old = old.get(key).cloned().unwrap_or(Value::Null);
+                    let new = new.get(key).cloned().unwrap_or(Value::Null);
+                    (old != new).then(|| event(key, old, new))
+                })
+                .collect()
+        }
+    }
+}
+
+/// the changes between two snapshots of the issues directory, by id. the agent
+/// is the issue's owner, the best guess before anything is committed.
+fn diff_snapshots(
+    before: &HashMap<String, Issue>,
+    after: &HashMap<String, Issue>,
+    ts: OffsetDateTime,
+) -> Vec<ChangeEvent> {
+    let ids: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    ids.into_iter()
+        .flat_map(|id| {
+            let old = before.get(id);
+            let new = after.get(id);
+            let agent = new
+                .or(old)
+                .and_then(|issue| issue.frontmatter.owner.as_deref());
+            diff_issue(id, old, new, ts, agent)
+        })
+        .collect()
+}
+
+/// replay changes from the last `limit` commits touching `issues_dir`, oldest
+/// first. the agent is the commit author. outside a git repo this is empty.
+fn history_events(issues_dir: &Path, limit: usize) -> Result<Vec<ChangeEvent>> {
+    let limit_arg = format!("-n{}", limit);
+    let out = git::output(
+        &[
+            "log",
+            "--reverse",
+            &limit_arg,
+            "--format=%x1e%H%x09%an%x09%aI",
+            "--name-status",
+            "--no-renames",
+            "--relative",
+            "--",
+            ".",
+        ],
+        issues_dir,
+    )?;
+
+    let show = |rev: &str, file: &str| -> Option<Issue> {
+        let content = git::output(&["show", &format!("{}:./{}", rev, file)], issues_dir).ok()?;
+        Issue::parse(&content).ok()
+    };
+
+    let mut events = Vec::new();
+    for record in out.split('\x1e').filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let header: Vec<&str> = lines.next().unwrap_or_default().split('\t').collect();
+        let [sha, author, date] = header[..] else {
+            continue;
+        };
+        let Ok(ts) = OffsetDateTime::parse(date, &Rfc3339) else {
+            continue;
+        };
+
+        for line in lines {
+            let Some((status, file)) = line.split_once('\t') else {
+                continue;
+            };
+            // only issue files directly in the issues directory
+            let Some(stem) = file.strip_suffix(".md").filter(|s| !s.contains('/')) else {
+                continue;
+            };
+            let before = (status != "A")
+                .then(|| show(&format!("{}^", sha), file))
+                .flatten();
+            let after = (status != "D").then(|| show(sha, file)).flatten();
+            let id = after
+                .as_ref()
+                .or(before.as_ref())
+                .map_or(stem, |issue| issue.id());
+            events.extend(diff_issue(
+                id,
+                before.as_ref(),
+                after.as_ref(),
+                ts,
+                Some(author),
+            ));
+        }
+    }
+    Ok(events)
+}
+
+/// render a field value for the text log.
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) if s.contains('\n') => format!("({} lines)", s.lines().count()),
+        Value::String(s) => s.clone(),
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(format_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+fn format_event(event: &ChangeEvent, json: bool) -> String {
+    if json {
+        return serde_json::to_string(event).unwrap();
+    }
+    let ts = event.ts.format(&Rfc3339).unwrap_or_default();
+    let mut line = format!(
+        "{}  {}  {}: {} -> {}",
+        ts,
+        event.id,
+        event.field,
+        format_value(&event.old),
+        format_value(&event.new)
+    );
+    if let Some(agent) = &event.agent {
+        line.push_str(&format!("  ({})", agent));
+    }
+    line
+}
+
+pub fn cmd_log(cli: &Cli, paths: &RepoPaths, follow: bool, limit: usize) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+    let issues_dir = paths.issues_dir(&config);
+    let mut stdout = std::io::stdout();
+
+    if !follow {
+        for event in history_events(&issues_dir, limit)? {
+            writeln!(stdout, "{}", format_event(&event, cli.json))?;
+        }
+        return Ok(());
+    }
+
+    // poll until interrupted (ctrl-c), one line per change
+    let mut snapshot = load_all_issues(paths, &config)?;
+    loop {
+        std::thread::sleep(FOLLOW_INTERVAL);
+        let current = load_all_issues(paths, &config)?;
+        for event in diff_snapshots(&snapshot, &current, OffsetDateTime::now_utc()) {
+            writeln!(stdout, "{}", format_event(&event, cli.json))?;
+        }
+        stdout.flush()?;
+        snapshot = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issue::{Priority, Status};
+    use std::fs;
+
+    fn issue(id: &str) -> Issue {
+        Issue::new(
+            id.to_string(),
+            format!("issue {}", id),
+            Priority::P2,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_diff_issue_reports_changed_fields() {
+        let before = issue("brd-aaaa");
+        let mut after = before.clone();
+        after.frontmatter.status = Status::Doing;
+        after.frontmatter.owner = Some("agent-one".to_string());
+        after.frontmatter.started_at = Some(OffsetDateTime::now_utc());
+        after.frontmatter.tags = vec!["ui".to_string()];
+
+        let ts = OffsetDateTime::now_utc();
+        let events = diff_issue(
+            "brd-aaaa",
+            Some(&before),
+            Some(&after),
+            ts,
+            Some("agent-one"),
+        );
+        let changes: Vec<_> = events
+            .iter()
+            .map(|e| (e.field.as_str(), e.old.clone(), e.new.clone()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("owner", Value::Null, "agent-one".into()),
+                ("status", "open".into(), "doing".into()),
+                ("tags", Value::Null, serde_json::json!(["ui"])),
+            ]
+        );
+
+        let created = diff_issue("brd-aaaa", None, Some(&before), ts, None);
+        assert_eq!(created[0].field, "created");
+        assert_eq!(created[0].new, "issue brd-aaaa");
+        assert!(diff_issue("brd-aaaa", Some(&before), Some(&before), ts, None).is_empty());
+    }
+
+    #[test]
+    fn test_diff_issue_skips_escalated_at() {
+        let before = issue("brd-aaaa");
+        let mut after = before.clone();
+        after.frontmatter.priority = Priority::P1;
+        after.frontmatter.escalated_at = Some(OffsetDateTime::now_utc());
+
+        let events = diff_issue(
+            "brd-aaaa",
+            Some(&before),
+            Some(&after),
+            OffsetDateTime::now_utc(),
+            None,
+        );
+        let fields: Vec<_> = events.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["priority"]);
+    }
+
+    #[test]
+    fn test_diff_snapshots_uses_owner_as_agent() {
+        let mut doing = issue("brd-aaaa");
+        let before = HashMap::from([(doing.id().to_string(), doing.clone())]);
+        doing.frontmatter.status = Status::Done;
+        doing.frontmatter.owner = Some("agent-one".to_string());
+        let added = issue("brd-bbbb");
+        let after = HashMap::from([
+            (doing.id().to_string(), doing),
+            (added.id().to_string(), added),
+        ]);
+
+        let events = diff_snapshots(&before, &after, OffsetDateTime::now_utc());
+        let fields: Vec<_> = events
+            .iter()
+            .map(|e| (e.id.as_str(), e.field.as_str(), e.agent.as_deref()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("brd-aaaa", "owner", Some("agent-one")),
+                ("brd-aaaa", "status", Some("agent-one")),
+                ("brd-bbbb", "created", None),
+            ]
+        );
+
+        let line = format_event(&events[1], true);
+        let json: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(json["field"], "status");
+        assert_eq!(json["old"], "open");
+        assert_eq!(json["new"], "done");
+    }
+
+    #[test]
+    fn test_history_events_replays_commits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        git::test::run_ok(root, &["init"]);
+        git::test::run_ok(root, &["config", "user.email", "test@test.com"]);
+        git::test::run_ok(root, &["config", "user.name", "tester"]);
+        git::test::run_ok(root, &["config", "commit.gpgsign", "false"]);
+        let issues_dir = root.join(".braid/issues");
+        fs::create_dir_all(&issues_dir).unwrap();
+
+        let mut tracked = issue("brd-aaaa");
+        tracked.save(&issues_dir.join("brd-aaaa.md")).unwrap();
+        git::test::run_ok(root, &["add", "."]);
+        git::test::run_ok(root, &["commit", "-m", "add"]);
+
+        tracked.frontmatter.priority = Priority::P0;
+        tracked.save(&issues_dir.join("brd-aaaa.md")).unwrap();
+        fs::write(root.join("README.md"), "unrelated").unwrap();
+        git::test::run_ok(root, &["add", "."]);
+        git::test::run_ok(root, &["commit", "-m", "bump"]);
+
+        let events = history_events(&issues_dir, 10).unwrap();
+        let fields: Vec<_> = events
+            .iter()
+            .map(|e| (e.field.as_str(), e.new.clone(), e.agent.as_deref()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("created", "issue brd-aaaa".into(), Some("tester")),
+                ("priority", "P0".into(), Some("tester")),
+            ]
+        );
+
+        // the limit keeps the newest commits
+        let events = history_events(&issues_dir, 1).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].field, "priority");
+    }
+}
@@ -1,22 +1,30 @@
 //! brd ls command.
 
-use std::time::Instant;
+use std::collections::{HashMap, HashSet};
+use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
 
 use crossterm::style::{Attribute, Color, SetAttribute, SetForegroundColor};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute};
 use time::OffsetDateTime;
+use unicode_width::UnicodeWidthStr;
 
-use crate::cli::Cli;
+use crate::cli::{Cli, LsArgs};
 use crate::config::Config;
-use crate::date::format_scheduled;
-use crate::error::Result;
-use crate::graph::compute_derived;
-use crate::issue::{Issue, IssueType, Priority, Status};
-use crate::repo::RepoPaths;
+use crate::date::{format_scheduled, parse_past_date};
+use crate::error::{BrdError, Result};
+use crate::graph::{DerivedState, compute_derived, get_transitive_dependents};
+use crate::issue::{Issue, IssueType, Priority, Status, resolve_issue_id};
+use crate::output::{Output, format_json_list};
+use crate::repo::{self, RepoPaths};
+use crate::text::{pad_right, truncate};
+use crate::theme::{Theme, to_crossterm};
 
-use super::{issue_to_json, load_all_issues};
+use super::{issue_to_json, load_all_issues, load_archived_issues};
 
 /// Format an age duration in short human format (e.g., "5m", "2h", "3d", "1w", "3mo", "1y").
-fn format_age(created_at: OffsetDateTime) -> String {
+pub(super) fn format_age(created_at: OffsetDateTime) -> String {
     let now = OffsetDateTime::now_utc();
     let duration = now - created_at;
     let minutes = duration.whole_minutes();
@@ -39,30 +47,409 @@ fn format_age(created_at: OffsetDateTime) -> String {
     }
 }
 
+/// Skip `offset` items and keep at most `limit`, returning the page and how many items follow it.
+fn paginate<T>(items: Vec<T>, offset: usize, limit: Option<usize>) -> (Vec<T>, usize) {
+    let mut page: Vec<T> = items.into_iter().skip(offset).collect();
+    let remaining = match limit {
+        Some(limit) if page.len() > limit => {
+            let remaining = page.len() - limit;
+            page.truncate(limit);
+            remaining
+        }
+        _ => 0,
+    };
+    (page, remaining)
+}
+
 /// Maximum number of done issues to show by default
 const DEFAULT_DONE_LIMIT: usize = 10;
 
 /// Maximum number of open issues to show by default
 const DEFAULT_OPEN_LIMIT: usize = 15;
 
-#[allow(clippy::too_many_arguments)]
-pub fn cmd_ls(
-    cli: &Cli,
-    paths: &RepoPaths,
-    status_filter: Option<&str>,
-    priority_filter: Option<&str>,
-    ready_only: bool,
-    blocked_only: bool,
-    scheduled_only: bool,
-    tag_filter: &[String],
-    show_all: bool,
-) -> Result<()> {
+/// `--created-*` / `--updated-*` bounds. "after" is inclusive, "before" exclusive.
+#[derive(Debug, Default)]
+struct DateFilters {
+    created_after: Option<OffsetDateTime>,
+    created_before: Option<OffsetDateTime>,
+    updated_after: Option<OffsetDateTime>,
+    updated_before: Option<OffsetDateTime>,
+}
+
+impl DateFilters {
+    fn from_args(args: &LsArgs) -> Result<Self> {
+        let parse = |s: &Option<String>| s.as_deref().map(parse_past_date).transpose();
+        Ok(Self {
+            created_after: parse(&args.created_after)?,
+            created_before: parse(&args.created_before)?,
+            updated_after: parse(&args.updated_after)?,
+            updated_before: parse(&args.updated_before)?,
+        })
+    }
+
+    fn matches(&self, issue: &Issue) -> bool {
+        fn in_range(
+            t: OffsetDateTime,
+            after: Option<OffsetDateTime>,
+            before: Option<OffsetDateTime>,
+        ) -> bool {
+            after.is_none_or(|a| t >= a) && before.is_none_or(|b| t < b)
+        }
+        in_range(
+            issue.frontmatter.created_at,
+            self.created_after,
+            self.created_before,
+        ) && in_range(issue.updated_at(), self.updated_after, self.updated_before)
+    }
+}
+
+/// how often `brd ls --watch` re-renders.
+const WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+pub fn cmd_ls(cli: &Cli, paths: &RepoPaths, args: &LsArgs) -> Result<()> {
+    if !args.watch {
+        let mut out = Output::new(args.output.as_deref())?;
+        render_ls(cli, paths, args, &mut out)?;
+        out.flush()?;
+        return Ok(());
+    }
+    if cli.json || args.ndjson {
+        return Err(BrdError::InvalidInput(
+            "--watch cannot be combined with --json".to_string(),
+        ));
+    }
+    if args.output.is_some() {
+        return Err(BrdError::InvalidInput(
+            "--watch cannot be combined with --output".to_string(),
+        ));
+    }
+
+    // poll until interrupted (ctrl-c)
+    loop {
+        execute!(
+            std::io::stdout(),
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        render_ls(cli, paths, args, &mut Output::stdout())?;
+        std::thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// what `brd ls --group-by` splits issues by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LsGroup {
+    Status,
+    Priority,
+    Owner,
+    Tag,
+    /// the meta issues an issue depends on
+    Milestone,
+}
+
+impl std::str::FromStr for LsGroup {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "status" => Ok(LsGroup::Status),
+            "priority" => Ok(LsGroup::Priority),
+            "owner" => Ok(LsGroup::Owner),
+            "tag" => Ok(LsGroup::Tag),
+            "milestone" => Ok(LsGroup::Milestone),
+            _ => Err(BrdError::ParseError(
+                "group-by".into(),
+                format!(
+                    "invalid group '{}'. use status, priority, owner, tag, or milestone",
+                    s
+                ),
+            )),
+        }
+    }
+}
+
+/// group for issues without an owner, tag, or milestone.
+const NO_GROUP: &str = "(none)";
+
+/// the groups `issue` falls in. an issue with several tags or meta parents
+/// is listed under each of them.
+fn group_keys(issue: &Issue, issues: &HashMap<String, Issue>, by: LsGroup) -> Vec<String> {
+    let keys: Vec<String> = match by {
+        LsGroup::Status => vec![issue.status().to_string()],
+        LsGroup::Priority => vec![issue.priority().to_string()],
+        LsGroup::Owner => issue.frontmatter.owner.iter().cloned().collect(),
+        LsGroup::Tag => issue.tags().to_vec(),
+        LsGroup::Milestone => issue
+            .deps()
+            .iter()
+            .filter(|dep| {
+                issues
+                    .get(dep.as_str())
+                    .is_some_and(|d| d.issue_type() == Some(IssueType::Meta))
+            })
+            .cloned()
+            .collect(),
+    };
+    if keys.is_empty() {
+        vec![NO_GROUP.to_string()]
+    } else {
+        keys
+    }
+}
+
+/// sort key for a group: statuses in `brd ls` order, everything else by
+/// name with `(none)` last. priorities sort by name already.
+fn group_order(key: &str, by: LsGroup) -> (u8, &str) {
+    let rank = match by {
+        LsGroup::Status => match key.parse::<Status>() {
+            Ok(Status::Doing) => 0,
+            Ok(Status::Open) => 1,
+            Ok(Status::Done) => 2,
+            _ => 3,
+        },
+        _ => u8::from(key == NO_GROUP),
+    };
+    (rank, key)
+}
+
+/// split `listed` into groups, keeping list order within each group.
+fn group_issues<'a>(
+    listed: &[&'a Issue],
+    issues: &HashMap<String, Issue>,
+    by: LsGroup,
+) -> Vec<(String, Vec<&'a Issue>)> {
+    let mut groups: Vec<(String, Vec<&'a Issue>)> = Vec::new();
+    for &issue in listed {
+        for key in group_keys(issue, issues, by) {
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, members)) => members.push(issue),
+                None => groups.push((key, vec![issue])),
+            }
+        }
+    }
+    groups.sort_by(|(a, _), (b, _)| group_order(a, by).cmp(&group_order(b, by)));
+    groups
+}
+
+/// `--group-by` json output: an object keyed by group. serde_json's `Map`
+/// sorts its keys, so this writes them in section order instead.
+struct GroupedJson(Vec<(String, serde_json::Value)>);
+
+impl serde::Serialize for GroupedJson {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, value)| (key, value)))
+    }
+}
+
+/// a group's section header, e.g. `open (3)`. milestones show the meta
+/// issue's title after its id.
+fn group_header(key: &str, count: usize, by: LsGroup, issues: &HashMap<String, Issue>) -> String {
+    match issues.get(key) {
+        Some(meta) if by == LsGroup::Milestone => {
+            format!("{} {} ({})", key, meta.title(), count)
+        }
+        _ => format!("{} ({})", key, count),
+    }
+}
+
+/// a column `brd ls --columns` can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LsColumn {
+    Id,
+    Priority,
+    Status,
+    Owner,
+    Title,
+    Tags,
+    Updated,
+    Age,
+    Type,
+    Deps,
+}
+
+impl LsColumn {
+    const ALL: [(&'static str, LsColumn); 10] = [
+        ("id", LsColumn::Id),
+        ("priority", LsColumn::Priority),
+        ("status", LsColumn::Status),
+        ("owner", LsColumn::Owner),
+        ("title", LsColumn::Title),
+        ("tags", LsColumn::Tags),
+        ("updated", LsColumn::Updated),
+        ("age", LsColumn::Age),
+        ("type", LsColumn::Type),
+        ("deps", LsColumn::Deps),
+    ];
+
+    fn cell(self, issue: &Issue, issues: &HashMap<String, Issue>) -> String {
+        match self {
+            LsColumn::Id => issue.id().to_string(),
+            LsColumn::Priority => issue.priority().to_string(),
+            LsColumn::Status => issue.status().to_string(),
+            LsColumn::Owner => issue
+                .frontmatter
+                .owner
+                .clone()
+                .unwrap_or_else(|| "-".into()),
+            LsColumn::Title => issue.title().to_string(),
+            LsColumn::Tags => issue
+                .tags()
+                .iter()
+                .map(|tag| format!("#{}", tag))
+                .collect::<Vec<_>>()
+                .join(" "),
+            LsColumn::Updated => format_age(issue.updated_at()),
+            LsColumn::Age => format_age(issue.frontmatter.created_at),
+            LsColumn::Type => issue
+                .issue_type()
+                .map(|t| t.to_string())
+                .unwrap_or_default(),
+            LsColumn::Deps => {
+                deps_indicator(issue, &compute_derived(issue, issues)).unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// compact dependency progress for `--deps`, e.g. `[2/3 done]`, with a
+/// trailing `!` when a dep doesn't exist. None for issues without deps.
+fn deps_indicator(issue: &Issue, derived: &DerivedState) -> Option<String> {
+    let total = issue.deps().len();
+    if total == 0 {
+        return None;
+    }
+    let done = total - derived.open_deps.len() - derived.missing_deps.len();
+    let missing = if derived.missing_deps.is_empty() {
+        ""
+    } else {
+        "!"
+    };
+    Some(format!("[{}/{} done]{}", done, total, missing))
+}
+
+/// parse `--columns id,priority,title`, rejecting unknown or empty names.
+fn parse_columns(spec: &str) -> Result<Vec<LsColumn>> {
+    spec.split(',')
+        .map(|name| {
+            let name = name.trim().to_lowercase();
+            LsColumn::ALL
+                .iter()
+                .find(|(known, _)| *known == name)
+                .map(|(_, column)| *column)
+                .ok_or_else(|| {
+                    let known: Vec<_> = LsColumn::ALL.iter().map(|(n, _)| *n).collect();
+                    BrdError::ParseError(
+                        "columns".to_string(),
+                        format!("unknown column '{}' (expected: {})", name, known.join(", ")),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// how wide rows may be: the terminal's width when writing to one, else
+/// unlimited so piped and `--output` listings keep full titles.
+fn output_width(out: &Output) -> Option<usize> {
+    if out.is_file() || !std::io::stdout().is_terminal() {
+        return None;
+    }
+    terminal::size().ok().map(|(width, _)| width as usize)
+}
+
+/// lay out `issues` as rows of the chosen columns, two spaces apart. every
+/// column but the last is padded to its widest cell; the last is truncated
+/// with "…" so rows fit in `max_width`, if given.
+fn format_column_rows(
+    rows: &[&Issue],
+    issues: &HashMap<String, Issue>,
+    columns: &[LsColumn],
+    max_width: Option<usize>,
+) -> Vec<String> {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|issue| columns.iter().map(|c| c.cell(issue, issues)).collect())
+        .collect();
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| cells.iter().map(|row| row[i].width()).max().unwrap_or(0))
+        .collect();
+
+    cells
+        .into_iter()
+        .map(|row| {
+            let last = row.len().saturating_sub(1);
+            let mut line = String::new();
+            for (i, cell) in row.into_iter().enumerate() {
+                if i > 0 {
+                    line.push_str("  ");
+                }
+                if i < last {
+                    line.push_str(&pad_right(&cell, widths[i]));
+                    continue;
+                }
+                match max_width {
+                    Some(width) => {
+                        let room = width.saturating_sub(line.width());
+                        line.push_str(&truncate(&cell, room));
+                    }
+                    None => line.push_str(&cell),
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+fn render_ls(cli: &Cli, paths: &RepoPaths, args: &LsArgs, out: &mut Output) -> Result<()> {
     let start = Instant::now();
     let config = Config::load(&paths.config_path())?;
-    let issues = load_all_issues(paths, &config)?;
+    let issues = if args.archived {
+        load_archived_issues(paths, &config)?
+    } else {
+        load_all_issues(paths, &config)?
+    };
 
-    let status_filter: Option<Status> = status_filter.map(|s| s.parse()).transpose()?;
-    let priority_filter: Option<Priority> = priority_filter.map(|p| p.parse()).transpose()?;
+    let status_filter: Option<Status> = args.status.as_deref().map(|s| s.parse()).transpose()?;
+    let priority_filter: Option<Priority> =
+        args.priority.as_deref().map(|p| p.parse()).transpose()?;
+    let owner_filter: Option<String> = if args.mine {
+        Some(repo::get_agent_id(&paths.worktree_root))
+    } else {
+        args.owner.clone()
+    };
+    let scheduled_only = args.scheduled;
+    // explicit paging replaces the default open/done limits
+    let paginated = args.limit.is_some() || args.offset.is_some();
+    // grouping shows every match, so section counts are the real totals
+    let show_all = args.all || paginated || args.group_by.is_some();
+    let tag_filter = &args.tag;
+    let date_filters = DateFilters::from_args(args)?;
+    // --blocked-by: everything downstream of the blocker in the dependency graph
+    let blocked_by: Option<(String, HashSet<String>)> = match args.blocked_by.as_deref() {
+        Some(partial) => {
+            let blocker = resolve_issue_id(partial, &issues)?;
+            let downstream = get_transitive_dependents(&blocker, &issues)
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            Some((blocker, downstream))
+        }
+        None => None,
+    };
+    let theme = Theme::from_config(&config.theme);
+    let use_color = out.use_color(cli);
+    let mut columns: Option<Vec<LsColumn>> =
+        args.columns.as_deref().map(parse_columns).transpose()?;
+    let group_by: Option<LsGroup> = args.group_by.as_deref().map(|g| g.parse()).transpose()?;
+    // --deps with --columns adds the deps column unless it was asked for
+    if args.deps
+        && let Some(columns) = columns.as_mut()
+        && !columns.contains(&LsColumn::Deps)
+    {
+        columns.push(LsColumn::Deps);
+    }
 
     let filtered: Vec<&Issue> = issues
         .values()
@@ -89,18 +476,32 @@ pub fn cmd_ls(
             {
                 return false;
             }
-            if ready_only && !derived.is_ready {
+            if let Some(owner) = owner_filter.as_deref()
+                && issue.frontmatter.owner.as_deref() != Some(owner)
+            {
+                return false;
+            }
+            if args.ready && !derived.is_ready_for(issue.status(), &config.ready_includes) {
                 return false;
             }
-            if blocked_only && !derived.is_blocked {
+            if args.blocked && !derived.is_blocked {
                 return false;
             }
             if !tag_filter.is_empty() && !tag_filter.iter().all(|tag| issue.tags().contains(tag)) {
                 return false;
             }
+            if !date_filters.matches(issue) {
+                return false;
+            }
+            if let Some((_, downstream)) = &blocked_by
+                && !downstream.contains(issue.id())
+            {
+                return false;
+            }
             true
         })
         .collect();
+    let match_count = filtered.len();
 
     // partition into doing, open, scheduled, and resolved (done/skip) issues
     let mut doing: Vec<&Issue> = Vec::new();
@@ -122,8 +523,8 @@ pub fn cmd_ls(
     }
 
     // sort doing and open by priority, created_at, id
-    doing.sort_by(|a, b| a.cmp_by_priority(b));
-    open.sort_by(|a, b| a.cmp_by_priority(b));
+    doing.sort_by(|a, b| a.cmp_for_listing(b));
+    open.sort_by(|a, b| a.cmp_for_listing(b));
 
     // sort scheduled issues by scheduled_for date (soonest first)
     scheduled.sort_by(|a, b| {
@@ -133,17 +534,7 @@ pub fn cmd_ls(
     });
 
     // sort resolved issues by completed_at (most recent first), then by id for stability
-    resolved.sort_by(|a, b| {
-        let a_time = a
-            .frontmatter
-            .completed_at
-            .unwrap_or(a.frontmatter.created_at);
-        let b_time = b
-            .frontmatter
-            .completed_at
-            .unwrap_or(b.frontmatter.created_at);
-        b_time.cmp(&a_time).then_with(|| a.id().cmp(b.id()))
-    });
+    resolved.sort_by(|a, b| a.cmp_for_listing(b));
 
     // compute total counts BEFORE truncation
     let total_doing = doing.len();
@@ -184,190 +575,162 @@ pub fn cmd_ls(
     } else {
         doing.into_iter().chain(open).chain(resolved).collect()
     };
+    let total = filtered.len();
+    let (filtered, hidden_after_page) = paginate(filtered, args.offset.unwrap_or(0), args.limit);
 
-    if cli.json {
-        let json: Vec<_> = filtered
+    if args.ndjson {
+        let items: Vec<_> = filtered
             .iter()
-            .map(|issue| issue_to_json(issue, &issues))
+            .map(|issue| issue_to_json(issue, &issues, &config.ready_includes))
             .collect();
-        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        write!(out, "{}", format_json_list(&items, true))?;
+    } else if cli.json
+        && let Some(by) = group_by
+    {
+        let groups = group_issues(&filtered, &issues, by)
+            .into_iter()
+            .map(|(key, members)| {
+                let items: Vec<_> = members
+                    .iter()
+                    .map(|issue| issue_to_json(issue, &issues, &config.ready_includes))
+                    .collect();
+                let value = serde_json::json!({
+                    "count": items.len(),
+                    "issues": items,
+                });
+                (key, value)
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&GroupedJson(groups)).unwrap();
+        writeln!(out, "{}", json)?;
+    } else if cli.json {
+        let items: Vec<_> = filtered
+            .iter()
+            .map(|issue| issue_to_json(issue, &issues, &config.ready_includes))
+            .collect();
+        // keep the plain array shape unless paging was requested
+        let json = if paginated {
+            serde_json::json!({
+                "total": total,
+                "offset": args.offset.unwrap_or(0),
+                "limit": args.limit,
+                "items": items,
+            })
+        } else {
+            serde_json::Value::Array(items)
+        };
+        writeln!(out, "{}", serde_json::to_string_pretty(&json).unwrap())?;
     } else {
         // use pre-computed totals
         let open_count = total_open + total_doing;
 
         if filtered.is_empty() {
-            println!("No issues found.");
+            writeln!(out, "No issues found.")?;
         }
 
-        // track position to insert indicator
-        let mut printed_count = 0;
-        let indicator_after = total_doing + total_open.min(DEFAULT_OPEN_LIMIT);
-
-        for issue in &filtered {
-            let derived = compute_derived(issue, &issues);
-            let deps_info = if issue.deps().is_empty() {
-                String::new()
-            } else if issue.issue_type() == Some(IssueType::Meta) {
-                // meta issues show progress as "done/total"
-                let total = issue.deps().len();
-                let done = total - derived.open_deps.len();
-                format!(" ({}/{})", done, total)
-            } else {
-                format!(
-                    " (deps:{} open:{})",
-                    issue.deps().len(),
-                    derived.open_deps.len()
-                )
-            };
-
-            // show owner for doing issues (max 12 chars)
-            let owner_info = if issue.status() == Status::Doing {
-                issue
-                    .frontmatter
-                    .owner
-                    .as_ref()
-                    .map(|o| {
-                        let truncated: String = o.chars().take(12).collect();
-                        format!(" ({})", truncated)
-                    })
-                    .unwrap_or_default()
-            } else {
-                String::new()
-            };
-
-            // apply styling based on status, priority, and type
-            let is_resolved = matches!(issue.status(), Status::Done | Status::Skip);
-            let is_doing = issue.status() == Status::Doing;
-            let use_color = !cli.no_color;
-            let priority_color = if use_color && !is_resolved {
-                match issue.priority() {
-                    Priority::P0 => Some(Color::Red),
-                    Priority::P1 => Some(Color::Yellow),
-                    _ => None,
-                }
-            } else {
-                None
-            };
-
-            if use_color {
-                if is_resolved {
-                    print!("{}", SetAttribute(Attribute::Dim));
+        let max_width = output_width(out);
+        // one row, from the laid out --columns row if there is one
+        let write_row = |out: &mut Output,
+                         issue: &Issue,
+                         column_row: Option<&String>|
+         -> Result<()> {
+            if let Some(row) = column_row {
+                let is_resolved = matches!(issue.status(), Status::Done | Status::Skip);
+                if use_color && is_resolved {
+                    write!(
+                        out,
+                        "{}{}{}",
+                        SetAttribute(Attribute::Dim),
+                        row,
+                        SetAttribute(Attribute::Reset)
+                    )?;
                 } else {
-                    // status styling: doing gets underline
-                    if is_doing {
-                        print!("{}", SetAttribute(Attribute::Underlined));
-                    }
-                    // type styling
-                    match issue.issue_type() {
-                        Some(IssueType::Design) => print!("{}", SetAttribute(Attribute::Italic)),
-                        Some(IssueType::Meta) => print!("{}", SetAttribute(Attribute::Bold)),
-                        None => {}
-                    }
+                    write!(out, "{}", row)?;
                 }
-            }
-
-            // age/schedule column: padded to 7 chars (max is "in 99mo")
-            // for scheduled issues, show "in Xd" format; otherwise show age
-            let age_col = if derived.is_scheduled {
-                let scheduled = format_scheduled(issue.frontmatter.scheduled_for.unwrap());
-                format!("{:>7}", scheduled)
             } else {
-                let age = format_age(issue.frontmatter.created_at);
-                format!("{:>7}", age)
-            };
-
-            // type column: "design", "meta", or padded empty (8 chars)
-            let type_col = match issue.issue_type() {
-                Some(IssueType::Design) => "design  ",
-                Some(IssueType::Meta) => "meta    ",
-                None => "        ",
-            };
-
-            // status column: padded to 5 chars (length of "doing")
-            let status_col = match issue.status() {
-                Status::Open => "open ",
-                Status::Doing => "doing",
-                Status::Done => "done ",
-                Status::Skip => "skip ",
-            };
-
-            print!("{}  ", issue.id());
-            if let Some(color) = priority_color {
-                print!("{}", SetForegroundColor(color));
-            }
-            print!("{}", issue.priority());
-            if priority_color.is_some() {
-                print!("{}", SetForegroundColor(Color::Reset));
-            }
-            print!(
-                "  {}  {}{}  {}{}",
-                age_col,
-                type_col,
-                status_col,
-                issue.title(),
-                deps_info
-            );
-
-            if !issue.tags().is_empty() {
-                print!(" ");
-                for (i, tag) in issue.tags().iter().enumerate() {
-                    if i > 0 {
-                        print!(" ");
-                    }
-                    if use_color {
-                        let color = if tag == "bug" {
-                            Color::Red
-                        } else {
-                            Color::Cyan
-                        };
-                        print!(
-                            "{}#{}{}",
-                            SetForegroundColor(color),
-                            tag,
-                            SetForegroundColor(Color::Reset)
-                        );
-                    } else {
-                        print!("#{}", tag);
-                    }
-                }
+                write_default_row(out, issue, &issues, &theme, use_color, max_width, args.deps)?;
             }
-
-            if use_color && (is_resolved || is_doing || issue.issue_type().is_some()) {
-                print!("{}", SetAttribute(Attribute::Reset));
+            writeln!(out)?;
+            Ok(())
+        };
+        let write_hidden_open = |out: &mut Output| -> Result<()> {
+            if use_color {
+                writeln!(
+                    out,
+                    "{}... +{} more open{}",
+                    SetAttribute(Attribute::Dim),
+                    hidden_open,
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                writeln!(out, "... +{} more open", hidden_open)?;
             }
+            Ok(())
+        };
 
-            // print owner in magenta
-            if !owner_info.is_empty() {
+        if let Some(by) = group_by {
+            for (i, (key, members)) in group_issues(&filtered, &issues, by).iter().enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                let header = group_header(key, members.len(), by, &issues);
                 if use_color {
-                    print!(
-                        "{}{}{}",
-                        SetForegroundColor(Color::Magenta),
-                        owner_info,
+                    writeln!(
+                        out,
+                        "{}{}{}{}",
+                        SetAttribute(Attribute::Bold),
+                        SetForegroundColor(to_crossterm(theme.header)),
+                        header,
                         SetAttribute(Attribute::Reset)
-                    );
+                    )?;
                 } else {
-                    print!("{}", owner_info);
+                    writeln!(out, "{}", header)?;
+                }
+                // columns line up within each group
+                let column_rows = columns
+                    .as_deref()
+                    .map(|columns| format_column_rows(members, &issues, columns, max_width));
+                for (j, issue) in members.iter().enumerate() {
+                    write_row(out, issue, column_rows.as_ref().map(|rows| &rows[j]))?;
                 }
             }
-            println!();
-
-            printed_count += 1;
-
-            // print indicator after last open issue (before resolved)
-            if printed_count == indicator_after && hidden_open > 0 {
-                if use_color {
-                    println!(
-                        "{}... +{} more open{}",
-                        SetAttribute(Attribute::Dim),
-                        hidden_open,
-                        SetAttribute(Attribute::Reset)
-                    );
-                } else {
-                    println!("... +{} more open", hidden_open);
+        } else {
+            // --columns: lay out every row up front so the columns line up
+            let column_rows = columns
+                .as_deref()
+                .map(|columns| format_column_rows(&filtered, &issues, columns, max_width));
+
+            // track position to insert indicator
+            let indicator_after = total_doing + total_open.min(DEFAULT_OPEN_LIMIT);
+
+            for (printed_count, issue) in filtered.iter().enumerate() {
+                write_row(
+                    out,
+                    issue,
+                    column_rows.as_ref().map(|rows| &rows[printed_count]),
+                )?;
+
+                // print indicator after last open issue (before resolved)
+                if printed_count + 1 == indicator_after && hidden_open > 0 {
+                    write_hidden_open(out)?;
                 }
             }
         }
 
+        if hidden_after_page > 0 {
+            if use_color {
+                writeln!(
+                    out,
+                    "{}... and {} more{}",
+                    SetAttribute(Attribute::Dim),
+                    hidden_after_page,
+                    SetAttribute(Attribute::Reset)
+                )?;
+            } else {
+                writeln!(out, "... and {} more", hidden_after_page)?;
+            }
+        }
+
         // print indicator after resolved issues
         if hidden_resolved > 0 {
             // use status name if filtering, otherwise generic "resolved"
@@ -376,19 +739,21 @@ pub fn cmd_ls(
                 Some(Status::Skip) => "skip",
                 _ => "resolved",
             };
-            if !cli.no_color {
-                println!(
+            if use_color {
+                writeln!(
+                    out,
                     "{}... +{} more {} (--all to show all){}",
                     SetAttribute(Attribute::Dim),
                     hidden_resolved,
                     status_name,
                     SetAttribute(Attribute::Reset)
-                );
+                )?;
             } else {
-                println!(
+                writeln!(
+                    out,
                     "... +{} more {} (--all to show all)",
                     hidden_resolved, status_name
-                );
+                )?;
             }
         }
 
@@ -396,6 +761,9 @@ pub fn cmd_ls(
 
         // build summary line: open (open+doing), plus non-zero resolved counts
         let mut parts = Vec::new();
+        if let Some((blocker, _)) = &blocked_by {
+            parts.push(format!("blocked by {}: {}", blocker, match_count));
+        }
         if scheduled_only {
             parts.push(format!("scheduled: {}", total_scheduled));
         } else {
@@ -414,17 +782,592 @@ pub fn cmd_ls(
             }
         }
 
-        println!("{} | took: {}ms", parts.join(" | "), elapsed_ms);
+        writeln!(out, "{} | took: {}ms", parts.join(" | "), elapsed_ms)?;
     }
 
     Ok(())
 }
 
+/// fewest title chars kept when fitting a row to the terminal; narrower than
+/// this and the row wraps instead.
+const MIN_TITLE_WIDTH: usize = 20;
+
+/// write one issue in the default `brd ls` layout, without the newline. with
+/// `max_width`, the title shrinks so the row fits. with `deps`, the row ends
+/// in a `[2/3 done]` indicator instead of the inline deps counts.
+fn write_default_row(
+    out: &mut Output,
+    issue: &Issue,
+    issues: &HashMap<String, Issue>,
+    theme: &Theme,
+    use_color: bool,
+    max_width: Option<usize>,
+    deps: bool,
+) -> Result<()> {
+    let derived = compute_derived(issue, issues);
+    let indicator = deps.then(|| deps_indicator(issue, &derived)).flatten();
+    let deps_info = if issue.deps().is_empty() || deps {
+        String::new()
+    } else if issue.issue_type() == Some(IssueType::Meta) {
+        // meta issues show progress as "done/total"
+        let total = issue.deps().len();
+        let done = total - derived.open_deps.len();
+        format!(" ({}/{})", done, total)
+    } else {
+        format!(
+            " (deps:{} open:{})",
+            issue.deps().len(),
+            derived.open_deps.len()
+        )
+    };
+
+    // show owner for doing issues (max 12 chars)
+    let owner_info = if issue.status() == Status::Doing {
+        issue
+            .frontmatter
+            .owner
+            .as_ref()
+            .map(|o| format!(" ({})", truncate(o, 12)))
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    // apply styling based on status, priority, and type
+    let is_resolved = matches!(issue.status(), Status::Done | Status::Skip);
+    let is_doing = issue.status() == Status::Doing;
+    let priority_color = if use_color && !is_resolved {
+        match issue.priority() {
+            Priority::P0 => Some(to_crossterm(theme.urgent)),
+            Priority::P1 => Some(to_crossterm(theme.highlight)),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if use_color {
+        if is_resolved {
+            write!(out, "{}", SetAttribute(Attribute::Dim))?;
+        } else {
+            // status styling: doing gets underline
+            if is_doing {
+                write!(out, "{}", SetAttribute(Attribute::Underlined))?;
+            }
+            // type styling
+            match issue.issue_type() {
+                Some(IssueType::Design) => write!(out, "{}", SetAttribute(Attribute::Italic))?,
+                Some(IssueType::Meta) => write!(out, "{}", SetAttribute(Attribute::Bold))?,
+                None => {}
+            }
+        }
+    }
+
+    // age/schedule column: padded to 7 chars (max is "in 99mo")
+    // for scheduled issues, show "in Xd" format; otherwise show age
+    let age_col = if derived.is_scheduled {
+        let scheduled = format_scheduled(issue.frontmatter.scheduled_for.unwrap());
+        format!("{:>7}", scheduled)
+    } else {
+        let age = format_age(issue.frontmatter.created_at);
+        format!("{:>7}", age)
+    };
+
+    // type column: "design", "meta", or padded empty (8 chars)
+    let type_col = match issue.issue_type() {
+        Some(IssueType::Design) => "design  ",
+        Some(IssueType::Meta) => "meta    ",
+        None => "        ",
+    };
+
+    // status column: padded to 5 chars (length of "doing")
+    let status_col = match issue.status() {
+        Status::Open => "open ",
+        Status::Doing => "doing",
+        Status::Done => "done ",
+        Status::Skip => "skip ",
+    };
+
+    // everything on the row but the title, to see how much room it gets
+    let title = match max_width {
+        Some(width) => {
+            let tags_width: usize = issue.tags().iter().map(|t| t.width() + 2).sum();
+            let fixed = issue.id().width()
+                + format!("  P0  {}  {}{}  ", age_col, type_col, status_col).width()
+                + deps_info.width()
+                + tags_width
+                + owner_info.width()
+                + indicator.as_ref().map_or(0, |i| i.width() + 1);
+            let room = width.saturating_sub(fixed).max(MIN_TITLE_WIDTH);
+            truncate(issue.title(), room)
+        }
+        None => issue.title().to_string(),
+    };
+
+    write!(out, "{}  ", issue.id())?;
+    if let Some(color) = priority_color {
+        write!(out, "{}", SetForegroundColor(color))?;
+    }
+    write!(out, "{}", issue.priority())?;
+    if priority_color.is_some() {
+        write!(out, "{}", SetForegroundColor(Color::Reset))?;
+    }
+    write!(
+        out,
+        "  {}  {}{}  {}{}",
+        age_col, type_col, status_col, title, deps_info
+    )?;
+
+    if !issue.tags().is_empty() {
+        write!(out, " ")?;
+        for (i, tag) in issue.tags().iter().enumerate() {
+            if i > 0 {
+                write!(out, " ")?;
+            }
+            if use_color {
+                let color = if tag == "bug" {
+                    to_crossterm(theme.urgent)
+                } else {
+                    to_crossterm(theme.header)
+                };
+                write!(
+                    out,
+                    "{}#{}{}",
+                    SetForegroundColor(color),
+                    tag,
+                    SetForegroundColor(Color::Reset)
+                )?;
+            } else {
+                write!(out, "#{}", tag)?;
+            }
+        }
+    }
+
+    if use_color && (is_resolved || is_doing || issue.issue_type().is_some()) {
+        write!(out, "{}", SetAttribute(Attribute::Reset))?;
+    }
+
+    // print owner in magenta
+    if !owner_info.is_empty() {
+        if use_color {
+            write!(
+                out,
+                "{}{}{}",
+                SetForegroundColor(Color::Magenta),
+                owner_info,
+                SetAttribute(Attribute::Reset)
+            )?;
+        } else {
+            write!(out, "{}", owner_info)?;
+        }
+    }
+
+    // --deps: progress indicator, in the blocked color while anything is open or missing
+    if let Some(indicator) = indicator {
+        let blocking = !derived.open_deps.is_empty() || !derived.missing_deps.is_empty();
+        if use_color && blocking {
+            write!(
+                out,
+                " {}{}{}",
+                SetForegroundColor(to_crossterm(theme.blocked)),
+                indicator,
+                SetForegroundColor(Color::Reset)
+            )?;
+        } else {
+            write!(out, " {}", indicator)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::{TestRepo, test_cli};
     use time::Duration;
 
+    #[test]
+    fn test_ls_watch_rejects_json() {
+        let repo = TestRepo::builder().build();
+        let mut cli = test_cli();
+        cli.json = true;
+        let args = LsArgs {
+            watch: true,
+            ..Default::default()
+        };
+        let err = cmd_ls(&cli, &repo.paths, &args).unwrap_err();
+        assert!(err.to_string().contains("--watch"));
+    }
+
+    #[test]
+    fn test_ls_blocked_by_lists_transitive_dependents() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-root").create();
+        repo.issue("brd-mid").deps(&["brd-root"]).create();
+        repo.issue("brd-leaf").deps(&["brd-mid"]).create();
+        repo.issue("brd-other").create();
+
+        let out_path = repo.paths.worktree_root.join("ls.json");
+        let mut cli = test_cli();
+        cli.json = true;
+        let args = LsArgs {
+            blocked_by: Some("root".to_string()),
+            output: Some(out_path.clone()),
+            ..Default::default()
+        };
+        cmd_ls(&cli, &repo.paths, &args).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&out_path).unwrap()).unwrap();
+        let mut ids: Vec<_> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["id"].as_str().unwrap())
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["brd-leaf", "brd-mid"]);
+
+        let args = LsArgs {
+            blocked_by: Some("brd-nope".to_string()),
+            ..Default::default()
+        };
+        let err = cmd_ls(&cli, &repo.paths, &args).unwrap_err();
+        assert!(matches!(err, BrdError::IssueNotFound(_)));
+    }
+
+    #[test]
+    fn test_ls_ndjson_prints_one_issue_per_line() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        repo.issue("brd-bbbb").create();
+        repo.issue("brd-cccc").create();
+
+        let out_path = repo.paths.worktree_root.join("ls.ndjson");
+        let args = LsArgs {
+            ndjson: true,
+            limit: Some(2),
+            output: Some(out_path.clone()),
+            ..Default::default()
+        };
+        cmd_ls(&test_cli(), &repo.paths, &args).unwrap();
+        let output = std::fs::read_to_string(&out_path).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let item: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(item["id"].as_str().unwrap().starts_with("brd-"));
+        }
+    }
+
+    #[test]
+    fn test_ls_columns_selects_and_orders_fields() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa")
+            .title("fix the parser")
+            .status(Status::Doing)
+            .owner("agent-one")
+            .tags(&["bug"])
+            .create();
+        repo.issue("brd-bbbb").title("write docs").create();
+
+        let out_path = repo.paths.worktree_root.join("ls.txt");
+        let args = LsArgs {
+            columns: Some("owner, id,title".to_string()),
+            output: Some(out_path.clone()),
+            ..Default::default()
+        };
+        cmd_ls(&test_cli(), &repo.paths, &args).unwrap();
+        let output = std::fs::read_to_string(&out_path).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines[0], "agent-one  brd-aaaa  fix the parser");
+        assert_eq!(lines[1], "-          brd-bbbb  write docs");
+
+        let args = LsArgs {
+            columns: Some("id,colour".to_string()),
+            ..Default::default()
+        };
+        let err = cmd_ls(&test_cli(), &repo.paths, &args).unwrap_err();
+        assert!(err.to_string().contains("unknown column 'colour'"));
+        assert!(parse_columns("id,").is_err());
+    }
+
+    #[test]
+    fn test_group_issues() {
+        let repo = TestRepo::builder().build();
+        let meta = repo
+            .issue("brd-meta")
+            .title("auth epic")
+            .issue_type(IssueType::Meta)
+            .create();
+        let a = repo
+            .issue("brd-aaaa")
+            .status(Status::Done)
+            .tags(&["bug", "ui"])
+            .deps(&["brd-meta"])
+            .create();
+        let b = repo
+            .issue("brd-bbbb")
+            .status(Status::Doing)
+            .tags(&["ui"])
+            .create();
+        let c = repo.issue("brd-cccc").owner("agent-one").create();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let listed = vec![&b, &c, &a, &meta];
+
+        let keys = |groups: Vec<(String, Vec<&Issue>)>| -> Vec<(String, Vec<String>)> {
+            groups
+                .into_iter()
+                .map(|(k, m)| (k, m.iter().map(|i| i.id().to_string()).collect()))
+                .collect()
+        };
+        let group = |by| keys(group_issues(&listed, &issues, by));
+        let s = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            group(LsGroup::Status),
+            vec![
+                ("doing".into(), s(&["brd-bbbb"])),
+                ("open".into(), s(&["brd-cccc", "brd-meta"])),
+                ("done".into(), s(&["brd-aaaa"])),
+            ]
+        );
+        // an issue with two tags is in both groups; untagged comes last
+        assert_eq!(
+            group(LsGroup::Tag),
+            vec![
+                ("bug".into(), s(&["brd-aaaa"])),
+                ("ui".into(), s(&["brd-bbbb", "brd-aaaa"])),
+                (NO_GROUP.into(), s(&["brd-cccc", "brd-meta"])),
+            ]
+        );
+        assert_eq!(
+            group(LsGroup::Owner),
+            vec![
+                ("agent-one".into(), s(&["brd-cccc"])),
+                (NO_GROUP.into(), s(&["brd-bbbb", "brd-aaaa", "brd-meta"])),
+            ]
+        );
+        assert_eq!(
+            group(LsGroup::Milestone)[0],
+            ("brd-meta".into(), s(&["brd-aaaa"]))
+        );
+        assert_eq!(
+            group_header("brd-meta", 1, LsGroup::Milestone, &issues),
+            "brd-meta auth epic (1)"
+        );
+        assert!("colour".parse::<LsGroup>().is_err());
+    }
+
+    #[test]
+    fn test_ls_group_by_prints_sections() {
+        let repo = TestRepo::builder().build();
+        // more than the default open limit, all in the P2 group
+        for i in 0..DEFAULT_OPEN_LIMIT {
+            repo.issue(&format!("brd-p2{:02}", i)).create();
+        }
+        repo.issue("brd-aaaa").priority(Priority::P1).create();
+        repo.issue("brd-bbbb").priority(Priority::P3).create();
+        repo.issue("brd-cccc")
+            .priority(Priority::P1)
+            .tags(&["bug"])
+            .create();
+
+        let out_path = repo.paths.worktree_root.join("ls.txt");
+        let args = LsArgs {
+            group_by: Some("priority".to_string()),
+            columns: Some("id".to_string()),
+            output: Some(out_path.clone()),
+            ..Default::default()
+        };
+        cmd_ls(&test_cli(), &repo.paths, &args).unwrap();
+        let output = std::fs::read_to_string(&out_path).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines[..4], ["P1 (2)", "brd-aaaa", "brd-cccc", ""]);
+        // grouping never truncates
+        assert_eq!(lines[4], format!("P2 ({})", DEFAULT_OPEN_LIMIT));
+        let p3 = 5 + DEFAULT_OPEN_LIMIT;
+        assert_eq!(lines[p3..p3 + 3], ["", "P3 (1)", "brd-bbbb"]);
+        assert!(!output.contains("more open"));
+
+        // filters apply before grouping
+        let args = LsArgs {
+            group_by: Some("priority".to_string()),
+            tag: vec!["bug".to_string()],
+            output: Some(out_path.clone()),
+            ..Default::default()
+        };
+        let mut cli = test_cli();
+        cli.json = true;
+        cmd_ls(&cli, &repo.paths, &args).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&out_path).unwrap()).unwrap();
+        let groups = json.as_object().unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(json["P1"]["count"], 1);
+        assert_eq!(json["P1"]["issues"][0]["id"], "brd-cccc");
+    }
+
+    #[test]
+    fn test_ls_deps_shows_progress_indicator() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-done").status(Status::Done).create();
+        repo.issue("brd-open").create();
+        repo.issue("brd-aaaa")
+            .title("partly unblocked")
+            .deps(&["brd-done", "brd-open", "brd-gone"])
+            .create();
+        repo.issue("brd-bbbb")
+            .title("fully unblocked")
+            .deps(&["brd-done"])
+            .create();
+
+        let out_path = repo.paths.worktree_root.join("ls.txt");
+        let ls = |columns: Option<&str>| {
+            let args = LsArgs {
+                deps: true,
+                status: Some("open".to_string()),
+                columns: columns.map(String::from),
+                output: Some(out_path.clone()),
+                ..Default::default()
+            };
+            cmd_ls(&test_cli(), &repo.paths, &args).unwrap();
+            std::fs::read_to_string(&out_path).unwrap()
+        };
+
+        let output = ls(None);
+        let row = |id: &str| {
+            output
+                .lines()
+                .find(|l| l.starts_with(id))
+                .unwrap()
+                .to_string()
+        };
+        assert!(row("brd-aaaa").ends_with("partly unblocked [1/3 done]!"));
+        assert!(row("brd-bbbb").ends_with("fully unblocked [1/1 done]"));
+        assert!(!row("brd-aaaa").contains("deps:"));
+        assert!(row("brd-open").ends_with("brd-open"));
+
+        // with --columns, the deps column is added at the end
+        let output = ls(Some("id"));
+        assert!(output.contains("brd-aaaa  [1/3 done]!"));
+    }
+
+    #[test]
+    fn test_format_column_rows_truncates_last_column() {
+        let repo = TestRepo::builder().build();
+        let issue = repo
+            .issue("brd-aaaa")
+            .title("a rather long title")
+            .tags(&["ui"])
+            .create();
+        let columns = parse_columns("id,tags,title").unwrap();
+
+        let rows = format_column_rows(&[&issue], &HashMap::new(), &columns, None);
+        assert_eq!(rows, vec!["brd-aaaa  #ui  a rather long title"]);
+
+        let rows = format_column_rows(&[&issue], &HashMap::new(), &columns, Some(22));
+        assert_eq!(rows, vec!["brd-aaaa  #ui  a rath…"]);
+        assert_eq!(rows[0].chars().count(), 22);
+    }
+
+    #[test]
+    fn test_format_column_rows_aligns_wide_owners() {
+        let repo = TestRepo::builder().build();
+        let wide = repo
+            .issue("brd-aaaa")
+            .title("日本語のタイトル")
+            .owner("山田")
+            .create();
+        let narrow = repo.issue("brd-bbbb").title("plain").owner("bob").create();
+        let columns = parse_columns("owner,title,id").unwrap();
+
+        let rows = format_column_rows(&[&wide, &narrow], &HashMap::new(), &columns, None);
+        // "山田" is four cells wide, so "bob" gets one extra space of padding
+        assert_eq!(rows[0], "山田  日本語のタイトル  brd-aaaa");
+        assert_eq!(rows[1], "bob   plain             brd-bbbb");
+        let id_col = |row: &str| row[..row.find("brd-").unwrap()].width();
+        assert_eq!(id_col(&rows[0]), id_col(&rows[1]));
+
+        // the last column is cut by cells too
+        let columns = parse_columns("id,title").unwrap();
+        let rows = format_column_rows(&[&wide], &HashMap::new(), &columns, Some(17));
+        assert_eq!(rows[0], "brd-aaaa  日本語…");
+        assert_eq!(rows[0].width(), 17);
+    }
+
+    #[test]
+    fn test_default_row_fits_title_to_width() {
+        let repo = TestRepo::builder().build();
+        let issue = repo
+            .issue("brd-aaaa")
+            .title("a title that is much too long for a narrow terminal window")
+            .tags(&["ui"])
+            .create();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let theme = Theme::from_config(&repo.config.theme);
+
+        let row = |max_width: Option<usize>| {
+            let path = repo.paths.worktree_root.join("row.txt");
+            let mut out = Output::new(Some(&path)).unwrap();
+            write_default_row(&mut out, &issue, &issues, &theme, false, max_width, false).unwrap();
+            out.flush().unwrap();
+            drop(out);
+            std::fs::read_to_string(&path).unwrap()
+        };
+
+        assert!(row(None).contains("narrow terminal window #ui"));
+        let narrow = row(Some(80));
+        assert_eq!(narrow.chars().count(), 80);
+        assert!(narrow.contains("…"));
+        assert!(narrow.ends_with(" #ui"));
+        // never squeezed below the minimum title width
+        let tiny = row(Some(10));
+        assert!(tiny.contains(&truncate(issue.title(), MIN_TITLE_WIDTH)));
+    }
+
+    #[test]
+    fn test_date_filters() {
+        let at = |s: &str| parse_past_date(s).unwrap();
+        let mut jan = Issue::new("brd-jan".into(), "jan".into(), Priority::P2, vec![]);
+        jan.frontmatter.created_at = at("2025-01-10");
+        let mut feb = Issue::new("brd-feb".into(), "feb".into(), Priority::P2, vec![]);
+        feb.frontmatter.created_at = at("2025-02-10");
+        // created in january, but completed in march
+        jan.frontmatter.completed_at = Some(at("2025-03-05"));
+
+        let created_feb = DateFilters {
+            created_after: Some(at("2025-02-01")),
+            ..Default::default()
+        };
+        assert!(!created_feb.matches(&jan));
+        assert!(created_feb.matches(&feb));
+
+        let created_before_feb = DateFilters {
+            created_before: Some(at("2025-02-10")),
+            ..Default::default()
+        };
+        assert!(created_before_feb.matches(&jan));
+        assert!(!created_before_feb.matches(&feb)); // before is exclusive
+
+        let updated_march = DateFilters {
+            updated_after: Some(at("2025-03-01")),
+            updated_before: Some(at("2025-04-01")),
+            ..Default::default()
+        };
+        assert!(updated_march.matches(&jan));
+        assert!(!updated_march.matches(&feb));
+    }
+
+    #[test]
+    fn test_paginate_limit_and_offset() {
+        let items: Vec<u32> = (0..10).collect();
+
+        assert_eq!(paginate(items.clone(), 0, None), (items.clone(), 0));
+        assert_eq!(paginate(items.clone(), 0, Some(3)), (vec![0, 1, 2], 7));
+        assert_eq!(paginate(items.clone(), 8, Some(3)), (vec![8, 9], 0));
+        assert_eq!(paginate(items, 20, Some(3)), (vec![], 0));
+    }
+
     #[test]
     fn test_format_age_minutes() {
         let now = OffsetDateTime::now_utc();
@@ -4,14 +4,37 @@ use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::git;
-use crate::repo::RepoPaths;
+use crate::repo::{self, RepoPaths};
 
-pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
-    // step 1: check for clean working tree
-    if !git::is_clean(&paths.worktree_root)? {
-        return Err(BrdError::Other(
-            "working tree is dirty - commit or stash changes first".to_string(),
-        ));
+/// the error for a dirty working tree, naming the changed files.
+fn dirty_tree_error(paths: &RepoPaths) -> BrdError {
+    let status = git::output(&["status", "--porcelain"], &paths.worktree_root).unwrap_or_default();
+    let files: Vec<&str> = status.lines().map(|l| l.get(3..).unwrap_or(l)).collect();
+    let mut shown = files.iter().take(5).copied().collect::<Vec<_>>().join(", ");
+    if files.len() > 5 {
+        shown.push_str(&format!(", and {} more", files.len() - 5));
+    }
+
+    // in an agent worktree, stray changes are usually unfinished work
+    let commit_hint = if repo::is_agent_worktree(&paths.worktree_root) {
+        "commit the work you want merged (`brd commit`)"
+    } else {
+        "commit or stash them manually"
+    };
+    BrdError::DirtyWorkingTree(format!(
+        "working tree is dirty ({}) - uncommitted changes are not merged\n\n\
+         options:\n  \
+         - {}\n  \
+         - use --stash to set them aside during the merge and restore them after",
+        shown, commit_hint
+    ))
+}
+
+pub fn cmd_merge(cli: &Cli, paths: &RepoPaths, stash: bool) -> Result<()> {
+    // step 1: a clean working tree, unless --stash sets changes aside below
+    let clean = git::is_clean(&paths.worktree_root)?;
+    if !clean && !stash {
+        return Err(dirty_tree_error(paths));
     }
 
     let branch = git::current_branch(&paths.worktree_root)?;
@@ -31,6 +54,34 @@ pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         return Ok(());
     }
 
+    let mut stashed = false;
+    if !clean {
+        if !cli.json {
+            println!("stashing uncommitted changes...");
+        }
+        stashed = git::stash_push(&paths.worktree_root, "brd agent merge: stashing changes")?;
+    }
+
+    let result = merge_to_main(cli, paths, &branch);
+
+    // restore stashed changes whether or not the merge went through
+    if stashed {
+        if !cli.json {
+            println!("restoring stashed changes...");
+        }
+        if !git::stash_pop(&paths.worktree_root)? {
+            eprintln!(
+                "warning: restoring stashed changes conflicted; resolve the conflicts, \
+                 then `git stash drop` (your changes are kept in `git stash list`)"
+            );
+        }
+    }
+
+    result
+}
+
+/// rebase onto origin/main, fast-forward main, and reset to it.
+fn merge_to_main(cli: &Cli, paths: &RepoPaths, branch: &str) -> Result<()> {
     let (display_ref, push_ref) = if branch == "HEAD" {
         let short_head = git::output(&["rev-parse", "--short", "HEAD"], &paths.worktree_root)?;
         (
@@ -38,7 +89,7 @@ pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
             "HEAD:main".to_string(),
         )
     } else {
-        (branch.clone(), format!("{}:main", branch))
+        (branch.to_string(), format!("{}:main", branch))
     };
 
     if !cli.json {
@@ -50,7 +101,7 @@ pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         println!("  fetching origin main...");
     }
     if !git::run(&["fetch", "origin", "main"], &paths.worktree_root)? {
-        return Err(BrdError::Other("failed to fetch origin main".to_string()));
+        return Err(BrdError::Git("failed to fetch origin main".to_string()));
     }
 
     // step 3: rebase onto origin/main
@@ -62,7 +113,7 @@ pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
         // rebase failed - abort and tell user
         let _ = git::run(&["rebase", "--abort"], &paths.worktree_root);
         let stderr = String::from_utf8_lossy(&rebase_output.stderr);
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Conflict(format!(
             "rebase failed - resolve conflicts manually:\n{}",
             stderr.trim()
         )));
@@ -79,12 +130,12 @@ pub fn cmd_merge(cli: &Cli, paths: &RepoPaths) -> Result<()> {
             || stderr.contains("rejected")
             || stderr.contains("failed to push")
         {
-            return Err(BrdError::Other(
+            return Err(BrdError::Conflict(
                 "push rejected (not fast-forward) - main has moved, run `brd agent merge` again"
                     .to_string(),
             ));
         }
-        return Err(BrdError::Other(format!("push failed: {}", stderr.trim())));
+        return Err(BrdError::Git(format!("push failed: {}", stderr.trim())));
     }
 
     // step 5: reset to origin/main
@@ -181,7 +232,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -192,16 +243,85 @@ mod tests {
 
         std::fs::write(dir.path().join("dirty.txt"), "dirty\n").unwrap();
 
-        let err = cmd_merge(&cli, &paths).unwrap_err();
+        let err = cmd_merge(&cli, &paths, false).unwrap_err();
         assert!(err.to_string().contains("working tree is dirty"));
     }
 
+    #[test]
+    fn test_dirty_error_names_files_and_stash_option() {
+        let (dir, paths) = create_repo();
+        let cli = make_cli();
+        git_ok(dir.path(), &["checkout", "-b", "feature"]);
+
+        std::fs::write(dir.path().join("dirty.txt"), "dirty\n").unwrap();
+
+        let err = cmd_merge(&cli, &paths, false).unwrap_err().to_string();
+        assert!(err.contains("working tree is dirty (dirty.txt)"));
+        assert!(err.contains("--stash"));
+    }
+
+    #[test]
+    fn test_merge_stash_on_main_leaves_changes_alone() {
+        let (dir, paths) = create_repo();
+        let cli = make_cli();
+
+        std::fs::write(dir.path().join("README.md"), "wip\n").unwrap();
+
+        // nothing to merge from main, so nothing gets stashed
+        cmd_merge(&cli, &paths, true).unwrap();
+        let readme = std::fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(readme, "wip\n");
+        assert_eq!(git::stash_count(dir.path()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_dirty_hint_ignores_agent_toml_in_main_checkout() {
+        let (dir, paths) = create_repo();
+        let cli = make_cli();
+        git_ok(dir.path(), &["checkout", "-b", "feature"]);
+
+        // a main checkout can hold agent.toml too; it isn't an agent worktree
+        std::fs::write(dir.path().join(".braid/agent.toml"), "agent_id = \"x\"\n").unwrap();
+
+        let err = cmd_merge(&cli, &paths, false).unwrap_err().to_string();
+        assert!(err.contains("commit or stash them manually"));
+        assert!(!err.contains("brd commit"));
+    }
+
+    #[test]
+    fn test_merge_stash_restores_changes() {
+        let (dir, paths) = create_repo();
+        let cli = make_cli();
+
+        let remote = tempdir().unwrap();
+        git_ok(remote.path(), &["init", "--bare"]);
+        git_ok(
+            dir.path(),
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        );
+        git_ok(dir.path(), &["push", "origin", "main"]);
+
+        git_ok(dir.path(), &["checkout", "-b", "feature"]);
+        std::fs::write(dir.path().join("feature.txt"), "done\n").unwrap();
+        git_ok(dir.path(), &["add", "feature.txt"]);
+        git_ok(dir.path(), &["commit", "-m", "feature"]);
+        std::fs::write(dir.path().join("README.md"), "wip\n").unwrap();
+
+        cmd_merge(&cli, &paths, true).unwrap();
+
+        let merged = git::output(&["ls-tree", "--name-only", "main"], remote.path()).unwrap();
+        assert!(merged.contains("feature.txt"));
+        let readme = std::fs::read_to_string(dir.path().join("README.md")).unwrap();
+        assert_eq!(readme, "wip\n");
+        assert_eq!(git::stash_count(dir.path()).unwrap(), 0);
+    }
+
     #[test]
     fn test_merge_on_main_branch() {
         let (_dir, paths) = create_repo();
         let cli = make_cli();
 
-        let result = cmd_merge(&cli, &paths);
+        let result = cmd_merge(&cli, &paths, false);
         assert!(result.is_ok(), "expected Ok, got: {:?}", result);
     }
 }
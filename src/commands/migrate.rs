@@ -1,11 +1,40 @@
 //! brd migrate command.
 
 use crate::cli::Cli;
-use crate::config::Config;
-use crate::error::Result;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, FilenameScheme};
+use crate::error::{BrdError, Result};
 use crate::issue::Issue;
 use crate::migrate::{self, CURRENT_SCHEMA};
-use crate::repo::RepoPaths;
+use crate::repo::{self, RepoPaths};
+
+use super::doctor::issues_needing_migration;
+
+/// Refuse to change issues from an agent worktree while some are at an older
+/// schema: rewriting a few at the current schema leaves the branch half
+/// migrated and conflicting with main. Main checkouts are left to `brd doctor`.
+pub fn require_current_issue_schema(paths: &RepoPaths, config: &Config) -> Result<()> {
+    if !repo::is_agent_worktree(&paths.worktree_root) {
+        return Ok(());
+    }
+    let outdated = issues_needing_migration(&paths.issues_dir(config))?;
+    if outdated.is_empty() {
+        return Ok(());
+    }
+    Err(BrdError::SchemaMismatch(format!(
+        "{} issue(s) in this agent worktree are at an older schema than this brd writes (v{})
+
+for agent worktrees:
+  1. rebase onto main: git fetch origin main && git rebase origin/main
+  2. run `brd migrate` to bring any remaining issues up to v{}
+
+NEVER manually edit schema_version in issue files.",
+        outdated.len(),
+        CURRENT_SCHEMA,
+        CURRENT_SCHEMA
+    )))
+}
 
 pub fn cmd_migrate(cli: &Cli, paths: &RepoPaths, dry_run: bool) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
@@ -49,9 +78,10 @@ pub fn cmd_migrate(cli: &Cli, paths: &RepoPaths, dry_run: bool) -> Result<()> {
             continue;
         }
 
-        let id = path
-            .file_stem()
-            .and_then(|s| s.to_str())
+        let id = frontmatter
+            .get("id")
+            .and_then(|id| id.as_str())
+            .or_else(|| path.file_stem().and_then(|s| s.to_str()))
             .unwrap_or("unknown")
             .to_string();
 
@@ -93,29 +123,105 @@ pub fn cmd_migrate(cli: &Cli, paths: &RepoPaths, dry_run: bool) -> Result<()> {
         }
     }
 
+    // rename files to match filename_scheme
+    let renames = plan_renames(&issues_dir, config.filename_scheme)?;
+    for rename in &renames {
+        if !dry_run {
+            std::fs::rename(&rename.from, &rename.to)?;
+        }
+        if !cli.json {
+            let verb = if dry_run { "would rename" } else { "renamed" };
+            println!(
+                "{} {}: {} → {}",
+                verb,
+                rename.id,
+                file_name(&rename.from),
+                file_name(&rename.to)
+            );
+        }
+    }
+
     if cli.json {
+        let renamed: Vec<_> = renames
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "id": r.id,
+                    "from": file_name(&r.from),
+                    "to": file_name(&r.to),
+                })
+            })
+            .collect();
         let json = serde_json::json!({
             "ok": true,
             "dry_run": dry_run,
             "migrated": if dry_run { 0 } else { migrated.len() },
             "would_migrate": if dry_run { results.len() } else { 0 },
-            "issues": results
+            "issues": results,
+            "filename_scheme": config.filename_scheme.to_string(),
+            "renamed": renamed,
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
-    } else if results.is_empty() {
-        println!("All issues are up to date (schema v{}).", CURRENT_SCHEMA);
+    } else if results.is_empty() && renames.is_empty() {
+        println!(
+            "All issues are up to date (schema v{}, {} file names).",
+            CURRENT_SCHEMA, config.filename_scheme
+        );
     } else if dry_run {
         println!(
-            "\n{} issue(s) would be migrated. Run without --dry-run to apply.",
-            results.len()
+            "\n{} issue(s) would be migrated and {} renamed. Run without --dry-run to apply.",
+            results.len(),
+            renames.len()
         );
     } else {
-        println!("\nMigrated {} issue(s).", migrated.len());
+        println!(
+            "\nMigrated {} issue(s), renamed {}.",
+            migrated.len(),
+            renames.len()
+        );
     }
 
     Ok(())
 }
 
+/// an issue file whose name doesn't match the configured scheme.
+struct Rename {
+    id: String,
+    from: PathBuf,
+    to: PathBuf,
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// issue files in `issues_dir` to rename so they follow `scheme`, sorted by id.
+/// files that fail to parse, or whose target name is taken, are left alone.
+fn plan_renames(issues_dir: &Path, scheme: FilenameScheme) -> Result<Vec<Rename>> {
+    let mut renames = Vec::new();
+    for entry in std::fs::read_dir(issues_dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+        let Ok(issue) = Issue::load(&path) else {
+            continue;
+        };
+        let to = issues_dir.join(issue.file_name(scheme));
+        if to != path && !to.exists() {
+            renames.push(Rename {
+                id: issue.id().to_string(),
+                from: path,
+                to,
+            });
+        }
+    }
+    renames.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(renames)
+}
+
 /// Parse frontmatter from markdown content.
 fn parse_frontmatter(content: &str) -> Result<(String, String)> {
     let content = content.trim_start();
@@ -170,7 +276,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -218,4 +324,43 @@ mod tests {
 
         assert_eq!(read_schema_version(&issue_path), CURRENT_SCHEMA);
     }
+
+    fn issue_file_names(paths: &RepoPaths, config: &Config) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(paths.issues_dir(config))
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_cmd_migrate_renames_to_filename_scheme() {
+        let (_dir, paths, mut config) = create_test_repo();
+        let issue = Issue::new(
+            "brd-aaaa".to_string(),
+            "Fix login bug".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        write_issue(&paths, &config, &issue);
+        config.filename_scheme = FilenameScheme::IdSlug;
+        config.save(&paths.config_path()).unwrap();
+
+        let cli = make_cli(true);
+        cmd_migrate(&cli, &paths, true).unwrap();
+        assert_eq!(issue_file_names(&paths, &config), vec!["brd-aaaa.md"]);
+
+        cmd_migrate(&cli, &paths, false).unwrap();
+        assert_eq!(
+            issue_file_names(&paths, &config),
+            vec!["brd-aaaa-fix-login-bug.md"]
+        );
+
+        // and back again
+        config.filename_scheme = FilenameScheme::Id;
+        config.save(&paths.config_path()).unwrap();
+        cmd_migrate(&cli, &paths, false).unwrap();
+        assert_eq!(issue_file_names(&paths, &config), vec!["brd-aaaa.md"]);
+    }
 }
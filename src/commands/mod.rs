@@ -3,6 +3,7 @@
 mod add;
 mod agent;
 mod agent_run;
+mod attach;
 mod commit;
 mod completions;
 mod config;
@@ -10,22 +11,36 @@ mod dep;
 mod doctor;
 mod done;
 mod edit;
+mod errors;
+mod escalate;
+mod groom;
 mod init;
+mod link;
+mod log;
 mod ls;
 mod merge;
 mod migrate;
 mod path;
+mod pick;
 mod ready;
+mod recent;
 mod reopen;
+mod report;
+mod restore;
 mod rm;
 mod search;
+mod search_index;
 mod set;
+mod set_status;
 mod show;
 mod skip;
 mod start;
 mod status;
 mod sync;
+mod templates;
 mod tui;
+mod version;
+mod whoami;
 
 pub use add::cmd_add;
 pub use agent::{
@@ -36,7 +51,9 @@ pub use agent::{
     check_agents_block,
     cmd_agent_branch,
     cmd_agent_init,
+    cmd_agent_list,
     cmd_agent_pr,
+    cmd_agent_remove,
     cmd_agents_inject,
     cmd_agents_show,
     extract_mode,
@@ -47,86 +64,99 @@ pub use agent_run::{
     cmd_agent_attach, cmd_agent_clean, cmd_agent_kill, cmd_agent_logs, cmd_agent_ps,
     cmd_agent_send, cmd_agent_spawn,
 };
+pub use attach::cmd_attach;
 pub use commit::cmd_commit;
 pub use completions::cmd_completions;
 pub use config::{
     cmd_config_auto_sync, cmd_config_external_repo, cmd_config_issues_branch, cmd_config_show,
 };
-pub use dep::{cmd_dep_add, cmd_dep_rm};
+pub use dep::{cmd_dep_add, cmd_dep_ls, cmd_dep_rm, cmd_dep_rm_all, cmd_dep_swap};
 pub use doctor::cmd_doctor;
 pub use done::cmd_done;
 pub use edit::cmd_edit;
+pub use errors::cmd_errors;
+pub use escalate::cmd_escalate;
+pub use groom::cmd_groom;
 pub use init::cmd_init;
+pub use link::cmd_link;
+pub use log::cmd_log;
 pub use ls::cmd_ls;
 pub use merge::cmd_merge;
-pub use migrate::cmd_migrate;
+pub use migrate::{cmd_migrate, require_current_issue_schema};
 pub use path::cmd_path;
 pub use ready::cmd_ready;
+pub use recent::cmd_recent;
 pub use reopen::cmd_reopen;
+pub use report::cmd_report_cycle_time;
+pub use restore::cmd_restore;
 pub use rm::cmd_rm;
 pub use search::cmd_search;
 pub use set::cmd_set;
-pub use show::cmd_show;
+pub use set_status::cmd_set_status;
+pub use show::{ShowView, cmd_show};
 pub use skip::cmd_skip;
 pub use start::{
     claim_issue, cmd_start, commit_and_push_issues_branch, commit_and_push_main, sync_with_main,
 };
 pub use status::cmd_status;
 pub use sync::cmd_sync;
+pub use templates::cmd_templates;
 pub use tui::cmd_tui;
+pub use version::cmd_version;
+pub use whoami::cmd_whoami;
 
 use std::collections::HashMap;
 
+use crate::api::{self, LoadFailure};
 use crate::config::Config;
 use crate::error::Result;
-use crate::graph::{compute_derived, get_dependents};
-use crate::issue::Issue;
+use crate::graph::{compute_derived, get_dependents, get_related};
+use crate::issue::{Issue, Status};
 use crate::repo::RepoPaths;
 
 // re-export functions from issue for use by command modules
 pub(crate) use crate::issue::{generate_issue_id, resolve_issue_id};
 
-/// load all issues from the issues directory.
+/// load all issues from the issues directory, warning about files that fail
+/// to parse. archived issues live in a separate directory and are not included.
 pub(crate) fn load_all_issues(
     paths: &RepoPaths,
     config: &Config,
 ) -> Result<HashMap<String, Issue>> {
-    let mut issues = HashMap::new();
-    let issues_dir = paths.issues_dir(config);
-
-    if !issues_dir.exists() {
-        return Ok(issues);
-    }
+    let (issues, failures) = api::load_issues_with_failures(paths, config)?;
+    warn_load_failures(&failures);
+    Ok(issues)
+}
 
-    for entry in std::fs::read_dir(&issues_dir)? {
-        let entry = entry?;
-        let path = entry.path();
+/// load issues archived by `brd rm`, warning about files that fail to parse.
+pub(crate) fn load_archived_issues(
+    paths: &RepoPaths,
+    config: &Config,
+) -> Result<HashMap<String, Issue>> {
+    let (issues, failures) = api::load_archived_issues_with_failures(paths, config)?;
+    warn_load_failures(&failures);
+    Ok(issues)
+}
 
-        if path.extension().is_some_and(|e| e == "md") {
-            match Issue::load(&path) {
-                Ok(issue) => {
-                    issues.insert(issue.id().to_string(), issue);
-                }
-                Err(e) => {
-                    let err_str = e.to_string();
-                    eprintln!("warning: failed to load {}: {}", path.display(), e);
-                    if err_str.contains("invalid type: map, expected a string") {
-                        eprintln!(
-                            "  hint: strings containing colons must be quoted, e.g. '- \"foo: bar\"'"
-                        );
-                    }
-                }
-            }
+fn warn_load_failures(failures: &[LoadFailure]) {
+    for (path, e) in failures {
+        eprintln!("warning: failed to load {}: {}", path.display(), e);
+        if e.to_string()
+            .contains("invalid type: map, expected a string")
+        {
+            eprintln!(
+                "  hint: strings containing colons must be quoted, e.g. '- \"foo: bar\"', or run `brd doctor --fix`"
+            );
         }
     }
-
-    Ok(issues)
 }
 
 /// convert an issue to JSON format.
+/// `is_ready` follows `ready_includes`, like `brd ready`.
 pub(crate) fn issue_to_json(
     issue: &Issue,
     all_issues: &HashMap<String, Issue>,
+    ready_includes: &[Status],
 ) -> serde_json::Value {
     let derived = compute_derived(issue, all_issues);
     let dependents = get_dependents(issue.id(), all_issues);
@@ -139,14 +169,18 @@ pub(crate) fn issue_to_json(
         "type": issue.frontmatter.issue_type.as_ref().map(|t| t.to_string()),
         "deps": issue.deps(),
         "dependents": dependents,
+        "related": get_related(issue.id(), all_issues),
         "tags": issue.tags(),
         "owner": issue.frontmatter.owner,
         "created_at": issue.frontmatter.created_at.format(&time::format_description::well_known::Rfc3339).unwrap(),
         "started_at": issue.frontmatter.started_at.map(|t| t.format(&time::format_description::well_known::Rfc3339).unwrap()),
         "completed_at": issue.frontmatter.completed_at.map(|t| t.format(&time::format_description::well_known::Rfc3339).unwrap()),
+        "cycle_time_secs": issue.cycle_time().map(|d| d.whole_seconds()),
         "acceptance": issue.frontmatter.acceptance,
+        "commits": issue.frontmatter.commits,
+        "attachments": issue.frontmatter.attachments,
         "derived": {
-            "is_ready": derived.is_ready,
+            "is_ready": derived.is_ready_for(issue.status(), ready_includes),
             "open_deps": derived.open_deps,
             "missing_deps": derived.missing_deps,
             "is_blocked": derived.is_blocked
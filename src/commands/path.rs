@@ -3,6 +3,7 @@
 use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
+use crate::issue::find_issue_file;
 use crate::repo::RepoPaths;
 
 use super::{load_all_issues, resolve_issue_id};
@@ -18,7 +19,9 @@ pub fn cmd_path(cli: &Cli, paths: &RepoPaths, id: &str) -> Result<()> {
         return Err(BrdError::IssueNotFound(id.to_string()));
     }
 
-    let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
+    let issues_dir = paths.issues_dir(&config);
+    let issue_path = find_issue_file(&issues_dir, &full_id)
+        .unwrap_or_else(|| issues_dir.join(issues[&full_id].file_name(config.filename_scheme)));
 
     // canonicalize to absolute path, fall back to the computed path if it doesn't exist yet
     let absolute_path = issue_path
@@ -0,0 +1,268 @@
+//! inline interactive issue picker for commands whose id argument was omitted.
+
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType},
+};
+
+use crate::cli::Cli;
+use crate::error::{BrdError, Result};
+use crate::issue::{Issue, Status};
+
+/// maximum number of candidates rendered at once.
+const VISIBLE_ROWS: usize = 10;
+
+/// let the user pick one of `candidates` interactively, returning its full id.
+///
+/// only runs when stdin and stdout are terminals and `--json` is off; otherwise
+/// errors so scripts keep having to pass ids explicitly.
+pub fn pick_issue(cli: &Cli, prompt: &str, candidates: Vec<&Issue>) -> Result<String> {
+    if cli.json || !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return Err(BrdError::NotInteractive(
+            "missing issue ID. pass one explicitly, or run in a terminal to pick interactively"
+                .to_string(),
+        ));
+    }
+    if candidates.is_empty() {
+        return Err(BrdError::Other("no issues to pick from".to_string()));
+    }
+
+    let mut picker = Picker::new(candidates);
+    terminal::enable_raw_mode()?;
+    let result = run_picker(&mut picker, prompt);
+    terminal::disable_raw_mode()?;
+
+    match result? {
+        Some(id) => Ok(id),
+        None => Err(BrdError::Cancelled),
+    }
+}
+
+fn run_picker(picker: &mut Picker, prompt: &str) -> Result<Option<String>> {
+    let mut err = io::stderr();
+    loop {
+        render(&mut err, picker, prompt)?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match picker.handle_key(key) {
+            PickerAction::Continue => {}
+            PickerAction::Cancel => {
+                execute!(err, terminal::Clear(ClearType::FromCursorDown))?;
+                return Ok(None);
+            }
+            PickerAction::Select(id) => {
+                execute!(err, terminal::Clear(ClearType::FromCursorDown))?;
+                return Ok(Some(id));
+            }
+        }
+    }
+}
+
+/// draw the prompt and matches below the cursor, then return the cursor to the prompt line.
+fn render(out: &mut impl Write, picker: &Picker, prompt: &str) -> Result<()> {
+    queue!(
+        out,
+        cursor::MoveToColumn(0),
+        terminal::Clear(ClearType::FromCursorDown),
+        Print(format!("{prompt}> {}", picker.query)),
+    )?;
+
+    let matches = picker.matches();
+    let start = picker.selected.saturating_sub(VISIBLE_ROWS - 1);
+    let mut rows = 0u16;
+    for (i, issue) in matches.iter().enumerate().skip(start).take(VISIBLE_ROWS) {
+        let marker = if i == picker.selected { "▶ " } else { "  " };
+        queue!(out, Print("\r\n"))?;
+        if i == picker.selected {
+            queue!(out, SetAttribute(Attribute::Reverse))?;
+        }
+        queue!(
+            out,
+            Print(format!(
+                "{marker}{}  {}  {:<5}  {}",
+                issue.id(),
+                issue.priority(),
+                issue.status(),
+                issue.title()
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+        rows += 1;
+    }
+    if matches.is_empty() {
+        queue!(out, Print("\r\n  (no matches)"))?;
+        rows += 1;
+    }
+
+    let col = (prompt.chars().count() + 2 + picker.query.chars().count()) as u16;
+    queue!(out, cursor::MoveUp(rows), cursor::MoveToColumn(col))?;
+    out.flush()?;
+    Ok(())
+}
+
+/// what the picker should do after a key press.
+#[derive(Debug, PartialEq, Eq)]
+enum PickerAction {
+    Continue,
+    Cancel,
+    Select(String),
+}
+
+/// picker state: the query typed so far and the selected index into the matches.
+struct Picker<'a> {
+    candidates: Vec<&'a Issue>,
+    query: String,
+    selected: usize,
+}
+
+impl<'a> Picker<'a> {
+    /// candidates are ordered active-first, then by priority.
+    fn new(mut candidates: Vec<&'a Issue>) -> Self {
+        candidates.sort_by(|a, b| {
+            let a_resolved = matches!(a.status(), Status::Done | Status::Skip);
+            let b_resolved = matches!(b.status(), Status::Done | Status::Skip);
+            a_resolved
+                .cmp(&b_resolved)
+                .then_with(|| a.cmp_by_priority(b))
+        });
+        Self {
+            candidates,
+            query: String::new(),
+            selected: 0,
+        }
+    }
+
+    fn matches(&self) -> Vec<&'a Issue> {
+        self.candidates
+            .iter()
+            .filter(|issue| issue.matches_query(&self.query))
+            .copied()
+            .collect()
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> PickerAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => PickerAction::Cancel,
+            KeyCode::Char('c') if ctrl => PickerAction::Cancel,
+            KeyCode::Enter => match self.matches().get(self.selected) {
+                Some(issue) => PickerAction::Select(issue.id().to_string()),
+                None => PickerAction::Continue,
+            },
+            KeyCode::Up => {
+                self.selected = self.selected.saturating_sub(1);
+                PickerAction::Continue
+            }
+            KeyCode::Char('p') if ctrl => {
+                self.selected = self.selected.saturating_sub(1);
+                PickerAction::Continue
+            }
+            KeyCode::Down => {
+                self.select_next();
+                PickerAction::Continue
+            }
+            KeyCode::Char('n') if ctrl => {
+                self.select_next();
+                PickerAction::Continue
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+                PickerAction::Continue
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.selected = 0;
+                PickerAction::Continue
+            }
+            _ => PickerAction::Continue,
+        }
+    }
+
+    fn select_next(&mut self) {
+        let len = self.matches().len();
+        if self.selected + 1 < len {
+            self.selected += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issue::Priority;
+    use crate::test_utils::test_cli;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn issue(id: &str, title: &str, priority: Priority, status: Status) -> Issue {
+        let mut issue = Issue::new(id.to_string(), title.to_string(), priority, vec![]);
+        issue.frontmatter.status = status;
+        issue
+    }
+
+    #[test]
+    fn test_picker_orders_active_first_then_priority() {
+        let done = issue("brd-aaaa", "finished", Priority::P0, Status::Done);
+        let low = issue("brd-bbbb", "later", Priority::P3, Status::Open);
+        let high = issue("brd-cccc", "urgent", Priority::P0, Status::Open);
+        let picker = Picker::new(vec![&done, &low, &high]);
+
+        let ids: Vec<_> = picker.matches().iter().map(|i| i.id()).collect();
+        assert_eq!(ids, vec!["brd-cccc", "brd-bbbb", "brd-aaaa"]);
+    }
+
+    #[test]
+    fn test_picker_filters_and_selects() {
+        let a = issue("brd-aaaa", "fix login", Priority::P2, Status::Open);
+        let b = issue("brd-bbbb", "add logout", Priority::P2, Status::Open);
+        let c = issue("brd-cccc", "write docs", Priority::P2, Status::Open);
+        let mut picker = Picker::new(vec![&a, &b, &c]);
+
+        for ch in "LOG".chars() {
+            picker.handle_key(key(KeyCode::Char(ch)));
+        }
+        assert_eq!(picker.matches().len(), 2);
+
+        picker.handle_key(key(KeyCode::Down));
+        picker.handle_key(key(KeyCode::Down)); // clamped at the last match
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            PickerAction::Select("brd-bbbb".to_string())
+        );
+    }
+
+    #[test]
+    fn test_picker_cancel_and_empty_enter() {
+        let a = issue("brd-aaaa", "fix login", Priority::P2, Status::Open);
+        let mut picker = Picker::new(vec![&a]);
+
+        picker.handle_key(key(KeyCode::Char('z')));
+        assert_eq!(
+            picker.handle_key(key(KeyCode::Enter)),
+            PickerAction::Continue
+        );
+        assert_eq!(picker.handle_key(key(KeyCode::Esc)), PickerAction::Cancel);
+    }
+
+    #[test]
+    fn test_pick_issue_requires_terminal() {
+        // tests never run with a tty on stdin and stdout, and json mode always refuses
+        let mut cli = test_cli();
+        cli.json = true;
+        let a = issue("brd-aaaa", "fix login", Priority::P2, Status::Open);
+        let err = pick_issue(&cli, "show", vec![&a]).unwrap_err();
+        assert!(err.to_string().contains("missing issue ID"));
+    }
+}
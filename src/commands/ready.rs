@@ -2,16 +2,23 @@
 
 use std::collections::HashMap;
 use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
 use std::time::Instant;
 
 use crossterm::style::{Attribute, Color, SetAttribute, SetForegroundColor};
+use time::OffsetDateTime;
 
+use crate::api;
 use crate::cli::Cli;
-use crate::config::Config;
+use crate::config::{Config, NextStrategy};
+use crate::date::format_duration;
 use crate::error::Result;
-use crate::graph::get_ready_issues;
+use crate::graph::{open_dependent_counts, weighted_score};
 use crate::issue::{Issue, IssueType, Priority, Status};
+use crate::output::{Output, format_json_list};
 use crate::repo::RepoPaths;
+use crate::theme::{Theme, to_crossterm};
 
 use super::{issue_to_json, load_all_issues};
 
@@ -19,18 +26,19 @@ fn format_ready_output(
     ready: &[&Issue],
     issues: &HashMap<String, Issue>,
     json: bool,
+    ndjson: bool,
     use_color: bool,
+    config: &Config,
     elapsed_ms: u128,
 ) -> String {
-    if json {
+    if json || ndjson {
         let json: Vec<_> = ready
             .iter()
-            .map(|issue| issue_to_json(issue, issues))
+            .map(|issue| issue_to_json(issue, issues, &config.ready_includes))
             .collect();
-        let mut output = serde_json::to_string_pretty(&json).unwrap();
-        output.push('\n');
-        return output;
+        return format_json_list(&json, ndjson);
     }
+    let theme = Theme::from_config(&config.theme);
 
     let mut output = String::new();
     let open_count = ready.len();
@@ -43,8 +51,8 @@ fn format_ready_output(
         let is_doing = issue.status() == Status::Doing;
         let priority_color = if use_color {
             match issue.priority() {
-                Priority::P0 => Some(Color::Red),
-                Priority::P1 => Some(Color::Yellow),
+                Priority::P0 => Some(to_crossterm(theme.urgent)),
+                Priority::P1 => Some(to_crossterm(theme.highlight)),
                 _ => None,
             }
         } else {
@@ -91,9 +99,9 @@ fn format_ready_output(
                 }
                 if use_color {
                     let color = if tag == "bug" {
-                        Color::Red
+                        to_crossterm(theme.urgent)
                     } else {
-                        Color::Cyan
+                        to_crossterm(theme.header)
                     };
                     let _ = write!(
                         output,
@@ -119,15 +127,252 @@ fn format_ready_output(
     output
 }
 
-pub fn cmd_ready(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+/// the ready issues `brd start` picks from when no id is given, best first.
+/// meta issues are skipped: they track other work rather than being work.
+/// so are doing issues (listed with `ready_includes = ["open", "doing"]`),
+/// which are already claimed.
+pub(crate) fn start_candidates<'a>(ready: &[&'a Issue]) -> Vec<&'a Issue> {
+    ready
+        .iter()
+        .copied()
+        .filter(|issue| {
+            issue.status() == Status::Open && issue.issue_type() != Some(IssueType::Meta)
+        })
+        .collect()
+}
+
+/// the `cmp_by_priority` tie-break between two issues, e.g. `priority (P1 vs P2)`.
+fn priority_reason(chosen: &Issue, runner_up: &Issue, now: OffsetDateTime) -> String {
+    let (a, b) = (
+        chosen.frontmatter.created_at,
+        runner_up.frontmatter.created_at,
+    );
+    if chosen.priority() != runner_up.priority() {
+        format!(
+            "priority ({} vs {})",
+            chosen.priority(),
+            runner_up.priority()
+        )
+    } else if a != b {
+        format!(
+            "older created_at ({} vs {} ago)",
+            format_duration(now - a),
+            format_duration(now - b)
+        )
+    } else {
+        "lower id".to_string()
+    }
+}
+
+/// a ranking value, higher first.
+type Metric = Box<dyn Fn(&Issue) -> i64>;
+
+/// what a strategy ranks by before falling back to `cmp_by_priority`, with a
+/// label for the explanation. None for `priority`.
+fn strategy_metric(
+    strategy: NextStrategy,
+    issues: &HashMap<String, Issue>,
+    now: OffsetDateTime,
+) -> Option<(&'static str, Metric)> {
+    if strategy == NextStrategy::Priority {
+        return None;
+    }
+    let counts = open_dependent_counts(issues);
+    let open_dependents = move |issue: &Issue| counts.get(issue.id()).copied().unwrap_or(0);
+    Some(match strategy {
+        NextStrategy::Weighted => (
+            "weighted score",
+            Box::new(move |issue| weighted_score(issue, open_dependents(issue), now)),
+        ),
+        _ => (
+            "open dependents",
+            Box::new(move |issue| open_dependents(issue) as i64),
+        ),
+    })
+}
+
+/// narrate how `brd start` without an id picks from the ranked ready issues:
+/// the pick, the runner-up, and the reasons in order.
+fn explain_pick<'a>(
+    ready: &[&'a Issue],
+    issues: &HashMap<String, Issue>,
+    strategy: NextStrategy,
+    now: OffsetDateTime,
+) -> (Option<&'a Issue>, Option<&'a Issue>, Vec<String>) {
+    let candidates = start_candidates(ready);
+    let skipped_doing = ready
+        .iter()
+        .filter(|issue| issue.status() == Status::Doing)
+        .count();
+    let skipped_meta = ready.len() - candidates.len() - skipped_doing;
+    let mut skipped = Vec::new();
+    if skipped_meta > 0 {
+        skipped.push(format!("{} meta", skipped_meta));
+    }
+    if skipped_doing > 0 {
+        skipped.push(format!("{} already doing", skipped_doing));
+    }
+    let mut reasons = Vec::new();
+
+    let Some(&chosen) = candidates.first() else {
+        reasons.push(if skipped.is_empty() {
+            "no issue is ready: every open issue is blocked or scheduled".to_string()
+        } else {
+            format!(
+                "no open, non-meta issue is ready; brd start skips the rest ({})",
+                skipped.join(", ")
+            )
+        });
+        return (None, None, reasons);
+    };
+
+    reasons.push("ready: open, all deps done, not scheduled for later".to_string());
+    if !skipped.is_empty() {
+        reasons.push(format!("skipped ready issue(s): {}", skipped.join(", ")));
+    }
+    let metric = strategy_metric(strategy, issues, now);
+    match &metric {
+        Some((label, value)) => reasons.push(format!(
+            "highest {} among {} candidate(s) ({} strategy): {}",
+            label,
+            candidates.len(),
+            strategy,
+            value(chosen)
+        )),
+        None => reasons.push(format!(
+            "highest priority among {} candidate(s): {}",
+            candidates.len(),
+            chosen.priority()
+        )),
+    }
+
+    let runner_up = candidates.get(1).copied();
+    let Some(runner_up) = runner_up else {
+        reasons.push("no runner-up: it is the only candidate".to_string());
+        return (Some(chosen), None, reasons);
+    };
+    let fallback = priority_reason(chosen, runner_up, now);
+    reasons.push(match metric {
+        Some((label, value)) if value(chosen) != value(runner_up) => format!(
+            "beats runner-up {} on {} ({} vs {})",
+            runner_up.id(),
+            label,
+            value(chosen),
+            value(runner_up)
+        ),
+        Some((label, value)) => format!(
+            "ties runner-up {} on {} ({}), broken by {}",
+            runner_up.id(),
+            label,
+            value(chosen),
+            fallback
+        ),
+        None if chosen.priority() != runner_up.priority() => {
+            format!("beats runner-up {} on {}", runner_up.id(), fallback)
+        }
+        None => format!(
+            "ties runner-up {} on {}, broken by {}",
+            runner_up.id(),
+            chosen.priority(),
+            fallback
+        ),
+    });
+    (Some(chosen), Some(runner_up), reasons)
+}
+
+fn format_explain_output(
+    ready: &[&Issue],
+    issues: &HashMap<String, Issue>,
+    strategy: NextStrategy,
+    ready_includes: &[Status],
+    json: bool,
+    now: OffsetDateTime,
+) -> String {
+    let (chosen, runner_up, reasons) = explain_pick(ready, issues, strategy, now);
+
+    if json {
+        let json = serde_json::json!({
+            "strategy": strategy.to_string(),
+            "next": chosen.map(|issue| issue_to_json(issue, issues, ready_includes)),
+            "runner_up": runner_up.map(|issue| issue_to_json(issue, issues, ready_includes)),
+            "reasons": reasons,
+        });
+        let mut output = serde_json::to_string_pretty(&json).unwrap();
+        output.push('\n');
+        return output;
+    }
+
+    let mut output = String::new();
+    match chosen {
+        Some(issue) => {
+            let _ = writeln!(
+                output,
+                "next: {}  {}  {}",
+                issue.id(),
+                issue.priority(),
+                issue.title()
+            );
+        }
+        None => {
+            let _ = writeln!(output, "next: none");
+        }
+    }
+    for reason in &reasons {
+        let _ = writeln!(output, "  - {}", reason);
+    }
+    if let Some(issue) = runner_up {
+        let _ = writeln!(
+            output,
+            "runner-up: {}  {}  {}",
+            issue.id(),
+            issue.priority(),
+            issue.title()
+        );
+    }
+    output
+}
+
+pub fn cmd_ready(
+    cli: &Cli,
+    paths: &RepoPaths,
+    explain: bool,
+    ndjson: bool,
+    output: Option<&Path>,
+) -> Result<()> {
     let start = Instant::now();
     let config = Config::load(&paths.config_path())?;
     let issues = load_all_issues(paths, &config)?;
-    let ready = get_ready_issues(&issues);
+    let now = OffsetDateTime::now_utc();
+    let ready = api::ranked_ready(&issues, &config, now);
+
+    if explain {
+        let mut out = Output::new(output)?;
+        let text = format_explain_output(
+            &ready,
+            &issues,
+            config.next_strategy,
+            &config.ready_includes,
+            cli.json,
+            now,
+        );
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        return Ok(());
+    }
 
     let elapsed_ms = start.elapsed().as_millis();
-    let output = format_ready_output(&ready, &issues, cli.json, !cli.no_color, elapsed_ms);
-    print!("{output}");
+    let mut out = Output::new(output)?;
+    let text = format_ready_output(
+        &ready,
+        &issues,
+        cli.json,
+        ndjson,
+        out.use_color(cli),
+        &config,
+        elapsed_ms,
+    );
+    out.write_all(text.as_bytes())?;
+    out.flush()?;
 
     Ok(())
 }
@@ -135,8 +380,9 @@ pub fn cmd_ready(cli: &Cli, paths: &RepoPaths) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{get_ready_issues, rank_ready};
     use crate::issue::{IssueType, Priority, Status};
-    use time::{Duration, OffsetDateTime};
+    use time::Duration;
 
     fn make_issue(id: &str, priority: Priority, status: Status, deps: Vec<&str>) -> Issue {
         let mut issue = Issue::new(
@@ -162,8 +408,9 @@ mod tests {
         issues.insert(blocked_issue.id().to_string(), blocked_issue);
         issues.insert(done_issue.id().to_string(), done_issue);
 
-        let ready = get_ready_issues(&issues);
-        let output = format_ready_output(&ready, &issues, false, false, 7);
+        let ready = get_ready_issues(&issues, &[Status::Open]);
+        let output =
+            format_ready_output(&ready, &issues, false, false, false, &Config::default(), 7);
 
         assert!(output.contains("brd-ready"));
         assert!(!output.contains("brd-blocked"));
@@ -185,8 +432,9 @@ mod tests {
         issues.insert(issue_p2.id().to_string(), issue_p2);
         issues.insert(issue_p0.id().to_string(), issue_p0);
 
-        let ready = get_ready_issues(&issues);
-        let output = format_ready_output(&ready, &issues, true, false, 0);
+        let ready = get_ready_issues(&issues, &[Status::Open]);
+        let output =
+            format_ready_output(&ready, &issues, true, false, false, &Config::default(), 0);
         let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
 
         assert_eq!(json[0]["id"], "brd-p0");
@@ -199,10 +447,102 @@ mod tests {
         let blocked_issue = make_issue("brd-blocked", Priority::P1, Status::Open, vec!["brd-miss"]);
         issues.insert(blocked_issue.id().to_string(), blocked_issue);
 
-        let ready = get_ready_issues(&issues);
-        let output = format_ready_output(&ready, &issues, false, false, 0);
+        let ready = get_ready_issues(&issues, &[Status::Open]);
+        let output =
+            format_ready_output(&ready, &issues, false, false, false, &Config::default(), 0);
 
         assert!(output.contains("No ready issues."));
         assert!(output.contains("open: 0 | took: 0ms"));
     }
+
+    #[test]
+    fn test_explain_pick_breaks_priority_tie_by_age() {
+        let now = OffsetDateTime::now_utc();
+        let mut issues = HashMap::new();
+        for (id, priority, days) in [
+            ("brd-newer", Priority::P1, 1),
+            ("brd-older", Priority::P1, 3),
+            ("brd-lower", Priority::P2, 9),
+        ] {
+            let mut issue = make_issue(id, priority, Status::Open, vec![]);
+            issue.frontmatter.created_at = now - Duration::days(days);
+            issues.insert(id.to_string(), issue);
+        }
+
+        let ready = get_ready_issues(&issues, &[Status::Open]);
+        let (chosen, runner_up, reasons) =
+            explain_pick(&ready, &issues, NextStrategy::Priority, now);
+        assert_eq!(chosen.unwrap().id(), "brd-older");
+        assert_eq!(runner_up.unwrap().id(), "brd-newer");
+        assert_eq!(reasons[1], "highest priority among 3 candidate(s): P1");
+        assert_eq!(
+            reasons[2],
+            "ties runner-up brd-newer on P1, broken by older created_at (3d vs 1d ago)"
+        );
+
+        let output = format_explain_output(
+            &ready,
+            &issues,
+            NextStrategy::Priority,
+            &[Status::Open],
+            false,
+            now,
+        );
+        assert!(output.starts_with("next: brd-older  P1"));
+        assert!(output.contains("runner-up: brd-newer  P1"));
+    }
+
+    #[test]
+    fn test_explain_pick_skips_meta_like_start() {
+        let now = OffsetDateTime::now_utc();
+        let mut issues = HashMap::new();
+        let mut meta = make_issue("brd-meta", Priority::P0, Status::Open, vec![]);
+        meta.frontmatter.issue_type = Some(IssueType::Meta);
+        issues.insert(meta.id().to_string(), meta);
+        let task = make_issue("brd-task", Priority::P3, Status::Open, vec![]);
+        issues.insert(task.id().to_string(), task);
+
+        let ready = get_ready_issues(&issues, &[Status::Open]);
+        let (chosen, runner_up, reasons) =
+            explain_pick(&ready, &issues, NextStrategy::Priority, now);
+        assert_eq!(chosen.unwrap().id(), "brd-task");
+        assert!(runner_up.is_none());
+        assert!(reasons.contains(&"skipped ready issue(s): 1 meta".to_string()));
+
+        let output = format_explain_output(
+            &ready,
+            &issues,
+            NextStrategy::Priority,
+            &[Status::Open],
+            true,
+            now,
+        );
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["next"]["id"], "brd-task");
+        assert!(json["runner_up"].is_null());
+    }
+
+    #[test]
+    fn test_explain_pick_unblock_strategy() {
+        let now = OffsetDateTime::now_utc();
+        let mut issues = HashMap::new();
+        for issue in [
+            make_issue("brd-urgent", Priority::P0, Status::Open, vec![]),
+            make_issue("brd-root", Priority::P2, Status::Open, vec![]),
+            make_issue("brd-child", Priority::P1, Status::Open, vec!["brd-root"]),
+        ] {
+            issues.insert(issue.id().to_string(), issue);
+        }
+
+        let mut ready = get_ready_issues(&issues, &[Status::Open]);
+        rank_ready(&mut ready, &issues, NextStrategy::Unblock, now);
+        let (chosen, runner_up, reasons) =
+            explain_pick(&ready, &issues, NextStrategy::Unblock, now);
+        assert_eq!(chosen.unwrap().id(), "brd-root");
+        assert_eq!(runner_up.unwrap().id(), "brd-urgent");
+        assert_eq!(
+            reasons[2],
+            "beats runner-up brd-urgent on open dependents (1 vs 0)"
+        );
+    }
 }
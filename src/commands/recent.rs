@@ -0,0 +1,192 @@
+//! brd recent command - feed of recently changed issues.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
+
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::date::parse_duration;
+use crate::error::{BrdError, Result};
+use crate::issue::{Issue, Status};
+use crate::output::{Output, format_json_list};
+use crate::repo::RepoPaths;
+
+use super::ls::format_age;
+use super::{issue_to_json, load_all_issues};
+
+/// the most recent recorded transition of an issue and when it happened.
+fn last_activity(issue: &Issue) -> (OffsetDateTime, &'static str) {
+    let fm = &issue.frontmatter;
+    let mut latest = (fm.created_at, "created");
+    if let Some(started) = fm.started_at
+        && started >= latest.0
+    {
+        latest = (started, "started");
+    }
+    if let Some(completed) = fm.completed_at
+        && completed >= latest.0
+    {
+        let event = if issue.status() == Status::Skip {
+            "skipped"
+        } else {
+            "completed"
+        };
+        latest = (completed, event);
+    }
+    latest
+}
+
+/// issues with activity at or after `cutoff`, newest first.
+fn recent_issues(
+    issues: &HashMap<String, Issue>,
+    cutoff: OffsetDateTime,
+) -> Vec<(&Issue, OffsetDateTime, &'static str)> {
+    let mut recent: Vec<_> = issues
+        .values()
+        .filter_map(|issue| {
+            let (at, event) = last_activity(issue);
+            (at >= cutoff).then_some((issue, at, event))
+        })
+        .collect();
+    recent.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.id().cmp(b.0.id())));
+    recent
+}
+
+fn format_recent_output(
+    recent: &[(&Issue, OffsetDateTime, &'static str)],
+    issues: &HashMap<String, Issue>,
+    ready_includes: &[Status],
+    json: bool,
+    ndjson: bool,
+    since: &str,
+) -> String {
+    if json || ndjson {
+        let json: Vec<_> = recent
+            .iter()
+            .map(|(issue, at, event)| {
+                let mut value = issue_to_json(issue, issues, ready_includes);
+                value["event"] = (*event).into();
+                value["event_at"] = at.format(&Rfc3339).unwrap().into();
+                value
+            })
+            .collect();
+        return format_json_list(&json, ndjson);
+    }
+
+    let mut output = String::new();
+    for (issue, at, event) in recent {
+        let _ = writeln!(
+            output,
+            "{:>4} ago  {}  {:<9}  {}",
+            format_age(*at),
+            issue.id(),
+            event,
+            issue.title()
+        );
+    }
+    let _ = writeln!(output, "changed in the last {}: {}", since, recent.len());
+    output
+}
+
+pub fn cmd_recent(
+    cli: &Cli,
+    paths: &RepoPaths,
+    since: &str,
+    ndjson: bool,
+    output: Option<&Path>,
+) -> Result<()> {
+    let window = parse_duration(since)?;
+    let config = Config::load(&paths.config_path())?;
+    let issues = load_all_issues(paths, &config)?;
+
+    let cutoff = OffsetDateTime::now_utc()
+        .checked_sub(window)
+        .ok_or_else(|| {
+            BrdError::ParseError("duration".into(), format!("duration too large: '{since}'"))
+        })?;
+    let recent = recent_issues(&issues, cutoff);
+    let mut out = Output::new(output)?;
+    out.write_all(
+        format_recent_output(
+            &recent,
+            &issues,
+            &config.ready_includes,
+            cli.json,
+            ndjson,
+            since,
+        )
+        .as_bytes(),
+    )?;
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issue::Priority;
+    use crate::test_utils::{TestRepo, test_cli};
+    use time::Duration;
+
+    fn issue_at(id: &str, created_ago: Duration) -> Issue {
+        let mut issue = Issue::new(id.to_string(), format!("{id} title"), Priority::P2, vec![]);
+        issue.frontmatter.created_at = OffsetDateTime::now_utc() - created_ago;
+        issue
+    }
+
+    #[test]
+    fn test_last_activity_picks_latest_transition() {
+        let now = OffsetDateTime::now_utc();
+        let mut issue = issue_at("brd-aaaa", Duration::days(3));
+        assert_eq!(last_activity(&issue).1, "created");
+
+        issue.frontmatter.started_at = Some(now - Duration::days(2));
+        assert_eq!(last_activity(&issue).1, "started");
+
+        issue.frontmatter.completed_at = Some(now - Duration::days(1));
+        issue.frontmatter.status = Status::Done;
+        assert_eq!(last_activity(&issue).1, "completed");
+
+        issue.frontmatter.status = Status::Skip;
+        assert_eq!(last_activity(&issue).1, "skipped");
+    }
+
+    #[test]
+    fn test_recent_issues_filters_window_and_sorts_newest_first() {
+        let mut issues = HashMap::new();
+        for (id, ago) in [
+            ("brd-old", Duration::days(5)),
+            ("brd-mid", Duration::hours(12)),
+            ("brd-new", Duration::hours(1)),
+        ] {
+            issues.insert(id.to_string(), issue_at(id, ago));
+        }
+        // started recently, so it counts even though it was created long ago
+        issues.get_mut("brd-old").unwrap().frontmatter.started_at =
+            Some(OffsetDateTime::now_utc() - Duration::hours(6));
+
+        let cutoff = OffsetDateTime::now_utc() - Duration::days(1);
+        let recent = recent_issues(&issues, cutoff);
+        let ids: Vec<_> = recent.iter().map(|(i, _, _)| i.id()).collect();
+        assert_eq!(ids, vec!["brd-new", "brd-old", "brd-mid"]);
+        assert_eq!(recent[1].2, "started");
+
+        let output = format_recent_output(&recent, &issues, &[Status::Open], true, false, "1d");
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 3);
+        assert_eq!(json[0]["id"], "brd-new");
+        assert_eq!(json[0]["event"], "created");
+    }
+
+    #[test]
+    fn test_recent_rejects_window_past_the_calendar() {
+        let repo = TestRepo::builder().build();
+        let result = cmd_recent(&test_cli(), &repo.paths, "99999999999w", false, None);
+        assert!(matches!(result, Err(BrdError::ParseError(_, _))));
+    }
+}
@@ -23,15 +23,15 @@ pub fn cmd_reopen(cli: &Cli, paths: &RepoPaths, id: &str) -> Result<()> {
 
         issue.frontmatter.status = Status::Open;
         issue.frontmatter.owner = None;
+        issue.frontmatter.started_at = None;
         issue.frontmatter.completed_at = None;
 
-        let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
-        issue.save(&issue_path)?;
+        issue.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
     }
 
     if cli.json {
         let issue = issues.get(&full_id).unwrap();
-        let json = issue_to_json(issue, &issues);
+        let json = issue_to_json(issue, &issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         println!("Reopened: {}", full_id);
@@ -59,6 +59,7 @@ mod tests {
         let issue = issues.get("brd-aaaa").unwrap();
         assert_eq!(issue.status(), Status::Open);
         assert!(issue.frontmatter.owner.is_none());
+        assert!(issue.frontmatter.started_at.is_none());
         assert!(issue.frontmatter.completed_at.is_none());
     }
 
@@ -0,0 +1,318 @@
+//! brd report commands - analytics over issue history.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
+
+use time::{Duration, OffsetDateTime};
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::date::{format_duration, parse_duration};
+use crate::error::{BrdError, Result};
+use crate::issue::{Issue, Status};
+use crate::output::Output;
+use crate::repo::RepoPaths;
+
+use super::load_all_issues;
+
+/// how to split the cycle-time report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupBy {
+    Priority,
+    Type,
+}
+
+impl std::str::FromStr for GroupBy {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "priority" | "p" => Ok(GroupBy::Priority),
+            "type" | "t" => Ok(GroupBy::Type),
+            _ => Err(BrdError::ParseError(
+                "group-by".into(),
+                format!("invalid group '{}'. use priority or type", s),
+            )),
+        }
+    }
+}
+
+/// average and median over a set of durations.
+#[derive(Debug, PartialEq, Eq)]
+struct TimeStats {
+    count: usize,
+    average: Duration,
+    median: Duration,
+}
+
+impl TimeStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let count = samples.len();
+        let total: Duration = samples.iter().copied().sum();
+        let median = if count.is_multiple_of(2) {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2
+        } else {
+            samples[count / 2]
+        };
+        Some(Self {
+            count,
+            average: total / count as u32,
+            median,
+        })
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "count": self.count,
+            "avg_secs": self.average.whole_seconds(),
+            "median_secs": self.median.whole_seconds(),
+        })
+    }
+}
+
+/// lead and cycle time for one group of completed issues.
+#[derive(Debug)]
+struct GroupReport {
+    group: String,
+    completed: usize,
+    lead: Option<TimeStats>,
+    cycle: Option<TimeStats>,
+}
+
+/// compute lead time (created → completed) and cycle time (started → completed)
+/// for done issues completed at or after `cutoff`.
+fn cycle_time_report(
+    issues: &HashMap<String, Issue>,
+    cutoff: OffsetDateTime,
+    group_by: Option<GroupBy>,
+) -> Vec<GroupReport> {
+    let mut groups: BTreeMap<String, Vec<&Issue>> = BTreeMap::new();
+    for issue in issues.values() {
+        let Some(completed) = issue.frontmatter.completed_at else {
+            continue;
+        };
+        if issue.status() != Status::Done || completed < cutoff {
+            continue;
+        }
+        let key = match group_by {
+            None => "all".to_string(),
+            Some(GroupBy::Priority) => issue.priority().to_string(),
+            Some(GroupBy::Type) => issue
+                .issue_type()
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "issue".to_string()),
+        };
+        groups.entry(key).or_default().push(issue);
+    }
+
+    groups
+        .into_iter()
+        .map(|(group, issues)| {
+            let lead = issues
+                .iter()
+                .filter_map(|i| Some(i.frontmatter.completed_at? - i.frontmatter.created_at))
+                .collect();
+            let cycle = issues.iter().filter_map(|i| i.cycle_time()).collect();
+            GroupReport {
+                group,
+                completed: issues.len(),
+                lead: TimeStats::from_samples(lead),
+                cycle: TimeStats::from_samples(cycle),
+            }
+        })
+        .collect()
+}
+
+fn format_cycle_time_output(report: &[GroupReport], json: bool, since: &str) -> String {
+    if json {
+        let groups: Vec<_> = report
+            .iter()
+            .map(|g| {
+                serde_json::json!({
+                    "group": g.group,
+                    "completed": g.completed,
+                    "lead_time": g.lead.as_ref().map(TimeStats::to_json),
+                    "cycle_time": g.cycle.as_ref().map(TimeStats::to_json),
+                })
+            })
+            .collect();
+        let json = serde_json::json!({ "since": since, "groups": groups });
+        let mut output = serde_json::to_string_pretty(&json).unwrap();
+        output.push('\n');
+        return output;
+    }
+
+    let mut output = String::new();
+    if report.is_empty() {
+        let _ = writeln!(output, "no issues completed in the last {}.", since);
+        return output;
+    }
+
+    let fmt = |stats: &Option<TimeStats>, f: fn(&TimeStats) -> Duration| {
+        stats
+            .as_ref()
+            .map(|s| format_duration(f(s)))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    let _ = writeln!(
+        output,
+        "{:<8}  {:>4}  {:>9}  {:>9}  {:>9}  {:>9}",
+        "group", "done", "lead avg", "lead p50", "cycle avg", "cycle p50"
+    );
+    for g in report {
+        let _ = writeln!(
+            output,
+            "{:<8}  {:>4}  {:>9}  {:>9}  {:>9}  {:>9}",
+            g.group,
+            g.completed,
+            fmt(&g.lead, |s| s.average),
+            fmt(&g.lead, |s| s.median),
+            fmt(&g.cycle, |s| s.average),
+            fmt(&g.cycle, |s| s.median),
+        );
+    }
+    let _ = writeln!(output, "completed in the last {}", since);
+    output
+}
+
+pub fn cmd_report_cycle_time(
+    cli: &Cli,
+    paths: &RepoPaths,
+    since: &str,
+    group_by: Option<&str>,
+    output: Option<&Path>,
+) -> Result<()> {
+    let window = parse_duration(since)?;
+    let group_by: Option<GroupBy> = group_by.map(|g| g.parse()).transpose()?;
+    let config = Config::load(&paths.config_path())?;
+    let issues = load_all_issues(paths, &config)?;
+
+    let cutoff = OffsetDateTime::now_utc()
+        .checked_sub(window)
+        .ok_or_else(|| {
+            BrdError::ParseError("duration".into(), format!("duration too large: '{since}'"))
+        })?;
+    let report = cycle_time_report(&issues, cutoff, group_by);
+    let mut out = Output::new(output)?;
+    out.write_all(format_cycle_time_output(&report, cli.json, since).as_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::issue::{IssueType, Priority};
+    use crate::test_utils::{TestRepo, test_cli};
+
+    fn done_issue(
+        id: &str,
+        priority: Priority,
+        lead_hours: i64,
+        cycle_hours: Option<i64>,
+    ) -> Issue {
+        let completed = OffsetDateTime::now_utc() - Duration::hours(1);
+        let mut issue = Issue::new(id.to_string(), id.to_string(), priority, vec![]);
+        issue.frontmatter.status = Status::Done;
+        issue.frontmatter.created_at = completed - Duration::hours(lead_hours);
+        issue.frontmatter.started_at = cycle_hours.map(|h| completed - Duration::hours(h));
+        issue.frontmatter.completed_at = Some(completed);
+        issue
+    }
+
+    fn to_map(issues: Vec<Issue>) -> HashMap<String, Issue> {
+        issues
+            .into_iter()
+            .map(|i| (i.id().to_string(), i))
+            .collect()
+    }
+
+    #[test]
+    fn test_time_stats_average_and_median() {
+        let stats = TimeStats::from_samples(vec![
+            Duration::hours(4),
+            Duration::hours(1),
+            Duration::hours(10),
+            Duration::hours(3),
+        ])
+        .unwrap();
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.average, Duration::minutes(270));
+        assert_eq!(stats.median, Duration::minutes(210));
+        assert!(TimeStats::from_samples(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_report_skips_unfinished_skipped_and_old_issues() {
+        let mut skipped = done_issue("brd-skip", Priority::P2, 5, None);
+        skipped.frontmatter.status = Status::Skip;
+        let mut old = done_issue("brd-old", Priority::P2, 5, None);
+        old.frontmatter.completed_at = Some(OffsetDateTime::now_utc() - Duration::days(90));
+        let open = Issue::new("brd-open".into(), "open".into(), Priority::P2, vec![]);
+        let issues = to_map(vec![
+            done_issue("brd-aaaa", Priority::P2, 10, Some(2)),
+            done_issue("brd-bbbb", Priority::P2, 20, None),
+            skipped,
+            old,
+            open,
+        ]);
+
+        let cutoff = OffsetDateTime::now_utc() - Duration::days(30);
+        let report = cycle_time_report(&issues, cutoff, None);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].group, "all");
+        assert_eq!(report[0].completed, 2);
+        assert_eq!(
+            report[0].lead.as_ref().unwrap().average,
+            Duration::hours(15)
+        );
+        // only one issue has a start time
+        let cycle = report[0].cycle.as_ref().unwrap();
+        assert_eq!((cycle.count, cycle.median), (1, Duration::hours(2)));
+    }
+
+    #[test]
+    fn test_report_groups_by_priority_and_type() {
+        let mut design = done_issue("brd-dddd", Priority::P1, 8, Some(4));
+        design.frontmatter.issue_type = Some(IssueType::Design);
+        let issues = to_map(vec![
+            done_issue("brd-aaaa", Priority::P0, 2, Some(1)),
+            done_issue("brd-bbbb", Priority::P2, 6, Some(3)),
+            design,
+        ]);
+        let cutoff = OffsetDateTime::now_utc() - Duration::days(30);
+
+        let by_priority = cycle_time_report(&issues, cutoff, Some(GroupBy::Priority));
+        let groups: Vec<_> = by_priority.iter().map(|g| g.group.as_str()).collect();
+        assert_eq!(groups, vec!["P0", "P1", "P2"]);
+
+        let by_type = cycle_time_report(&issues, cutoff, Some(GroupBy::Type));
+        let groups: Vec<_> = by_type.iter().map(|g| g.group.as_str()).collect();
+        assert_eq!(groups, vec!["design", "issue"]);
+
+        let output = format_cycle_time_output(&by_type, true, "30d");
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["groups"][1]["completed"], 2);
+        assert_eq!(json["groups"][1]["cycle_time"]["median_secs"], 2 * 3600);
+    }
+
+    #[test]
+    fn test_group_by_rejects_unknown() {
+        assert!("owner".parse::<GroupBy>().is_err());
+        assert_eq!("Priority".parse::<GroupBy>().unwrap(), GroupBy::Priority);
+    }
+
+    #[test]
+    fn test_report_rejects_window_past_the_calendar() {
+        let repo = TestRepo::builder().build();
+        let result = cmd_report_cycle_time(&test_cli(), &repo.paths, "99999999999w", None, None);
+        assert!(matches!(result, Err(BrdError::ParseError(_, _))));
+    }
+}
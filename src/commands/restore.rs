@@ -0,0 +1,82 @@
+//! brd restore command.
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::{BrdError, Result};
+use crate::issue::find_issue_file;
+use crate::lock::LockGuard;
+use crate::repo::RepoPaths;
+
+use super::{load_all_issues, load_archived_issues, resolve_issue_id};
+
+pub fn cmd_restore(cli: &Cli, paths: &RepoPaths, id: &str) -> Result<()> {
+    let config = Config::load(&paths.config_path())?;
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+
+    let archived = load_archived_issues(paths, &config)?;
+    let full_id = resolve_issue_id(id, &archived)?;
+
+    if load_all_issues(paths, &config)?.contains_key(&full_id) {
+        return Err(BrdError::AlreadyExists(format!(
+            "issue {} already exists; refusing to overwrite it",
+            full_id
+        )));
+    }
+
+    let archive_dir = paths.archive_dir(&config);
+    let archived_path = find_issue_file(&archive_dir, &full_id)
+        .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
+    let file_name = archived[&full_id].file_name(config.filename_scheme);
+    std::fs::rename(archived_path, paths.issues_dir(&config).join(file_name))?;
+
+    if cli.json {
+        let json = serde_json::json!({
+            "ok": true,
+            "restored": full_id,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        println!("Restored: {}", full_id);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::cmd_rm;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    #[test]
+    fn test_restore_brings_back_archived_issue() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        cmd_rm(&test_cli(), &repo.paths, "brd-aaaa", false, false).unwrap();
+        assert!(
+            load_all_issues(&repo.paths, &repo.config)
+                .unwrap()
+                .is_empty()
+        );
+
+        cmd_restore(&test_cli(), &repo.paths, "aaaa").unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(issues.contains_key("brd-aaaa"));
+        assert!(
+            load_archived_issues(&repo.paths, &repo.config)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_restore_unknown_id() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        // active issues can't be restored, only archived ones
+        let err = cmd_restore(&test_cli(), &repo.paths, "brd-aaaa").unwrap_err();
+        assert!(matches!(err, BrdError::IssueNotFound(_)));
+    }
+}
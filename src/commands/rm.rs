@@ -3,13 +3,13 @@
 use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
-use crate::issue::Status;
+use crate::issue::{Status, find_issue_file};
 use crate::lock::LockGuard;
 use crate::repo::RepoPaths;
 
 use super::{load_all_issues, resolve_issue_id};
 
-pub fn cmd_rm(cli: &Cli, paths: &RepoPaths, id: &str, force: bool) -> Result<()> {
+pub fn cmd_rm(cli: &Cli, paths: &RepoPaths, id: &str, force: bool, purge: bool) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
     let _lock = LockGuard::acquire(&paths.lock_path())?;
 
@@ -22,24 +22,38 @@ pub fn cmd_rm(cli: &Cli, paths: &RepoPaths, id: &str, force: bool) -> Result<()>
 
     // safety: refuse to delete issues in progress unless forced
     if issue.status() == Status::Doing && !force {
-        return Err(BrdError::Other(format!(
+        return Err(BrdError::Conflict(format!(
             "issue {} is in progress (use --force to delete anyway)",
             full_id
         )));
     }
 
-    // delete issue file
-    let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
-    std::fs::remove_file(&issue_path)?;
+    let issue_path = find_issue_file(&paths.issues_dir(&config), &full_id)
+        .ok_or_else(|| BrdError::IssueNotFound(full_id.clone()))?;
+    if purge {
+        std::fs::remove_file(&issue_path)?;
+    } else {
+        // archive by default so the issue can be restored with `brd restore`
+        let archive_dir = paths.archive_dir(&config);
+        std::fs::create_dir_all(&archive_dir)?;
+        let file_name = issue_path.file_name().unwrap_or_default();
+        std::fs::rename(&issue_path, archive_dir.join(file_name))?;
+    }
 
     if cli.json {
+        let key = if purge { "deleted" } else { "archived" };
         let json = serde_json::json!({
             "ok": true,
-            "deleted": full_id,
+            key: full_id,
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
-    } else {
+    } else if purge {
         println!("Deleted: {}", full_id);
+    } else {
+        println!(
+            "Archived: {} (restore with `brd restore {}`)",
+            full_id, full_id
+        );
     }
 
     Ok(())
@@ -77,7 +91,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -95,9 +109,29 @@ mod tests {
         assert!(issue_path.exists());
 
         let cli = make_cli(false);
-        cmd_rm(&cli, &paths, "brd-aaaa", false).unwrap();
+        cmd_rm(&cli, &paths, "brd-aaaa", false, false).unwrap();
 
         assert!(!issue_path.exists());
+        assert!(paths.archive_dir(&config).join("brd-aaaa.md").exists());
+        assert!(load_all_issues(&paths, &config).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rm_purge_deletes_without_archiving() {
+        let (_dir, paths, config) = create_test_repo();
+        let issue = Issue::new(
+            "brd-aaaa".to_string(),
+            "issue a".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        write_issue(&paths, &config, &issue);
+
+        let cli = make_cli(false);
+        cmd_rm(&cli, &paths, "brd-aaaa", false, true).unwrap();
+
+        assert!(!paths.issues_dir(&config).join("brd-aaaa.md").exists());
+        assert!(!paths.archive_dir(&config).join("brd-aaaa.md").exists());
     }
 
     #[test]
@@ -114,7 +148,7 @@ mod tests {
         let issue_path = paths.issues_dir(&config).join("brd-aaaa.md");
 
         let cli = make_cli(false);
-        let err = cmd_rm(&cli, &paths, "brd-aaaa", false).unwrap_err();
+        let err = cmd_rm(&cli, &paths, "brd-aaaa", false, false).unwrap_err();
         assert!(err.to_string().contains("in progress"));
         assert!(issue_path.exists());
     }
@@ -133,7 +167,7 @@ mod tests {
         let issue_path = paths.issues_dir(&config).join("brd-aaaa.md");
 
         let cli = make_cli(false);
-        cmd_rm(&cli, &paths, "brd-aaaa", true).unwrap();
+        cmd_rm(&cli, &paths, "brd-aaaa", true, false).unwrap();
 
         assert!(!issue_path.exists());
     }
@@ -157,7 +191,7 @@ mod tests {
         write_issue(&paths, &config, &issue_b);
 
         let cli = make_cli(false);
-        let err = cmd_rm(&cli, &paths, "aaa", false).unwrap_err();
+        let err = cmd_rm(&cli, &paths, "aaa", false, false).unwrap_err();
         assert!(matches!(err, BrdError::AmbiguousId(_, _)));
     }
 }
@@ -1,23 +1,288 @@
-//! brd search command - prints instructions for searching issues.
+//! brd search command - find issues by title, tags, acceptance, or body text.
 
-use crate::cli::Cli;
+use std::collections::HashMap;
+
+use crate::cli::{Cli, SearchArgs, SearchField};
 use crate::config::Config;
-use crate::error::Result;
+use crate::error::{BrdError, Result};
+use crate::issue::Issue;
 use crate::repo::RepoPaths;
 
-pub fn cmd_search(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+use super::load_all_issues;
+use super::search_index::SearchIndex;
+
+const ALL_FIELDS: [SearchField; 4] = [
+    SearchField::Title,
+    SearchField::Tags,
+    SearchField::Acceptance,
+    SearchField::Body,
+];
+
+/// a body line shown in results, numbered from 1 within the body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BodyLine {
+    line: usize,
+    text: String,
+    is_match: bool,
+}
+
+/// one issue that matched, with how well it matched.
+#[derive(Debug)]
+struct SearchHit<'a> {
+    issue: &'a Issue,
+    score: u32,
+    fields: Vec<SearchField>,
+    /// matching body lines and their context, one group per run of nearby matches
+    groups: Vec<Vec<BodyLine>>,
+}
+
+fn field_name(field: SearchField) -> &'static str {
+    match field {
+        SearchField::Title => "title",
+        SearchField::Tags => "tags",
+        SearchField::Acceptance => "acceptance",
+        SearchField::Body => "body",
+    }
+}
+
+/// group matching body lines with `context` lines around them, merging
+/// groups that touch or overlap.
+fn body_groups(body: &str, needle: &str, context: usize) -> Vec<Vec<BodyLine>> {
+    let lines: Vec<&str> = body.lines().collect();
+    let matches: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.to_lowercase().contains(needle))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &i in &matches {
+        let start = i.saturating_sub(context);
+        let end = (i + context).min(lines.len() - 1);
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            (start..=end)
+                .map(|i| BodyLine {
+                    line: i + 1,
+                    text: lines[i].to_string(),
+                    is_match: matches.binary_search(&i).is_ok(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// score an issue against a lowercase `needle`. an exact title match ranks
+/// above a title substring, which ranks above tags, acceptance, then body.
+fn score_issue<'a>(
+    issue: &'a Issue,
+    needle: &str,
+    fields: &[SearchField],
+    context: usize,
+) -> Option<SearchHit<'a>> {
+    let mut score = 0;
+    let mut matched = Vec::new();
+    let mut groups = Vec::new();
+
+    for &field in fields {
+        let field_score = match field {
+            SearchField::Title => {
+                let title = issue.title().to_lowercase();
+                if title == needle {
+                    100
+                } else if title.contains(needle) {
+                    50
+                } else {
+                    0
+                }
+            }
+            SearchField::Tags => {
+                let tags = issue.tags();
+                if tags.iter().any(|t| t.to_lowercase() == needle) {
+                    30
+                } else if tags.iter().any(|t| t.to_lowercase().contains(needle)) {
+                    20
+                } else {
+                    0
+                }
+            }
+            SearchField::Acceptance => {
+                let hits = issue
+                    .frontmatter
+                    .acceptance
+                    .iter()
+                    .filter(|ac| ac.to_lowercase().contains(needle))
+                    .count();
+                if hits > 0 { 10 + hits.min(5) as u32 } else { 0 }
+            }
+            SearchField::Body => {
+                groups = body_groups(&issue.body, needle, context);
+                let hits = groups.iter().flatten().filter(|l| l.is_match).count();
+                if hits > 0 { 5 + hits.min(5) as u32 } else { 0 }
+            }
+        };
+        if field_score > 0 {
+            score += field_score;
+            matched.push(field);
+        }
+    }
+
+    (score > 0).then_some(SearchHit {
+        issue,
+        score,
+        fields: matched,
+        groups,
+    })
+}
+
+/// issues matching `query`, best match first; ties keep the usual priority order.
+fn search_issues<'a>(
+    issues: &'a HashMap<String, Issue>,
+    query: &str,
+    fields: &[SearchField],
+    context: usize,
+) -> Vec<SearchHit<'a>> {
+    let needle = query.to_lowercase();
+    let mut hits: Vec<_> = issues
+        .values()
+        .filter_map(|issue| score_issue(issue, &needle, fields, context))
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.issue.cmp_by_priority(b.issue))
+    });
+    hits
+}
+
+fn format_hits(hits: &[SearchHit]) -> String {
+    use std::fmt::Write as _;
+
+    let mut output = String::new();
+    for (i, hit) in hits.iter().enumerate() {
+        if i > 0 {
+            let _ = writeln!(output);
+        }
+        let fields: Vec<_> = hit.fields.iter().map(|f| field_name(*f)).collect();
+        let _ = writeln!(
+            output,
+            "{}  {}  {}  {}  [{}]",
+            hit.issue.id(),
+            hit.issue.priority(),
+            hit.issue.status(),
+            hit.issue.title(),
+            fields.join(", ")
+        );
+        for (g, group) in hit.groups.iter().enumerate() {
+            if g > 0 {
+                let _ = writeln!(output, "  --");
+            }
+            for line in group {
+                let sep = if line.is_match { ':' } else { '-' };
+                let _ = writeln!(output, "  {}{} {}", line.line, sep, line.text);
+            }
+        }
+    }
+    output
+}
+
+fn hits_to_json(query: &str, fields: &[SearchField], hits: &[SearchHit]) -> serde_json::Value {
+    let results: Vec<_> = hits
+        .iter()
+        .map(|hit| {
+            let lines: Vec<_> = hit
+                .groups
+                .iter()
+                .flatten()
+                .map(|l| serde_json::json!({ "line": l.line, "text": l.text, "match": l.is_match }))
+                .collect();
+            serde_json::json!({
+                "id": hit.issue.id(),
+                "title": hit.issue.title(),
+                "priority": hit.issue.priority().to_string(),
+                "status": hit.issue.status().to_string(),
+                "score": hit.score,
+                "fields": hit.fields.iter().map(|f| field_name(*f)).collect::<Vec<_>>(),
+                "lines": lines,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "query": query,
+        "fields": fields.iter().map(|f| field_name(*f)).collect::<Vec<_>>(),
+        "results": results,
+    })
+}
+
+pub fn cmd_search(cli: &Cli, paths: &RepoPaths, args: &SearchArgs) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
     let issues_dir = paths.issues_dir(&config);
 
+    let index_path = paths.search_index_path();
+
+    let Some(query) = args.query.as_deref() else {
+        if args.reindex {
+            let index = SearchIndex::open(&index_path, &issues_dir, true)?;
+            if cli.json {
+                let json = serde_json::json!({ "reindexed": index.len() });
+                println!("{}", serde_json::to_string_pretty(&json).unwrap());
+            } else {
+                println!("reindexed {} issue(s)", index.len());
+            }
+        } else {
+            print_grep_hints(cli, &issues_dir);
+        }
+        return Ok(());
+    };
+    if query.trim().is_empty() {
+        return Err(BrdError::InvalidInput("search query is empty".to_string()));
+    }
+
+    let fields: &[SearchField] = if args.field.is_empty() {
+        &ALL_FIELDS
+    } else {
+        &args.field
+    };
+    // the index narrows which files are loaded; queries too short for it
+    // fall back to scanning every issue
+    let index = SearchIndex::open(&index_path, &issues_dir, args.reindex)?;
+    let issues = match index.load_candidates(query) {
+        Some(candidates) => candidates,
+        None => load_all_issues(paths, &config)?,
+    };
+    let hits = search_issues(&issues, query, fields, args.context);
+
+    if cli.json {
+        let json = hits_to_json(query, fields, &hits);
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else if hits.is_empty() {
+        println!("no issues match '{}'", query);
+    } else {
+        print!("{}", format_hits(&hits));
+    }
+
+    Ok(())
+}
+
+fn print_grep_hints(cli: &Cli, issues_dir: &std::path::Path) {
     if cli.json {
         let json = serde_json::json!({
             "issues_dir": issues_dir.to_string_lossy(),
-            "hint": "use grep or rg to search issue files"
+            "hint": "pass a query, or use grep or rg to search issue files"
         });
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
-        println!("braid issues are plain markdown files. use grep or rg to search:");
+        println!("braid issues are plain markdown files. pass a query, or use grep or rg:");
         println!();
+        println!("  brd search <query>");
         println!("  rg <pattern> {}", issues_dir.display());
         println!("  grep -r <pattern> {}", issues_dir.display());
         println!();
@@ -31,8 +296,6 @@ pub fn cmd_search(cli: &Cli, paths: &RepoPaths) -> Result<()> {
             issues_dir.display()
         );
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
@@ -71,7 +334,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -80,7 +343,7 @@ mod tests {
         let (_dir, paths) = create_repo();
         let cli = make_cli(false);
 
-        let result = cmd_search(&cli, &paths);
+        let result = cmd_search(&cli, &paths, &SearchArgs::default());
         assert!(result.is_ok());
     }
 
@@ -90,7 +353,7 @@ mod tests {
         let cli = make_cli(true);
 
         // Just verify it doesn't error - output goes to stdout
-        let result = cmd_search(&cli, &paths);
+        let result = cmd_search(&cli, &paths, &SearchArgs::default());
         assert!(result.is_ok());
     }
 
@@ -106,7 +369,7 @@ mod tests {
         .unwrap();
 
         let cli = make_cli(false);
-        let result = cmd_search(&cli, &paths);
+        let result = cmd_search(&cli, &paths, &SearchArgs::default());
         assert!(result.is_ok());
     }
 
@@ -120,7 +383,201 @@ mod tests {
         };
 
         let cli = make_cli(false);
-        let result = cmd_search(&cli, &paths);
+        let result = cmd_search(&cli, &paths, &SearchArgs::default());
         assert!(result.is_err());
     }
+
+    // =========================================================================
+    // query search tests
+    // =========================================================================
+
+    use crate::test_utils::{TestRepo, test_cli};
+
+    fn ids(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|h| h.issue.id().to_string()).collect()
+    }
+
+    #[test]
+    fn test_exact_title_ranks_above_body() {
+        let repo = TestRepo::builder().build();
+        let mut body_issue = repo.issue("brd-body").title("something else").create();
+        body_issue.body = "we should cache this\nlater".to_string();
+        body_issue
+            .save(&repo.paths.issues_dir(&repo.config).join("brd-body.md"))
+            .unwrap();
+        repo.issue("brd-part").title("cache invalidation").create();
+        repo.issue("brd-exct").title("Cache").create();
+        repo.issue("brd-none").title("unrelated").create();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let hits = search_issues(&issues, "cache", &ALL_FIELDS, 2);
+        assert_eq!(ids(&hits), vec!["brd-exct", "brd-part", "brd-body"]);
+    }
+
+    #[test]
+    fn test_field_selection() {
+        let repo = TestRepo::builder().build();
+        let mut issue = repo
+            .issue("brd-abc1")
+            .title("parser")
+            .tags(&["cache"])
+            .create();
+        issue.body = "the cache is cold".to_string();
+        issue
+            .save(&repo.paths.issues_dir(&repo.config).join("brd-abc1.md"))
+            .unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert!(search_issues(&issues, "cache", &[SearchField::Title], 2).is_empty());
+
+        let hits = search_issues(&issues, "cache", &[SearchField::Tags], 2);
+        assert_eq!(hits[0].fields, vec![SearchField::Tags]);
+        assert!(hits[0].groups.is_empty());
+
+        let hits = search_issues(&issues, "cache", &ALL_FIELDS, 2);
+        assert_eq!(hits[0].fields, vec![SearchField::Tags, SearchField::Body]);
+    }
+
+    #[test]
+    fn test_body_groups_context_and_merging() {
+        let body = "one\ntwo match\nthree\nfour\nfive\nsix\nseven match\neight";
+
+        let groups = body_groups(body, "match", 1);
+        let lines: Vec<Vec<usize>> = groups
+            .iter()
+            .map(|g| g.iter().map(|l| l.line).collect())
+            .collect();
+        assert_eq!(lines, vec![vec![1, 2, 3], vec![6, 7, 8]]);
+        assert!(groups[0][1].is_match);
+        assert!(!groups[0][0].is_match);
+
+        // wider context merges the two groups into one
+        let groups = body_groups(body, "match", 2);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].first().unwrap().line, 1);
+        assert_eq!(groups[0].last().unwrap().line, 8);
+
+        // no context: just the matching lines, still grouped separately
+        let groups = body_groups(body, "match", 0);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1][0].line, 7);
+    }
+
+    #[test]
+    fn test_format_hits_grep_style() {
+        let repo = TestRepo::builder().build();
+        let mut issue = repo.issue("brd-abc1").title("design").create();
+        issue.body = "intro\nthe cache layer\noutro".to_string();
+        issue
+            .save(&repo.paths.issues_dir(&repo.config).join("brd-abc1.md"))
+            .unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        let hits = search_issues(&issues, "cache", &ALL_FIELDS, 1);
+        let output = format_hits(&hits);
+        assert!(output.starts_with("brd-abc1  P2  open  design  [body]\n"));
+        assert!(output.contains("  1- intro\n  2: the cache layer\n  3- outro\n"));
+
+        let json = hits_to_json("cache", &ALL_FIELDS, &hits);
+        assert_eq!(json["results"][0]["lines"][1]["line"], 2);
+        assert_eq!(json["results"][0]["lines"][1]["match"], true);
+        assert_eq!(json["results"][0]["fields"][0], "body");
+    }
+
+    fn indexed_search(repo: &TestRepo, query: &str) -> Vec<String> {
+        let issues_dir = repo.paths.issues_dir(&repo.config);
+        let index = SearchIndex::open(&repo.paths.search_index_path(), &issues_dir, false).unwrap();
+        let issues = index
+            .load_candidates(query)
+            .unwrap_or_else(|| load_all_issues(&repo.paths, &repo.config).unwrap());
+        ids(&search_issues(&issues, query, &ALL_FIELDS, 2))
+    }
+
+    fn brute_force_search(repo: &TestRepo, query: &str) -> Vec<String> {
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        ids(&search_issues(&issues, query, &ALL_FIELDS, 2))
+    }
+
+    #[test]
+    fn test_index_matches_brute_force() {
+        let repo = TestRepo::builder().build();
+        let issues_dir = repo.paths.issues_dir(&repo.config);
+        let bodies = [
+            "the Cache layer is slow\nneeds work",
+            "parser rewrite\nsee the cache notes",
+            "Über-caching with ünïcode",
+            "",
+        ];
+        for (i, body) in bodies.iter().enumerate() {
+            let id = format!("brd-ix{:02}", i);
+            let mut issue = repo
+                .issue(&id)
+                .title(&format!("issue {} cache", i % 2))
+                .tags(&["perf"])
+                .create();
+            issue.body = body.to_string();
+            issue.frontmatter.acceptance = vec![format!("criterion {}", i)];
+            issue.save(&issues_dir.join(format!("{}.md", id))).unwrap();
+        }
+
+        let queries = [
+            "cache",
+            "CACHE",
+            "the cache",
+            "ca",
+            "x",
+            "perf",
+            "criterion 2",
+            "über",
+            "ünïcode",
+            "slow\nneeds",
+            "nothing here",
+            "issue 1 cache",
+        ];
+        let check = |repo: &TestRepo| {
+            for query in queries {
+                assert_eq!(
+                    indexed_search(repo, query),
+                    brute_force_search(repo, query),
+                    "query {:?}",
+                    query
+                );
+            }
+        };
+        check(&repo);
+        assert!(repo.paths.search_index_path().exists());
+
+        // edits, additions, and removals are picked up incrementally
+        let path = issues_dir.join("brd-ix00.md");
+        let mut issue = Issue::load(&path).unwrap();
+        issue.body = "no longer about that".to_string();
+        issue.save(&path).unwrap();
+        std::fs::remove_file(issues_dir.join("brd-ix01.md")).unwrap();
+        let mut added = repo.issue("brd-ix99").title("late").create();
+        added.body = "cache criterion".to_string();
+        added.save(&issues_dir.join("brd-ix99.md")).unwrap();
+
+        check(&repo);
+        assert!(!indexed_search(&repo, "slow").contains(&"brd-ix00".to_string()));
+        assert_eq!(indexed_search(&repo, "criterion 1"), Vec::<String>::new());
+        assert!(indexed_search(&repo, "cache criterion").contains(&"brd-ix99".to_string()));
+    }
+
+    #[test]
+    fn test_reindex_rebuilds_stale_index() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-abc1").title("cache miss").create();
+        let index_path = repo.paths.search_index_path();
+        std::fs::write(&index_path, "not json").unwrap();
+
+        let cli = test_cli();
+        let args = SearchArgs {
+            reindex: true,
+            ..SearchArgs::default()
+        };
+        cmd_search(&cli, &repo.paths, &args).unwrap();
+        let content = std::fs::read_to_string(&index_path).unwrap();
+        assert!(content.contains("brd-abc1.md"));
+        assert_eq!(indexed_search(&repo, "miss"), vec!["brd-abc1"]);
+    }
 }
@@ -0,0 +1,312 @@
+//! trigram index over issue text, cached in `.git/brd/search-index.json`.
+//!
+//! the index only narrows down which issue files `brd search` has to load:
+//! every candidate is still scored by the regular matcher, so results are the
+//! same as a full scan. entries are refreshed when an issue file's mtime or
+//! size changes.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::issue::Issue;
+
+/// bump when the index format or tokenization changes.
+const INDEX_VERSION: u32 = 1;
+
+/// what an issue file looked like when it was indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct FileStamp {
+    secs: u64,
+    nanos: u32,
+    len: u64,
+}
+
+impl FileStamp {
+    fn of(path: &Path) -> Option<Self> {
+        let meta = std::fs::metadata(path).ok()?;
+        let mtime = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            secs: mtime.as_secs(),
+            nanos: mtime.subsec_nanos(),
+            len: meta.len(),
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct SearchIndex {
+    version: u32,
+    issues_dir: PathBuf,
+    /// indexed issue files, sorted by name; a file's position is its doc id
+    files: Vec<(String, FileStamp)>,
+    /// lowercase trigram -> delta-encoded doc ids, decoded only when queried.
+    /// keeping postings as strings makes loading the index much cheaper than
+    /// parsing every issue file.
+    postings: BTreeMap<String, String>,
+}
+
+/// lowercase trigrams of each line of `text`. lines are split so that a
+/// trigram never spans a line break, matching how body lines are searched.
+fn trigrams(text: &str) -> BTreeSet<String> {
+    let mut grams = BTreeSet::new();
+    for line in text.to_lowercase().lines() {
+        let chars: Vec<char> = line.chars().collect();
+        for window in chars.windows(3) {
+            grams.insert(window.iter().collect());
+        }
+    }
+    grams
+}
+
+fn issue_trigrams(issue: &Issue) -> BTreeSet<String> {
+    let mut grams = trigrams(issue.title());
+    for text in issue
+        .tags()
+        .iter()
+        .chain(&issue.frontmatter.acceptance)
+        .chain(std::iter::once(&issue.body))
+    {
+        grams.extend(trigrams(text));
+    }
+    grams
+}
+
+fn encode_ids(ids: &BTreeSet<usize>) -> String {
+    let mut out = String::new();
+    let mut prev = 0;
+    for (i, &id) in ids.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        out.push_str(&(id - prev).to_string());
+        prev = id;
+    }
+    out
+}
+
+fn decode_ids(encoded: &str) -> BTreeSet<usize> {
+    let mut id = 0;
+    encoded
+        .split(' ')
+        .filter_map(|delta| delta.parse::<usize>().ok())
+        .map(|delta| {
+            id += delta;
+            id
+        })
+        .collect()
+}
+
+/// stamps of all issue files currently in `issues_dir`.
+fn scan_dir(issues_dir: &Path) -> Result<BTreeMap<String, FileStamp>> {
+    let mut files = BTreeMap::new();
+    if !issues_dir.exists() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(issues_dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "md")
+            && let Some(name) = path.file_name().and_then(|n| n.to_str())
+            && let Some(stamp) = FileStamp::of(&path)
+        {
+            files.insert(name.to_string(), stamp);
+        }
+    }
+    Ok(files)
+}
+
+impl SearchIndex {
+    fn empty(issues_dir: &Path) -> Self {
+        Self {
+            version: INDEX_VERSION,
+            issues_dir: issues_dir.to_path_buf(),
+            ..Self::default()
+        }
+    }
+
+    /// load the cached index, falling back to an empty one if it's missing,
+    /// unreadable, from another brd version, or for another issues dir.
+    fn load(path: &Path, issues_dir: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|index| index.version == INDEX_VERSION && index.issues_dir == issues_dir)
+            .unwrap_or_else(|| Self::empty(issues_dir))
+    }
+
+    /// load the index at `path` and bring it up to date with `issues_dir`,
+    /// saving it if anything changed. `rebuild` discards the cached index.
+    pub(super) fn open(path: &Path, issues_dir: &Path, rebuild: bool) -> Result<Self> {
+        let mut index = if rebuild {
+            Self::empty(issues_dir)
+        } else {
+            Self::load(path, issues_dir)
+        };
+        if index.refresh()? || rebuild {
+            let json = serde_json::to_string(&index).map_err(std::io::Error::other)?;
+            crate::atomic::write_atomic(path, json)?;
+        }
+        Ok(index)
+    }
+
+    /// re-index files that were added, changed, or removed. returns true if
+    /// the index changed.
+    fn refresh(&mut self) -> Result<bool> {
+        let current = scan_dir(&self.issues_dir)?;
+        let unchanged = self.files.len() == current.len()
+            && self
+                .files
+                .iter()
+                .all(|(name, stamp)| current.get(name) == Some(stamp));
+        if unchanged {
+            return Ok(false);
+        }
+
+        // keep files whose stamp still matches and index the rest
+        let mut files: BTreeMap<String, FileStamp> = self
+            .files
+            .iter()
+            .filter(|(name, stamp)| current.get(name) == Some(stamp))
+            .cloned()
+            .collect();
+        let mut added = Vec::new();
+        for (name, stamp) in &current {
+            if files.contains_key(name) {
+                continue;
+            }
+            // files that fail to parse stay out of the index; the full
+            // scan reports them
+            let Ok(issue) = Issue::load(&self.issues_dir.join(name)) else {
+                continue;
+            };
+            added.push((name.clone(), issue_trigrams(&issue)));
+            files.insert(name.clone(), *stamp);
+        }
+
+        // renumber: old doc ids map to their position in the new file list
+        let new_ids: HashMap<&str, usize> = files
+            .keys()
+            .enumerate()
+            .map(|(id, name)| (name.as_str(), id))
+            .collect();
+        let remap: Vec<Option<usize>> = self
+            .files
+            .iter()
+            .map(|(name, stamp)| (current.get(name) == Some(stamp)).then(|| new_ids[name.as_str()]))
+            .collect();
+
+        let mut postings: BTreeMap<String, BTreeSet<usize>> = self
+            .postings
+            .iter()
+            .map(|(gram, encoded)| {
+                let ids = decode_ids(encoded)
+                    .into_iter()
+                    .filter_map(|id| remap.get(id).copied().flatten())
+                    .collect();
+                (gram.clone(), ids)
+            })
+            .collect();
+        for (name, grams) in added {
+            let id = new_ids[name.as_str()];
+            for gram in grams {
+                postings.entry(gram).or_default().insert(id);
+            }
+        }
+
+        self.postings = postings
+            .into_iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(gram, ids)| (gram, encode_ids(&ids)))
+            .collect();
+        self.files = files.into_iter().collect();
+        Ok(true)
+    }
+
+    /// number of indexed issue files.
+    pub(super) fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// issue files that could contain `needle` (case-insensitive), or `None`
+    /// if the needle is too short to narrow the search.
+    fn candidates(&self, needle: &str) -> Option<Vec<&str>> {
+        let grams = trigrams(needle);
+        if grams.is_empty() {
+            return None;
+        }
+        let mut result: Option<BTreeSet<usize>> = None;
+        for gram in &grams {
+            let Some(encoded) = self.postings.get(gram) else {
+                return Some(Vec::new());
+            };
+            let ids = decode_ids(encoded);
+            result = Some(match result {
+                None => ids,
+                Some(acc) => acc.intersection(&ids).copied().collect(),
+            });
+        }
+        let names = result
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|id| self.files.get(id))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        Some(names)
+    }
+
+    /// load the issues that could match `needle`. returns `None` when the
+    /// needle is too short to use the index and every issue must be scanned.
+    pub(super) fn load_candidates(&self, needle: &str) -> Option<HashMap<String, Issue>> {
+        let names = self.candidates(needle)?;
+        let issues = names
+            .iter()
+            .filter_map(|name| Issue::load(&self.issues_dir.join(name)).ok())
+            .map(|issue| (issue.id().to_string(), issue))
+            .collect();
+        Some(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigrams_are_lowercase_and_per_line() {
+        let grams = trigrams("AbCd\nef");
+        let grams: Vec<_> = grams.iter().map(String::as_str).collect();
+        assert_eq!(grams, vec!["abc", "bcd"]);
+        assert!(trigrams("ab").is_empty());
+    }
+
+    #[test]
+    fn test_id_encoding_round_trips() {
+        let ids: BTreeSet<usize> = [0, 3, 4, 17, 1000].into_iter().collect();
+        let encoded = encode_ids(&ids);
+        assert_eq!(encoded, "0 3 1 13 983");
+        assert_eq!(decode_ids(&encoded), ids);
+        assert!(decode_ids("").is_empty());
+    }
+
+    #[test]
+    fn test_candidates_intersect_postings() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut index = SearchIndex::empty(dir.path());
+        let stamp = FileStamp {
+            secs: 0,
+            nanos: 0,
+            len: 0,
+        };
+        index.files = vec![("a.md".to_string(), stamp), ("b.md".to_string(), stamp)];
+        index.postings.insert("cac".to_string(), "0 1".to_string());
+        index.postings.insert("ach".to_string(), "0".to_string());
+
+        assert_eq!(index.candidates("cach"), Some(vec!["a.md"]));
+        assert_eq!(index.candidates("CAC"), Some(vec!["a.md", "b.md"]));
+        assert_eq!(index.candidates("xyz"), Some(vec![]));
+        assert_eq!(index.candidates("ca"), None);
+    }
+}
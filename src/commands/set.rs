@@ -28,7 +28,7 @@ pub fn cmd_set(cli: &Cli, paths: &RepoPaths, id: &str, field: &str, value: &str)
                 issue.frontmatter.priority = priority;
             }
             "status" | "s" => {
-                return Err(BrdError::Other(
+                return Err(BrdError::InvalidInput(
                     "cannot set status directly\n\n\
                      use these commands instead:\n  \
                      brd start <id>   - start working (sets status to doing)\n  \
@@ -75,20 +75,19 @@ pub fn cmd_set(cli: &Cli, paths: &RepoPaths, id: &str, field: &str, value: &str)
                 }
             }
             _ => {
-                return Err(BrdError::Other(format!(
+                return Err(BrdError::InvalidInput(format!(
                     "unknown field '{}'. supported: priority, type, owner, title, tag, scheduled-for",
                     field
                 )));
             }
         }
 
-        let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
-        issue.save(&issue_path)?;
+        issue.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
     }
 
     if cli.json {
         let issue = issues.get(&full_id).unwrap();
-        let json = issue_to_json(issue, &issues);
+        let json = issue_to_json(issue, &issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         // format value appropriately for each field type
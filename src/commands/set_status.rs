@@ -0,0 +1,171 @@
+//! brd set-status command - apply one status to several issues at once.
+
+use crate::api;
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::Result;
+use crate::issue::Status;
+use crate::repo::{self, RepoPaths};
+
+use super::done::warn_unchecked;
+use super::start::auto_push;
+use super::{issue_to_json, load_all_issues};
+
+/// parse a status name, accepting `todo` as an alias for `open`.
+fn parse_status(s: &str) -> Result<Status> {
+    if s.eq_ignore_ascii_case("todo") {
+        return Ok(Status::Open);
+    }
+    s.parse()
+}
+
+/// the verb of the single-issue command that makes the same change, used
+/// for the commit message.
+fn action(status: Status) -> &'static str {
+    match status {
+        Status::Open => "reopen",
+        Status::Doing => "start",
+        Status::Done => "done",
+        Status::Skip => "skip",
+    }
+}
+
+pub fn cmd_set_status(
+    cli: &Cli,
+    paths: &RepoPaths,
+    status: &str,
+    ids: &[String],
+    force: bool,
+    no_push: bool,
+) -> Result<()> {
+    let status = parse_status(status)?;
+    let config = Config::load(&paths.config_path())?;
+    let agent_id = repo::get_agent_id(&paths.worktree_root);
+    let updated = api::set_status(paths, &config, ids, status, &agent_id, force)?;
+
+    if status == Status::Done && !force && !cli.json {
+        for issue in &updated {
+            warn_unchecked(issue);
+        }
+    }
+    let updated_ids: Vec<_> = updated.iter().map(|issue| issue.id()).collect();
+    auto_push(
+        paths,
+        &config,
+        &updated_ids.join(" "),
+        action(status),
+        cli,
+        no_push,
+    )?;
+
+    if cli.json {
+        let issues = load_all_issues(paths, &config)?;
+        let json: Vec<_> = updated
+            .iter()
+            .map(|issue| issue_to_json(issue, &issues, &config.ready_includes))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        for issue in &updated {
+            println!("{} -> {}", issue.id(), status);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BrdError;
+    use crate::issue::IssueType;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    #[test]
+    fn test_set_status_updates_all_issues() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        repo.issue("brd-bbbb").create();
+
+        let ids = vec!["aaaa".to_string(), "bbbb".to_string()];
+        cmd_set_status(&test_cli(), &repo.paths, "doing", &ids, false, true).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        for id in ["brd-aaaa", "brd-bbbb"] {
+            let issue = &issues[id];
+            assert_eq!(issue.status(), Status::Doing);
+            assert!(issue.frontmatter.owner.is_some());
+            assert!(issue.frontmatter.started_at.is_some());
+        }
+
+        cmd_set_status(&test_cli(), &repo.paths, "todo", &ids, false, true).unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        for id in ["brd-aaaa", "brd-bbbb"] {
+            let issue = &issues[id];
+            assert_eq!(issue.status(), Status::Open);
+            assert!(issue.frontmatter.owner.is_none());
+            assert!(issue.frontmatter.started_at.is_none());
+        }
+    }
+
+    #[test]
+    fn test_set_status_done_clears_owner() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa")
+            .status(Status::Doing)
+            .owner("someone")
+            .create();
+
+        let ids = vec!["brd-aaaa".to_string()];
+        cmd_set_status(&test_cli(), &repo.paths, "DONE", &ids, false, true).unwrap();
+
+        let issue = &load_all_issues(&repo.paths, &repo.config).unwrap()["brd-aaaa"];
+        assert_eq!(issue.status(), Status::Done);
+        assert!(issue.frontmatter.owner.is_none());
+        assert!(issue.frontmatter.completed_at.is_some());
+    }
+
+    #[test]
+    fn test_set_status_rejects_bad_input_without_changes() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        let err = cmd_set_status(
+            &test_cli(),
+            &repo.paths,
+            "finished",
+            &["aaaa".into()],
+            false,
+            true,
+        )
+        .unwrap_err();
+        assert!(matches!(err, BrdError::ParseError(_, _)));
+
+        let ids = vec!["aaaa".to_string(), "zzzz".to_string()];
+        let err = cmd_set_status(&test_cli(), &repo.paths, "done", &ids, false, true).unwrap_err();
+        assert!(matches!(err, BrdError::IssueNotFound(_)));
+
+        let issue = &load_all_issues(&repo.paths, &repo.config).unwrap()["brd-aaaa"];
+        assert_eq!(issue.status(), Status::Open);
+    }
+
+    #[test]
+    fn test_set_status_done_checks_design_issues() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+        repo.issue("brd-bbbb")
+            .issue_type(IssueType::Design)
+            .create();
+
+        let ids = vec!["aaaa".to_string(), "bbbb".to_string()];
+        let err = cmd_set_status(&test_cli(), &repo.paths, "done", &ids, false, true).unwrap_err();
+        assert!(matches!(err, BrdError::InvalidInput(_)));
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].status(), Status::Open);
+
+        cmd_set_status(&test_cli(), &repo.paths, "done", &ids, true, true).unwrap();
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-aaaa"].status(), Status::Done);
+        assert_eq!(issues["brd-bbbb"].status(), Status::Done);
+    }
+}
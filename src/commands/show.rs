@@ -2,17 +2,25 @@
 
 use std::collections::HashMap;
 use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::Path;
 
-use crossterm::style::{Attribute, SetAttribute};
+use crossterm::style::{Attribute, ResetColor, SetAttribute, SetForegroundColor};
 
 use crate::cli::Cli;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
-use crate::graph::get_dependents;
-use crate::issue::{Issue, Status};
+use crate::git::{self, CommitDetails};
+use crate::graph::{get_dependents, get_related, get_soft_dependents, get_transitive_dependents};
+use crate::is_interactive;
+use crate::issue::{Issue, Status, resolve_issue_id};
+use crate::output::Output;
 use crate::repo::RepoPaths;
+use crate::theme::{Theme, to_crossterm};
+use crate::tui;
 
-use super::{issue_to_json, load_all_issues, resolve_issue_id};
+use super::pick::pick_issue;
+use super::{issue_to_json, load_all_issues};
 
 /// status symbol matching TUI conventions.
 fn status_symbol(status: &Status) -> &'static str {
@@ -56,6 +64,7 @@ fn sort_deps_open_first(ids: &[String], issues: &HashMap<String, Issue>) -> Vec<
 fn format_dep_lines(
     ids: &[String],
     issues: &HashMap<String, Issue>,
+    theme: &Theme,
     no_color: bool,
 ) -> Vec<String> {
     let sorted = sort_deps_open_first(ids, issues);
@@ -77,8 +86,15 @@ fn format_dep_lines(
                 } else {
                     line
                 }
+            } else if no_color {
+                format!("  ! {} MISSING", dep_id)
             } else {
-                format!("  ? {} (missing)", dep_id)
+                format!(
+                    "{}  ! {} MISSING{}",
+                    SetForegroundColor(to_crossterm(theme.error)),
+                    dep_id,
+                    ResetColor
+                )
             }
         })
         .collect()
@@ -87,15 +103,19 @@ fn format_dep_lines(
 fn format_show_output(
     issue: &Issue,
     issues: &HashMap<String, Issue>,
+    config: &Config,
     json: bool,
     no_color: bool,
 ) -> String {
     if json {
-        let mut output = serde_json::to_string_pretty(&issue_to_json(issue, issues)).unwrap();
+        let mut output =
+            serde_json::to_string_pretty(&issue_to_json(issue, issues, &config.ready_includes))
+                .unwrap();
         output.push('\n');
         return output;
     }
 
+    let theme = Theme::from_config(&config.theme);
     let mut output = String::new();
 
     let _ = writeln!(output, "ID:       {}", issue.id());
@@ -109,15 +129,57 @@ fn format_show_output(
 
     if !issue.deps().is_empty() {
         let _ = writeln!(output, "Blocked by:");
-        for line in format_dep_lines(issue.deps(), issues, no_color) {
+        for line in format_dep_lines(issue.deps(), issues, &theme, no_color) {
             let _ = writeln!(output, "{}", line);
         }
+
+        // dangling references (e.g. after `brd rm` without --cascade) block
+        // nothing but are easy to miss in the list above
+        let missing: Vec<&str> = issue
+            .deps()
+            .iter()
+            .filter(|id| !issues.contains_key(*id))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            let warning = format!(
+                "warning: {} missing {}: {} (remove with `brd dep rm {} <id>`)",
+                missing.len(),
+                if missing.len() == 1 {
+                    "dependency"
+                } else {
+                    "dependencies"
+                },
+                missing.join(", "),
+                issue.id()
+            );
+            if no_color {
+                let _ = writeln!(output, "{}", warning);
+            } else {
+                let _ = writeln!(
+                    output,
+                    "{}{}{}",
+                    SetForegroundColor(to_crossterm(theme.error)),
+                    warning,
+                    ResetColor
+                );
+            }
+        }
     }
 
     let dependents = get_dependents(issue.id(), issues);
     if !dependents.is_empty() {
         let _ = writeln!(output, "Blocks:");
-        for line in format_dep_lines(&dependents, issues, no_color) {
+        for line in format_dep_lines(&dependents, issues, &theme, no_color) {
+            let _ = writeln!(output, "{}", line);
+        }
+    }
+
+    // soft links never block, so they get their own section
+    let related = get_related(issue.id(), issues);
+    if !related.is_empty() {
+        let _ = writeln!(output, "Related:");
+        for line in format_dep_lines(&related, issues, &theme, no_color) {
             let _ = writeln!(output, "{}", line);
         }
     }
@@ -138,6 +200,22 @@ fn format_show_output(
         }
     }
 
+    if !issue.frontmatter.commits.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(output, "Commits:");
+        for sha in &issue.frontmatter.commits {
+            let _ = writeln!(output, "  {}", &sha[..sha.len().min(12)]);
+        }
+    }
+
+    if !issue.frontmatter.attachments.is_empty() {
+        let _ = writeln!(output);
+        let _ = writeln!(output, "Attachments:");
+        for path in &issue.frontmatter.attachments {
+            let _ = writeln!(output, "  {}", path);
+        }
+    }
+
     if !issue.body.is_empty() {
         let _ = writeln!(output);
         let _ = writeln!(output, "{}", issue.body);
@@ -146,27 +224,325 @@ fn format_show_output(
     output
 }
 
-pub fn cmd_show(cli: &Cli, paths: &RepoPaths, id: &str, context: bool) -> Result<()> {
+/// what `brd show` prints about the issue.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShowView {
+    /// the issue itself
+    #[default]
+    Details,
+    /// the issue plus the full content of its deps and dependents (`--context`)
+    Context,
+    /// everything downstream of the issue (`--dependents`)
+    Dependents,
+    /// commits linked with `brd link` (`--log`)
+    Log,
+}
+
+/// separates issues when `brd show` is given several ids.
+const ISSUE_RULE: &str = "────────────────────────────────────────";
+
+pub fn cmd_show(
+    cli: &Cli,
+    paths: &RepoPaths,
+    ids: &[String],
+    view: ShowView,
+    pager: bool,
+    output: Option<&Path>,
+) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
     let issues = load_all_issues(paths, &config)?;
-    let full_id = resolve_issue_id(id, &issues)?;
-    let issue = issues
-        .get(&full_id)
-        .ok_or_else(|| BrdError::IssueNotFound(id.to_string()))?;
-
-    if context && !cli.json {
-        let output = format_context_output(issue, &issues, cli.no_color);
-        print!("{output}");
+
+    if pager {
+        if cli.json || !is_interactive() {
+            return Err(BrdError::NotInteractive(
+                "brd show --pager requires an interactive terminal".to_string(),
+            ));
+        }
+        if ids.len() > 1 {
+            return Err(BrdError::InvalidInput(
+                "brd show --pager takes at most one issue ID".to_string(),
+            ));
+        }
+        // without an id, start at the top of the listing
+        let start_id = match ids.first() {
+            Some(partial) => resolve_issue_id(partial, &issues)?,
+            None => String::new(),
+        };
+        return tui::run_pager(paths, &start_id);
+    }
+
+    if ids.len() <= 1 {
+        let full_id = match ids.first() {
+            Some(partial) => resolve_issue_id(partial, &issues)?,
+            None => pick_issue(cli, "show", issues.values().collect())?,
+        };
+        let mut out = Output::new(output)?;
+        let no_color = !out.use_color(cli);
+        let text = render_issue(
+            cli,
+            paths,
+            &config,
+            &issues[&full_id],
+            &issues,
+            view,
+            no_color,
+        )?;
+        out.write_all(text.as_bytes())?;
+        out.flush()?;
+        return Ok(());
+    }
+
+    // show every id that resolves, then fail with the ones that didn't
+    let mut full_ids = Vec::new();
+    let mut failed = Vec::new();
+    for partial in ids {
+        match resolve_issue_id(partial, &issues) {
+            Ok(full_id) => full_ids.push(full_id),
+            Err(e) => failed.push((partial, e)),
+        }
+    }
+
+    let mut out = Output::new(output)?;
+    let no_color = !out.use_color(cli);
+    if cli.json {
+        // the formatters render json text; collect it back into one array
+        let items = full_ids
+            .iter()
+            .map(|id| {
+                let text = render_issue(cli, paths, &config, &issues[id], &issues, view, no_color)?;
+                Ok(serde_json::from_str(&text)?)
+            })
+            .collect::<Result<Vec<serde_json::Value>>>()?;
+        // one document, so unresolved ids are reported here rather than by main
+        let errors: Vec<_> = failed
+            .iter()
+            .map(|(id, e)| {
+                serde_json::json!({
+                    "id": id,
+                    "code": e.code_str(),
+                    "message": e.to_string(),
+                })
+            })
+            .collect();
+        let json = serde_json::json!({ "issues": items, "errors": errors });
+        writeln!(out, "{}", serde_json::to_string_pretty(&json).unwrap())?;
+        out.flush()?;
+        return match failed.into_iter().next() {
+            Some((_, e)) => Err(BrdError::Reported(Box::new(e))),
+            None => Ok(()),
+        };
     } else {
-        let output = format_show_output(issue, &issues, cli.json, cli.no_color);
-        print!("{output}");
+        for (i, id) in full_ids.iter().enumerate() {
+            if i > 0 {
+                writeln!(out, "\n{}\n", ISSUE_RULE)?;
+            }
+            out.write_all(
+                render_issue(cli, paths, &config, &issues[id], &issues, view, no_color)?.as_bytes(),
+            )?;
+        }
     }
+    out.flush()?;
 
-    Ok(())
+    if failed.is_empty() {
+        return Ok(());
+    }
+    // main reports the returned error; report the rest here
+    for (_, e) in &failed[1..] {
+        eprintln!("error: {}", e);
+    }
+    Err(failed.remove(0).1)
+}
+
+/// render one issue in the requested view.
+fn render_issue(
+    cli: &Cli,
+    paths: &RepoPaths,
+    config: &Config,
+    issue: &Issue,
+    issues: &HashMap<String, Issue>,
+    view: ShowView,
+    no_color: bool,
+) -> Result<String> {
+    Ok(match view {
+        ShowView::Log => {
+            let commits = issue
+                .frontmatter
+                .commits
+                .iter()
+                .map(|sha| {
+                    let details = git::commit_details(&paths.worktree_root, sha)?;
+                    Ok((sha.clone(), details))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let theme = Theme::from_config(&config.theme);
+            format_log_output(issue, &commits, &theme, cli.json, no_color)
+        }
+        ShowView::Dependents => format_dependents_output(issue, issues, cli.json, no_color),
+        ShowView::Context if !cli.json => format_context_output(issue, issues, config, no_color),
+        ShowView::Context | ShowView::Details => {
+            format_show_output(issue, issues, config, cli.json, no_color)
+        }
+    })
+}
+
+/// format an issue's linked commits, one line each. commits that can't be
+/// found (e.g. rewritten by a rebase) are marked missing.
+fn format_log_output(
+    issue: &Issue,
+    commits: &[(String, Option<CommitDetails>)],
+    theme: &Theme,
+    json: bool,
+    no_color: bool,
+) -> String {
+    if json {
+        let items: Vec<_> = commits
+            .iter()
+            .map(|(sha, details)| match details {
+                Some(details) => serde_json::json!({
+                    "sha": details.sha,
+                    "short_sha": details.short_sha,
+                    "date": details.date,
+                    "author": details.author,
+                    "subject": details.subject,
+                    "missing": false,
+                }),
+                None => serde_json::json!({ "sha": sha, "missing": true }),
+            })
+            .collect();
+        let json = serde_json::json!({ "id": issue.id(), "commits": items });
+        let mut output = serde_json::to_string_pretty(&json).unwrap();
+        output.push('\n');
+        return output;
+    }
+
+    let mut output = String::new();
+    if commits.is_empty() {
+        let _ = writeln!(
+            output,
+            "no commits linked to {} (link one with `brd link {}`)",
+            issue.id(),
+            issue.id()
+        );
+        return output;
+    }
+
+    let _ = writeln!(
+        output,
+        "{} commit{} linked to {}:",
+        commits.len(),
+        if commits.len() == 1 { "" } else { "s" },
+        issue.id()
+    );
+    for (sha, details) in commits {
+        match details {
+            Some(details) => {
+                let _ = writeln!(
+                    output,
+                    "  {} {} {}  {}",
+                    details.short_sha, details.date, details.author, details.subject
+                );
+            }
+            None => {
+                let short: String = sha.chars().take(7).collect();
+                let line = format!("  {} MISSING (not in this repo)", short);
+                if no_color {
+                    let _ = writeln!(output, "{}", line);
+                } else {
+                    let _ = writeln!(
+                        output,
+                        "{}{}{}",
+                        SetForegroundColor(to_crossterm(theme.error)),
+                        line,
+                        ResetColor
+                    );
+                }
+            }
+        }
+    }
+    output
+}
+
+/// format everything downstream of an issue: what it blocks, directly or
+/// through other issues, indented by depth.
+fn format_dependents_output(
+    issue: &Issue,
+    issues: &HashMap<String, Issue>,
+    json: bool,
+    no_color: bool,
+) -> String {
+    // hard dependents as a tree, then issues soft-linked to this one (direct
+    // only, since soft links don't block and so don't chain)
+    let mut dependents: Vec<(String, usize, bool)> = get_transitive_dependents(issue.id(), issues)
+        .into_iter()
+        .map(|(id, depth)| (id, depth, false))
+        .collect();
+    for id in get_soft_dependents(issue.id(), issues) {
+        if !dependents.iter().any(|(hard, _, _)| *hard == id) {
+            dependents.push((id, 1, true));
+        }
+    }
+
+    if json {
+        let items: Vec<_> = dependents
+            .iter()
+            .map(|(id, depth, soft)| {
+                let dep = &issues[id];
+                serde_json::json!({
+                    "id": id,
+                    "title": dep.title(),
+                    "status": dep.status().to_string(),
+                    "depth": depth,
+                    "soft": soft,
+                })
+            })
+            .collect();
+        let json = serde_json::json!({ "id": issue.id(), "dependents": items });
+        let mut output = serde_json::to_string_pretty(&json).unwrap();
+        output.push('\n');
+        return output;
+    }
+
+    let mut output = String::new();
+    if dependents.is_empty() {
+        let _ = writeln!(output, "nothing depends on {}", issue.id());
+        return output;
+    }
+
+    let _ = writeln!(output, "{} dependents of {}:", dependents.len(), issue.id());
+    for (id, depth, soft) in &dependents {
+        let dep = &issues[id];
+        let status = dep.status();
+        let line = format!(
+            "{}{} {} ({}){}  {}",
+            "  ".repeat(*depth),
+            status_symbol(&status),
+            id,
+            status,
+            if *soft { " [soft]" } else { "" },
+            truncate_title(dep.title(), 60)
+        );
+        if !no_color && (is_resolved(&status) || *soft) {
+            let _ = writeln!(
+                output,
+                "{}{}{}",
+                SetAttribute(Attribute::Dim),
+                line,
+                SetAttribute(Attribute::Reset)
+            );
+        } else {
+            let _ = writeln!(output, "{}", line);
+        }
+    }
+    output
 }
 
 /// format output with full context: the issue plus all deps and dependents content.
-fn format_context_output(issue: &Issue, issues: &HashMap<String, Issue>, no_color: bool) -> String {
+fn format_context_output(
+    issue: &Issue,
+    issues: &HashMap<String, Issue>,
+    config: &Config,
+    no_color: bool,
+) -> String {
     let mut output = String::new();
 
     // main issue
@@ -175,7 +551,7 @@ fn format_context_output(issue: &Issue, issues: &HashMap<String, Issue>, no_colo
     let _ = write!(
         output,
         "{}",
-        format_show_output(issue, issues, false, no_color)
+        format_show_output(issue, issues, config, false, no_color)
     );
 
     // dependencies
@@ -195,7 +571,7 @@ fn format_context_output(issue: &Issue, issues: &HashMap<String, Issue>, no_colo
                 }
             } else {
                 let _ = writeln!(output);
-                let _ = writeln!(output, "--- {} (missing) ---", dep_id);
+                let _ = writeln!(output, "--- {} MISSING ---", dep_id);
             }
         }
     }
@@ -225,7 +601,10 @@ fn format_context_output(issue: &Issue, issues: &HashMap<String, Issue>, no_colo
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ThemeConfig;
+    use crate::error::ExitCode;
     use crate::issue::{IssueType, Priority, Status};
+    use crossterm::style::Color;
     use std::fs;
     use tempfile::tempdir;
 
@@ -254,7 +633,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -270,6 +649,8 @@ mod tests {
         issue.frontmatter.owner = Some("agent-one".to_string());
         issue.frontmatter.acceptance = vec!["do a thing".to_string(), "do another".to_string()];
         issue.frontmatter.issue_type = Some(IssueType::Meta);
+        issue.frontmatter.commits = vec!["0123456789abcdef0123456789abcdef01234567".to_string()];
+        issue.frontmatter.attachments = vec!["docs/design.md".to_string()];
         issue.body = "more details".to_string();
 
         let mut dep_issue = Issue::new(
@@ -284,7 +665,7 @@ mod tests {
         issues.insert(issue.id().to_string(), issue.clone());
         issues.insert(dep_issue.id().to_string(), dep_issue);
 
-        let output = format_show_output(&issue, &issues, false, true);
+        let output = format_show_output(&issue, &issues, &Config::default(), false, true);
 
         assert!(output.contains("ID:       brd-1234"));
         assert!(output.contains("Title:    test issue"));
@@ -293,11 +674,16 @@ mod tests {
         assert!(output.contains("Type:     meta"));
         assert!(output.contains("Blocked by:"));
         assert!(output.contains("  ○ brd-aaaa (open)  dep issue"));
-        assert!(output.contains("  ? brd-missing (missing)"));
+        assert!(output.contains("  ! brd-missing MISSING"));
+        assert!(output.contains(
+            "warning: 1 missing dependency: brd-missing (remove with `brd dep rm brd-1234 <id>`)"
+        ));
         assert!(output.contains("Tags:     visual, urgent"));
         assert!(output.contains("Owner:    agent-one"));
         assert!(output.contains("Acceptance:"));
+        assert!(output.contains("Attachments:\n  docs/design.md"));
         assert!(output.contains("  - do a thing"));
+        assert!(output.contains("Commits:\n  0123456789ab\n"));
         assert!(output.contains("more details"));
     }
 
@@ -323,7 +709,7 @@ mod tests {
         issues.insert(issue.id().to_string(), issue.clone());
         issues.insert(dep_issue.id().to_string(), dep_issue);
 
-        let output = format_show_output(&issue, &issues, true, true);
+        let output = format_show_output(&issue, &issues, &Config::default(), true, true);
         let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
 
         assert_eq!(json["id"], "brd-1234");
@@ -333,6 +719,30 @@ mod tests {
         assert_eq!(json["derived"]["is_ready"], true);
     }
 
+    #[test]
+    fn test_format_show_output_json_is_ready_follows_ready_includes() {
+        let mut issue = Issue::new(
+            "brd-1234".to_string(),
+            "in progress".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        issue.frontmatter.status = Status::Doing;
+        let issues = HashMap::from([(issue.id().to_string(), issue.clone())]);
+
+        let is_ready = |config: &Config| {
+            let output = format_show_output(&issue, &issues, config, true, true);
+            let json: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+            json["derived"]["is_ready"].clone()
+        };
+        assert_eq!(is_ready(&Config::default()), false);
+        let config = Config {
+            ready_includes: vec![Status::Open, Status::Doing],
+            ..Config::default()
+        };
+        assert_eq!(is_ready(&config), true);
+    }
+
     #[test]
     fn test_cmd_show_ambiguous_id() {
         let (_dir, paths, config) = create_test_repo();
@@ -352,19 +762,140 @@ mod tests {
         write_issue(&paths, &config, &issue_b);
 
         let cli = make_cli(false);
-        let err = cmd_show(&cli, &paths, "aaa", false).unwrap_err();
+        let err = cmd_show(
+            &cli,
+            &paths,
+            &["aaa".into()],
+            ShowView::Details,
+            false,
+            None,
+        )
+        .unwrap_err();
         assert!(matches!(err, BrdError::AmbiguousId(_, _)));
     }
 
+    #[test]
+    fn test_cmd_show_multiple_ids() {
+        let (dir, paths, config) = create_test_repo();
+        for id in ["brd-aaaa", "brd-bbbb"] {
+            let issue = Issue::new(id.to_string(), format!("issue {id}"), Priority::P2, vec![]);
+            write_issue(&paths, &config, &issue);
+        }
+        let out_path = dir.path().join("show.json");
+
+        let ids = vec!["aaaa".to_string(), "bbbb".to_string()];
+        cmd_show(
+            &make_cli(true),
+            &paths,
+            &ids,
+            ShowView::Details,
+            false,
+            Some(&out_path),
+        )
+        .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+        assert_eq!(json["issues"][0]["id"], "brd-aaaa");
+        assert_eq!(json["issues"][1]["id"], "brd-bbbb");
+        assert_eq!(json["errors"], serde_json::json!([]));
+
+        // with --json, bad ids land in the same document
+        let ids = vec!["aaaa".to_string(), "zzzz".to_string()];
+        let err = cmd_show(
+            &make_cli(true),
+            &paths,
+            &ids,
+            ShowView::Details,
+            false,
+            Some(&out_path),
+        )
+        .unwrap_err();
+        assert!(matches!(err, BrdError::Reported(_)));
+        assert_eq!(err.exit_code(), ExitCode::IssueNotFound);
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&out_path).unwrap()).unwrap();
+        assert_eq!(json["issues"][0]["id"], "brd-aaaa");
+        assert_eq!(json["errors"][0]["id"], "zzzz");
+        assert_eq!(json["errors"][0]["code"], "issue_not_found");
+
+        // a bad id still shows the rest, then fails
+        let out_path = dir.path().join("show.txt");
+        let ids = vec!["aaaa".to_string(), "zzzz".to_string(), "bbbb".to_string()];
+        let err = cmd_show(
+            &make_cli(false),
+            &paths,
+            &ids,
+            ShowView::Details,
+            false,
+            Some(&out_path),
+        )
+        .unwrap_err();
+        assert!(matches!(err, BrdError::IssueNotFound(_)));
+        let output = fs::read_to_string(&out_path).unwrap();
+        assert!(output.contains("issue brd-aaaa"));
+        assert!(output.contains(ISSUE_RULE));
+        assert!(output.contains("issue brd-bbbb"));
+    }
+
     #[test]
     fn test_cmd_show_issue_not_found() {
         let (_dir, paths, _config) = create_test_repo();
 
         let cli = make_cli(false);
-        let err = cmd_show(&cli, &paths, "brd-missing", false).unwrap_err();
+        let err = cmd_show(
+            &cli,
+            &paths,
+            &["brd-missing".into()],
+            ShowView::Details,
+            false,
+            None,
+        )
+        .unwrap_err();
         assert!(matches!(err, BrdError::IssueNotFound(_)));
     }
 
+    #[test]
+    fn test_format_log_output_marks_missing_commits() {
+        let issue = Issue::new(
+            "brd-1234".to_string(),
+            "test issue".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        let found = CommitDetails {
+            sha: "0123456789abcdef".to_string(),
+            short_sha: "0123456".to_string(),
+            date: "2026-10-01".to_string(),
+            author: "tester".to_string(),
+            subject: "fix parser".to_string(),
+        };
+        let commits = vec![
+            (found.sha.clone(), Some(found)),
+            ("fedcba9876543210".to_string(), None),
+        ];
+
+        let output = format_log_output(&issue, &commits, &Theme::default(), false, true);
+        assert!(output.starts_with("2 commits linked to brd-1234:\n"));
+        assert!(output.contains("  0123456 2026-10-01 tester  fix parser\n"));
+        assert!(output.contains("  fedcba9 MISSING (not in this repo)\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&format_log_output(
+            &issue,
+            &commits,
+            &Theme::default(),
+            true,
+            true,
+        ))
+        .unwrap();
+        assert_eq!(json["commits"][0]["subject"], "fix parser");
+        assert_eq!(json["commits"][0]["missing"], false);
+        assert_eq!(json["commits"][1]["sha"], "fedcba9876543210");
+        assert_eq!(json["commits"][1]["missing"], true);
+
+        let empty = format_log_output(&issue, &[], &Theme::default(), false, true);
+        assert!(empty.starts_with("no commits linked to brd-1234"));
+    }
+
     #[test]
     fn test_format_show_output_dependents_show_status() {
         // parent issue that other issues depend on
@@ -397,7 +928,7 @@ mod tests {
         issues.insert(dependent_open.id().to_string(), dependent_open);
         issues.insert(dependent_done.id().to_string(), dependent_done);
 
-        let output = format_show_output(&parent, &issues, false, true);
+        let output = format_show_output(&parent, &issues, &Config::default(), false, true);
 
         // dependents should show status symbol, id, status, and title — sorted open first
         assert!(output.contains("Blocks:"));
@@ -408,4 +939,157 @@ mod tests {
         let done_pos = output.find("brd-child2").unwrap();
         assert!(open_pos < done_pos);
     }
+
+    #[test]
+    fn test_format_show_output_missing_deps_in_error_color() {
+        let issue = Issue::new(
+            "brd-1234".to_string(),
+            "test issue".to_string(),
+            Priority::P1,
+            vec!["brd-gone".to_string(), "brd-lost".to_string()],
+        );
+        let mut issues = HashMap::new();
+        issues.insert(issue.id().to_string(), issue.clone());
+
+        let config = Config {
+            theme: ThemeConfig {
+                error: Some("magenta".to_string()),
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+        let output = format_show_output(&issue, &issues, &config, false, false);
+        let error = SetForegroundColor(Color::DarkMagenta).to_string();
+        assert!(output.contains(&format!("{}  ! brd-gone MISSING", error)));
+        assert!(output.contains(&format!(
+            "{}warning: 2 missing dependencies: brd-gone, brd-lost",
+            error
+        )));
+
+        // no warning without dangling deps
+        let plain = Issue::new("brd-5678".into(), "plain".into(), Priority::P2, vec![]);
+        let output = format_show_output(&plain, &issues, &Config::default(), false, true);
+        assert!(!output.contains("warning"));
+    }
+
+    #[test]
+    fn test_format_dependents_output_lists_transitive_dependents() {
+        let root = Issue::new("brd-root".into(), "root".into(), Priority::P1, vec![]);
+        let child = Issue::new(
+            "brd-child".into(),
+            "child".into(),
+            Priority::P2,
+            vec!["brd-root".into()],
+        );
+        let mut grandchild = Issue::new(
+            "brd-grand".into(),
+            "grandchild".into(),
+            Priority::P2,
+            vec!["brd-child".into()],
+        );
+        grandchild.frontmatter.status = Status::Done;
+        let issues: HashMap<_, _> = [root.clone(), child, grandchild]
+            .into_iter()
+            .map(|i| (i.id().to_string(), i))
+            .collect();
+
+        let output = format_dependents_output(&root, &issues, false, true);
+        assert_eq!(
+            output,
+            "2 dependents of brd-root:\n  ○ brd-child (open)  child\n    ✓ brd-grand (done)  grandchild\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_dependents_output(&root, &issues, true, true)).unwrap();
+        assert_eq!(json["dependents"][1]["id"], "brd-grand");
+        assert_eq!(json["dependents"][1]["depth"], 2);
+
+        let output = format_dependents_output(&issues["brd-grand"], &issues, false, true);
+        assert_eq!(output, "nothing depends on brd-grand\n");
+    }
+
+    #[test]
+    fn test_format_show_output_related_section() {
+        let mut issue = Issue::new(
+            "brd-aaaa".to_string(),
+            "main issue".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        issue.frontmatter.deps.related = vec!["brd-bbbb".to_string()];
+        // links point one way, but show on both ends
+        let mut other = Issue::new(
+            "brd-cccc".to_string(),
+            "links back".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        other.frontmatter.deps.related = vec!["brd-aaaa".to_string()];
+        let target = Issue::new(
+            "brd-bbbb".to_string(),
+            "related work".to_string(),
+            Priority::P2,
+            vec![],
+        );
+
+        let mut issues = HashMap::new();
+        for i in [&issue, &other, &target] {
+            issues.insert(i.id().to_string(), i.clone());
+        }
+
+        let output = format_show_output(&issue, &issues, &Config::default(), false, true);
+        assert!(output.contains("Related:\n  ○ brd-bbbb (open)  related work"));
+        assert!(output.contains("  ○ brd-cccc (open)  links back"));
+        assert!(!output.contains("Blocked by:"));
+
+        let json: serde_json::Value = serde_json::from_str(&format_show_output(
+            &issue,
+            &issues,
+            &Config::default(),
+            true,
+            true,
+        ))
+        .unwrap();
+        assert_eq!(json["related"], serde_json::json!(["brd-bbbb", "brd-cccc"]));
+        assert_eq!(json["deps"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_format_dependents_output_marks_soft_links() {
+        let root = Issue::new(
+            "brd-root".to_string(),
+            "root".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        let hard = Issue::new(
+            "brd-hard".to_string(),
+            "hard child".to_string(),
+            Priority::P2,
+            vec!["brd-root".to_string()],
+        );
+        let mut soft = Issue::new(
+            "brd-soft".to_string(),
+            "soft child".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        soft.frontmatter.deps.related = vec!["brd-root".to_string()];
+
+        let mut issues = HashMap::new();
+        for i in [&root, &hard, &soft] {
+            issues.insert(i.id().to_string(), i.clone());
+        }
+
+        let output = format_dependents_output(&root, &issues, false, true);
+        assert!(output.starts_with("2 dependents of brd-root:"));
+        assert!(output.contains("  ○ brd-hard (open)  hard child"));
+        assert!(output.contains("  ○ brd-soft (open) [soft]  soft child"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_dependents_output(&root, &issues, true, true)).unwrap();
+        assert_eq!(json["dependents"][0]["soft"], false);
+        assert_eq!(json["dependents"][1]["id"], "brd-soft");
+        assert_eq!(json["dependents"][1]["soft"], true);
+    }
 }
@@ -25,13 +25,12 @@ pub fn cmd_skip(cli: &Cli, paths: &RepoPaths, id: &str) -> Result<()> {
         issue.frontmatter.owner = None;
         issue.mark_completed();
 
-        let issue_path = paths.issues_dir(&config).join(format!("{}.md", full_id));
-        issue.save(&issue_path)?;
+        issue.save_in(&paths.issues_dir(&config), config.filename_scheme)?;
     }
 
     if cli.json {
         let issue = issues.get(&full_id).unwrap();
-        let json = issue_to_json(issue, &issues);
+        let json = issue_to_json(issue, &issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         println!("Skipped: {}", full_id);
@@ -1,14 +1,17 @@
 //! brd start command with auto-sync.
 
+use std::path::Path;
+
 use crate::cli::Cli;
-use crate::config::Config;
+use crate::config::{CommitMode, Config};
 use crate::error::{BrdError, Result};
 use crate::git;
-use crate::graph::get_ready_issues;
-use crate::issue::{Issue, IssueType, Status};
+use crate::graph::{get_ready_issues, rank_ready};
+use crate::issue::{Issue, Status};
 use crate::lock::LockGuard;
 use crate::repo::{self, RepoPaths};
 
+use super::ready::start_candidates;
 use super::{issue_to_json, load_all_issues, resolve_issue_id};
 
 /// Claim an issue by setting status to Doing and owner.
@@ -22,21 +25,17 @@ pub fn claim_issue(
 ) -> Result<std::path::PathBuf> {
     if issue.status() == Status::Doing && !force {
         let owner = issue.frontmatter.owner.as_deref().unwrap_or("unknown");
-        return Err(BrdError::Other(format!(
-            "issue {} is already being worked on by '{}' (use --force to reassign)",
-            issue.id(),
-            owner
-        )));
+        return Err(BrdError::ClaimConflict(
+            issue.id().to_string(),
+            owner.to_string(),
+        ));
     }
 
     issue.frontmatter.status = Status::Doing;
     issue.frontmatter.owner = Some(agent_id.to_string());
     issue.mark_started();
 
-    let issue_path = paths.issues_dir(config).join(format!("{}.md", issue.id()));
-    issue.save(&issue_path)?;
-
-    Ok(issue_path)
+    issue.save_in(&paths.issues_dir(config), config.filename_scheme)
 }
 
 /// Check for done issues that haven't been pushed to main yet.
@@ -59,7 +58,7 @@ fn check_unshipped_done_issues(
     // Check which ones are different from origin/main
     let mut unshipped = Vec::new();
     for issue in local_done {
-        let issue_file = format!(".braid/issues/{}.md", issue.id());
+        let issue_file = format!(".braid/issues/{}", issue.file_name(config.filename_scheme));
         // Check if file differs from origin/main
         let diff_output = git::output(
             &["diff", "origin/main", "--", &issue_file],
@@ -79,7 +78,11 @@ fn check_unshipped_done_issues(
                 unshipped.len(),
                 unshipped.join(", ")
             );
-            eprintln!("  consider running `brd agent merge` first");
+            if repo::is_agent_worktree(&paths.worktree_root) {
+                eprintln!("  consider running `brd agent merge` first");
+            } else {
+                eprintln!("  consider pushing them to origin/main first");
+            }
         }
         Ok(Some(unshipped))
     }
@@ -90,7 +93,7 @@ pub fn sync_issues_branch(paths: &RepoPaths, config: &Config, cli: &Cli) -> Resu
     let branch = config
         .issues_branch
         .as_ref()
-        .ok_or_else(|| BrdError::Other("issues_branch not configured".to_string()))?;
+        .ok_or_else(|| BrdError::Config("issues_branch not configured".to_string()))?;
 
     let issues_wt = paths.ensure_issues_worktree(branch)?;
 
@@ -119,7 +122,7 @@ pub fn sync_issues_branch(paths: &RepoPaths, config: &Config, cli: &Cli) -> Resu
         && !git::run(&["rebase", &remote_branch], &issues_wt)?
     {
         let _ = git::run(&["rebase", "--abort"], &issues_wt);
-        return Err(BrdError::Other(
+        return Err(BrdError::Conflict(
             "rebase failed on issues branch - resolve conflicts manually or use --no-sync"
                 .to_string(),
         ));
@@ -139,6 +142,9 @@ pub fn sync_with_main(paths: &RepoPaths, cli: &Cli, stash: bool) -> Result<()> {
         return Ok(());
     }
 
+    git::require_commits(&paths.worktree_root)?;
+    git::require_branch(&paths.worktree_root)?;
+
     if !cli.json {
         eprintln!("syncing with origin/main...");
     }
@@ -160,7 +166,7 @@ pub fn sync_with_main(paths: &RepoPaths, cli: &Cli, stash: bool) -> Result<()> {
             }
             stashed = git::stash_push(&paths.worktree_root, "brd start: stashing changes")?;
         } else {
-            return Err(BrdError::Other(
+            return Err(BrdError::DirtyWorkingTree(
                 "working tree has uncommitted changes outside .braid\n\n\
                  options:\n  \
                  - commit or stash manually first\n  \
@@ -200,7 +206,7 @@ pub fn sync_with_main(paths: &RepoPaths, cli: &Cli, stash: bool) -> Result<()> {
             }
             let _ = git::stash_pop(&paths.worktree_root);
         }
-        return Err(BrdError::Other(
+        return Err(BrdError::Conflict(
             "rebase failed - resolve conflicts manually or use --no-sync".to_string(),
         ));
     }
@@ -211,7 +217,7 @@ pub fn sync_with_main(paths: &RepoPaths, cli: &Cli, stash: bool) -> Result<()> {
             eprintln!("  restoring stashed changes...");
         }
         if !git::stash_pop(&paths.worktree_root)? {
-            return Err(BrdError::Other(
+            return Err(BrdError::Git(
                 "sync succeeded but failed to restore stashed changes\n\
                  your changes are in `git stash` - run `git stash pop` to restore"
                     .to_string(),
@@ -222,29 +228,133 @@ pub fn sync_with_main(paths: &RepoPaths, cli: &Cli, stash: bool) -> Result<()> {
     Ok(())
 }
 
+/// Pull from remote before a mutating command, if `auto_pull` is enabled.
+/// Does nothing when `no_sync` is set.
+pub fn auto_pull(
+    paths: &RepoPaths,
+    config: &Config,
+    cli: &Cli,
+    no_sync: bool,
+    stash: bool,
+) -> Result<()> {
+    if no_sync || !config.auto_pull {
+        return Ok(());
+    }
+
+    if config.is_issues_branch_mode() {
+        sync_issues_branch(paths, config, cli)
+    } else {
+        sync_with_main(paths, cli, stash)
+    }
+}
+
+/// Commit and push after a mutating command, if `auto_push` is enabled.
+/// Does nothing when `no_push` is set. If auto_push is false, changes are saved
+/// locally and visible to local agents; use `brd sync` to batch-commit when ready.
+pub fn auto_push(
+    paths: &RepoPaths,
+    config: &Config,
+    issue_id: &str,
+    action: &str,
+    cli: &Cli,
+    no_push: bool,
+) -> Result<()> {
+    if no_push || !config.auto_push {
+        return Ok(());
+    }
+
+    if config.is_issues_branch_mode() {
+        commit_and_push_issues_branch_with_action(paths, config, issue_id, action, cli)
+    } else {
+        commit_and_push_main_with_action(paths, config, issue_id, action, cli)
+    }
+}
+
+/// subject prefix of the commits braid makes for issue changes.
+pub(crate) const BRAID_COMMIT_PREFIX: &str = "chore(braid): ";
+
+/// subject of a braid commit that several actions were amended into.
+const SQUASHED_COMMIT_SUBJECT: &str = "chore(braid): update issues";
+
+/// stage `.braid` in `cwd` and commit it according to `mode`. returns true if
+/// HEAD moved (a new or amended commit); false if nothing was staged or `mode`
+/// is `none`.
+pub(crate) fn commit_braid_changes(cwd: &Path, message: &str, mode: CommitMode) -> Result<bool> {
+    if !git::run(&["add", ".braid"], cwd)? {
+        return Err(BrdError::Git("failed to stage .braid".to_string()));
+    }
+    if mode == CommitMode::None || git::run(&["diff", "--cached", "--quiet"], cwd)? {
+        return Ok(false);
+    }
+
+    if mode == CommitMode::Amend && head_is_unpushed_braid_commit(cwd)? {
+        let previous = git::output(&["log", "-1", "--format=%B"], cwd)?;
+        let message = amended_commit_message(&previous, message);
+        return git::run(&["commit", "--amend", "-m", &message], cwd);
+    }
+    git::run(&["commit", "-m", message], cwd)
+}
+
+/// true if HEAD is a braid commit that no remote branch contains yet, so
+/// amending it never rewrites history another agent may have pulled.
+fn head_is_unpushed_braid_commit(cwd: &Path) -> Result<bool> {
+    let subject = git::output(&["log", "-1", "--format=%s"], cwd)?;
+    if !subject.starts_with(BRAID_COMMIT_PREFIX) {
+        return Ok(false);
+    }
+    let remotes = git::output(&["branch", "--remotes", "--contains", "HEAD"], cwd)?;
+    Ok(remotes.is_empty())
+}
+
+/// the message for amending `message` into the braid commit `previous`: one
+/// subject, with each action as a bullet in the body.
+fn amended_commit_message(previous: &str, message: &str) -> String {
+    let mut lines = previous.lines();
+    let subject = lines.next().unwrap_or_default();
+    let mut actions: Vec<&str> = if subject == SQUASHED_COMMIT_SUBJECT {
+        lines.filter_map(|l| l.strip_prefix("- ")).collect()
+    } else {
+        vec![subject.strip_prefix(BRAID_COMMIT_PREFIX).unwrap_or(subject)]
+    };
+    actions.push(message.strip_prefix(BRAID_COMMIT_PREFIX).unwrap_or(message));
+
+    let body: Vec<String> = actions.iter().map(|a| format!("- {}", a)).collect();
+    format!("{}\n\n{}", SQUASHED_COMMIT_SUBJECT, body.join("\n"))
+}
+
 /// Commit and push the claim to main with retry logic.
-pub fn commit_and_push_main(paths: &RepoPaths, issue_id: &str, cli: &Cli) -> Result<()> {
-    commit_and_push_main_with_action(paths, issue_id, "start", cli)
+pub fn commit_and_push_main(
+    paths: &RepoPaths,
+    config: &Config,
+    issue_id: &str,
+    cli: &Cli,
+) -> Result<()> {
+    commit_and_push_main_with_action(paths, config, issue_id, "start", cli)
 }
 
 /// Commit and push to main with a custom action prefix for the commit message.
 pub fn commit_and_push_main_with_action(
     paths: &RepoPaths,
+    config: &Config,
     issue_id: &str,
     action: &str,
     cli: &Cli,
 ) -> Result<()> {
-    // Commit
-    if !git::run(&["add", ".braid"], &paths.worktree_root)? {
-        return Err(BrdError::Other("failed to stage .braid".to_string()));
-    }
+    // a commit on a detached HEAD would be orphaned once the user switches away
+    git::require_branch(&paths.worktree_root)?;
 
-    let commit_msg = format!("chore(braid): {} {}", action, issue_id);
-    if !git::run(&["commit", "-m", &commit_msg], &paths.worktree_root)? {
-        // Nothing to commit is ok
+    // Commit
+    let commit_msg = format!("{}{} {}", BRAID_COMMIT_PREFIX, action, issue_id);
+    let committed = commit_braid_changes(&paths.worktree_root, &commit_msg, config.commit_mode)?;
+    if config.commit_mode == CommitMode::None {
         if !cli.json {
-            eprintln!("  (no changes to commit)");
+            eprintln!("  (commit_mode = none: .braid changes staged, not committed)");
         }
+        return Ok(());
+    }
+    if !committed && !cli.json {
+        // Nothing to commit is ok
+        eprintln!("  (no changes to commit)");
     }
 
     // Skip push if no origin remote
@@ -255,6 +365,8 @@ pub fn commit_and_push_main_with_action(
         return Ok(());
     }
 
+    git::require_commits(&paths.worktree_root)?;
+
     // Push with retry
     const MAX_RETRIES: u32 = 2;
     for attempt in 0..=MAX_RETRIES {
@@ -273,18 +385,18 @@ pub fn commit_and_push_main_with_action(
 
             // Pull and rebase
             if !git::run(&["fetch", "origin", "main"], &paths.worktree_root)? {
-                return Err(BrdError::Other("failed to fetch during retry".to_string()));
+                return Err(BrdError::Git("failed to fetch during retry".to_string()));
             }
             if !git::run(&["rebase", "origin/main"], &paths.worktree_root)? {
                 let _ = git::run(&["rebase", "--abort"], &paths.worktree_root);
-                return Err(BrdError::Other(
+                return Err(BrdError::Conflict(
                     "rebase failed during push retry - resolve manually".to_string(),
                 ));
             }
         }
     }
 
-    Err(BrdError::Other(format!(
+    Err(BrdError::Conflict(format!(
         "push failed after {} retries - another agent may have pushed. \
          run `git pull --rebase origin main` and check if issue is still available",
         MAX_RETRIES
@@ -312,22 +424,33 @@ pub fn commit_and_push_issues_branch_with_action(
     let branch = config
         .issues_branch
         .as_ref()
-        .ok_or_else(|| BrdError::Other("issues_branch not configured".to_string()))?;
+        .ok_or_else(|| BrdError::Config("issues_branch not configured".to_string()))?;
 
     let issues_wt = paths.ensure_issues_worktree(branch)?;
 
     // Commit
-    if !git::run(&["add", ".braid"], &issues_wt)? {
-        return Err(BrdError::Other(
-            "failed to stage .braid in sync worktree".to_string(),
-        ));
+    let commit_msg = format!("{}{} {}", BRAID_COMMIT_PREFIX, action, issue_id);
+    let committed = commit_braid_changes(&issues_wt, &commit_msg, config.commit_mode)?;
+    if config.commit_mode == CommitMode::None {
+        if !cli.json {
+            eprintln!(
+                "  (commit_mode = none: .braid changes staged in sync worktree, not committed)"
+            );
+        }
+        return Ok(());
     }
-
-    let commit_msg = format!("chore(braid): {} {}", action, issue_id);
-    if !git::run(&["commit", "-m", &commit_msg], &issues_wt)? && !cli.json {
+    if !committed && !cli.json {
         eprintln!("  (no changes to commit in sync branch)");
     }
 
+    // Skip push if no origin remote
+    if !git::has_remote(&issues_wt, "origin") {
+        if !cli.json {
+            eprintln!("  (no origin remote, skipping push)");
+        }
+        return Ok(());
+    }
+
     // Push with retry
     const MAX_RETRIES: u32 = 2;
     for attempt in 0..=MAX_RETRIES {
@@ -345,20 +468,20 @@ pub fn commit_and_push_issues_branch_with_action(
             }
 
             if !git::run(&["fetch", "origin", branch], &issues_wt)? {
-                return Err(BrdError::Other(
+                return Err(BrdError::Git(
                     "failed to fetch sync branch during retry".to_string(),
                 ));
             }
             if !git::run(&["rebase", &format!("origin/{}", branch)], &issues_wt)? {
                 let _ = git::run(&["rebase", "--abort"], &issues_wt);
-                return Err(BrdError::Other(
+                return Err(BrdError::Conflict(
                     "rebase failed on sync branch - resolve manually".to_string(),
                 ));
             }
         }
     }
 
-    Err(BrdError::Other(format!(
+    Err(BrdError::Conflict(format!(
         "push to sync branch failed after {} retries",
         MAX_RETRIES
     )))
@@ -382,13 +505,7 @@ pub fn cmd_start(
     }
 
     // Step 2: Sync with remote if auto_pull is enabled
-    if !no_sync && config.auto_pull {
-        if config.is_issues_branch_mode() {
-            sync_issues_branch(paths, &config, cli)?;
-        } else {
-            sync_with_main(paths, cli, stash)?;
-        }
-    }
+    auto_pull(paths, &config, cli, no_sync, stash)?;
 
     // Step 3: Reload issues and claim
     let _lock = LockGuard::acquire(&paths.lock_path())?;
@@ -398,12 +515,13 @@ pub fn cmd_start(
     let full_id = match id {
         Some(partial) => resolve_issue_id(partial, &issues)?,
         None => {
-            let ready = get_ready_issues(&issues);
-            ready
-                .into_iter()
-                .find(|issue| issue.issue_type() != Some(IssueType::Meta))
+            let mut ready = get_ready_issues(&issues, &config.ready_includes);
+            let now = time::OffsetDateTime::now_utc();
+            rank_ready(&mut ready, &issues, config.next_strategy, now);
+            start_candidates(&ready)
+                .first()
                 .map(|i| i.id().to_string())
-                .ok_or_else(|| BrdError::Other("no ready issues".to_string()))?
+                .ok_or(BrdError::NoReadyIssues)?
         }
     };
 
@@ -411,7 +529,7 @@ pub fn cmd_start(
 
     // Warn if agent already has uncompleted work
     if !cli.json {
-        let active_issues: Vec<_> = issues
+        let mut active_issues: Vec<_> = issues
             .values()
             .filter(|i| {
                 i.status() == Status::Doing
@@ -419,6 +537,7 @@ pub fn cmd_start(
                     && i.id() != full_id
             })
             .collect();
+        active_issues.sort_by(|a, b| a.cmp_by_priority(b));
 
         if !active_issues.is_empty() {
             eprintln!(
@@ -442,23 +561,21 @@ pub fn cmd_start(
     }
 
     // Step 4: Commit and push if auto_push is enabled (unless --no-push)
-    // If auto_push is false, the file is saved locally and visible to local agents.
-    // Use `brd sync` to batch-commit when ready.
-    if !no_push && config.auto_push {
-        if config.is_issues_branch_mode() {
-            commit_and_push_issues_branch(paths, &config, &full_id, cli)?;
-        } else {
-            commit_and_push_main(paths, &full_id, cli)?;
-        }
-    }
+    auto_push(paths, &config, &full_id, "start", cli, no_push)?;
 
     // Output
     if cli.json {
         let issue = issues.get(&full_id).unwrap();
-        let json = issue_to_json(issue, &issues);
+        let json = issue_to_json(issue, &issues, &config.ready_includes);
         println!("{}", serde_json::to_string_pretty(&json).unwrap());
     } else {
         println!("Started: {} (owner: {})", full_id, agent_id);
+        if repo::is_agent_worktree(&paths.worktree_root) {
+            eprintln!(
+                "when finished: `brd done {}`, then `brd agent merge`",
+                full_id
+            );
+        }
     }
 
     Ok(())
@@ -467,7 +584,7 @@ pub fn cmd_start(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::issue::Priority;
+    use crate::issue::{IssueType, Priority};
     use crate::test_utils::{TestRepo, test_cli};
     use std::fs;
     use tempfile::tempdir;
@@ -513,6 +630,46 @@ mod tests {
         assert_eq!(meta.status(), Status::Open);
     }
 
+    #[test]
+    fn test_start_auto_pick_follows_next_strategy() {
+        let repo = TestRepo::builder().with_agent("tester").build();
+        let mut config = repo.config.clone();
+        config.next_strategy = crate::config::NextStrategy::Unblock;
+        config.save(&repo.paths.config_path()).unwrap();
+        repo.issue("brd-urgent").priority(Priority::P0).create();
+        repo.issue("brd-root").priority(Priority::P3).create();
+        repo.issue("brd-child").deps(&["brd-root"]).create();
+
+        cmd_start(&test_cli(), &repo.paths, None, false, true, true, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-root"].status(), Status::Doing);
+        assert_eq!(issues["brd-urgent"].status(), Status::Open);
+    }
+
+    #[test]
+    fn test_start_auto_pick_skips_doing_when_ready_includes_it() {
+        let repo = TestRepo::builder().with_agent("tester").build();
+        let mut config = repo.config.clone();
+        config.ready_includes = vec![Status::Open, Status::Doing];
+        config.save(&repo.paths.config_path()).unwrap();
+        repo.issue("brd-busy")
+            .priority(Priority::P0)
+            .status(Status::Doing)
+            .owner("someone")
+            .create();
+        repo.issue("brd-free").priority(Priority::P2).create();
+
+        cmd_start(&test_cli(), &repo.paths, None, false, true, true, false).unwrap();
+
+        let issues = load_all_issues(&repo.paths, &repo.config).unwrap();
+        assert_eq!(issues["brd-free"].status(), Status::Doing);
+        assert_eq!(
+            issues["brd-busy"].frontmatter.owner.as_deref(),
+            Some("someone")
+        );
+    }
+
     #[test]
     fn test_start_requires_force_for_doing_issue() {
         let repo = TestRepo::builder().with_agent("tester").build();
@@ -693,4 +850,58 @@ mod tests {
             "uncommitted content"
         );
     }
+
+    #[test]
+    fn test_amended_commit_message_collects_actions() {
+        let first =
+            amended_commit_message("chore(braid): add brd-aaaa", "chore(braid): start brd-aaaa");
+        assert_eq!(
+            first,
+            "chore(braid): update issues\n\n- add brd-aaaa\n- start brd-aaaa"
+        );
+        let second = amended_commit_message(&first, "chore(braid): done brd-aaaa");
+        assert_eq!(
+            second,
+            "chore(braid): update issues\n\n- add brd-aaaa\n- start brd-aaaa\n- done brd-aaaa"
+        );
+    }
+
+    #[test]
+    fn test_commit_braid_changes_modes() {
+        let (_dir, paths) = create_git_repo();
+        let root = &paths.worktree_root;
+        let count = || git::output(&["rev-list", "--count", "HEAD"], root).unwrap();
+        let issue = root.join(".braid/issues/brd-aaaa.md");
+        fs::create_dir_all(issue.parent().unwrap()).unwrap();
+
+        // amend never folds into a non-braid commit
+        fs::write(&issue, "1").unwrap();
+        assert!(
+            commit_braid_changes(root, "chore(braid): add brd-aaaa", CommitMode::Amend).unwrap()
+        );
+        assert_eq!(count(), "2");
+
+        fs::write(&issue, "2").unwrap();
+        assert!(
+            commit_braid_changes(root, "chore(braid): start brd-aaaa", CommitMode::Amend).unwrap()
+        );
+        assert_eq!(count(), "2");
+        let subject = git::output(&["log", "-1", "--format=%s"], root).unwrap();
+        assert_eq!(subject, "chore(braid): update issues");
+
+        // none stages without committing
+        fs::write(&issue, "3").unwrap();
+        assert!(
+            !commit_braid_changes(root, "chore(braid): done brd-aaaa", CommitMode::None).unwrap()
+        );
+        assert_eq!(count(), "2");
+        assert!(!git::run(&["diff", "--cached", "--quiet"], root).unwrap());
+
+        assert!(
+            commit_braid_changes(root, "chore(braid): done brd-aaaa", CommitMode::PerAction)
+                .unwrap()
+        );
+        assert_eq!(count(), "3");
+        assert!(!commit_braid_changes(root, "chore(braid): noop", CommitMode::PerAction).unwrap());
+    }
 }
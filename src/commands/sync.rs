@@ -1,11 +1,26 @@
 //! brd sync command - sync issues with the sync branch.
 
 use crate::cli::Cli;
-use crate::config::Config;
+use crate::config::{CommitMode, Config};
 use crate::error::{BrdError, Result};
 use crate::git;
+use crate::lock::LockGuard;
 use crate::repo::RepoPaths;
 
+use super::escalate::{escalate_issues, format_escalation};
+use super::start::commit_braid_changes;
+
+const SYNC_COMMIT_MESSAGE: &str = "chore(braid): sync issues";
+
+/// `brd sync` exists to share changes, so it commits even with `commit_mode =
+/// none`; `amend` still folds into an unpushed braid commit.
+fn sync_commit_mode(config: &Config) -> CommitMode {
+    match config.commit_mode {
+        CommitMode::Amend => CommitMode::Amend,
+        CommitMode::PerAction | CommitMode::None => CommitMode::PerAction,
+    }
+}
+
 fn has_upstream(branch: &str, cwd: &std::path::Path) -> Result<bool> {
     git::run(
         &["rev-parse", "--abbrev-ref", &format!("{branch}@{{u}}")],
@@ -13,31 +28,246 @@ fn has_upstream(branch: &str, cwd: &std::path::Path) -> Result<bool> {
     )
 }
 
-pub fn cmd_sync(cli: &Cli, paths: &RepoPaths, push: bool) -> Result<()> {
+pub fn cmd_sync(
+    cli: &Cli,
+    paths: &RepoPaths,
+    push: bool,
+    pull_only: bool,
+    push_only: bool,
+) -> Result<()> {
     let config = Config::load(&paths.config_path())?;
 
-    let branch = config.issues_branch.as_ref().ok_or_else(|| {
-        BrdError::Other(
-            "not in sync branch mode. use `brd init --sync-branch <name>` to enable".to_string(),
-        )
-    })?;
+    if config.is_external_repo_mode() {
+        return Err(BrdError::Config(
+            "issues live in an external repo; sync that repo with git directly".to_string(),
+        ));
+    }
+    let Some(branch) = config.issues_branch.as_ref() else {
+        if !pull_only {
+            escalate_before_sync(cli, paths, &config)?;
+        }
+        return sync_git_native(cli, paths, &config, pull_only, push_only);
+    };
 
     // ensure issues worktree exists
     let issues_wt = paths.ensure_issues_worktree(branch)?;
 
     let has_upstream = has_upstream(branch, &issues_wt)?;
-    let should_push = has_upstream || push;
+    let should_pull = !push_only;
+    let should_push = (has_upstream || push || push_only) && !pull_only;
 
     if !cli.json {
-        if should_push {
+        if pull_only {
+            println!("pulling issues from remote '{}'...", branch);
+        } else if push_only {
+            println!("pushing issues to remote '{}'...", branch);
+        } else if should_push {
             println!("Syncing issues with remote '{}'...", branch);
         } else {
             println!("syncing issues locally on '{}'...", branch);
         }
     }
 
+    let mut pulled = false;
+    if should_pull {
+        pulled = pull_issues(cli, branch, &issues_wt, has_upstream)?;
+    }
+
+    // 5. check for any changes to commit (pull-only leaves local changes alone)
+    if !pull_only {
+        escalate_before_sync(cli, paths, &config)?;
+        let has_uncommitted = !git::is_clean(&issues_wt)?;
+        if has_uncommitted {
+            if !cli.json {
+                println!("  committing issue changes...");
+            }
+            commit_braid_changes(&issues_wt, SYNC_COMMIT_MESSAGE, sync_commit_mode(&config))?;
+        }
+    }
+
+    // 6. push to remote
+    if should_push {
+        if !cli.json {
+            println!("  pushing to origin/{}...", branch);
+        }
+        if !git::run(&["push", "origin", branch], &issues_wt)? {
+            // try with --set-upstream if first push
+            if !git::run(&["push", "--set-upstream", "origin", branch], &issues_wt)? {
+                return Err(BrdError::Git(format!(
+                    "failed to push to origin/{}. you may need to pull and retry.",
+                    branch
+                )));
+            }
+        }
+    }
+
+    if cli.json {
+        let json = serde_json::json!({
+            "ok": true,
+            "branch": branch,
+            "issues_worktree": issues_wt.to_string_lossy(),
+            "pulled": pulled,
+            "pushed": should_push,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        println!("Sync complete.");
+    }
+
+    Ok(())
+}
+
+/// with `escalate_on_sync`, raise the priority of stale issues so the bumps
+/// go out with this sync.
+fn escalate_before_sync(cli: &Cli, paths: &RepoPaths, config: &Config) -> Result<()> {
+    let Some(after_days) = config
+        .escalate_after_days
+        .filter(|_| config.escalate_on_sync)
+    else {
+        return Ok(());
+    };
+    let _lock = LockGuard::acquire(&paths.lock_path())?;
+    for escalation in escalate_issues(paths, config, after_days, false)? {
+        if !cli.json {
+            println!("  escalated {}", format_escalation(&escalation));
+        }
+    }
+    Ok(())
+}
+
+/// sync issues stored with the code: commit `.braid` changes, rebase onto
+/// origin/main, and push. unrelated uncommitted changes are stashed for the
+/// duration so a dirty working tree doesn't block the rebase.
+fn sync_git_native(
+    cli: &Cli,
+    paths: &RepoPaths,
+    config: &Config,
+    pull_only: bool,
+    push_only: bool,
+) -> Result<()> {
+    let root = &paths.worktree_root;
+    git::require_branch(root)?;
+    let has_origin = git::has_remote(root, "origin");
+    let should_pull = !push_only && has_origin;
+    let should_push = !pull_only && has_origin;
+
+    if !cli.json {
+        println!("syncing issues with origin/main...");
+        if !has_origin {
+            println!("  (no origin remote, committing locally)");
+        }
+    }
+
+    // 1. stash everything but .braid; pull-only stashes issue edits too
+    // since they won't be committed
+    let dirty = !git::is_clean(root)?;
+    let stashed = if dirty && pull_only {
+        git::stash_push(root, "brd sync: stashing local changes")?
+    } else if dirty {
+        git::stash_push_excluding(root, "brd sync: stashing local changes", ".braid")?
+    } else {
+        false
+    };
+    if stashed && !cli.json {
+        println!("  stashed uncommitted changes");
+    }
+
+    let result = sync_git_native_steps(
+        cli,
+        root,
+        sync_commit_mode(config),
+        pull_only,
+        should_pull,
+        should_push,
+    );
+
+    // 2. restore stashed changes, even if the sync failed
+    let mut stash_conflict = false;
+    if stashed {
+        if !cli.json {
+            println!("  restoring stashed changes...");
+        }
+        stash_conflict = !git::stash_pop(root)?;
+        if stash_conflict {
+            eprintln!(
+                "warning: restoring stashed changes conflicted; resolve the conflicts, \
+                 then `git stash drop` (your changes are kept in `git stash list`)"
+            );
+        }
+    }
+
+    let pulled = result?;
+    if cli.json {
+        let json = serde_json::json!({
+            "ok": true,
+            "branch": "main",
+            "pulled": pulled,
+            "pushed": should_push,
+            "stashed": stashed,
+            "stash_conflict": stash_conflict,
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        println!("Sync complete.");
+    }
+    Ok(())
+}
+
+/// commit, rebase, and push for [`sync_git_native`]. returns true if origin/main
+/// was rebased onto.
+fn sync_git_native_steps(
+    cli: &Cli,
+    root: &std::path::Path,
+    commit_mode: CommitMode,
+    pull_only: bool,
+    should_pull: bool,
+    should_push: bool,
+) -> Result<bool> {
+    if !pull_only && !git::is_clean(root)? {
+        if !cli.json {
+            println!("  committing issue changes...");
+        }
+        commit_braid_changes(root, SYNC_COMMIT_MESSAGE, commit_mode)?;
+    }
+
+    let mut pulled = false;
+    if should_pull && git::run(&["fetch", "origin", "main"], root)? {
+        if !cli.json {
+            println!("  rebasing onto origin/main...");
+        }
+        if !git::run(&["rebase", "origin/main"], root)? {
+            let _ = git::run(&["rebase", "--abort"], root);
+            return Err(BrdError::Conflict(
+                "rebase failed - there may be conflicts. resolve manually".to_string(),
+            ));
+        }
+        pulled = true;
+    }
+
+    if should_push {
+        if !cli.json {
+            println!("  pushing to origin/main...");
+        }
+        if !git::run(&["push", "origin", "HEAD:main"], root)? {
+            return Err(BrdError::Git(
+                "failed to push to origin/main. you may need to pull and retry.".to_string(),
+            ));
+        }
+    }
+
+    Ok(pulled)
+}
+
+/// fetch and rebase the issues worktree onto its remote branch, preserving local changes.
+/// returns true if the remote branch was fetched and rebased onto.
+fn pull_issues(
+    cli: &Cli,
+    branch: &str,
+    issues_wt: &std::path::Path,
+    has_upstream: bool,
+) -> Result<bool> {
     // 1. check for local changes in issues worktree
-    let has_local_changes = !git::is_clean(&issues_wt)?;
+    let has_local_changes = !git::is_clean(issues_wt)?;
     let mut stashed = false;
 
     // 2. stash local changes if any
@@ -45,7 +275,7 @@ pub fn cmd_sync(cli: &Cli, paths: &RepoPaths, push: bool) -> Result<()> {
         if !cli.json {
             println!("  stashing local changes...");
         }
-        stashed = git::stash_push(&issues_wt, "brd sync: stashing local changes")?;
+        stashed = git::stash_push(issues_wt, "brd sync: stashing local changes")?;
     }
 
     // 3. fetch and rebase
@@ -54,7 +284,7 @@ pub fn cmd_sync(cli: &Cli, paths: &RepoPaths, push: bool) -> Result<()> {
             println!("  fetching origin/{}...", branch);
         }
         // try to fetch; if remote doesn't exist, that's ok (first sync)
-        git::run(&["fetch", "origin", branch], &issues_wt)?
+        git::run(&["fetch", "origin", branch], issues_wt)?
     } else {
         false
     };
@@ -63,13 +293,13 @@ pub fn cmd_sync(cli: &Cli, paths: &RepoPaths, push: bool) -> Result<()> {
         if !cli.json {
             println!("  rebasing onto origin/{}...", branch);
         }
-        if !git::run(&["rebase", &format!("origin/{}", branch)], &issues_wt)? {
+        if !git::run(&["rebase", &format!("origin/{}", branch)], issues_wt)? {
             // abort rebase and restore
-            let _ = git::run(&["rebase", "--abort"], &issues_wt);
+            let _ = git::run(&["rebase", "--abort"], issues_wt);
             if stashed {
-                let _ = git::stash_pop(&issues_wt);
+                let _ = git::stash_pop(issues_wt);
             }
-            return Err(BrdError::Other(
+            return Err(BrdError::Conflict(
                 "rebase failed - there may be conflicts. resolve manually in the issues worktree"
                     .to_string(),
             ));
@@ -81,55 +311,14 @@ pub fn cmd_sync(cli: &Cli, paths: &RepoPaths, push: bool) -> Result<()> {
         if !cli.json {
             println!("  restoring local changes...");
         }
-        if !git::stash_pop(&issues_wt)? {
-            return Err(BrdError::Other(
+        if !git::stash_pop(issues_wt)? {
+            return Err(BrdError::Git(
                 "failed to restore local changes from stash".to_string(),
             ));
         }
     }
 
-    // 5. check for any changes to commit
-    let has_uncommitted = !git::is_clean(&issues_wt)?;
-    if has_uncommitted {
-        if !cli.json {
-            println!("  committing issue changes...");
-        }
-        if !git::run(&["add", ".braid"], &issues_wt)? {
-            return Err(BrdError::Other("failed to stage changes".to_string()));
-        }
-        if !git::run(&["commit", "-m", "chore(braid): sync issues"], &issues_wt)? {
-            return Err(BrdError::Other("failed to commit changes".to_string()));
-        }
-    }
-
-    // 6. push to remote
-    if should_push {
-        if !cli.json {
-            println!("  pushing to origin/{}...", branch);
-        }
-        if !git::run(&["push", "origin", branch], &issues_wt)? {
-            // try with --set-upstream if first push
-            if !git::run(&["push", "--set-upstream", "origin", branch], &issues_wt)? {
-                return Err(BrdError::Other(format!(
-                    "failed to push to origin/{}. you may need to pull and retry.",
-                    branch
-                )));
-            }
-        }
-    }
-
-    if cli.json {
-        let json = serde_json::json!({
-            "ok": true,
-            "branch": branch,
-            "issues_worktree": issues_wt.to_string_lossy(),
-        });
-        println!("{}", serde_json::to_string_pretty(&json).unwrap());
-    } else {
-        println!("Sync complete.");
-    }
-
-    Ok(())
+    Ok(remote_exists)
 }
 
 #[cfg(test)]
@@ -186,7 +375,7 @@ mod tests {
             repo: None,
             no_color: true,
             verbose: false,
-            command: crate::cli::Command::Doctor,
+            command: crate::cli::Command::Doctor { fix: false },
         }
     }
 
@@ -248,27 +437,61 @@ mod tests {
     }
 
     #[test]
-    fn test_sync_not_in_sync_mode() {
+    fn test_sync_external_repo_mode_errors() {
         let dir = setup_git_repo();
         let cli = make_cli();
         let paths = make_paths(&dir);
 
-        // create config without issues_branch
         fs::create_dir_all(dir.path().join(".braid")).unwrap();
         fs::write(
             dir.path().join(".braid/config.toml"),
-            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_repo = \"../issues\"\n",
         )
         .unwrap();
 
-        let result = cmd_sync(&cli, &paths, false);
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("not in sync branch mode")
+        let err = cmd_sync(&cli, &paths, false, false, false).unwrap_err();
+        assert!(err.to_string().contains("external repo"));
+    }
+
+    #[test]
+    fn test_sync_git_native_stashes_unrelated_changes() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+        let run = |args: &[&str]| assert!(git::run(args, dir.path()).unwrap(), "{:?}", args);
+
+        let remote = tempdir().unwrap();
+        assert!(git::run(&["init", "--bare"], remote.path()).unwrap());
+        run(&["branch", "-M", "main"]);
+        run(&["remote", "add", "origin", &remote.path().to_string_lossy()]);
+
+        fs::create_dir_all(dir.path().join(".braid/issues")).unwrap();
+        fs::write(
+            dir.path().join(".braid/config.toml"),
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\n",
+        )
+        .unwrap();
+        run(&["add", ".braid"]);
+        run(&["commit", "-m", "init braid"]);
+        run(&["push", "origin", "main"]);
+
+        // an issue edit plus unrelated work in progress
+        fs::write(dir.path().join(".braid/issues/tst-abcd.md"), "issue").unwrap();
+        fs::write(dir.path().join(".gitkeep"), "wip").unwrap();
+        fs::write(dir.path().join("scratch.rs"), "wip").unwrap();
+
+        cmd_sync(&cli, &paths, false, false, false).unwrap();
+
+        // the issue was committed and pushed, the code changes are back and uncommitted
+        let files = git::output(&["ls-tree", "-r", "--name-only", "main"], remote.path()).unwrap();
+        assert!(files.contains(".braid/issues/tst-abcd.md"));
+        assert!(!files.contains("scratch.rs"));
+        assert_eq!(
+            fs::read_to_string(dir.path().join(".gitkeep")).unwrap(),
+            "wip"
         );
+        assert!(dir.path().join("scratch.rs").exists());
+        assert_eq!(git::stash_count(dir.path()).unwrap(), 0);
     }
 
     #[test]
@@ -293,10 +516,82 @@ mod tests {
             .unwrap();
 
         // run sync - should create worktree
-        let result = cmd_sync(&cli, &paths, false);
+        let result = cmd_sync(&cli, &paths, false, false, false);
         assert!(result.is_ok());
 
         // verify worktree was created
         assert!(paths.brd_common_dir.join("issues").exists());
     }
+
+    #[test]
+    fn test_sync_pull_only_leaves_local_changes_uncommitted() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+
+        fs::create_dir_all(&paths.brd_common_dir).unwrap();
+        fs::create_dir_all(dir.path().join(".braid")).unwrap();
+        fs::write(
+            dir.path().join(".braid/config.toml"),
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_branch = \"braid-issues\"\n",
+        ).unwrap();
+
+        Command::new("git")
+            .args(["branch", "braid-issues"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        let issues_wt = paths.ensure_issues_worktree("braid-issues").unwrap();
+        fs::create_dir_all(issues_wt.join(".braid/issues")).unwrap();
+        fs::write(issues_wt.join(".braid/issues/tst-abcd.md"), "local").unwrap();
+
+        cmd_sync(&cli, &paths, false, true, false).unwrap();
+
+        // the local change is restored but not committed
+        assert!(issues_wt.join(".braid/issues/tst-abcd.md").exists());
+        assert!(!git::is_clean(&issues_wt).unwrap());
+    }
+
+    #[test]
+    fn test_sync_push_only_commits_local_changes() {
+        let dir = setup_git_repo();
+        let cli = make_cli();
+        let paths = make_paths(&dir);
+
+        // bare remote to push to
+        let remote = tempdir().unwrap();
+        Command::new("git")
+            .args(["init", "--bare"])
+            .current_dir(remote.path())
+            .output()
+            .unwrap();
+        Command::new("git")
+            .args(["remote", "add", "origin", &remote.path().to_string_lossy()])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        fs::create_dir_all(&paths.brd_common_dir).unwrap();
+        fs::create_dir_all(dir.path().join(".braid")).unwrap();
+        fs::write(
+            dir.path().join(".braid/config.toml"),
+            "schema_version = 6\nid_prefix = \"tst\"\nid_len = 4\nissues_branch = \"braid-issues\"\n",
+        ).unwrap();
+
+        Command::new("git")
+            .args(["branch", "braid-issues"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        let issues_wt = paths.ensure_issues_worktree("braid-issues").unwrap();
+        fs::create_dir_all(issues_wt.join(".braid/issues")).unwrap();
+        fs::write(issues_wt.join(".braid/issues/tst-abcd.md"), "local").unwrap();
+
+        cmd_sync(&cli, &paths, false, false, true).unwrap();
+
+        assert!(git::is_clean(&issues_wt).unwrap());
+        assert!(git::run(&["rev-parse", "--verify", "braid-issues"], remote.path()).unwrap());
+    }
 }
@@ -0,0 +1,45 @@
+//! brd templates command - list issue templates.
+
+use crate::cli::Cli;
+use crate::error::Result;
+use crate::repo::RepoPaths;
+use crate::template::list_templates;
+
+pub fn cmd_templates(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+    let templates_dir = paths.templates_dir();
+    let templates = list_templates(&templates_dir)?;
+
+    if cli.json {
+        let json: Vec<_> = templates
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "name": t.name,
+                    "description": t.frontmatter.description,
+                    "priority": t.frontmatter.priority.map(|p| p.to_string()),
+                    "type": t.frontmatter.issue_type.map(|t| t.to_string()),
+                    "tags": t.frontmatter.tags,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+        return Ok(());
+    }
+
+    if templates.is_empty() {
+        println!("no templates in {}", templates_dir.display());
+        return Ok(());
+    }
+
+    let width = templates.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for template in &templates {
+        match &template.frontmatter.description {
+            Some(description) => println!("{:<width$}  {}", template.name, description),
+            None => println!("{}", template.name),
+        }
+    }
+    println!();
+    println!("use with: brd add --template <name> \"<title>\"");
+
+    Ok(())
+}
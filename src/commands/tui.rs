@@ -6,10 +6,27 @@ use crate::is_interactive;
 use crate::repo::RepoPaths;
 use crate::tui;
 
-pub fn cmd_tui(_cli: &Cli, paths: &RepoPaths, force: bool) -> Result<()> {
+pub fn cmd_tui(
+    _cli: &Cli,
+    paths: &RepoPaths,
+    force: bool,
+    filter: Option<&str>,
+    status: &[String],
+    view: Option<&str>,
+) -> Result<()> {
+    // validate arguments before touching the terminal
+    let opts = tui::StartOptions {
+        view: view.map(|v| v.parse()).transpose()?,
+        filter: filter.map(String::from),
+        status: status
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<Vec<_>>>()?,
+    };
+
     // check for interactive terminal
     if !force && !is_interactive() {
-        return Err(BrdError::Other(
+        return Err(BrdError::NotInteractive(
             "brd tui requires an interactive terminal.\n\
              hint: use `brd ls` or `brd show <id>` instead\n\
              hint: use --force to override this check"
@@ -17,5 +34,5 @@ pub fn cmd_tui(_cli: &Cli, paths: &RepoPaths, force: bool) -> Result<()> {
         ));
     }
 
-    tui::run(paths)
+    tui::run(paths, &opts)
 }
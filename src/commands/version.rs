@@ -0,0 +1,116 @@
+//! brd version command - binary version and schema compatibility.
+
+use crate::cli::Cli;
+use crate::config::Config;
+use crate::error::Result;
+use crate::migrate::CURRENT_SCHEMA;
+use crate::repo::{self, RepoPaths};
+
+use super::doctor::issues_needing_migration;
+
+/// how the current repo relates to this binary's schema.
+#[derive(Debug, PartialEq, Eq)]
+struct RepoSchema {
+    schema: u32,
+    issues_needing_migration: usize,
+}
+
+impl RepoSchema {
+    fn load(paths: &RepoPaths) -> Result<Self> {
+        let config = Config::load(&paths.config_path())?;
+        let issues_needing_migration = if config.schema_version > CURRENT_SCHEMA {
+            // newer issue files may not even be readable by this brd
+            0
+        } else {
+            issues_needing_migration(&paths.issues_dir(&config))?.len()
+        };
+        Ok(Self {
+            schema: config.schema_version,
+            issues_needing_migration,
+        })
+    }
+
+    /// "upgrade" (brd too old), "migrate" (issues behind), or "ok".
+    fn status(&self) -> &'static str {
+        if self.schema > CURRENT_SCHEMA {
+            "upgrade"
+        } else if self.issues_needing_migration > 0 {
+            "migrate"
+        } else {
+            "ok"
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.status() {
+            "upgrade" => format!(
+                "schema v{} is newer than this brd, upgrade: cargo install braid",
+                self.schema
+            ),
+            "migrate" => format!(
+                "{} issue(s) below schema v{}, run `brd migrate`",
+                self.issues_needing_migration, CURRENT_SCHEMA
+            ),
+            _ => format!("up to date (config schema v{})", self.schema),
+        }
+    }
+}
+
+/// print the brd version and schema info. works outside a braid repo;
+/// inside one it also reports the repo's schema.
+pub fn cmd_version(cli: &Cli) -> Result<()> {
+    let repo_schema = match repo::discover(cli.repo.as_deref()) {
+        Ok(paths) if paths.config_path().exists() => Some(RepoSchema::load(&paths)?),
+        _ => None,
+    };
+
+    if cli.json {
+        let json = serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "schema": CURRENT_SCHEMA,
+            "repo": repo_schema.as_ref().map(|r| serde_json::json!({
+                "schema": r.schema,
+                "issues_needing_migration": r.issues_needing_migration,
+                "status": r.status(),
+            })),
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        println!("brd {}", env!("CARGO_PKG_VERSION"));
+        println!("schema: v{}", CURRENT_SCHEMA);
+        if let Some(repo_schema) = &repo_schema {
+            println!("repo:   {}", repo_schema.describe());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestRepo;
+
+    #[test]
+    fn test_repo_schema_status() {
+        let repo = TestRepo::builder().build();
+        repo.issue("brd-aaaa").create();
+
+        let schema = RepoSchema::load(&repo.paths).unwrap();
+        assert_eq!(schema.status(), "ok");
+
+        let behind = RepoSchema {
+            schema: CURRENT_SCHEMA,
+            issues_needing_migration: 2,
+        };
+        assert_eq!(behind.status(), "migrate");
+        assert!(behind.describe().contains("brd migrate"));
+
+        let ahead = RepoSchema {
+            schema: CURRENT_SCHEMA + 1,
+            issues_needing_migration: 0,
+        };
+        assert_eq!(ahead.status(), "upgrade");
+        assert!(ahead.describe().contains("cargo install braid"));
+    }
+}
@@ -0,0 +1,43 @@
+//! brd whoami command - print the resolved agent identity.
+
+use crate::cli::Cli;
+use crate::error::Result;
+use crate::repo::{self, RepoPaths};
+
+/// Print the agent ID that claims would be made under, and where it came from.
+pub fn cmd_whoami(cli: &Cli, paths: &RepoPaths) -> Result<()> {
+    let (agent_id, source) = repo::resolve_agent_id(&paths.worktree_root);
+
+    if cli.json {
+        let json = serde_json::json!({
+            "agent_id": agent_id,
+            "source": source.to_string(),
+            "worktree": paths.worktree_root.to_string_lossy(),
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        println!("{}", agent_id);
+        println!("  source:   {}", source);
+        println!("  worktree: {}", paths.worktree_root.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repo::AgentIdSource;
+    use crate::test_utils::{TestRepo, test_cli};
+
+    #[test]
+    fn test_whoami_reads_agent_toml() {
+        let repo = TestRepo::builder().with_agent("tester").build();
+
+        let (agent_id, source) = repo::resolve_agent_id(&repo.paths.worktree_root);
+        assert_eq!(agent_id, "tester");
+        assert_eq!(source, AgentIdSource::AgentToml);
+
+        cmd_whoami(&test_cli(), &repo.paths).unwrap();
+    }
+}
@@ -4,11 +4,12 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 use crate::error::{BrdError, Result};
+use crate::issue::Status;
 use crate::migrate::CURRENT_SCHEMA;
 
 /// Build a context-aware schema mismatch error message.
 ///
-/// If `worktree_root` is provided and contains `.braid/agent.toml`, the message
+/// If `worktree_root` is provided and is an agent worktree, the message
 /// includes agent-specific guidance (rebase onto main first).
 pub fn schema_mismatch_error(
     location: &str,
@@ -16,9 +17,7 @@ pub fn schema_mismatch_error(
     supported_version: u32,
     worktree_root: Option<&Path>,
 ) -> BrdError {
-    let is_agent_worktree = worktree_root
-        .map(|root| root.join(".braid/agent.toml").exists())
-        .unwrap_or(false);
+    let is_agent_worktree = worktree_root.is_some_and(crate::repo::is_agent_worktree);
 
     let base_msg = format!(
         "{} uses schema v{}, but this brd only supports up to v{}",
@@ -38,7 +37,7 @@ NEVER manually edit schema_version in config files."#
         "\n\nupgrade brd: cargo install braid"
     };
 
-    BrdError::Other(format!("{}{}", base_msg, guidance))
+    BrdError::SchemaMismatch(format!("{}{}", base_msg, guidance))
 }
 
 /// Default value for auto_pull and auto_push (true for safety).
@@ -46,6 +45,27 @@ fn default_true() -> bool {
     true
 }
 
+/// default `ready_includes`: only open issues are ready.
+fn default_ready_includes() -> Vec<Status> {
+    vec![Status::Open]
+}
+
+fn is_default_ready_includes(statuses: &[Status]) -> bool {
+    statuses == [Status::Open]
+}
+
+/// parse a comma-separated status list, e.g. `open,doing`.
+fn parse_status_list(key: &str, value: &str) -> Result<Vec<Status>> {
+    value
+        .split(',')
+        .map(|s| {
+            s.trim().parse().map_err(|_| {
+                BrdError::ParseError(key.to_string(), format!("invalid status: {}", s))
+            })
+        })
+        .collect()
+}
+
 /// the braid configuration stored in `.braid/config.toml`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -70,6 +90,287 @@ pub struct Config {
     /// default diff renderer for TUI ("native", "delta", "diff-so-fancy")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff_renderer: Option<String>,
+    /// color overrides for TUI and CLI output
+    #[serde(default, skip_serializing_if = "ThemeConfig::is_empty")]
+    pub theme: ThemeConfig,
+    /// instruction file that `brd agent inject` targets by default (default: AGENTS.md)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agents_file: Option<String>,
+    /// how auto-pushed mutations are committed (default: per-action)
+    #[serde(default, skip_serializing_if = "CommitMode::is_default")]
+    pub commit_mode: CommitMode,
+    /// how long finished agent sessions are kept before `brd agent spawn` reaps
+    /// them (e.g. "14d"); unset keeps them until `brd agent clean`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_retention: Option<String>,
+    /// days an open issue may go untouched before `brd escalate` raises its
+    /// priority; unset disables escalation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub escalate_after_days: Option<u32>,
+    /// whether `brd sync` runs `brd escalate` first
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub escalate_on_sync: bool,
+    /// how `brd start` picks the next issue and `brd ready` orders them
+    /// (default: priority)
+    #[serde(default, skip_serializing_if = "NextStrategy::is_default")]
+    pub next_strategy: NextStrategy,
+    /// statuses an issue with all deps done may have to count as ready
+    /// (default: ["open"]; add "doing" to surface in-progress work to resume)
+    #[serde(
+        default = "default_ready_includes",
+        skip_serializing_if = "is_default_ready_includes"
+    )]
+    pub ready_includes: Vec<Status>,
+    /// whether `brd done` refuses issues with unchecked acceptance items
+    /// unless `--force` is given; otherwise it only warns
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub require_acceptance_on_done: bool,
+    /// how issue files are named (default: id); `brd migrate` renames
+    /// existing files after a change
+    #[serde(default, skip_serializing_if = "FilenameScheme::is_default")]
+    pub filename_scheme: FilenameScheme,
+}
+
+/// how `brd add`/`start`/`done` record issue changes when they auto-push.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommitMode {
+    /// one commit per action
+    #[default]
+    PerAction,
+    /// fold into the previous braid commit until it is pushed or a non-braid
+    /// commit lands on top
+    Amend,
+    /// stage `.braid` but leave committing (and pushing) to the user or a hook
+    None,
+}
+
+impl CommitMode {
+    fn is_default(&self) -> bool {
+        *self == CommitMode::default()
+    }
+}
+
+impl std::fmt::Display for CommitMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommitMode::PerAction => write!(f, "per-action"),
+            CommitMode::Amend => write!(f, "amend"),
+            CommitMode::None => write!(f, "none"),
+        }
+    }
+}
+
+impl std::str::FromStr for CommitMode {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "per-action" => Ok(CommitMode::PerAction),
+            "amend" => Ok(CommitMode::Amend),
+            "none" => Ok(CommitMode::None),
+            _ => Err(BrdError::ParseError(
+                "commit_mode".to_string(),
+                format!("invalid commit mode: {s} (valid: per-action, amend, none)"),
+            )),
+        }
+    }
+}
+
+/// how ready issues are ranked for `brd start` and `brd ready`.
+/// see docs/configuration.md for the formulas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NextStrategy {
+    /// priority, then oldest created_at, then id
+    #[default]
+    Priority,
+    /// most open dependents first, then as `priority`
+    Unblock,
+    /// a score combining priority, open dependents, and age
+    Weighted,
+}
+
+impl NextStrategy {
+    fn is_default(&self) -> bool {
+        *self == NextStrategy::default()
+    }
+}
+
+impl std::fmt::Display for NextStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NextStrategy::Priority => write!(f, "priority"),
+            NextStrategy::Unblock => write!(f, "unblock"),
+            NextStrategy::Weighted => write!(f, "weighted"),
+        }
+    }
+}
+
+impl std::str::FromStr for NextStrategy {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "priority" => Ok(NextStrategy::Priority),
+            "unblock" => Ok(NextStrategy::Unblock),
+            "weighted" => Ok(NextStrategy::Weighted),
+            _ => Err(BrdError::ParseError(
+                "next_strategy".to_string(),
+                format!("invalid next strategy: {s} (valid: priority, unblock, weighted)"),
+            )),
+        }
+    }
+}
+
+/// how issue files in the issues dir are named.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FilenameScheme {
+    /// `<id>.md`
+    #[default]
+    Id,
+    /// `<id>-<title slug>.md`, e.g. `brd-ab12-fix-login.md`
+    IdSlug,
+}
+
+impl FilenameScheme {
+    fn is_default(&self) -> bool {
+        *self == FilenameScheme::default()
+    }
+}
+
+impl std::fmt::Display for FilenameScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilenameScheme::Id => write!(f, "id"),
+            FilenameScheme::IdSlug => write!(f, "id-slug"),
+        }
+    }
+}
+
+impl std::str::FromStr for FilenameScheme {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "id" => Ok(FilenameScheme::Id),
+            "id-slug" => Ok(FilenameScheme::IdSlug),
+            _ => Err(BrdError::ParseError(
+                "filename_scheme".to_string(),
+                format!("invalid filename scheme: {s} (valid: id, id-slug)"),
+            )),
+        }
+    }
+}
+
+/// the `[theme]` section: a color per semantic role. unset roles use the default theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// headers and accents (default: cyan)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+    /// selection, focus, and in-progress work (default: yellow)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<String>,
+    /// ready issues (default: green)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready: Option<String>,
+    /// blocked issues (default: red)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<String>,
+    /// P0 priority and bug tags (default: magenta)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub urgent: Option<String>,
+    /// completed issues (default: green)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub done: Option<String>,
+    /// errors and missing references (default: red)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// per-user preferences from `~/.config/braid/config.toml`.
+///
+/// only user-scoped keys are allowed here; repo policy (id prefix, sync mode,
+/// issues branch, ...) belongs in the shared `.braid/config.toml`, so those
+/// keys are rejected rather than silently ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserConfig {
+    /// default diff renderer for TUI
+    #[serde(default)]
+    pub diff_renderer: Option<String>,
+    /// color overrides, merged role by role over the repo theme
+    #[serde(default)]
+    pub theme: ThemeConfig,
+}
+
+impl UserConfig {
+    /// `$XDG_CONFIG_HOME/braid/config.toml`, falling back to `~/.config/braid/config.toml`.
+    pub fn path() -> Option<std::path::PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(std::path::PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(base.join("braid").join("config.toml"))
+    }
+
+    /// load the user config. a missing file is not an error.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let user: UserConfig = toml::from_str(&content)
+            .map_err(|e| BrdError::ParseError(path.display().to_string(), e.to_string()))?;
+        user.theme.validate()?;
+        Ok(Some(user))
+    }
+}
+
+impl ThemeConfig {
+    /// fill in every role set in `other`, keeping ours for the rest.
+    fn merge_from(&mut self, other: &ThemeConfig) {
+        for (ours, theirs) in [
+            (&mut self.header, &other.header),
+            (&mut self.highlight, &other.highlight),
+            (&mut self.ready, &other.ready),
+            (&mut self.blocked, &other.blocked),
+            (&mut self.urgent, &other.urgent),
+            (&mut self.done, &other.done),
+            (&mut self.error, &other.error),
+        ] {
+            if theirs.is_some() {
+                ours.clone_from(theirs);
+            }
+        }
+    }
+
+    /// returns true if no role is overridden.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// check that every configured color parses.
+    pub fn validate(&self) -> Result<()> {
+        for value in [
+            &self.header,
+            &self.highlight,
+            &self.ready,
+            &self.blocked,
+            &self.urgent,
+            &self.done,
+            &self.error,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            crate::theme::parse_color(value)?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for Config {
@@ -83,6 +384,16 @@ impl Default for Config {
             auto_pull: true,
             auto_push: true,
             diff_renderer: None,
+            theme: ThemeConfig::default(),
+            agents_file: None,
+            commit_mode: CommitMode::default(),
+            session_retention: None,
+            escalate_after_days: None,
+            escalate_on_sync: false,
+            next_strategy: NextStrategy::default(),
+            ready_includes: default_ready_includes(),
+            require_acceptance_on_done: false,
+            filename_scheme: FilenameScheme::default(),
         }
     }
 }
@@ -102,13 +413,36 @@ impl Config {
         self.issues_branch.is_some()
     }
 
+    /// the instruction file the agents block goes into when no `--file` is given.
+    pub fn agents_file_name(&self) -> &str {
+        self.agents_file.as_deref().unwrap_or("AGENTS.md")
+    }
+
     /// returns true if external repo mode is enabled.
     pub fn is_external_repo_mode(&self) -> bool {
         self.issues_repo.is_some()
     }
 
-    /// load config from a file path, applying migrations if needed.
+    /// load config from a file path, then layer the user config and `BRAID_*`
+    /// env overrides on top. precedence is env > user (user-scoped keys only) >
+    /// file > default. commands that write the config back use
+    /// [`Config::load_file`] so neither layer ends up in config.toml.
     pub fn load(path: &Path) -> Result<Self> {
+        let mut config = Self::load_file(path)?;
+        if let Some(user_path) = UserConfig::path()
+            && let Some(user) = UserConfig::load(&user_path)?
+        {
+            config.apply_user_config(&user);
+        }
+        if config.apply_env_overrides(std::env::vars())? {
+            config.validate(None)?;
+        }
+        Ok(config)
+    }
+
+    /// load config from a file path, applying migrations if needed.
+    /// ignores env overrides.
+    pub fn load_file(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)?;
 
         // parse as generic TOML value to check for migrations
@@ -131,12 +465,81 @@ impl Config {
         Ok(config)
     }
 
+    /// apply user preferences. these are personal, so they win over the repo file.
+    fn apply_user_config(&mut self, user: &UserConfig) {
+        if user.diff_renderer.is_some() {
+            self.diff_renderer.clone_from(&user.diff_renderer);
+        }
+        self.theme.merge_from(&user.theme);
+    }
+
+    /// apply `BRAID_<OPTION>` overrides (e.g. `BRAID_AUTO_PUSH=false`,
+    /// `BRAID_ID_PREFIX=acme`). `BRD_<OPTION>` is accepted as an alias;
+    /// `BRAID_` wins when both are set. an empty value clears optional
+    /// settings. returns true if any override applied.
+    fn apply_env_overrides(
+        &mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<bool> {
+        let optional = |value: String| (!value.is_empty()).then_some(value);
+        let mut overrides: Vec<(bool, String, String, String)> = vars
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let (primary, option) = match key.strip_prefix("BRAID_") {
+                    Some(option) => (true, option.to_string()),
+                    None => (false, key.strip_prefix("BRD_")?.to_string()),
+                };
+                Some((primary, option, key, value))
+            })
+            .collect();
+        // aliases first, so a `BRAID_` value overwrites them
+        overrides.sort_by_key(|(primary, ..)| *primary);
+
+        let mut applied = false;
+        for (_, option, key, value) in overrides {
+            match option.as_str() {
+                "ID_PREFIX" => self.id_prefix = value,
+                "ID_LEN" => {
+                    self.id_len = value.parse().map_err(|_| {
+                        BrdError::ParseError(key.clone(), format!("invalid number: {}", value))
+                    })?
+                }
+                "ISSUES_BRANCH" => self.issues_branch = optional(value),
+                "ISSUES_REPO" => self.issues_repo = optional(value),
+                "AUTO_PULL" => self.auto_pull = parse_env_bool(&key, &value)?,
+                "AUTO_PUSH" => self.auto_push = parse_env_bool(&key, &value)?,
+                "DIFF_RENDERER" => self.diff_renderer = optional(value),
+                "AGENTS_FILE" => self.agents_file = optional(value),
+                "COMMIT_MODE" => self.commit_mode = value.parse()?,
+                "SESSION_RETENTION" => self.session_retention = optional(value),
+                "ESCALATE_AFTER_DAYS" => {
+                    self.escalate_after_days = optional(value)
+                        .map(|v| {
+                            v.parse().map_err(|_| {
+                                BrdError::ParseError(key.clone(), format!("invalid number: {}", v))
+                            })
+                        })
+                        .transpose()?
+                }
+                "ESCALATE_ON_SYNC" => self.escalate_on_sync = parse_env_bool(&key, &value)?,
+                "NEXT_STRATEGY" => self.next_strategy = value.parse()?,
+                "FILENAME_SCHEME" => self.filename_scheme = value.parse()?,
+                "READY_INCLUDES" => self.ready_includes = parse_status_list(&key, &value)?,
+                "REQUIRE_ACCEPTANCE_ON_DONE" => {
+                    self.require_acceptance_on_done = parse_env_bool(&key, &value)?
+                }
+                _ => continue,
+            }
+            applied = true;
+        }
+        Ok(applied)
+    }
+
     /// save config to a file path.
     pub fn save(&self, path: &Path) -> Result<()> {
         let content = toml::to_string_pretty(self)
             .map_err(|e| BrdError::Other(format!("failed to serialize config: {e}")))?;
-        std::fs::write(path, content)?;
-        Ok(())
+        crate::atomic::write_atomic(path, content)
     }
 
     /// validate the config.
@@ -166,8 +569,46 @@ impl Config {
                 ),
             ));
         }
+        self.theme.validate()?;
+        self.session_retention()?;
+        if self.escalate_after_days == Some(0) {
+            return Err(BrdError::ParseError(
+                "config".to_string(),
+                "escalate_after_days must be at least 1".to_string(),
+            ));
+        }
+        if self.ready_includes.is_empty()
+            || self
+                .ready_includes
+                .iter()
+                .any(|s| !matches!(s, Status::Open | Status::Doing))
+        {
+            return Err(BrdError::ParseError(
+                "config".to_string(),
+                "ready_includes must list \"open\" and/or \"doing\"".to_string(),
+            ));
+        }
         Ok(())
     }
+
+    /// the parsed `session_retention`, if set.
+    pub fn session_retention(&self) -> Result<Option<time::Duration>> {
+        self.session_retention
+            .as_deref()
+            .map(crate::date::parse_duration)
+            .transpose()
+    }
+}
+
+fn parse_env_bool(key: &str, value: &str) -> Result<bool> {
+    match value.to_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "0" | "false" | "no" | "off" => Ok(false),
+        _ => Err(BrdError::ParseError(
+            key.to_string(),
+            format!("invalid boolean value: {}", value),
+        )),
+    }
 }
 
 /// apply config file migrations. returns true if any migrations were applied.
@@ -268,5 +709,205 @@ mod tests {
         config.id_len = 4;
         config.id_prefix = "x".to_string();
         assert!(config.validate(None).is_err());
+
+        config.id_prefix = "brd".to_string();
+        config.theme.highlight = Some("#0000ff".to_string());
+        assert!(config.validate(None).is_ok());
+
+        config.theme.highlight = Some("puce".to_string());
+        assert!(config.validate(None).is_err());
+
+        config.theme.highlight = None;
+        config.session_retention = Some("14d".to_string());
+        assert!(config.validate(None).is_ok());
+        assert_eq!(
+            config.session_retention().unwrap(),
+            Some(time::Duration::days(14))
+        );
+
+        config.session_retention = Some("two weeks".to_string());
+        assert!(config.validate(None).is_err());
+    }
+
+    #[test]
+    fn test_theme_section_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+
+        // default theme is omitted from the file
+        Config::default().save(&path).unwrap();
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("[theme]"));
+
+        let mut config = Config::default();
+        config.theme.header = Some("blue".to_string());
+        config.save(&path).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("[theme]"));
+
+        let loaded = Config::load(&path).unwrap();
+        assert_eq!(loaded.theme.header.as_deref(), Some("blue"));
+    }
+
+    #[test]
+    fn test_user_config_merges_user_scoped_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "diff_renderer = \"delta\"\n\n[theme]\nhighlight = \"magenta\"\n",
+        )
+        .unwrap();
+        let user = UserConfig::load(&path).unwrap().unwrap();
+
+        let mut config = Config::default();
+        config.theme.header = Some("blue".to_string());
+        config.theme.highlight = Some("yellow".to_string());
+        config.apply_user_config(&user);
+
+        assert_eq!(config.diff_renderer.as_deref(), Some("delta"));
+        assert_eq!(config.theme.highlight.as_deref(), Some("magenta"));
+        // roles the user didn't set keep the repo value
+        assert_eq!(config.theme.header.as_deref(), Some("blue"));
+    }
+
+    #[test]
+    fn test_user_config_rejects_repo_keys_and_tolerates_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        assert_eq!(UserConfig::load(&path).unwrap(), None);
+
+        std::fs::write(&path, "id_prefix = \"mine\"\n").unwrap();
+        let err = UserConfig::load(&path).unwrap_err();
+        assert!(err.to_string().contains("id_prefix"));
+
+        std::fs::write(&path, "[theme]\nready = \"puce\"\n").unwrap();
+        assert!(UserConfig::load(&path).is_err());
+    }
+
+    fn env(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_env_overrides_layer_over_file_values() {
+        let mut config = Config {
+            id_prefix: "file".to_string(),
+            issues_branch: Some("braid-issues".to_string()),
+            ..Default::default()
+        };
+        let applied = config
+            .apply_env_overrides(env(&[
+                ("BRAID_AUTO_PUSH", "false"),
+                ("BRAID_ID_PREFIX", "env"),
+                ("BRAID_ISSUES_BRANCH", ""),
+                ("BRD_AGENT_ID", "not-a-config-option"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+
+        assert!(applied);
+        assert!(!config.auto_push);
+        assert!(config.auto_pull, "untouched options keep file values");
+        assert_eq!(config.id_prefix, "env");
+        assert_eq!(config.issues_branch, None);
+    }
+
+    #[test]
+    fn test_env_overrides_accept_brd_alias() {
+        let mut config = Config::default();
+        let applied = config
+            .apply_env_overrides(env(&[
+                ("BRAID_ID_PREFIX", "braid"),
+                ("BRD_ID_PREFIX", "brd-alias"),
+                ("BRD_AUTO_PUSH", "false"),
+            ]))
+            .unwrap();
+
+        assert!(applied);
+        assert!(!config.auto_push);
+        assert_eq!(config.id_prefix, "braid", "BRAID_ wins over the alias");
+    }
+
+    #[test]
+    fn test_env_overrides_none_set() {
+        let mut config = Config::default();
+        let applied = config
+            .apply_env_overrides(env(&[("HOME", "/root")]))
+            .unwrap();
+        assert!(!applied);
+        assert_eq!(config.id_prefix, "brd");
+    }
+
+    #[test]
+    fn test_env_overrides_reject_bad_values() {
+        let mut config = Config::default();
+        assert!(
+            config
+                .apply_env_overrides(env(&[("BRD_AUTO_PULL", "maybe")]))
+                .is_err()
+        );
+        assert!(
+            config
+                .apply_env_overrides(env(&[("BRD_ID_LEN", "six")]))
+                .is_err()
+        );
+
+        // parses, but the merged config is out of range
+        config
+            .apply_env_overrides(env(&[("BRD_ID_LEN", "20")]))
+            .unwrap();
+        assert!(config.validate(None).is_err());
+    }
+
+    #[test]
+    fn test_commit_mode_round_trip() {
+        let config: Config = toml::from_str(
+            "schema_version = 9\nid_prefix = \"brd\"\nid_len = 4\ncommit_mode = \"amend\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.commit_mode, CommitMode::Amend);
+        assert!(
+            toml::to_string(&config)
+                .unwrap()
+                .contains("commit_mode = \"amend\"")
+        );
+
+        // the default stays out of config.toml
+        let config = Config::default();
+        assert!(!toml::to_string(&config).unwrap().contains("commit_mode"));
+        assert!("squash".parse::<CommitMode>().is_err());
+    }
+
+    #[test]
+    fn test_ready_includes_parse_and_validate() {
+        let base = "schema_version = 9\nid_prefix = \"brd\"\nid_len = 4\n";
+        let config: Config = toml::from_str(base).unwrap();
+        assert_eq!(config.ready_includes, vec![Status::Open]);
+        assert!(!toml::to_string(&config).unwrap().contains("ready_includes"));
+
+        let config: Config =
+            toml::from_str(&format!("{}ready_includes = [\"open\", \"doing\"]\n", base)).unwrap();
+        assert_eq!(config.ready_includes, vec![Status::Open, Status::Doing]);
+        config.validate(None).unwrap();
+
+        for bad in ["[]", "[\"done\"]"] {
+            let config: Config =
+                toml::from_str(&format!("{}ready_includes = {}\n", base, bad)).unwrap();
+            assert!(config.validate(None).is_err(), "{} should be rejected", bad);
+        }
+
+        let mut config = Config::default();
+        config
+            .apply_env_overrides(env(&[("BRD_READY_INCLUDES", "doing")]))
+            .unwrap();
+        assert_eq!(config.ready_includes, vec![Status::Doing]);
+        assert!(
+            config
+                .apply_env_overrides(env(&[("BRD_READY_INCLUDES", "open,todo")]))
+                .is_err()
+        );
     }
 }
@@ -1,4 +1,4 @@
-//! date parsing utilities for scheduled_for field.
+//! date and duration parsing utilities.
 
 use time::{Duration, OffsetDateTime, Time};
 
@@ -7,6 +7,7 @@ use crate::error::{BrdError, Result};
 /// parse a date string into an OffsetDateTime.
 /// supports:
 /// - ISO dates: "2025-02-15" (midnight UTC)
+/// - relative seconds, minutes, hours: "+30s", "+15m", "+3h"
 /// - relative days: "+7d" (7 days from now)
 /// - relative weeks: "+2w" (2 weeks from now)
 /// - relative months: "+1mo" (1 month from now, approximated as 30 days)
@@ -20,24 +21,8 @@ pub fn parse_scheduled_date(input: &str) -> Result<OffsetDateTime> {
         return Ok(tomorrow.replace_time(Time::MIDNIGHT));
     }
 
-    // relative formats: +Nd, +Nw, +Nmo
+    // relative formats: +Nmo, or +N with any parse_duration suffix (+7d, +2w, ...)
     if let Some(rest) = input.strip_prefix('+') {
-        if let Some(days_str) = rest.strip_suffix('d') {
-            let days: i64 = days_str.parse().map_err(|_| {
-                BrdError::ParseError("date".into(), format!("invalid days: {}", rest))
-            })?;
-            let target = OffsetDateTime::now_utc() + Duration::days(days);
-            return Ok(target);
-        }
-
-        if let Some(weeks_str) = rest.strip_suffix('w') {
-            let weeks: i64 = weeks_str.parse().map_err(|_| {
-                BrdError::ParseError("date".into(), format!("invalid weeks: {}", rest))
-            })?;
-            let target = OffsetDateTime::now_utc() + Duration::weeks(weeks);
-            return Ok(target);
-        }
-
         if let Some(months_str) = rest.strip_suffix("mo") {
             let months: i64 = months_str.parse().map_err(|_| {
                 BrdError::ParseError("date".into(), format!("invalid months: {}", rest))
@@ -47,23 +32,24 @@ pub fn parse_scheduled_date(input: &str) -> Result<OffsetDateTime> {
             return Ok(target);
         }
 
-        return Err(BrdError::ParseError(
-            "date".into(),
-            format!(
-                "invalid relative format '{}'. use +Nd, +Nw, or +Nmo (e.g., +7d, +2w, +1mo)",
-                input
-            ),
-        ));
+        let duration = parse_duration(rest).map_err(|_| {
+            BrdError::ParseError(
+                "date".into(),
+                format!(
+                    "invalid relative format '{}'. use +N followed by s, m, h, d, w, or mo (e.g., +3h, +7d, +2w, +1mo)",
+                    input
+                ),
+            )
+        })?;
+        return OffsetDateTime::now_utc()
+            .checked_add(duration)
+            .ok_or_else(|| {
+                BrdError::ParseError("date".into(), format!("out of range: {}", input))
+            });
     }
 
-    // ISO date format: YYYY-MM-DD
-    if input.len() == 10 && input.chars().nth(4) == Some('-') && input.chars().nth(7) == Some('-') {
-        let format = time::format_description::parse("[year]-[month]-[day]")
-            .map_err(|e| BrdError::ParseError("date".into(), format!("format error: {}", e)))?;
-        let date = time::Date::parse(&input, &format).map_err(|e| {
-            BrdError::ParseError("date".into(), format!("invalid date '{}': {}", input, e))
-        })?;
-        return Ok(date.with_time(Time::MIDNIGHT).assume_utc());
+    if let Some(date) = parse_iso_date(&input) {
+        return date;
     }
 
     Err(BrdError::ParseError(
@@ -75,6 +61,119 @@ pub fn parse_scheduled_date(input: &str) -> Result<OffsetDateTime> {
     ))
 }
 
+/// parse a past point in time for filters.
+/// supports ISO dates ("2025-02-15", midnight UTC) and durations ago ("7d", "2w").
+pub fn parse_past_date(input: &str) -> Result<OffsetDateTime> {
+    let input = input.trim();
+    if let Some(date) = parse_iso_date(input) {
+        return date;
+    }
+
+    let ago = parse_duration(input).map_err(|_| {
+        BrdError::ParseError(
+            "date".into(),
+            format!(
+                "invalid date '{}'. use YYYY-MM-DD or a duration ago like 3h, 7d, 2w",
+                input
+            ),
+        )
+    })?;
+    OffsetDateTime::now_utc()
+        .checked_sub(ago)
+        .ok_or_else(|| BrdError::ParseError("date".into(), format!("out of range: {}", input)))
+}
+
+/// parse a YYYY-MM-DD date at midnight UTC.
+/// returns None if the input isn't shaped like an ISO date.
+fn parse_iso_date(input: &str) -> Option<Result<OffsetDateTime>> {
+    if input.len() != 10 || input.chars().nth(4) != Some('-') || input.chars().nth(7) != Some('-') {
+        return None;
+    }
+    let format = match time::format_description::parse("[year]-[month]-[day]") {
+        Ok(format) => format,
+        Err(e) => {
+            return Some(Err(BrdError::ParseError(
+                "date".into(),
+                format!("format error: {}", e),
+            )));
+        }
+    };
+    Some(
+        time::Date::parse(input, &format)
+            .map(|date| date.with_time(Time::MIDNIGHT).assume_utc())
+            .map_err(|e| {
+                BrdError::ParseError("date".into(), format!("invalid date '{}': {}", input, e))
+            }),
+    )
+}
+
+/// parse a relative duration like "30s", "15m", "3h", "7d", or "2w".
+///
+/// the input must be a non-negative integer followed by exactly one unit suffix.
+/// compound ("1d2h"), unitless ("5"), and ambiguous ("5mo", "5M") inputs are rejected.
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    let invalid = |reason: &str| {
+        BrdError::ParseError(
+            "duration".into(),
+            format!("{reason} '{input}'. use N followed by s, m, h, d, or w (e.g., 3h, 7d, 2w)"),
+        )
+    };
+
+    if input.is_empty() {
+        return Err(invalid("empty duration"));
+    }
+
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| invalid("missing unit in"))?;
+    let (amount, unit) = input.split_at(split);
+    if amount.is_empty() {
+        return Err(invalid("missing amount in"));
+    }
+
+    let unit_seconds: i64 = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => return Err(invalid("invalid unit in")),
+    };
+
+    let seconds = amount
+        .parse::<i64>()
+        .ok()
+        .and_then(|n| n.checked_mul(unit_seconds))
+        .ok_or_else(|| invalid("duration too large:"))?;
+
+    Ok(Duration::seconds(seconds))
+}
+
+/// format a duration compactly with up to two units, e.g. "45s", "12m", "3h 5m", "2d 4h".
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.whole_seconds().max(0);
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+
+    if days > 0 {
+        if hours > 0 {
+            format!("{days}d {hours}h")
+        } else {
+            format!("{days}d")
+        }
+    } else if hours > 0 {
+        if minutes > 0 {
+            format!("{hours}h {minutes}m")
+        } else {
+            format!("{hours}h")
+        }
+    } else if minutes > 0 {
+        format!("{minutes}m")
+    } else {
+        format!("{secs}s")
+    }
+}
+
 /// format a future scheduled date for display.
 /// returns "in Xh", "in Xd", "in Xw", or "in Xmo" format.
 pub fn format_scheduled(scheduled_for: OffsetDateTime) -> String {
@@ -155,10 +254,76 @@ mod tests {
     #[test]
     fn test_parse_invalid() {
         assert!(parse_scheduled_date("invalid").is_err());
-        assert!(parse_scheduled_date("+abc").is_err());
+        let err = parse_scheduled_date("+abc").unwrap_err().to_string();
+        assert!(err.contains("s, m, h, d, w, or mo"), "{err}");
         assert!(parse_scheduled_date("2025-13-01").is_err());
     }
 
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("30s").unwrap(), Duration::seconds(30));
+        assert_eq!(parse_duration("15m").unwrap(), Duration::minutes(15));
+        assert_eq!(parse_duration("3h").unwrap(), Duration::hours(3));
+        assert_eq!(parse_duration("7d").unwrap(), Duration::days(7));
+        assert_eq!(parse_duration("2w").unwrap(), Duration::weeks(2));
+        assert_eq!(parse_duration(" 0d ").unwrap(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_parse_duration_rejects_bad_input() {
+        for input in [
+            "", "  ", "d", "5", "-5d", "+5d", "1.5h", "5x", "5mo", "5M", "1d2h", "5 d", "abc",
+        ] {
+            assert!(
+                parse_duration(input).is_err(),
+                "expected error for {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_overflow() {
+        assert!(parse_duration("99999999999999999999s").is_err());
+        assert!(parse_duration(&format!("{}w", i64::MAX / 2)).is_err());
+        let max_secs = format!("{}s", i64::MAX);
+        assert_eq!(
+            parse_duration(&max_secs).unwrap(),
+            Duration::seconds(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_parse_past_date() {
+        let date = parse_past_date("2025-02-15").unwrap();
+        assert_eq!((date.year(), date.day()), (2025, 15));
+        assert_eq!(date.time(), Time::MIDNIGHT);
+
+        let diff = OffsetDateTime::now_utc() - parse_past_date("7d").unwrap();
+        assert!(diff.whole_days() >= 6 && diff.whole_days() <= 7);
+
+        assert!(parse_past_date("+7d").is_err());
+        assert!(parse_past_date("2025-13-01").is_err());
+        assert!(parse_past_date("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::seconds(45)), "45s");
+        assert_eq!(format_duration(Duration::minutes(12)), "12m");
+        assert_eq!(format_duration(Duration::minutes(185)), "3h 5m");
+        assert_eq!(format_duration(Duration::hours(3)), "3h");
+        assert_eq!(format_duration(Duration::hours(52)), "2d 4h");
+        assert_eq!(format_duration(Duration::days(2)), "2d");
+        assert_eq!(format_duration(Duration::seconds(-5)), "0s");
+    }
+
+    #[test]
+    fn test_parse_relative_hours() {
+        let result = parse_scheduled_date("+3h").unwrap();
+        let diff = result - OffsetDateTime::now_utc();
+        assert!(diff.whole_hours() >= 2 && diff.whole_hours() <= 3);
+    }
+
     #[test]
     fn test_format_scheduled_hours() {
         let now = OffsetDateTime::now_utc();
@@ -16,6 +16,9 @@ pub enum ExitCode {
     ParseError = 16,
     NotInitialized = 17,
     AlreadyInitialized = 18,
+    SchemaMismatch = 20,
+    DirtyWorkingTree = 21,
+    LockHeld = 22,
 }
 
 impl From<ExitCode> for i32 {
@@ -38,10 +41,15 @@ pub enum BrdError {
     #[error("issue not found: {0}")]
     IssueNotFound(String),
 
-    #[error("ambiguous issue id '{0}': matches {1:?}")]
-    AmbiguousId(String, Vec<String>),
+    /// a partial id matched several issues, given as (id, title) pairs.
+    #[error(
+        "ambiguous issue id '{0}' matches {count} issues:{list}\nhint: use more of the id",
+        count = .1.len(),
+        list = format_candidates(.1)
+    )]
+    AmbiguousId(String, Vec<(String, String)>),
 
-    #[error("claim conflict: issue '{0}' is claimed by '{1}'")]
+    #[error("issue {0} is already being worked on by '{1}' (use --force to reassign)")]
     ClaimConflict(String, String),
 
     #[error("invalid graph: cycle detected")]
@@ -62,8 +70,81 @@ pub enum BrdError {
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// bad arguments or flag combinations.
+    #[error("{0}")]
+    InvalidInput(String),
+
+    /// the command needs a terminal and stdin is not one.
+    #[error("{0}")]
+    NotInteractive(String),
+
+    /// a dependency change would introduce a cycle.
+    #[error("{0}")]
+    DependencyCycle(String),
+
+    /// the issue cannot move on until other issues do.
+    #[error("{0}")]
+    IssueBlocked(String),
+
+    #[error("no ready issues")]
+    NoReadyIssues,
+
+    /// someone else changed things first: a rejected push, a rebase with
+    /// conflicts, an issue or agent that is still busy.
+    #[error("{0}")]
+    Conflict(String),
+
+    /// uncommitted changes are in the way.
+    #[error("{0}")]
+    DirtyWorkingTree(String),
+
+    /// another brd process kept the lock for too long.
+    #[error("{0}")]
+    LockHeld(String),
+
+    /// the repo or its issues are at a schema this brd can't work with.
+    #[error("{0}")]
+    SchemaMismatch(String),
+
+    /// a git command failed.
+    #[error("{0}")]
+    Git(String),
+
+    /// an external tool (gh, claude) is missing or unusable.
+    #[error("{0}")]
+    ToolNotFound(String),
+
+    /// config is missing, invalid, or doesn't allow the operation.
+    #[error("{0}")]
+    Config(String),
+
+    #[error("{0}")]
+    AlreadyExists(String),
+
+    /// not available on this platform.
+    #[error("{0}")]
+    Unsupported(String),
+
+    #[error("cancelled")]
+    Cancelled,
+
+    /// anything without a more specific variant, mostly internal failures.
     #[error("{0}")]
     Other(String),
+
+    /// an error the command already reported in its own output, such as the
+    /// `errors` list of a `--json` document. only sets the exit code.
+    #[error("{0}")]
+    Reported(Box<BrdError>),
+}
+
+/// one indented `id  title` line per candidate issue.
+pub fn format_candidates(candidates: &[(String, String)]) -> String {
+    let width = candidates.iter().map(|(id, _)| id.len()).max().unwrap_or(0);
+    candidates
+        .iter()
+        .map(|(id, title)| format!("\n  {:<width$}  {}", id, title))
+        .collect()
 }
 
 impl BrdError {
@@ -80,8 +161,23 @@ impl BrdError {
             BrdError::ParseError(_, _) => ExitCode::ParseError,
             BrdError::AlreadyInitialized => ExitCode::AlreadyInitialized,
             BrdError::Io(_) => ExitCode::GenericFailure,
-            BrdError::Json(_) => ExitCode::GenericFailure,
-            BrdError::Other(_) => ExitCode::GenericFailure,
+            BrdError::InvalidInput(_) | BrdError::NotInteractive(_) => ExitCode::UsageError,
+            BrdError::DependencyCycle(_) => ExitCode::InvalidGraph,
+            BrdError::SchemaMismatch(_) => ExitCode::SchemaMismatch,
+            BrdError::DirtyWorkingTree(_) => ExitCode::DirtyWorkingTree,
+            BrdError::LockHeld(_) => ExitCode::LockHeld,
+            BrdError::Reported(e) => e.exit_code(),
+            BrdError::Json(_)
+            | BrdError::IssueBlocked(_)
+            | BrdError::NoReadyIssues
+            | BrdError::Conflict(_)
+            | BrdError::Git(_)
+            | BrdError::ToolNotFound(_)
+            | BrdError::Config(_)
+            | BrdError::AlreadyExists(_)
+            | BrdError::Unsupported(_)
+            | BrdError::Cancelled
+            | BrdError::Other(_) => ExitCode::GenericFailure,
         }
     }
 
@@ -99,13 +195,185 @@ impl BrdError {
             BrdError::AlreadyInitialized => "already_initialized",
             BrdError::Io(_) => "io_error",
             BrdError::Json(_) => "json_error",
+            BrdError::InvalidInput(_) => "invalid_input",
+            BrdError::NotInteractive(_) => "not_interactive",
+            BrdError::DependencyCycle(_) => "dependency_cycle",
+            BrdError::IssueBlocked(_) => "issue_blocked",
+            BrdError::NoReadyIssues => "no_ready_issues",
+            BrdError::Conflict(_) => "conflict",
+            BrdError::DirtyWorkingTree(_) => "dirty_working_tree",
+            BrdError::LockHeld(_) => "lock_held",
+            BrdError::SchemaMismatch(_) => "schema_mismatch",
+            BrdError::Git(_) => "git_error",
+            BrdError::ToolNotFound(_) => "tool_not_found",
+            BrdError::Config(_) => "config_error",
+            BrdError::AlreadyExists(_) => "already_exists",
+            BrdError::Unsupported(_) => "unsupported",
+            BrdError::Cancelled => "cancelled",
             BrdError::Other(_) => "error",
+            BrdError::Reported(e) => e.code_str(),
         }
     }
 }
 
 pub type Result<T> = std::result::Result<T, BrdError>;
 
+/// one documented error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub exit: ExitCode,
+    pub meaning: &'static str,
+}
+
+const fn error_code(code: &'static str, exit: ExitCode, meaning: &'static str) -> ErrorCode {
+    ErrorCode {
+        code,
+        exit,
+        meaning,
+    }
+}
+
+/// every `code_str` brd can return, for `brd errors` and docs/errors.md.
+pub const ERROR_CODES: &[ErrorCode] = &[
+    error_code(
+        "not_git_repo",
+        ExitCode::NotGitRepo,
+        "not inside a git repository",
+    ),
+    error_code(
+        "control_root_invalid",
+        ExitCode::ControlRootInvalid,
+        "the control root is missing or invalid",
+    ),
+    error_code(
+        "issue_not_found",
+        ExitCode::IssueNotFound,
+        "no issue matches the id",
+    ),
+    error_code(
+        "ambiguous_id",
+        ExitCode::AmbiguousId,
+        "a partial id matches several issues",
+    ),
+    error_code(
+        "claim_conflict",
+        ExitCode::ClaimConflict,
+        "`brd start` on an issue someone else is working on",
+    ),
+    error_code(
+        "invalid_graph",
+        ExitCode::InvalidGraph,
+        "the dependency graph has a cycle",
+    ),
+    error_code(
+        "dependency_cycle",
+        ExitCode::InvalidGraph,
+        "a dependency change would create a cycle",
+    ),
+    error_code(
+        "parse_error",
+        ExitCode::ParseError,
+        "an issue or file could not be parsed",
+    ),
+    error_code(
+        "not_initialized",
+        ExitCode::NotInitialized,
+        "braid is not set up in this repo",
+    ),
+    error_code(
+        "already_initialized",
+        ExitCode::AlreadyInitialized,
+        "`brd init` in a repo that already has braid",
+    ),
+    error_code(
+        "session_not_found",
+        ExitCode::SessionNotFound,
+        "no agent session matches the id",
+    ),
+    error_code(
+        "schema_mismatch",
+        ExitCode::SchemaMismatch,
+        "the repo is at a newer schema than this brd, or an agent worktree's issues need `brd migrate`",
+    ),
+    error_code(
+        "dirty_working_tree",
+        ExitCode::DirtyWorkingTree,
+        "uncommitted changes are in the way",
+    ),
+    error_code(
+        "lock_held",
+        ExitCode::LockHeld,
+        "another brd process held the lock for over 30s",
+    ),
+    error_code(
+        "invalid_input",
+        ExitCode::UsageError,
+        "bad arguments or flag combinations",
+    ),
+    error_code(
+        "not_interactive",
+        ExitCode::UsageError,
+        "the command needs a terminal",
+    ),
+    error_code(
+        "issue_blocked",
+        ExitCode::GenericFailure,
+        "the issue can't move on yet, e.g. a meta issue with open children",
+    ),
+    error_code(
+        "no_ready_issues",
+        ExitCode::GenericFailure,
+        "`brd start` without an id and nothing is ready",
+    ),
+    error_code(
+        "conflict",
+        ExitCode::GenericFailure,
+        "rejected push, rebase conflict, or an issue or agent that is still busy",
+    ),
+    error_code(
+        "git_error",
+        ExitCode::GenericFailure,
+        "a git command failed",
+    ),
+    error_code(
+        "tool_not_found",
+        ExitCode::GenericFailure,
+        "`gh` or `claude` is missing or unusable",
+    ),
+    error_code(
+        "config_error",
+        ExitCode::GenericFailure,
+        "config is missing, invalid, or doesn't allow the operation",
+    ),
+    error_code(
+        "already_exists",
+        ExitCode::GenericFailure,
+        "the branch, file, or setting already exists",
+    ),
+    error_code(
+        "unsupported",
+        ExitCode::GenericFailure,
+        "not available on this platform",
+    ),
+    error_code(
+        "cancelled",
+        ExitCode::GenericFailure,
+        "the interactive picker was dismissed",
+    ),
+    error_code("io_error", ExitCode::GenericFailure, "filesystem error"),
+    error_code(
+        "json_error",
+        ExitCode::GenericFailure,
+        "JSON serialization error",
+    ),
+    error_code(
+        "error",
+        ExitCode::GenericFailure,
+        "anything else, mostly internal failures",
+    ),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,7 +404,7 @@ mod tests {
             ExitCode::IssueNotFound
         );
         assert_eq!(
-            BrdError::AmbiguousId("brd-".into(), vec!["brd-a".into()]).exit_code(),
+            BrdError::AmbiguousId("brd-".into(), vec![("brd-a".into(), "a".into())]).exit_code(),
             ExitCode::AmbiguousId
         );
         assert_eq!(
@@ -175,7 +443,7 @@ mod tests {
             "issue_not_found"
         );
         assert_eq!(
-            BrdError::AmbiguousId("brd-".into(), vec!["brd-a".into()]).code_str(),
+            BrdError::AmbiguousId("brd-".into(), vec![("brd-a".into(), "a".into())]).code_str(),
             "ambiguous_id"
         );
         assert_eq!(
@@ -197,4 +465,166 @@ mod tests {
         );
         assert_eq!(BrdError::Other("oops".into()).code_str(), "error");
     }
+
+    #[test]
+    fn test_specific_variants_have_distinct_codes() {
+        let errors = [
+            BrdError::InvalidInput("x".into()),
+            BrdError::NotInteractive("x".into()),
+            BrdError::DependencyCycle("x".into()),
+            BrdError::IssueBlocked("x".into()),
+            BrdError::NoReadyIssues,
+            BrdError::Conflict("x".into()),
+            BrdError::DirtyWorkingTree("x".into()),
+            BrdError::Git("x".into()),
+            BrdError::ToolNotFound("x".into()),
+            BrdError::Config("x".into()),
+            BrdError::AlreadyExists("x".into()),
+            BrdError::Unsupported("x".into()),
+            BrdError::Cancelled,
+        ];
+        let mut codes: Vec<_> = errors.iter().map(|e| e.code_str()).collect();
+        assert!(!codes.contains(&"error"));
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+
+        assert_eq!(
+            BrdError::InvalidInput("x".into()).exit_code(),
+            ExitCode::UsageError
+        );
+        assert_eq!(
+            BrdError::DependencyCycle("x".into()).exit_code(),
+            ExitCode::InvalidGraph
+        );
+    }
+
+    /// one error of each variant. the match stops compiling when a variant
+    /// is added, as a reminder to document its code.
+    fn one_of_each() -> Vec<BrdError> {
+        let errors = vec![
+            BrdError::NotGitRepo,
+            BrdError::NotInitialized,
+            BrdError::ControlRootInvalid("x".into()),
+            BrdError::IssueNotFound("x".into()),
+            BrdError::AmbiguousId("x".into(), vec![]),
+            BrdError::ClaimConflict("x".into(), "y".into()),
+            BrdError::InvalidGraph,
+            BrdError::SessionNotFound("x".into()),
+            BrdError::ParseError("x".into(), "y".into()),
+            BrdError::AlreadyInitialized,
+            BrdError::Io(std::io::Error::other("x")),
+            BrdError::Json(serde_json::from_str::<u8>("x").unwrap_err()),
+            BrdError::InvalidInput("x".into()),
+            BrdError::NotInteractive("x".into()),
+            BrdError::DependencyCycle("x".into()),
+            BrdError::IssueBlocked("x".into()),
+            BrdError::NoReadyIssues,
+            BrdError::Conflict("x".into()),
+            BrdError::DirtyWorkingTree("x".into()),
+            BrdError::LockHeld("x".into()),
+            BrdError::SchemaMismatch("x".into()),
+            BrdError::Git("x".into()),
+            BrdError::ToolNotFound("x".into()),
+            BrdError::Config("x".into()),
+            BrdError::AlreadyExists("x".into()),
+            BrdError::Unsupported("x".into()),
+            BrdError::Cancelled,
+            BrdError::Other("x".into()),
+            BrdError::Reported(Box::new(BrdError::Other("x".into()))),
+        ];
+        for error in &errors {
+            match error {
+                BrdError::NotGitRepo
+                | BrdError::NotInitialized
+                | BrdError::ControlRootInvalid(_)
+                | BrdError::IssueNotFound(_)
+                | BrdError::AmbiguousId(_, _)
+                | BrdError::ClaimConflict(_, _)
+                | BrdError::InvalidGraph
+                | BrdError::SessionNotFound(_)
+                | BrdError::ParseError(_, _)
+                | BrdError::AlreadyInitialized
+                | BrdError::Io(_)
+                | BrdError::Json(_)
+                | BrdError::InvalidInput(_)
+                | BrdError::NotInteractive(_)
+                | BrdError::DependencyCycle(_)
+                | BrdError::IssueBlocked(_)
+                | BrdError::NoReadyIssues
+                | BrdError::Conflict(_)
+                | BrdError::DirtyWorkingTree(_)
+                | BrdError::LockHeld(_)
+                | BrdError::SchemaMismatch(_)
+                | BrdError::Git(_)
+                | BrdError::ToolNotFound(_)
+                | BrdError::Config(_)
+                | BrdError::AlreadyExists(_)
+                | BrdError::Unsupported(_)
+                | BrdError::Cancelled
+                | BrdError::Other(_)
+                | BrdError::Reported(_) => {}
+            }
+        }
+        errors
+    }
+
+    #[test]
+    fn test_error_codes_cover_every_variant() {
+        let errors = one_of_each();
+        for error in &errors {
+            let entry = ERROR_CODES
+                .iter()
+                .find(|entry| entry.code == error.code_str())
+                .unwrap_or_else(|| panic!("{} is not in ERROR_CODES", error.code_str()));
+            assert_eq!(entry.exit, error.exit_code(), "{}", entry.code);
+        }
+        // and nothing stale is listed; a reported error reuses its inner code
+        let codes: std::collections::HashSet<_> = errors.iter().map(|e| e.code_str()).collect();
+        assert_eq!(ERROR_CODES.len(), codes.len());
+    }
+
+    #[test]
+    fn test_scripted_failure_modes_have_own_exit_codes() {
+        let errors = [
+            BrdError::IssueNotFound("x".into()),
+            BrdError::AmbiguousId("x".into(), vec![]),
+            BrdError::DependencyCycle("x".into()),
+            BrdError::DirtyWorkingTree("x".into()),
+            BrdError::LockHeld("x".into()),
+            BrdError::SchemaMismatch("x".into()),
+        ];
+        let mut exits: Vec<i32> = errors.iter().map(|e| e.exit_code().into()).collect();
+        assert!(!exits.contains(&1));
+        exits.sort();
+        exits.dedup();
+        assert_eq!(exits.len(), errors.len());
+    }
+
+    #[test]
+    fn test_docs_list_every_error_code() {
+        let docs = include_str!("../docs/errors.md");
+        let rows: Vec<(String, i32, String)> = docs
+            .lines()
+            .filter_map(|line| {
+                let cells: Vec<_> = line.trim().strip_prefix("| `")?.split(" | ").collect();
+                let [code, exit, meaning] = cells[..] else {
+                    return None;
+                };
+                Some((
+                    code.trim_end_matches('`').to_string(),
+                    exit.parse().ok()?,
+                    meaning.trim_end_matches(" |").to_string(),
+                ))
+            })
+            .collect();
+        let expected: Vec<(String, i32, String)> = ERROR_CODES
+            .iter()
+            .map(|e| (e.code.to_string(), e.exit.into(), e.meaning.to_string()))
+            .collect();
+        assert_eq!(
+            rows, expected,
+            "docs/errors.md is out of date; see `brd errors`"
+        );
+    }
 }
@@ -92,21 +92,32 @@ pub struct ParsedDiff {
     pub hunks: Vec<DiffHunk>,
 }
 
+/// Set on every git command brd runs, so hooks can tell brd's own commits
+/// apart from the user's.
+pub const INTERNAL_ENV: &str = "BRD_INTERNAL";
+
+/// Build a git command in `cwd`, marked with [`INTERNAL_ENV`].
+pub fn command(args: &[&str], cwd: &Path) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.args(args).current_dir(cwd).env(INTERNAL_ENV, "1");
+    cmd
+}
+
 /// Run a git command and return whether it succeeded.
 pub fn run(args: &[&str], cwd: &Path) -> Result<bool> {
-    let output = Command::new("git").args(args).current_dir(cwd).output()?;
+    let output = command(args, cwd).output()?;
     Ok(output.status.success())
 }
 
 /// Run a git command and return stdout as a string.
 pub fn output(args: &[&str], cwd: &Path) -> Result<String> {
-    let output = Command::new("git").args(args).current_dir(cwd).output()?;
+    let output = command(args, cwd).output()?;
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
 /// Run a git command and return the full output.
 pub fn run_full(args: &[&str], cwd: &Path) -> Result<Output> {
-    let output = Command::new("git").args(args).current_dir(cwd).output()?;
+    let output = command(args, cwd).output()?;
     Ok(output)
 }
 
@@ -120,7 +131,7 @@ pub fn is_clean(cwd: &Path) -> Result<bool> {
 pub fn current_branch(cwd: &Path) -> Result<String> {
     let branch = output(&["rev-parse", "--abbrev-ref", "HEAD"], cwd)?;
     if branch.is_empty() {
-        return Err(BrdError::Other("failed to get current branch".to_string()));
+        return Err(BrdError::Git("failed to get current branch".to_string()));
     }
     Ok(branch)
 }
@@ -136,16 +147,28 @@ pub fn has_remote_branch(cwd: &Path, remote: &str, branch: &str) -> bool {
     run(&["rev-parse", "--verify", &refspec], cwd).unwrap_or(false)
 }
 
+/// Get a remote's default branch name (e.g. "main") from `<remote>/HEAD`,
+/// falling back to `main` or `master` when that ref isn't set.
+pub fn remote_default_branch(cwd: &Path, remote: &str) -> Option<String> {
+    let head = format!("refs/remotes/{}/HEAD", remote);
+    let prefix = format!("{}/", remote);
+    let resolved = output(&["symbolic-ref", "--short", &head], cwd).unwrap_or_default();
+    if let Some(branch) = resolved.strip_prefix(&prefix) {
+        return Some(branch.to_string());
+    }
+    ["main", "master"]
+        .into_iter()
+        .find(|b| has_remote_branch(cwd, remote, b))
+        .map(String::from)
+}
+
 /// Run git rev-parse with the given arguments.
 /// Arguments are split on whitespace to support multi-arg calls like "--abbrev-ref HEAD".
 pub fn rev_parse(cwd: &Path, args: &str) -> Result<String> {
     let mut cmd_args = vec!["rev-parse"];
     cmd_args.extend(args.split_whitespace());
 
-    let out = Command::new("git")
-        .args(&cmd_args)
-        .current_dir(cwd)
-        .output()?;
+    let out = command(&cmd_args, cwd).output()?;
 
     if !out.status.success() {
         return Err(BrdError::NotGitRepo);
@@ -154,6 +177,42 @@ pub fn rev_parse(cwd: &Path, args: &str) -> Result<String> {
     Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
 }
 
+/// Check if HEAD points at a commit. false in a freshly `git init`'d repo.
+pub fn has_commits(cwd: &Path) -> bool {
+    run(&["rev-parse", "--verify", "--quiet", "HEAD"], cwd).unwrap_or(false)
+}
+
+/// Fail with guidance when the repo has no commits yet, since branching,
+/// rebasing, and pushing all need one.
+pub fn require_commits(cwd: &Path) -> Result<()> {
+    if has_commits(cwd) {
+        return Ok(());
+    }
+    Err(BrdError::Git(
+        "this repository has no commits yet\n\n\
+         make an initial commit first, e.g. `git commit --allow-empty -m \"initial commit\"`"
+            .to_string(),
+    ))
+}
+
+/// Check if HEAD is detached, e.g. after checking out a tag or mid-bisect.
+pub fn is_detached_head(cwd: &Path) -> bool {
+    output(&["rev-parse", "--abbrev-ref", "HEAD"], cwd).is_ok_and(|branch| branch == "HEAD")
+}
+
+/// Fail with guidance when HEAD is detached, since commits made there end up
+/// on no branch and pushes have nothing to push.
+pub fn require_branch(cwd: &Path) -> Result<()> {
+    if !is_detached_head(cwd) {
+        return Ok(());
+    }
+    Err(BrdError::Git(
+        "HEAD is detached (not on any branch)\n\n\
+         check out a branch first, e.g. `git switch main`"
+            .to_string(),
+    ))
+}
+
 /// Check if a branch exists.
 pub fn branch_exists(cwd: &Path, branch: &str) -> bool {
     run(&["rev-parse", "--verify", branch], cwd).unwrap_or(false)
@@ -176,7 +235,31 @@ pub fn stash_push(cwd: &Path, message: &str) -> Result<bool> {
         &["stash", "push", "--include-untracked", "-m", message],
         cwd,
     )? {
-        return Err(BrdError::Other("failed to stash changes".to_string()));
+        return Err(BrdError::Git("failed to stash changes".to_string()));
+    }
+    let after = stash_count(cwd)?;
+    Ok(after > before)
+}
+
+/// Stash changes outside `exclude` (a path relative to `cwd`), leaving that
+/// path's changes in the working tree. Returns true if a stash was created.
+pub fn stash_push_excluding(cwd: &Path, message: &str, exclude: &str) -> Result<bool> {
+    let before = stash_count(cwd)?;
+    let exclude = format!(":(exclude){}", exclude);
+    if !run(
+        &[
+            "stash",
+            "push",
+            "--include-untracked",
+            "-m",
+            message,
+            "--",
+            ".",
+            &exclude,
+        ],
+        cwd,
+    )? {
+        return Err(BrdError::Git("failed to stash changes".to_string()));
     }
     let after = stash_count(cwd)?;
     Ok(after > before)
@@ -530,6 +613,49 @@ pub fn log_commits(cwd: &Path, branch: &str, limit: usize) -> Result<Vec<CommitI
     Ok(commits)
 }
 
+/// Metadata for a single commit, as shown in an issue's commit log.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct CommitDetails {
+    /// full SHA
+    pub sha: String,
+    /// short SHA (first 7 chars)
+    pub short_sha: String,
+    /// author date (YYYY-MM-DD)
+    pub date: String,
+    /// author name
+    pub author: String,
+    /// first line of commit message
+    pub subject: String,
+}
+
+/// Look up a commit by SHA. Returns None if the object doesn't exist (e.g. it
+/// was rewritten by a rebase and garbage collected).
+pub fn commit_details(cwd: &Path, sha: &str) -> Result<Option<CommitDetails>> {
+    let spec = format!("{}^{{commit}}", sha);
+    let out = run_full(
+        &["show", "-s", "--format=%H%x09%as%x09%an%x09%s", &spec, "--"],
+        cwd,
+    )?;
+    if !out.status.success() {
+        return Ok(None);
+    }
+
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let mut fields = stdout.trim_end_matches('\n').splitn(4, '\t');
+    let (Some(sha), Some(date), Some(author), Some(subject)) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return Ok(None);
+    };
+    Ok(Some(CommitDetails {
+        sha: sha.to_string(),
+        short_sha: sha.chars().take(7).collect(),
+        date: date.to_string(),
+        author: author.to_string(),
+        subject: subject.to_string(),
+    }))
+}
+
 /// Count commits between two refs (base..head).
 pub fn commit_count(cwd: &Path, base: &str, head: &str) -> Result<usize> {
     let range = format!("{}..{}", base, head);
@@ -637,6 +763,18 @@ mod tests {
         assert!(branch == "main" || branch == "master");
     }
 
+    #[test]
+    fn test_is_detached_head() {
+        let dir = create_test_repo();
+        assert!(!is_detached_head(dir.path()));
+        assert!(require_branch(dir.path()).is_ok());
+
+        test::run_ok(dir.path(), &["checkout", "--detach"]);
+        assert!(is_detached_head(dir.path()));
+        let err = require_branch(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("git switch"));
+    }
+
     #[test]
     fn test_has_remote() {
         let dir = create_test_repo();
@@ -701,6 +839,21 @@ mod tests {
         assert_eq!(stash_count(dir.path()).unwrap(), 0);
     }
 
+    #[test]
+    fn test_stash_push_excluding_keeps_excluded_changes() {
+        let dir = create_test_repo();
+        std::fs::create_dir_all(dir.path().join(".braid/issues")).unwrap();
+        std::fs::write(dir.path().join(".braid/issues/brd-aaaa.md"), "issue").unwrap();
+        std::fs::write(dir.path().join("code.rs"), "code").unwrap();
+
+        assert!(stash_push_excluding(dir.path(), "test stash", ".braid").unwrap());
+        assert!(dir.path().join(".braid/issues/brd-aaaa.md").exists());
+        assert!(!dir.path().join("code.rs").exists());
+
+        assert!(stash_pop(dir.path()).unwrap());
+        assert!(dir.path().join("code.rs").exists());
+    }
+
     #[test]
     fn test_stash_pop_restores_changes() {
         let dir = create_test_repo();
@@ -1093,6 +1246,22 @@ diff --git a/file2.txt b/file2.txt
         assert_eq!(commits[0].short_sha.len(), 7);
     }
 
+    #[test]
+    fn test_commit_details() {
+        let dir = create_test_repo();
+        let sha = test::output(dir.path(), &["rev-parse", "HEAD"]);
+
+        let details = commit_details(dir.path(), &sha).unwrap().unwrap();
+        assert_eq!(details.sha, sha);
+        assert_eq!(details.short_sha.len(), 7);
+        assert_eq!(details.author, "test");
+        assert_eq!(details.subject, "init");
+        assert_eq!(details.date.len(), 10);
+
+        let gone = "0123456789abcdef0123456789abcdef01234567";
+        assert_eq!(commit_details(dir.path(), gone).unwrap(), None);
+    }
+
     #[test]
     fn test_commit_count() {
         let dir = create_test_repo();
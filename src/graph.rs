@@ -4,6 +4,7 @@ use std::collections::{HashMap, HashSet};
 
 use time::OffsetDateTime;
 
+use crate::config::NextStrategy;
 use crate::issue::{Issue, Status};
 
 /// derived information about an issue's dependency state.
@@ -21,6 +22,18 @@ pub struct DerivedState {
     pub is_scheduled: bool,
 }
 
+impl DerivedState {
+    /// whether an issue with `status` is ready when `ready_includes` is
+    /// `includes`: deps all done, not scheduled, and the status listed.
+    /// with just open listed, this is `is_ready`.
+    pub fn is_ready_for(&self, status: Status, includes: &[Status]) -> bool {
+        includes.contains(&status)
+            && self.open_deps.is_empty()
+            && self.missing_deps.is_empty()
+            && !self.is_scheduled
+    }
+}
+
 /// compute derived state for a single issue given a lookup of all issues.
 pub fn compute_derived(issue: &Issue, all_issues: &HashMap<String, Issue>) -> DerivedState {
     let mut open_deps = Vec::new();
@@ -72,7 +85,10 @@ pub fn find_cycles(issues: &HashMap<String, Issue>) -> Vec<Vec<String>> {
     let mut rec_stack = HashSet::new();
     let mut path = Vec::new();
 
-    for id in issues.keys() {
+    // walk ids in sorted order so the reported cycles are the same on every run
+    let mut ids: Vec<&String> = issues.keys().collect();
+    ids.sort();
+    for id in ids {
         if !visited.contains(id) {
             find_cycles_dfs(
                 id,
@@ -169,14 +185,15 @@ fn can_reach(
     false
 }
 
-/// get all ready issues, sorted by priority, created_at, then id.
-pub fn get_ready_issues(issues: &HashMap<String, Issue>) -> Vec<&Issue> {
+/// get all ready issues whose status is in `includes` (the `ready_includes`
+/// config), sorted by priority, created_at, then id.
+pub fn get_ready_issues<'a>(
+    issues: &'a HashMap<String, Issue>,
+    includes: &[Status],
+) -> Vec<&'a Issue> {
     let mut ready: Vec<&Issue> = issues
         .values()
-        .filter(|issue| {
-            let derived = compute_derived(issue, issues);
-            derived.is_ready
-        })
+        .filter(|issue| compute_derived(issue, issues).is_ready_for(issue.status(), includes))
         .collect();
 
     // sort by priority (P0 first), then created_at (oldest first), then id (lexicographic)
@@ -196,6 +213,125 @@ pub fn get_dependents(issue_id: &str, all_issues: &HashMap<String, Issue>) -> Ve
     dependents
 }
 
+/// get the issues that soft-link to the given one (list it as `related`).
+/// unlike hard dependents these are direct only: soft links don't chain.
+pub fn get_soft_dependents(issue_id: &str, all_issues: &HashMap<String, Issue>) -> Vec<String> {
+    let mut dependents: Vec<String> = all_issues
+        .iter()
+        .filter(|(_, issue)| issue.related().iter().any(|id| id == issue_id))
+        .map(|(id, _)| id.clone())
+        .collect();
+    dependents.sort();
+    dependents
+}
+
+/// get the issues soft-linked with the given one, in either direction: its own
+/// `related` entries plus every issue that lists it as related. sorted, no repeats.
+pub fn get_related(issue_id: &str, all_issues: &HashMap<String, Issue>) -> Vec<String> {
+    let own = all_issues
+        .get(issue_id)
+        .map(|issue| issue.related().to_vec())
+        .unwrap_or_default();
+    let mut related: Vec<String> = get_soft_dependents(issue_id, all_issues)
+        .into_iter()
+        .chain(own)
+        .collect();
+    related.sort();
+    related.dedup();
+    related
+}
+
+/// get every issue that depends on the given issue, directly or through other
+/// dependents, as `(id, depth)` in tree order (depth 1 = direct dependent).
+/// an issue reachable along several paths is listed once, at its first visit.
+pub fn get_transitive_dependents(
+    issue_id: &str,
+    all_issues: &HashMap<String, Issue>,
+) -> Vec<(String, usize)> {
+    fn visit(
+        id: &str,
+        depth: usize,
+        all_issues: &HashMap<String, Issue>,
+        seen: &mut HashSet<String>,
+        out: &mut Vec<(String, usize)>,
+    ) {
+        for dependent in get_dependents(id, all_issues) {
+            if seen.insert(dependent.clone()) {
+                out.push((dependent.clone(), depth));
+                visit(&dependent, depth + 1, all_issues, seen, out);
+            }
+        }
+    }
+
+    let mut seen = HashSet::from([issue_id.to_string()]);
+    let mut out = Vec::new();
+    visit(issue_id, 1, all_issues, &mut seen, &mut out);
+    out
+}
+
+/// for every issue, how many open or doing issues it blocks, directly or
+/// through other open dependents. done/skipped dependents don't count and
+/// don't pass the count through.
+pub fn open_dependent_counts(all_issues: &HashMap<String, Issue>) -> HashMap<String, usize> {
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for issue in all_issues.values() {
+        if matches!(issue.status(), Status::Open | Status::Doing) {
+            for dep in issue.deps() {
+                dependents.entry(dep).or_default().push(issue.id());
+            }
+        }
+    }
+
+    all_issues
+        .keys()
+        .map(|id| {
+            let mut seen = HashSet::from([id.as_str()]);
+            let mut stack = vec![id.as_str()];
+            while let Some(current) = stack.pop() {
+                for &dependent in dependents.get(current).into_iter().flatten() {
+                    if seen.insert(dependent) {
+                        stack.push(dependent);
+                    }
+                }
+            }
+            (id.clone(), seen.len() - 1)
+        })
+        .collect()
+}
+
+/// the `weighted` strategy's score, higher first:
+/// `10 × (3 − priority) + 5 × open dependents + min(age in days, 30) / 3`.
+pub fn weighted_score(issue: &Issue, open_dependents: usize, now: OffsetDateTime) -> i64 {
+    let priority = 3 - issue.priority() as i64;
+    let age_days = (now - issue.frontmatter.created_at)
+        .whole_days()
+        .clamp(0, 30);
+    10 * priority + 5 * open_dependents as i64 + age_days / 3
+}
+
+/// order ready issues best first under `strategy`. ties fall back to
+/// `Issue::cmp_by_priority`, which is the whole of the `priority` strategy.
+pub fn rank_ready(
+    ready: &mut [&Issue],
+    all_issues: &HashMap<String, Issue>,
+    strategy: NextStrategy,
+    now: OffsetDateTime,
+) {
+    if strategy == NextStrategy::Priority {
+        ready.sort_by(|a, b| a.cmp_by_priority(b));
+        return;
+    }
+    let counts = open_dependent_counts(all_issues);
+    let key = |issue: &Issue| {
+        let open_dependents = counts.get(issue.id()).copied().unwrap_or(0);
+        match strategy {
+            NextStrategy::Weighted => weighted_score(issue, open_dependents, now),
+            _ => open_dependents as i64,
+        }
+    };
+    ready.sort_by(|a, b| key(b).cmp(&key(a)).then_with(|| a.cmp_by_priority(b)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -297,4 +433,113 @@ mod tests {
         let dependents_d = get_dependents("d", &issues);
         assert!(dependents_d.is_empty());
     }
+
+    #[test]
+    fn test_get_transitive_dependents() {
+        // b and c depend on a, d depends on b and c, and a cycle d -> a
+        let mut issues = HashMap::new();
+        issues.insert("a".to_string(), make_issue("a", Status::Open, vec!["d"]));
+        issues.insert("b".to_string(), make_issue("b", Status::Open, vec!["a"]));
+        issues.insert("c".to_string(), make_issue("c", Status::Open, vec!["a"]));
+        issues.insert(
+            "d".to_string(),
+            make_issue("d", Status::Open, vec!["b", "c"]),
+        );
+
+        let dependents = get_transitive_dependents("a", &issues);
+        let expected = vec![("b", 1), ("d", 2), ("c", 1)];
+        let actual: Vec<_> = dependents.iter().map(|(id, d)| (id.as_str(), *d)).collect();
+        assert_eq!(actual, expected);
+
+        assert!(get_transitive_dependents("x", &issues).is_empty());
+    }
+
+    #[test]
+    fn test_open_dependent_counts_are_transitive() {
+        let mut issues = HashMap::new();
+        issues.insert("a".to_string(), make_issue("a", Status::Open, vec![]));
+        issues.insert("b".to_string(), make_issue("b", Status::Open, vec!["a"]));
+        issues.insert("c".to_string(), make_issue("c", Status::Doing, vec!["b"]));
+        // done dependents neither count nor pass the count through
+        issues.insert("d".to_string(), make_issue("d", Status::Done, vec!["a"]));
+        issues.insert("e".to_string(), make_issue("e", Status::Open, vec!["d"]));
+
+        let counts = open_dependent_counts(&issues);
+        assert_eq!(counts["a"], 2);
+        assert_eq!(counts["b"], 1);
+        assert_eq!(counts["c"], 0);
+        assert_eq!(counts["d"], 1);
+    }
+
+    #[test]
+    fn test_rank_ready_strategies() {
+        let now = OffsetDateTime::now_utc();
+        let mut issues = HashMap::new();
+        let mut urgent = make_issue("urgent", Status::Open, vec![]);
+        urgent.frontmatter.priority = Priority::P0;
+        let mut blocker = make_issue("blocker", Status::Open, vec![]);
+        blocker.frontmatter.priority = Priority::P2;
+        issues.insert("urgent".to_string(), urgent);
+        issues.insert("blocker".to_string(), blocker);
+        for id in ["x", "y", "z"] {
+            issues.insert(
+                id.to_string(),
+                make_issue(id, Status::Open, vec!["blocker"]),
+            );
+        }
+
+        let ids = |strategy| {
+            let mut ready = get_ready_issues(&issues, &[Status::Open]);
+            rank_ready(&mut ready, &issues, strategy, now);
+            ready.iter().map(|i| i.id().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(NextStrategy::Priority), ["urgent", "blocker"]);
+        assert_eq!(ids(NextStrategy::Unblock), ["blocker", "urgent"]);
+        // P0 with nothing waiting (30) vs P2 unblocking three (10 + 15)
+        assert_eq!(ids(NextStrategy::Weighted), ["urgent", "blocker"]);
+        assert_eq!(weighted_score(&issues["blocker"], 3, now), 25);
+    }
+
+    #[test]
+    fn test_get_ready_issues_respects_ready_includes() {
+        let mut issues = HashMap::new();
+        issues.insert("open".to_string(), make_issue("open", Status::Open, vec![]));
+        issues.insert(
+            "doing".to_string(),
+            make_issue("doing", Status::Doing, vec![]),
+        );
+        issues.insert(
+            "doing-blocked".to_string(),
+            make_issue("doing-blocked", Status::Doing, vec!["open"]),
+        );
+        issues.insert("done".to_string(), make_issue("done", Status::Done, vec![]));
+
+        let ids = |includes: &[Status]| {
+            let mut ids: Vec<_> = get_ready_issues(&issues, includes)
+                .iter()
+                .map(|i| i.id().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(&[Status::Open]), ["open"]);
+        assert_eq!(ids(&[Status::Open, Status::Doing]), ["doing", "open"]);
+        assert_eq!(ids(&[Status::Doing]), ["doing"]);
+    }
+
+    #[test]
+    fn test_related_deps_never_block() {
+        let mut issues = HashMap::new();
+        let mut a = make_issue("a", Status::Open, vec![]);
+        a.frontmatter.deps.related = vec!["b".to_string(), "gone".to_string()];
+        issues.insert("a".to_string(), a);
+        issues.insert("b".to_string(), make_issue("b", Status::Open, vec![]));
+
+        let derived = compute_derived(&issues["a"], &issues);
+        assert!(derived.is_ready);
+        assert!(derived.missing_deps.is_empty());
+        assert!(get_dependents("b", &issues).is_empty());
+        assert_eq!(get_related("b", &issues), ["a"]);
+        assert_eq!(get_related("a", &issues), ["b", "gone"]);
+    }
 }
@@ -1,10 +1,10 @@
 //! issue file parsing and writing.
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use time::OffsetDateTime;
 
-use crate::error::{BrdError, Result};
+use crate::error::{BrdError, Result, format_candidates};
 
 /// priority levels (P0 highest to P3 lowest).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -15,6 +15,18 @@ pub enum Priority {
     P3,
 }
 
+impl Priority {
+    /// the next more urgent priority, or None at P0.
+    pub fn raised(self) -> Option<Self> {
+        match self {
+            Priority::P0 => None,
+            Priority::P1 => Some(Priority::P0),
+            Priority::P2 => Some(Priority::P1),
+            Priority::P3 => Some(Priority::P2),
+        }
+    }
+}
+
 impl std::fmt::Display for Priority {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -113,6 +125,72 @@ impl std::str::FromStr for IssueType {
     }
 }
 
+/// how a dependency relates two issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DepKind {
+    /// a hard dep: the issue isn't ready until this one is done
+    #[serde(alias = "hard")]
+    Blocks,
+    /// a soft link: shown alongside the issue, but never blocks it
+    #[serde(alias = "soft")]
+    Related,
+}
+
+/// one entry of the `deps:` list: a bare id for a hard dep, or
+/// `{id, kind}` to spell out the kind.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum DepEntry {
+    Id(String),
+    Full { id: String, kind: DepKind },
+}
+
+/// an issue's dependencies. only `blocks` feeds readiness and the dependency
+/// graph; `related` links are informational. both live in `deps:` on disk, so
+/// files with only bare ids read and write exactly as before.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deps {
+    pub blocks: Vec<String>,
+    pub related: Vec<String>,
+}
+
+impl Serialize for Deps {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let hard = self.blocks.iter().cloned().map(DepEntry::Id);
+        let soft = self.related.iter().map(|id| DepEntry::Full {
+            id: id.clone(),
+            kind: DepKind::Related,
+        });
+        serializer.collect_seq(hard.chain(soft))
+    }
+}
+
+impl<'de> Deserialize<'de> for Deps {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let mut deps = Deps::default();
+        for entry in Vec::<DepEntry>::deserialize(deserializer)? {
+            match entry {
+                DepEntry::Id(id)
+                | DepEntry::Full {
+                    id,
+                    kind: DepKind::Blocks,
+                } => deps.blocks.push(id),
+                DepEntry::Full {
+                    id,
+                    kind: DepKind::Related,
+                } => deps.related.push(id),
+            }
+        }
+        Ok(deps)
+    }
+}
+
 /// the frontmatter of an issue file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueFrontmatter {
@@ -124,7 +202,7 @@ pub struct IssueFrontmatter {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub issue_type: Option<IssueType>,
     #[serde(default)]
-    pub deps: Vec<String>,
+    pub deps: Deps,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     #[serde(default)]
@@ -143,6 +221,13 @@ pub struct IssueFrontmatter {
         with = "time::serde::rfc3339::option"
     )]
     pub completed_at: Option<OffsetDateTime>,
+    /// when `brd escalate` last raised the priority
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "time::serde::rfc3339::option"
+    )]
+    pub escalated_at: Option<OffsetDateTime>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -151,6 +236,12 @@ pub struct IssueFrontmatter {
     pub scheduled_for: Option<OffsetDateTime>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub acceptance: Vec<String>,
+    /// shas of commits linked with `brd link`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commits: Vec<String>,
+    /// repo-relative paths added with `brd attach`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
 }
 
 /// a complete issue with frontmatter and markdown body.
@@ -173,14 +264,20 @@ impl Issue {
                 priority,
                 status: Status::Open,
                 issue_type: None,
-                deps,
+                deps: Deps {
+                    blocks: deps,
+                    related: Vec::new(),
+                },
                 tags: Vec::new(),
                 owner: None,
                 created_at: now,
                 started_at: None,
                 completed_at: None,
+                escalated_at: None,
                 scheduled_for: None,
                 acceptance: Vec::new(),
+                commits: Vec::new(),
+                attachments: Vec::new(),
             },
             body: String::new(),
         }
@@ -214,17 +311,17 @@ impl Issue {
         let content = std::fs::read_to_string(path)?;
         let issue = Self::parse(&content)?;
 
-        // validate id matches filename
-        let expected_id = path.file_stem().and_then(|s| s.to_str()).ok_or_else(|| {
+        // validate id matches filename: `<id>.md` or `<id>-<slug>.md`
+        let file_name = path.file_name().and_then(|s| s.to_str()).ok_or_else(|| {
             BrdError::ParseError(path.display().to_string(), "invalid filename".to_string())
         })?;
 
-        if issue.frontmatter.id != expected_id {
+        if !is_file_name_for(file_name, &issue.frontmatter.id) {
             return Err(BrdError::ParseError(
                 path.display().to_string(),
                 format!(
                     "id '{}' does not match filename '{}'",
-                    issue.frontmatter.id, expected_id
+                    issue.frontmatter.id, file_name
                 ),
             ));
         }
@@ -252,8 +349,33 @@ impl Issue {
     /// save the issue to a file.
     pub fn save(&self, path: &Path) -> Result<()> {
         let content = self.to_markdown()?;
-        std::fs::write(path, content)?;
-        Ok(())
+        crate::atomic::write_atomic(path, content)
+    }
+
+    /// file name for this issue under `scheme`.
+    pub fn file_name(&self, scheme: FilenameScheme) -> String {
+        let slug = match scheme {
+            FilenameScheme::Id => String::new(),
+            FilenameScheme::IdSlug => slugify(self.title()),
+        };
+        if slug.is_empty() {
+            format!("{}.md", self.id())
+        } else {
+            format!("{}-{}.md", self.id(), slug)
+        }
+    }
+
+    /// save into `issues_dir` under `scheme`, removing any file this issue was
+    /// stored in under another name (after a title change or scheme switch).
+    pub fn save_in(&self, issues_dir: &Path, scheme: FilenameScheme) -> Result<PathBuf> {
+        let path = issues_dir.join(self.file_name(scheme));
+        self.save(&path)?;
+        for old in issue_files(issues_dir, self.id())? {
+            if old != path {
+                std::fs::remove_file(old)?;
+            }
+        }
+        Ok(path)
     }
 
     /// Mark the issue as started (sets started_at if not already set).
@@ -268,6 +390,22 @@ impl Issue {
         self.frontmatter.completed_at = Some(OffsetDateTime::now_utc());
     }
 
+    /// the most recent of created_at, started_at, and completed_at.
+    pub fn updated_at(&self) -> OffsetDateTime {
+        let fm = &self.frontmatter;
+        [fm.started_at, fm.completed_at]
+            .into_iter()
+            .flatten()
+            .fold(fm.created_at, OffsetDateTime::max)
+    }
+
+    /// time from start to completion, if the issue has both timestamps.
+    pub fn cycle_time(&self) -> Option<time::Duration> {
+        let started = self.frontmatter.started_at?;
+        let completed = self.frontmatter.completed_at?;
+        Some(completed - started)
+    }
+
     /// convenience accessors
     pub fn id(&self) -> &str {
         &self.frontmatter.id
@@ -289,14 +427,38 @@ impl Issue {
         self.frontmatter.issue_type
     }
 
+    /// ids of the hard deps, the ones that block this issue.
     pub fn deps(&self) -> &[String] {
-        &self.frontmatter.deps
+        &self.frontmatter.deps.blocks
+    }
+
+    /// ids of the issues soft-linked with `kind: related`.
+    pub fn related(&self) -> &[String] {
+        &self.frontmatter.deps.related
     }
 
     pub fn tags(&self) -> &[String] {
         &self.frontmatter.tags
     }
 
+    /// acceptance items still written as an open checkbox (`[ ] ...`), without
+    /// the box. items with no box are plain criteria and never count.
+    pub fn unchecked_acceptance(&self) -> Vec<&str> {
+        self.frontmatter
+            .acceptance
+            .iter()
+            .filter_map(|ac| ac.trim_start().strip_prefix("[ ]"))
+            .map(str::trim)
+            .collect()
+    }
+
+    /// whether the issue's id or title contains `query` (case-insensitive).
+    /// an empty query matches everything.
+    pub fn matches_query(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.id().to_lowercase().contains(&query) || self.title().to_lowercase().contains(&query)
+    }
+
     /// compare two issues by priority, then created_at, then id.
     /// this is the canonical sort order for issue listings.
     pub fn cmp_by_priority(&self, other: &Self) -> std::cmp::Ordering {
@@ -309,37 +471,207 @@ impl Issue {
             })
             .then_with(|| self.id().cmp(other.id()))
     }
+
+    /// compare two issues in `brd ls` order: doing, then open (both by priority),
+    /// then done/skip with the most recently completed first.
+    pub fn cmp_for_listing(&self, other: &Self) -> std::cmp::Ordering {
+        fn group(status: Status) -> u8 {
+            match status {
+                Status::Doing => 0,
+                Status::Open => 1,
+                Status::Done | Status::Skip => 2,
+            }
+        }
+
+        group(self.status())
+            .cmp(&group(other.status()))
+            .then_with(|| {
+                if group(self.status()) == 2 {
+                    let a = self
+                        .frontmatter
+                        .completed_at
+                        .unwrap_or(self.frontmatter.created_at);
+                    let b = other
+                        .frontmatter
+                        .completed_at
+                        .unwrap_or(other.frontmatter.created_at);
+                    b.cmp(&a).then_with(|| self.id().cmp(other.id()))
+                } else {
+                    self.cmp_by_priority(other)
+                }
+            })
+    }
 }
 
 use std::collections::HashMap;
 
-use crate::config::Config;
+use crate::config::{Config, FilenameScheme};
 use rand::Rng;
 
+/// longest title slug used in `id-slug` file names.
+const MAX_SLUG_LEN: usize = 40;
+
+/// title slug for `id-slug` file names: lowercase ascii letters and digits,
+/// words joined by `-`, cut at a word boundary to at most 40 chars.
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    let words = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty());
+    for word in words {
+        let word = word.to_ascii_lowercase();
+        let sep = usize::from(!slug.is_empty());
+        if slug.len() + sep + word.len() > MAX_SLUG_LEN {
+            if slug.is_empty() {
+                slug = word[..MAX_SLUG_LEN].to_string();
+            }
+            break;
+        }
+        if sep == 1 {
+            slug.push('-');
+        }
+        slug.push_str(&word);
+    }
+    slug
+}
+
+/// whether `file_name` is `<id>.md` or `<id>-<slug>.md`.
+fn is_file_name_for(file_name: &str, id: &str) -> bool {
+    file_name
+        .strip_suffix(".md")
+        .and_then(|stem| stem.strip_prefix(id))
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// files in `dir` that hold issue `id`, under any filename scheme.
+/// slugged names are checked against the file's id, so `brd-a.md` never
+/// claims `brd-a-b.md`.
+pub fn issue_files(dir: &Path, id: &str) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if name == format!("{}.md", id)
+            || (is_file_name_for(name, id)
+                && Issue::load(&path).is_ok_and(|issue| issue.id() == id))
+        {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// the file holding issue `id` in `dir`, under any filename scheme.
+pub fn find_issue_file(dir: &Path, id: &str) -> Option<PathBuf> {
+    let plain = dir.join(format!("{}.md", id));
+    if plain.exists() {
+        return Some(plain);
+    }
+    issue_files(dir, id).ok()?.into_iter().next()
+}
+
 /// resolve a partial issue ID to a full ID.
+///
+/// several matches fail with the candidates; no match fails with the
+/// closest ids by edit distance, if any are close.
 pub fn resolve_issue_id(partial: &str, issues: &HashMap<String, Issue>) -> Result<String> {
     // exact match
     if issues.contains_key(partial) {
         return Ok(partial.to_string());
     }
 
+    // ids are lowercase, so compare case-insensitively from here on
+    let needle = partial.to_lowercase();
+    let exact: Vec<&String> = issues
+        .keys()
+        .filter(|id| id.to_lowercase() == needle)
+        .collect();
+    if let [id] = exact.as_slice() {
+        return Ok(id.to_string());
+    }
+
     // partial match
-    let matches: Vec<&str> = issues
+    let mut matches: Vec<&str> = issues
         .keys()
-        .filter(|id| id.contains(partial) || id.ends_with(partial))
+        .filter(|id| id.to_lowercase().contains(&needle))
         .map(|s| s.as_str())
         .collect();
+    matches.sort();
 
-    match matches.len() {
-        0 => Err(BrdError::IssueNotFound(partial.to_string())),
-        1 => Ok(matches[0].to_string()),
+    match matches.as_slice() {
+        [] => {
+            let suggestions = suggest_ids(&needle, issues);
+            if suggestions.is_empty() {
+                return Err(BrdError::IssueNotFound(partial.to_string()));
+            }
+            Err(BrdError::IssueNotFound(format!(
+                "{}\n\ndid you mean:{}",
+                partial,
+                format_candidates(&with_titles(&suggestions, issues))
+            )))
+        }
+        [id] => Ok(id.to_string()),
         _ => Err(BrdError::AmbiguousId(
             partial.to_string(),
-            matches.into_iter().map(String::from).collect(),
+            with_titles(&matches, issues),
         )),
     }
 }
 
+/// how many ids `resolve_issue_id` suggests at most.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// (id, title) pairs for `ids`.
+fn with_titles(ids: &[&str], issues: &HashMap<String, Issue>) -> Vec<(String, String)> {
+    ids.iter()
+        .map(|id| (id.to_string(), issues[*id].title().to_string()))
+        .collect()
+}
+
+/// ids within a small edit distance of `needle`, closest first. the needle
+/// is compared with both the full id and the part after the prefix, so
+/// `abd1` finds `brd-abc1`.
+fn suggest_ids<'a>(needle: &str, issues: &'a HashMap<String, Issue>) -> Vec<&'a str> {
+    // one typo per three characters, and at least one
+    let max_distance = (needle.chars().count() / 3).max(1);
+    let mut scored: Vec<(usize, &str)> = issues
+        .keys()
+        .filter_map(|id| {
+            let lower = id.to_lowercase();
+            let suffix = lower.split_once('-').map_or(lower.as_str(), |(_, s)| s);
+            let distance = edit_distance(needle, &lower).min(edit_distance(needle, suffix));
+            (distance <= max_distance).then_some((distance, id.as_str()))
+        })
+        .collect();
+    scored.sort();
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, id)| id)
+        .collect()
+}
+
+/// levenshtein distance between two strings, by chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            row[j + 1] = substitute.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
 /// generate a unique issue ID.
 pub fn generate_issue_id(config: &Config, issues_dir: &Path) -> Result<String> {
     let charset: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
@@ -354,9 +686,12 @@ pub fn generate_issue_id(config: &Config, issues_dir: &Path) -> Result<String> {
             .collect();
 
         let id = format!("{}-{}", config.id_prefix, suffix);
-        let path = issues_dir.join(format!("{}.md", id));
 
-        if !path.exists() {
+        // archived issues keep their ids so they can be restored
+        let archive_dir = crate::repo::archive_dir_for(issues_dir);
+        if find_issue_file(issues_dir, &id).is_none()
+            && find_issue_file(&archive_dir, &id).is_none()
+        {
             return Ok(id);
         }
     }
@@ -367,7 +702,7 @@ pub fn generate_issue_id(config: &Config, issues_dir: &Path) -> Result<String> {
 }
 
 /// split content into frontmatter and body.
-fn split_frontmatter(content: &str) -> Result<(&str, &str)> {
+pub(crate) fn split_frontmatter(content: &str) -> Result<(&str, &str)> {
     let content = content.trim_start();
     if !content.starts_with("---") {
         return Err(BrdError::ParseError(
@@ -419,6 +754,82 @@ This is the body.
         assert!(issue.body.contains("This is the body"));
     }
 
+    #[test]
+    fn test_cycle_time_requires_both_timestamps() {
+        let mut issue = Issue::new("t-a".into(), "a".into(), Priority::P2, vec![]);
+        assert!(issue.cycle_time().is_none());
+
+        let started = OffsetDateTime::UNIX_EPOCH;
+        issue.frontmatter.started_at = Some(started);
+        assert!(issue.cycle_time().is_none());
+
+        issue.frontmatter.completed_at = Some(started + time::Duration::hours(5));
+        assert_eq!(issue.cycle_time(), Some(time::Duration::hours(5)));
+    }
+
+    #[test]
+    fn test_cmp_for_listing_orders_doing_open_resolved() {
+        let mut doing = Issue::new("t-d".into(), "doing".into(), Priority::P3, vec![]);
+        doing.frontmatter.status = Status::Doing;
+        let open_p0 = Issue::new("t-a".into(), "urgent".into(), Priority::P0, vec![]);
+        let open_p2 = Issue::new("t-b".into(), "normal".into(), Priority::P2, vec![]);
+        let mut old_done = Issue::new("t-x".into(), "old".into(), Priority::P0, vec![]);
+        old_done.frontmatter.status = Status::Done;
+        old_done.frontmatter.completed_at = Some(OffsetDateTime::UNIX_EPOCH);
+        let mut new_done = Issue::new("t-y".into(), "new".into(), Priority::P3, vec![]);
+        new_done.frontmatter.status = Status::Done;
+        new_done.mark_completed();
+
+        let mut all = [&old_done, &open_p2, &new_done, &open_p0, &doing];
+        all.sort_by(|a, b| a.cmp_for_listing(b));
+        let ids: Vec<_> = all.iter().map(|i| i.id()).collect();
+        assert_eq!(ids, vec!["t-d", "t-a", "t-b", "t-y", "t-x"]);
+    }
+
+    #[test]
+    fn test_listing_order_is_stable_across_loads() {
+        // same priority and created_at everywhere, so only the id can break ties
+        let created = OffsetDateTime::UNIX_EPOCH;
+        let ids = ["t-f", "t-b", "t-h", "t-a", "t-e", "t-c", "t-g", "t-d"];
+        let sorted_ids = || {
+            // a fresh HashMap per "load" gets a fresh iteration order
+            let issues: HashMap<String, Issue> = ids
+                .iter()
+                .map(|id| {
+                    let mut issue = Issue::new(id.to_string(), "same".into(), Priority::P2, vec![]);
+                    issue.frontmatter.created_at = created;
+                    (id.to_string(), issue)
+                })
+                .collect();
+            let mut all: Vec<&Issue> = issues.values().collect();
+            all.sort_by(|a, b| a.cmp_for_listing(b));
+            all.iter().map(|i| i.id().to_string()).collect::<Vec<_>>()
+        };
+
+        let first = sorted_ids();
+        assert_eq!(
+            first,
+            vec!["t-a", "t-b", "t-c", "t-d", "t-e", "t-f", "t-g", "t-h"]
+        );
+        for _ in 0..10 {
+            assert_eq!(sorted_ids(), first);
+        }
+    }
+
+    #[test]
+    fn test_cmp_by_priority_breaks_ties_by_created_at_then_id() {
+        let mut older = Issue::new("t-z".into(), "older".into(), Priority::P1, vec![]);
+        older.frontmatter.created_at = OffsetDateTime::UNIX_EPOCH;
+        let mut newer = Issue::new("t-a".into(), "newer".into(), Priority::P1, vec![]);
+        newer.frontmatter.created_at = OffsetDateTime::UNIX_EPOCH + time::Duration::hours(1);
+        let urgent = Issue::new("t-m".into(), "urgent".into(), Priority::P0, vec![]);
+
+        let mut all = [&newer, &older, &urgent];
+        all.sort_by(|a, b| a.cmp_by_priority(b));
+        let ids: Vec<_> = all.iter().map(|i| i.id()).collect();
+        assert_eq!(ids, vec!["t-m", "t-z", "t-a"]);
+    }
+
     #[test]
     fn test_priority_ordering() {
         assert!(Priority::P0 < Priority::P1);
@@ -455,6 +866,21 @@ This is the body.
         assert_eq!(result.unwrap(), "abc");
     }
 
+    #[test]
+    fn test_resolve_issue_id_ignores_case() {
+        let issues = make_test_issues(&["brd-abc1", "brd-xyz2"]);
+        assert_eq!(resolve_issue_id("ABC1", &issues).unwrap(), "brd-abc1");
+        assert_eq!(resolve_issue_id("BRD-Abc1", &issues).unwrap(), "brd-abc1");
+
+        // an exact case-sensitive match wins over a case-insensitive one
+        let issues = make_test_issues(&["brd-ABC1", "brd-abc1"]);
+        assert_eq!(resolve_issue_id("brd-ABC1", &issues).unwrap(), "brd-ABC1");
+        assert!(matches!(
+            resolve_issue_id("Abc1", &issues),
+            Err(crate::error::BrdError::AmbiguousId(_, _))
+        ));
+    }
+
     #[test]
     fn test_resolve_issue_id_partial_suffix() {
         let issues = make_test_issues(&["brd-abc1", "brd-xyz2"]);
@@ -479,14 +905,65 @@ This is the body.
         match result {
             Err(crate::error::BrdError::AmbiguousId(partial, candidates)) => {
                 assert_eq!(partial, "abc");
-                assert_eq!(candidates.len(), 2);
-                assert!(candidates.contains(&"brd-abc1".to_string()));
-                assert!(candidates.contains(&"brd-abc2".to_string()));
+                assert_eq!(
+                    candidates,
+                    vec![
+                        ("brd-abc1".to_string(), "test issue brd-abc1".to_string()),
+                        ("brd-abc2".to_string(), "test issue brd-abc2".to_string()),
+                    ]
+                );
             }
             _ => panic!("expected AmbiguousId error"),
         }
     }
 
+    #[test]
+    fn test_resolve_issue_id_ambiguous_message_lists_titles() {
+        let issues = make_test_issues(&["tst-ab12", "tst-ac34", "tst-zz99"]);
+        let err = resolve_issue_id("tst-a", &issues).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "ambiguous issue id 'tst-a' matches 2 issues:\n  \
+             tst-ab12  test issue tst-ab12\n  \
+             tst-ac34  test issue tst-ac34\n\
+             hint: use more of the id"
+        );
+    }
+
+    #[test]
+    fn test_resolve_issue_id_not_found_suggests_close_ids() {
+        let issues = make_test_issues(&["brd-abc1", "brd-abd2", "brd-xyz9"]);
+        let err = resolve_issue_id("abx1", &issues).unwrap_err();
+        assert!(matches!(err, crate::error::BrdError::IssueNotFound(_)));
+        let message = err.to_string();
+        assert!(message.starts_with("issue not found: abx1\n\ndid you mean:"));
+        assert!(message.contains("brd-abc1  test issue brd-abc1"));
+        assert!(!message.contains("brd-xyz9"));
+
+        // a typo in the prefix is matched against the full id
+        let err = resolve_issue_id("bdr-xyz9", &issues)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("brd-xyz9"));
+    }
+
+    #[test]
+    fn test_suggest_ids_orders_by_distance() {
+        let issues = make_test_issues(&["brd-abcd", "brd-abce", "brd-wxyz", "brd-abzz"]);
+        assert_eq!(suggest_ids("abcf", &issues), vec!["brd-abcd", "brd-abce"]);
+        assert!(suggest_ids("qqqq", &issues).is_empty());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", "abc"), 0);
+        assert_eq!(edit_distance("abc", "abd"), 1);
+        assert_eq!(edit_distance("abc", "ab"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
     #[test]
     fn test_resolve_issue_id_not_found() {
         let issues = make_test_issues(&["brd-abc1", "brd-xyz2"]);
@@ -529,7 +1006,7 @@ updated_at: 2025-12-25T12:00:00Z
         assert_eq!(issue.frontmatter.priority, Priority::P2);
         assert_eq!(issue.frontmatter.status, Status::Open);
         // optional fields should have defaults
-        assert!(issue.frontmatter.deps.is_empty());
+        assert!(issue.frontmatter.deps.blocks.is_empty());
         assert!(issue.frontmatter.tags.is_empty());
         assert!(issue.frontmatter.owner.is_none());
         assert!(issue.frontmatter.issue_type.is_none());
@@ -568,7 +1045,7 @@ issue body here.
         assert_eq!(issue.frontmatter.priority, Priority::P0);
         assert_eq!(issue.frontmatter.status, Status::Doing);
         assert_eq!(issue.frontmatter.issue_type, Some(IssueType::Design));
-        assert_eq!(issue.frontmatter.deps, vec!["dep-1", "dep-2"]);
+        assert_eq!(issue.frontmatter.deps.blocks, vec!["dep-1", "dep-2"]);
         assert_eq!(issue.frontmatter.tags, vec!["bug", "urgent"]);
         assert_eq!(issue.frontmatter.owner, Some("agent-one".to_string()));
         assert_eq!(
@@ -801,6 +1278,16 @@ with multiple paragraphs.
             auto_pull: true,
             auto_push: true,
             diff_renderer: None,
+            theme: Default::default(),
+            agents_file: None,
+            commit_mode: crate::config::CommitMode::default(),
+            session_retention: None,
+            escalate_after_days: None,
+            escalate_on_sync: false,
+            next_strategy: crate::config::NextStrategy::default(),
+            ready_includes: vec![Status::Open],
+            require_acceptance_on_done: false,
+            filename_scheme: FilenameScheme::Id,
         };
         let temp_dir = tempfile::tempdir().unwrap();
 
@@ -824,6 +1311,16 @@ with multiple paragraphs.
             auto_pull: true,
             auto_push: true,
             diff_renderer: None,
+            theme: Default::default(),
+            agents_file: None,
+            commit_mode: crate::config::CommitMode::default(),
+            session_retention: None,
+            escalate_after_days: None,
+            escalate_on_sync: false,
+            next_strategy: crate::config::NextStrategy::default(),
+            ready_includes: vec![Status::Open],
+            require_acceptance_on_done: false,
+            filename_scheme: FilenameScheme::Id,
         };
         let temp_dir = tempfile::tempdir().unwrap();
 
@@ -844,6 +1341,16 @@ with multiple paragraphs.
             auto_pull: true,
             auto_push: true,
             diff_renderer: None,
+            theme: Default::default(),
+            agents_file: None,
+            commit_mode: crate::config::CommitMode::default(),
+            session_retention: None,
+            escalate_after_days: None,
+            escalate_on_sync: false,
+            next_strategy: crate::config::NextStrategy::default(),
+            ready_includes: vec![Status::Open],
+            require_acceptance_on_done: false,
+            filename_scheme: FilenameScheme::Id,
         };
         let temp_dir = tempfile::tempdir().unwrap();
 
@@ -874,6 +1381,16 @@ with multiple paragraphs.
             auto_pull: true,
             auto_push: true,
             diff_renderer: None,
+            theme: Default::default(),
+            agents_file: None,
+            commit_mode: crate::config::CommitMode::default(),
+            session_retention: None,
+            escalate_after_days: None,
+            escalate_on_sync: false,
+            next_strategy: crate::config::NextStrategy::default(),
+            ready_includes: vec![Status::Open],
+            require_acceptance_on_done: false,
+            filename_scheme: FilenameScheme::Id,
         };
         let temp_dir = tempfile::tempdir().unwrap();
 
@@ -888,4 +1405,113 @@ with multiple paragraphs.
             assert_ne!(id1, id2, "generated same ID as existing file");
         }
     }
+
+    #[test]
+    fn test_deps_mix_bare_ids_and_kinds() {
+        let content = r#"---
+schema_version: 9
+id: brd-aaaa
+title: mixed deps
+priority: P2
+status: open
+deps:
+  - brd-hard
+  - id: brd-also-hard
+    kind: blocks
+  - id: brd-link
+    kind: related
+  - id: brd-soft
+    kind: soft
+created_at: 2025-12-25T12:00:00Z
+---
+"#;
+        let issue = Issue::parse(content).unwrap();
+        assert_eq!(issue.deps(), ["brd-hard", "brd-also-hard"]);
+        assert_eq!(issue.related(), ["brd-link", "brd-soft"]);
+
+        // hard deps stay bare ids; related links are written out in full
+        let markdown = issue.to_markdown().unwrap();
+        assert!(
+            markdown
+                .contains("deps:\n- brd-hard\n- brd-also-hard\n- id: brd-link\n  kind: related\n")
+        );
+        let reparsed = Issue::parse(&markdown).unwrap();
+        assert_eq!(reparsed.frontmatter.deps, issue.frontmatter.deps);
+
+        let bad = content.replace("kind: soft", "kind: maybe");
+        assert!(Issue::parse(&bad).is_err());
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix login bug"), "fix-login-bug");
+        assert_eq!(slugify("  API: 500s on /users!! "), "api-500s-on-users");
+        assert_eq!(slugify("Über café"), "ber-caf");
+        assert_eq!(slugify("!!!"), "");
+        // cut at a word boundary
+        let long = "implement the thing that does the other thing with more words";
+        let slug = slugify(long);
+        assert_eq!(slug, "implement-the-thing-that-does-the-other");
+        assert!(slug.len() <= MAX_SLUG_LEN);
+        // a single overlong word is truncated
+        assert_eq!(slugify(&"a".repeat(50)).len(), MAX_SLUG_LEN);
+    }
+
+    #[test]
+    fn test_file_name_by_scheme() {
+        let issue = Issue::new("tst-ab12".into(), "Fix login".into(), Priority::P2, vec![]);
+        assert_eq!(issue.file_name(FilenameScheme::Id), "tst-ab12.md");
+        assert_eq!(
+            issue.file_name(FilenameScheme::IdSlug),
+            "tst-ab12-fix-login.md"
+        );
+        let untitled = Issue::new("tst-ab12".into(), "???".into(), Priority::P2, vec![]);
+        assert_eq!(untitled.file_name(FilenameScheme::IdSlug), "tst-ab12.md");
+
+        assert!(is_file_name_for("tst-ab12.md", "tst-ab12"));
+        assert!(is_file_name_for("tst-ab12-fix-login.md", "tst-ab12"));
+        assert!(!is_file_name_for("tst-ab123.md", "tst-ab12"));
+        assert!(!is_file_name_for("tst-ab12.txt", "tst-ab12"));
+    }
+
+    #[test]
+    fn test_load_accepts_slugged_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let issue = Issue::new("tst-ab12".into(), "Fix login".into(), Priority::P2, vec![]);
+        let path = dir.path().join("tst-ab12-fix-login.md");
+        issue.save(&path).unwrap();
+        assert_eq!(Issue::load(&path).unwrap().id(), "tst-ab12");
+
+        let wrong = dir.path().join("tst-zz99-fix-login.md");
+        issue.save(&wrong).unwrap();
+        let err = Issue::load(&wrong).unwrap_err();
+        assert!(err.to_string().contains("does not match filename"));
+    }
+
+    #[test]
+    fn test_save_in_replaces_old_file_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut issue = Issue::new("tst-ab12".into(), "Fix login".into(), Priority::P2, vec![]);
+        issue.save_in(dir.path(), FilenameScheme::Id).unwrap();
+        // another issue whose id extends this one's must be left alone
+        let other = Issue::new("tst-ab12-x".into(), "other".into(), Priority::P2, vec![]);
+        other.save_in(dir.path(), FilenameScheme::Id).unwrap();
+
+        let path = issue.save_in(dir.path(), FilenameScheme::IdSlug).unwrap();
+        assert!(path.ends_with("tst-ab12-fix-login.md"));
+        issue.frontmatter.title = "Fix logout".into();
+        issue.save_in(dir.path(), FilenameScheme::IdSlug).unwrap();
+
+        let mut names: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["tst-ab12-fix-logout.md", "tst-ab12-x.md"]);
+        assert_eq!(
+            find_issue_file(dir.path(), "tst-ab12").unwrap(),
+            dir.path().join("tst-ab12-fix-logout.md")
+        );
+        assert!(find_issue_file(dir.path(), "tst-zz99").is_none());
+    }
 }
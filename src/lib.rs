@@ -1,3 +1,5 @@
+pub mod api;
+pub mod atomic;
 pub mod cli;
 pub mod commands;
 pub mod config;
@@ -8,10 +10,14 @@ pub mod graph;
 pub mod issue;
 pub mod lock;
 pub mod migrate;
+pub mod output;
 pub mod repo;
 pub mod session;
+pub mod template;
 #[cfg(test)]
 pub mod test_utils;
+pub mod text;
+pub mod theme;
 pub mod tui;
 
 use std::io::IsTerminal;
@@ -3,8 +3,13 @@
 use fs2::FileExt;
 use std::fs::{File, OpenOptions};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
-use crate::error::Result;
+use crate::error::{BrdError, Result};
+
+/// how long `acquire` waits for another brd process before giving up.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
 /// a guard that holds an exclusive lock on the brd lock file.
 /// the lock is released when this guard is dropped.
@@ -13,24 +18,29 @@ pub struct LockGuard {
 }
 
 impl LockGuard {
-    /// acquire an exclusive lock on the lock file.
+    /// acquire an exclusive lock on the lock file, waiting up to 30s for
+    /// another brd process to release it.
     /// creates the lock file if it doesn't exist.
     pub fn acquire(lock_path: &Path) -> Result<Self> {
-        // ensure parent directory exists
-        if let Some(parent) = lock_path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-
-        let file = OpenOptions::new()
-            .read(true)
-            .write(true)
-            .create(true)
-            .truncate(false)
-            .open(lock_path)?;
-
-        file.lock_exclusive()?;
+        Self::acquire_within(lock_path, LOCK_TIMEOUT)
+    }
 
-        Ok(Self { _file: file })
+    fn acquire_within(lock_path: &Path, timeout: Duration) -> Result<Self> {
+        let start = Instant::now();
+        loop {
+            if let Some(guard) = Self::try_acquire(lock_path)? {
+                return Ok(guard);
+            }
+            if start.elapsed() >= timeout {
+                return Err(BrdError::LockHeld(format!(
+                    "another brd process has held {} for over {}s\n\
+                     hint: wait for it to finish, then retry",
+                    lock_path.display(),
+                    timeout.as_secs()
+                )));
+            }
+            std::thread::sleep(LOCK_POLL_INTERVAL);
+        }
     }
 
     /// try to acquire an exclusive lock without blocking.
@@ -61,9 +71,8 @@ impl LockGuard {
 mod tests {
     use super::*;
     use std::path::{Path, PathBuf};
-    use std::process::Command;
+    use std::process::{Child, Command};
     use std::thread::sleep;
-    use std::time::{Duration, Instant};
 
     use tempfile::tempdir;
 
@@ -81,6 +90,35 @@ mod tests {
         false
     }
 
+    /// run `lock_helper` in a child process and wait until it holds the lock.
+    fn spawn_lock_holder(lock_path: &Path, ready_path: &Path, release_path: &Path) -> Child {
+        let exe = std::env::current_exe().unwrap();
+        let child = Command::new(exe)
+            .arg("--exact")
+            .arg("lock::tests::lock_helper")
+            .env(
+                "BRD_LOCK_HELPER_LOCK",
+                lock_path.to_string_lossy().to_string(),
+            )
+            .env(
+                "BRD_LOCK_HELPER_READY",
+                ready_path.to_string_lossy().to_string(),
+            )
+            .env(
+                "BRD_LOCK_HELPER_RELEASE",
+                release_path.to_string_lossy().to_string(),
+            )
+            .env("BRD_LOCK_HELPER", "1")
+            .spawn()
+            .unwrap();
+
+        assert!(
+            wait_for_file(ready_path, Duration::from_secs(5)),
+            "timed out waiting for helper to lock"
+        );
+        child
+    }
+
     #[test]
     fn test_lock_acquire_creates_file() {
         let dir = tempdir().unwrap();
@@ -115,30 +153,7 @@ mod tests {
         let ready_path = dir.path().join(READY_FILENAME);
         let release_path = dir.path().join(RELEASE_FILENAME);
 
-        let exe = std::env::current_exe().unwrap();
-        let mut child = Command::new(exe)
-            .arg("--exact")
-            .arg("lock::tests::lock_helper")
-            .env(
-                "BRD_LOCK_HELPER_LOCK",
-                lock_path.to_string_lossy().to_string(),
-            )
-            .env(
-                "BRD_LOCK_HELPER_READY",
-                ready_path.to_string_lossy().to_string(),
-            )
-            .env(
-                "BRD_LOCK_HELPER_RELEASE",
-                release_path.to_string_lossy().to_string(),
-            )
-            .env("BRD_LOCK_HELPER", "1")
-            .spawn()
-            .unwrap();
-
-        assert!(
-            wait_for_file(&ready_path, Duration::from_secs(5)),
-            "timed out waiting for helper to lock"
-        );
+        let mut child = spawn_lock_holder(&lock_path, &ready_path, &release_path);
 
         let guard = LockGuard::try_acquire(&lock_path).unwrap();
         assert!(guard.is_none());
@@ -151,6 +166,23 @@ mod tests {
         assert!(guard.is_some());
     }
 
+    #[test]
+    fn test_acquire_times_out_when_locked() {
+        let dir = tempdir().unwrap();
+        let lock_path = dir.path().join("brd.lock");
+        let ready_path = dir.path().join(READY_FILENAME);
+        let release_path = dir.path().join(RELEASE_FILENAME);
+
+        let mut child = spawn_lock_holder(&lock_path, &ready_path, &release_path);
+
+        let result = LockGuard::acquire_within(&lock_path, Duration::from_millis(100));
+        assert!(matches!(result, Err(BrdError::LockHeld(_))));
+
+        std::fs::write(&release_path, "release").unwrap();
+        assert!(child.wait().unwrap().success());
+        assert!(LockGuard::acquire_within(&lock_path, Duration::from_millis(100)).is_ok());
+    }
+
     #[test]
     fn lock_helper() {
         if std::env::var("BRD_LOCK_HELPER").as_deref() != Ok("1") {
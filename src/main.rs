@@ -1,34 +1,47 @@
-use braid::cli::{AgentAction, Cli, Command, ConfigAction, DepAction};
+use braid::cli::{AgentAction, Cli, Command, ConfigAction, DepAction, ReportAction};
 use braid::commands::{
-    cmd_add, cmd_agent_attach, cmd_agent_branch, cmd_agent_clean, cmd_agent_init, cmd_agent_kill,
-    cmd_agent_logs, cmd_agent_pr, cmd_agent_ps, cmd_agent_send, cmd_agent_spawn, cmd_agents_inject,
-    cmd_agents_show, cmd_commit, cmd_completions, cmd_config_auto_sync, cmd_config_external_repo,
-    cmd_config_issues_branch, cmd_config_show, cmd_dep_add, cmd_dep_rm, cmd_doctor, cmd_done,
-    cmd_edit, cmd_init, cmd_ls, cmd_merge, cmd_migrate, cmd_path, cmd_ready, cmd_reopen, cmd_rm,
-    cmd_search, cmd_set, cmd_show, cmd_skip, cmd_start, cmd_status, cmd_sync, cmd_tui,
+    ShowView, cmd_add, cmd_agent_attach, cmd_agent_branch, cmd_agent_clean, cmd_agent_init,
+    cmd_agent_kill, cmd_agent_list, cmd_agent_logs, cmd_agent_pr, cmd_agent_ps, cmd_agent_remove,
+    cmd_agent_send, cmd_agent_spawn, cmd_agents_inject, cmd_agents_show, cmd_attach, cmd_commit,
+    cmd_completions, cmd_config_auto_sync, cmd_config_external_repo, cmd_config_issues_branch,
+    cmd_config_show, cmd_dep_add, cmd_dep_ls, cmd_dep_rm, cmd_dep_rm_all, cmd_dep_swap, cmd_doctor,
+    cmd_done, cmd_edit, cmd_errors, cmd_escalate, cmd_groom, cmd_init, cmd_link, cmd_log, cmd_ls,
+    cmd_merge, cmd_migrate, cmd_path, cmd_ready, cmd_recent, cmd_reopen, cmd_report_cycle_time,
+    cmd_restore, cmd_rm, cmd_search, cmd_set, cmd_set_status, cmd_show, cmd_skip, cmd_start,
+    cmd_status, cmd_sync, cmd_templates, cmd_tui, cmd_version, cmd_whoami,
+    require_current_issue_schema,
 };
 use braid::config::Config;
 use braid::error::{BrdError, Result};
+use braid::issue::DepKind;
 use braid::repo;
 use braid::verbose;
 use clap::Parser;
 
 fn main() {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    // --ndjson implies --json, errors included
+    cli.json |= cli.ndjson();
 
     let result = run(&cli);
 
     match result {
         Ok(()) => std::process::exit(0),
         Err(e) => {
-            if cli.json {
+            if matches!(e, BrdError::Reported(_)) {
+                // the command already printed it
+            } else if cli.json {
                 let json = serde_json::json!({
                     "ok": false,
                     "code": e.code_str(),
                     "message": e.to_string(),
                     "exit": i32::from(e.exit_code()),
                 });
-                println!("{}", serde_json::to_string_pretty(&json).unwrap());
+                if cli.ndjson() {
+                    println!("{}", json);
+                } else {
+                    println!("{}", serde_json::to_string_pretty(&json).unwrap());
+                }
             } else {
                 eprintln!("error: {}", e);
             }
@@ -45,6 +58,12 @@ fn run(cli: &Cli) -> Result<()> {
     if let Command::Completions { shell } = &cli.command {
         return cmd_completions(*shell);
     }
+    if let Command::Version = &cli.command {
+        return cmd_version(cli);
+    }
+    if let Command::Errors = &cli.command {
+        return cmd_errors(cli);
+    }
 
     // all other commands need repo discovery
     let paths = repo::discover(cli.repo.as_deref())?;
@@ -55,11 +74,19 @@ fn run(cli: &Cli) -> Result<()> {
     }
     verbose!(cli, "found .braid at {}", paths.braid_dir().display());
 
-    // validate config schema version early to prevent old brd from modifying upgraded repos
+    // validate config schema version early to prevent old brd from modifying upgraded repos.
+    // doctor is exempt: it reports schema mismatches itself, with upgrade/migrate hints.
     let config = Config::load(&paths.config_path())?;
-    config.validate(Some(&paths.worktree_root))?;
-    // also validate external/worktree configs if in those modes
-    paths.validate_resolved_config(&config)?;
+    if !matches!(cli.command, Command::Doctor { .. }) {
+        config.validate(Some(&paths.worktree_root))?;
+        // also validate external/worktree configs if in those modes
+        paths.validate_resolved_config(&config)?;
+    }
+    // an agent worktree with issues at an older schema must rebase and migrate
+    // before changing any, or it ends up half migrated
+    if mutates_issues(&cli.command) {
+        require_current_issue_schema(&paths, &config)?;
+    }
     verbose!(
         cli,
         "config: prefix={}, id_len={}, schema=v{}",
@@ -71,33 +98,77 @@ fn run(cli: &Cli) -> Result<()> {
     match &cli.command {
         Command::Init(_) => unreachable!(),
         Command::Add(args) => cmd_add(cli, &paths, args),
-        Command::Ls {
-            status,
-            priority,
-            ready,
-            blocked,
-            scheduled,
-            tag,
-            all,
-        } => cmd_ls(
+        Command::Ls(args) => cmd_ls(cli, &paths, args),
+        Command::Show {
+            ids,
+            context,
+            dependents,
+            log,
+            pager,
+            output,
+        } => cmd_show(
             cli,
             &paths,
-            status.as_deref(),
-            priority.as_deref(),
-            *ready,
-            *blocked,
-            *scheduled,
-            tag,
-            *all,
+            ids,
+            if *log {
+                ShowView::Log
+            } else if *dependents {
+                ShowView::Dependents
+            } else if *context {
+                ShowView::Context
+            } else {
+                ShowView::Details
+            },
+            *pager,
+            output.as_deref(),
         ),
-        Command::Show { id, context } => cmd_show(cli, &paths, id, *context),
         Command::Edit { id, force } => cmd_edit(cli, &paths, id.as_deref(), *force),
         Command::Set { id, field, value } => cmd_set(cli, &paths, id, field, value),
-        Command::Ready => cmd_ready(cli, &paths),
+        Command::Ready {
+            explain,
+            ndjson,
+            output,
+        } => cmd_ready(cli, &paths, *explain, *ndjson, output.as_deref()),
+        Command::Recent {
+            since,
+            ndjson,
+            output,
+        } => cmd_recent(cli, &paths, since, *ndjson, output.as_deref()),
+        Command::Escalate { after, dry_run } => cmd_escalate(cli, &paths, *after, *dry_run),
+        Command::Groom { stale_days, bump } => cmd_groom(cli, &paths, *stale_days, *bump),
+        Command::Log { follow, limit } => cmd_log(cli, &paths, *follow, *limit),
+        Command::Report { action } => match action {
+            ReportAction::CycleTime {
+                since,
+                group_by,
+                output,
+            } => cmd_report_cycle_time(cli, &paths, since, group_by.as_deref(), output.as_deref()),
+        },
         Command::Status => cmd_status(cli, &paths),
         Command::Dep { action } => match action {
-            DepAction::Add { blocked, blocker } => cmd_dep_add(cli, &paths, blocked, blocker),
-            DepAction::Rm { blocked, blocker } => cmd_dep_rm(cli, &paths, blocked, blocker),
+            DepAction::Add {
+                blocked,
+                blocker,
+                soft,
+            } => {
+                let kind = if *soft {
+                    DepKind::Related
+                } else {
+                    DepKind::Blocks
+                };
+                cmd_dep_add(cli, &paths, blocked.as_deref(), blocker.as_deref(), kind)
+            }
+            DepAction::Rm {
+                blocked,
+                blocker,
+                all,
+                yes,
+            } => match blocker {
+                Some(blocker) if !*all => cmd_dep_rm(cli, &paths, blocked, blocker),
+                _ => cmd_dep_rm_all(cli, &paths, blocked, *yes),
+            },
+            DepAction::Ls { id } => cmd_dep_ls(cli, &paths, id),
+            DepAction::Swap { blocked, old, new } => cmd_dep_swap(cli, &paths, blocked, old, new),
         },
         Command::Start {
             id,
@@ -114,22 +185,50 @@ fn run(cli: &Cli) -> Result<()> {
             *no_push,
             *stash,
         ),
-        Command::Done {
+        Command::Done(args) => cmd_done(cli, &paths, args),
+        Command::Skip { id } => cmd_skip(cli, &paths, id),
+        Command::Reopen { id } => cmd_reopen(cli, &paths, id),
+        Command::Link {
             id,
+            commit,
+            from_message,
+            install_hook,
+        } => cmd_link(
+            cli,
+            &paths,
+            id.as_deref(),
+            commit.as_deref(),
+            *from_message,
+            *install_hook,
+        ),
+        Command::Attach { id, path } => cmd_attach(cli, &paths, id, path),
+        Command::SetStatus {
+            status,
+            ids,
             force,
-            result,
             no_push,
-        } => cmd_done(cli, &paths, id.as_deref(), *force, result, *no_push),
-        Command::Skip { id } => cmd_skip(cli, &paths, id),
-        Command::Reopen { id } => cmd_reopen(cli, &paths, id),
+        } => cmd_set_status(cli, &paths, status, ids, *force, *no_push),
         Command::Path { id } => cmd_path(cli, &paths, id),
-        Command::Rm { id, force } => cmd_rm(cli, &paths, id, *force),
+        Command::Rm { id, force, purge } => cmd_rm(cli, &paths, id, *force, *purge),
+        Command::Restore { id } => cmd_restore(cli, &paths, id),
         Command::Agent { action } => match action {
-            AgentAction::Init { name, base } => cmd_agent_init(cli, &paths, name, base.as_deref()),
+            AgentAction::Init {
+                name,
+                base,
+                from_origin,
+            } => cmd_agent_init(cli, &paths, name, base.as_deref(), *from_origin),
+            AgentAction::List => cmd_agent_list(cli, &paths),
+            AgentAction::Remove {
+                name,
+                force,
+                delete_branch,
+            } => cmd_agent_remove(cli, &paths, name, *force, *delete_branch),
             AgentAction::Branch { id } => cmd_agent_branch(cli, &paths, id),
             AgentAction::Pr => cmd_agent_pr(cli, &paths),
-            AgentAction::Merge => cmd_merge(cli, &paths),
-            AgentAction::Inject { file } => cmd_agents_inject(&paths, file.as_deref()),
+            AgentAction::Merge { stash } => cmd_merge(cli, &paths, *stash),
+            AgentAction::Inject { file, name } => {
+                cmd_agents_inject(&paths, file.as_deref(), name.as_deref())
+            }
             AgentAction::Instructions => cmd_agents_show(),
             AgentAction::Spawn {
                 id,
@@ -155,25 +254,78 @@ fn run(cli: &Cli) -> Result<()> {
             } => cmd_agent_logs(cli, &paths, session, *follow, *tail, *raw),
             AgentAction::Send { session, message } => cmd_agent_send(cli, &paths, session, message),
             AgentAction::Attach { session } => cmd_agent_attach(cli, &paths, session),
-            AgentAction::Kill { session, force } => cmd_agent_kill(cli, &paths, session, *force),
-            AgentAction::Clean { all, force } => cmd_agent_clean(cli, &paths, *all, *force),
+            AgentAction::Kill {
+                session,
+                all,
+                force,
+            } => cmd_agent_kill(cli, &paths, session.as_deref(), *all, *force),
+            AgentAction::Clean {
+                all,
+                older_than,
+                force,
+            } => cmd_agent_clean(cli, &paths, *all, older_than.as_deref(), *force),
         },
-        Command::Doctor => cmd_doctor(cli, &paths),
+        Command::Whoami => cmd_whoami(cli, &paths),
+        Command::Templates => cmd_templates(cli, &paths),
+        Command::Doctor { fix } => cmd_doctor(cli, &paths, *fix),
         Command::Completions { .. } => unreachable!(),
-        Command::Tui { force } => cmd_tui(cli, &paths, *force),
+        Command::Version => unreachable!(),
+        Command::Errors => unreachable!(),
+        Command::Tui {
+            force,
+            filter,
+            status,
+            view,
+        } => cmd_tui(
+            cli,
+            &paths,
+            *force,
+            filter.as_deref(),
+            status,
+            view.as_deref(),
+        ),
         Command::Migrate { dry_run } => cmd_migrate(cli, &paths, *dry_run),
-        Command::Search => cmd_search(cli, &paths),
+        Command::Search(args) => cmd_search(cli, &paths, args),
         Command::Commit { message } => cmd_commit(cli, &paths, message.as_deref()),
-        Command::Sync { push } => cmd_sync(cli, &paths, *push),
+        Command::Sync {
+            push,
+            pull_only,
+            push_only,
+        } => cmd_sync(cli, &paths, *push, *pull_only, *push_only),
         Command::Config { action } => match action {
             None => cmd_config_show(cli, &paths),
-            Some(ConfigAction::IssuesBranch { name, clear, yes }) => {
-                cmd_config_issues_branch(cli, &paths, name.as_deref(), *clear, *yes)
-            }
-            Some(ConfigAction::ExternalRepo { path, clear, yes }) => {
-                cmd_config_external_repo(cli, &paths, path.as_deref(), *clear, *yes)
-            }
+            Some(ConfigAction::IssuesBranch {
+                name,
+                clear,
+                symlink,
+                yes,
+            }) => cmd_config_issues_branch(cli, &paths, name.as_deref(), *clear, *symlink, *yes),
+            Some(ConfigAction::ExternalRepo(args)) => cmd_config_external_repo(cli, &paths, args),
             Some(ConfigAction::AutoSync { enabled }) => cmd_config_auto_sync(cli, &paths, *enabled),
         },
     }
 }
+
+/// commands that write issue files.
+fn mutates_issues(command: &Command) -> bool {
+    match command {
+        Command::Add(_)
+        | Command::Edit { .. }
+        | Command::Set { .. }
+        | Command::Start { .. }
+        | Command::Done(_)
+        | Command::Skip { .. }
+        | Command::Reopen { .. }
+        | Command::Attach { .. }
+        | Command::SetStatus { .. }
+        | Command::Rm { .. }
+        | Command::Restore { .. }
+        | Command::Commit { .. }
+        | Command::Sync { .. } => true,
+        Command::Dep { action } => !matches!(action, DepAction::Ls { .. }),
+        Command::Escalate { dry_run, .. } => !dry_run,
+        Command::Groom { bump, .. } => *bump,
+        Command::Link { install_hook, .. } => !install_hook,
+        _ => false,
+    }
+}
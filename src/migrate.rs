@@ -8,7 +8,7 @@ use serde_yaml::Value;
 use crate::error::{BrdError, Result};
 
 /// The current schema version. All new issues are created with this version.
-pub const CURRENT_SCHEMA: u32 = 9;
+pub const CURRENT_SCHEMA: u32 = 10;
 
 /// Check if a schema version needs migration.
 pub fn needs_migration(schema_version: u32) -> bool {
@@ -64,6 +64,7 @@ fn apply_migration(frontmatter: Value, from_version: u32) -> Result<Value> {
         6 => migrate_v6_to_v7(frontmatter),
         7 => migrate_v7_to_v8(frontmatter),
         8 => migrate_v8_to_v9(frontmatter),
+        9 => migrate_v9_to_v10(frontmatter),
         _ => {
             // No migration needed for this version
             Ok(frontmatter)
@@ -225,6 +226,19 @@ fn migrate_v8_to_v9(mut frontmatter: Value) -> Result<Value> {
     Ok(frontmatter)
 }
 
+/// Migration from v9 to v10.
+/// - Allows soft deps written as `{id, kind: related}` objects (no data changes needed)
+/// - Adds optional `escalated_at` field
+/// - Adds optional `commits` list of linked commit shas
+/// - Adds optional `attachments` list of file paths
+fn migrate_v9_to_v10(mut frontmatter: Value) -> Result<Value> {
+    if let Value::Mapping(ref mut map) = frontmatter {
+        let schema_key = Value::String("schema_version".to_string());
+        map.insert(schema_key, Value::Number(10.into()));
+    }
+    Ok(frontmatter)
+}
+
 /// Summary of what migrations would be applied to get from one version to another.
 pub fn migration_summary(from_version: u32, to_version: u32) -> Vec<String> {
     let mut summaries = Vec::new();
@@ -242,6 +256,7 @@ pub fn migration_summary(from_version: u32, to_version: u32) -> Vec<String> {
                 summaries.push("v7→v8: replace updated_at with started_at/completed_at".to_string())
             }
             8 => summaries.push("v8→v9: add scheduled_for field".to_string()),
+            9 => summaries.push("v9→v10: allow related deps as {id, kind} entries; add escalated_at, commits, and attachments fields".to_string()),
             _ => {}
         }
     }
@@ -300,6 +315,17 @@ mod tests {
         assert!(migrated.get("schema_version").is_some());
     }
 
+    #[test]
+    fn test_migrate_v9_to_v10_keeps_fields() {
+        let yaml: Value =
+            serde_yaml::from_str("schema_version: 9\nid: test\ndeps: [brd-aaaa]").unwrap();
+        let (migrated, changed) = migrate_frontmatter(yaml, 10).unwrap();
+        assert!(changed);
+        assert_eq!(get_schema_version(&migrated).unwrap(), 10);
+        assert_eq!(migrated["deps"][0], "brd-aaaa");
+        assert_eq!(migration_summary(9, 10).len(), 1);
+    }
+
     #[test]
     fn test_no_migration_needed() {
         let yaml: Value = serde_yaml::from_str("schema_version: 2\nid: test").unwrap();
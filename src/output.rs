@@ -0,0 +1,124 @@
+//! output destination for commands that support `--output <path>`.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::cli::{Cli, should_color};
+use crate::error::{BrdError, Result};
+
+/// where a command writes its output: stdout, or a file given with `--output`.
+pub struct Output {
+    inner: Box<dyn Write>,
+    to_file: bool,
+}
+
+impl Output {
+    /// open `path` for writing (truncating it), or fall back to stdout.
+    pub fn new(path: Option<&Path>) -> Result<Self> {
+        match path {
+            Some(path) => {
+                let file = File::create(path).map_err(|e| {
+                    BrdError::Other(format!("failed to open {}: {}", path.display(), e))
+                })?;
+                Ok(Self {
+                    inner: Box::new(BufWriter::new(file)),
+                    to_file: true,
+                })
+            }
+            None => Ok(Self::stdout()),
+        }
+    }
+
+    pub fn stdout() -> Self {
+        Self {
+            inner: Box::new(io::stdout()),
+            to_file: false,
+        }
+    }
+
+    /// whether output is going to a file rather than stdout.
+    pub fn is_file(&self) -> bool {
+        self.to_file
+    }
+
+    /// whether to emit ANSI colors. files never get colors.
+    pub fn use_color(&self, cli: &Cli) -> bool {
+        !self.to_file && should_color(cli)
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// render a list for `--json` as a pretty array, or for `--ndjson` as one
+/// compact object per line. either way the result ends with a newline,
+/// except an empty ndjson list, which is empty.
+pub fn format_json_list(items: &[serde_json::Value], ndjson: bool) -> String {
+    if !ndjson {
+        let mut output = serde_json::to_string_pretty(items).unwrap();
+        output.push('\n');
+        return output;
+    }
+    let mut output = String::new();
+    for item in items {
+        output.push_str(&serde_json::to_string(item).unwrap());
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_cli;
+
+    #[test]
+    fn test_format_json_list_ndjson_is_one_object_per_line() {
+        let items = vec![
+            serde_json::json!({"id": "brd-aaaa", "tags": ["a", "b"]}),
+            serde_json::json!({"id": "brd-bbbb"}),
+        ];
+        assert_eq!(
+            format_json_list(&items, true),
+            "{\"id\":\"brd-aaaa\",\"tags\":[\"a\",\"b\"]}\n{\"id\":\"brd-bbbb\"}\n"
+        );
+        assert_eq!(format_json_list(&[], true), "");
+
+        let pretty = format_json_list(&items, false);
+        assert!(pretty.starts_with("[\n"));
+        assert!(pretty.ends_with("]\n"));
+    }
+
+    #[test]
+    fn test_output_writes_to_file_without_color() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+
+        let mut cli = test_cli();
+        cli.no_color = false;
+        let mut out = Output::new(Some(&path)).unwrap();
+        assert!(out.is_file());
+        assert!(!out.use_color(&cli));
+        writeln!(out, "hello").unwrap();
+        out.flush().unwrap();
+        drop(out);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello\n");
+    }
+
+    #[test]
+    fn test_output_reports_unwritable_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing/out.txt");
+        let err = Output::new(Some(&path)).err().unwrap();
+        assert!(err.to_string().contains("failed to open"));
+    }
+}
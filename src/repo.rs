@@ -17,6 +17,11 @@ pub struct RepoPaths {
     pub brd_common_dir: PathBuf,
 }
 
+/// the archive directory that sits next to `issues_dir` (e.g. `.braid/archive/`).
+pub fn archive_dir_for(issues_dir: &Path) -> PathBuf {
+    issues_dir.parent().unwrap_or(issues_dir).join("archive")
+}
+
 impl RepoPaths {
     /// path to `.braid/` in the current worktree
     pub fn braid_dir(&self) -> PathBuf {
@@ -53,6 +58,11 @@ impl RepoPaths {
         }
     }
 
+    /// directory holding issues archived by `brd rm`: `archive/` next to the issues dir.
+    pub fn archive_dir(&self, config: &Config) -> PathBuf {
+        archive_dir_for(&self.issues_dir(config))
+    }
+
     /// resolve the issues directory from an external repo path.
     /// returns None if the external repo can't be resolved.
     fn resolve_external_issues_dir(&self, external_path: &str) -> Option<PathBuf> {
@@ -126,7 +136,7 @@ impl RepoPaths {
             let current = git_rev_parse(&wt_path, "--abbrev-ref HEAD")?;
             let current_str = current.to_string_lossy();
             if current_str.trim() != branch {
-                return Err(BrdError::Other(format!(
+                return Err(BrdError::Config(format!(
                     "issues worktree exists but is on branch '{}', expected '{}'",
                     current_str.trim(),
                     branch
@@ -135,6 +145,11 @@ impl RepoPaths {
             return Ok(wt_path);
         }
 
+        // the issues branch is cut from HEAD, which needs a commit
+        if !crate::git::branch_exists(&self.worktree_root, branch) {
+            crate::git::require_commits(&self.worktree_root)?;
+        }
+
         // create the worktree
         let output = std::process::Command::new("git")
             .args(["worktree", "add", "--detach"])
@@ -145,7 +160,7 @@ impl RepoPaths {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to create issues worktree: {}",
                 stderr
             )));
@@ -159,7 +174,7 @@ impl RepoPaths {
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(BrdError::Other(format!(
+            return Err(BrdError::Git(format!(
                 "failed to checkout branch in issues worktree: {}",
                 stderr
             )));
@@ -173,11 +188,26 @@ impl RepoPaths {
         self.braid_dir().join("config.toml")
     }
 
+    /// path to `.braid/templates/` (issue templates for `brd add --template`)
+    pub fn templates_dir(&self) -> PathBuf {
+        self.braid_dir().join("templates")
+    }
+
     /// path to the local lock file (for single-machine coordination)
     pub fn lock_path(&self) -> PathBuf {
         self.brd_common_dir.join("lock")
     }
 
+    /// path to the saved TUI session state
+    pub fn tui_state_path(&self) -> PathBuf {
+        self.brd_common_dir.join("tui-state.json")
+    }
+
+    /// path to the cached full-text index used by `brd search`
+    pub fn search_index_path(&self) -> PathBuf {
+        self.brd_common_dir.join("search-index.json")
+    }
+
     /// validate that all resolved configs (external repo, issues worktree) are compatible.
     /// call this early to catch schema version mismatches before any commands run.
     pub fn validate_resolved_config(&self, local_config: &Config) -> Result<()> {
@@ -205,12 +235,12 @@ impl RepoPaths {
 
         // canonicalize to handle .. and symlinks
         let resolved = resolved.canonicalize().map_err(|_| {
-            BrdError::Other(format!("external issues repo not found: {}", external_path))
+            BrdError::Config(format!("external issues repo not found: {}", external_path))
         })?;
 
         // discover that repo's paths
         let external_paths = discover(Some(&resolved)).map_err(|_| {
-            BrdError::Other(format!(
+            BrdError::Git(format!(
                 "external path is not a git repository: {}",
                 resolved.display()
             ))
@@ -218,7 +248,7 @@ impl RepoPaths {
 
         // load that repo's config
         let external_config = Config::load(&external_paths.config_path())
-            .map_err(|e| BrdError::Other(format!("failed to load external repo config: {}", e)))?;
+            .map_err(|e| BrdError::Config(format!("failed to load external repo config: {}", e)))?;
 
         // check schema version
         if external_config.schema_version > CURRENT_SCHEMA {
@@ -232,7 +262,7 @@ impl RepoPaths {
 
         // check for chaining
         if external_config.is_external_repo_mode() {
-            return Err(BrdError::Other(
+            return Err(BrdError::Config(
                 "external repo cannot itself use external-repo mode (chaining not allowed)"
                     .to_string(),
             ));
@@ -254,7 +284,7 @@ impl RepoPaths {
         }
 
         let wt_config = Config::load(&wt_config_path).map_err(|e| {
-            BrdError::Other(format!("failed to load issues worktree config: {}", e))
+            BrdError::Config(format!("failed to load issues worktree config: {}", e))
         })?;
 
         if wt_config.schema_version > CURRENT_SCHEMA {
@@ -270,31 +300,96 @@ impl RepoPaths {
     }
 }
 
+/// where a resolved agent ID came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentIdSource {
+    /// the `BRD_AGENT_ID` env var
+    Env,
+    /// `.braid/agent.toml` in the worktree
+    AgentToml,
+    /// the `$USER` env var
+    User,
+    /// nothing set, fell back to "default-user"
+    Default,
+}
+
+impl std::fmt::Display for AgentIdSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentIdSource::Env => write!(f, "env"),
+            AgentIdSource::AgentToml => write!(f, "agent.toml"),
+            AgentIdSource::User => write!(f, "user"),
+            AgentIdSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// check if we're in a git worktree (not the main repo).
+/// compares git-dir with git-common-dir - they differ in a worktree.
+pub fn is_git_worktree(path: &Path) -> bool {
+    let git_dir = std::process::Command::new("git")
+        .args(["rev-parse", "--git-dir"])
+        .current_dir(path)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+    let git_common_dir = std::process::Command::new("git")
+        .args(["rev-parse", "--git-common-dir"])
+        .current_dir(path)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string());
+
+    match (git_dir, git_common_dir) {
+        (Some(dir), Some(common)) => dir != common,
+        _ => false,
+    }
+}
+
+/// whether `worktree_root` is an agent worktree made by `brd agent init`:
+/// a linked git worktree with `.braid/agent.toml`. `brd init` writes
+/// agent.toml on main too, so the file alone isn't enough.
+pub fn is_agent_worktree(worktree_root: &Path) -> bool {
+    worktree_root.join(".braid/agent.toml").exists() && is_git_worktree(worktree_root)
+}
+
+/// the `agent_id` in a worktree's `.braid/agent.toml`, if there is one.
+pub fn agent_toml_id(worktree_root: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(worktree_root.join(".braid/agent.toml")).ok()?;
+    let parsed = toml::from_str::<toml::Value>(&content).ok()?;
+    parsed.get("agent_id")?.as_str().map(String::from)
+}
+
 /// get the current agent ID:
 /// 1. BRD_AGENT_ID env var
 /// 2. .braid/agent.toml in worktree
 /// 3. fallback: $USER
 pub fn get_agent_id(worktree_root: &Path) -> String {
+    resolve_agent_id(worktree_root).0
+}
+
+/// get the current agent ID along with where it came from.
+/// see [`get_agent_id`] for the resolution order.
+pub fn resolve_agent_id(worktree_root: &Path) -> (String, AgentIdSource) {
     // 1. check env var
     if let Ok(id) = std::env::var("BRD_AGENT_ID") {
-        return id;
+        return (id, AgentIdSource::Env);
     }
 
     // 2. check .braid/agent.toml
-    let agent_toml = worktree_root.join(".braid/agent.toml");
-    if let Ok(content) = std::fs::read_to_string(&agent_toml)
-        && let Ok(parsed) = toml::from_str::<toml::Value>(&content)
-        && let Some(id) = parsed.get("agent_id").and_then(|v| v.as_str())
-    {
-        return id.to_string();
+    if let Some(id) = agent_toml_id(worktree_root) {
+        return (id, AgentIdSource::AgentToml);
     }
 
     // 3. fallback to $USER
     match std::env::var("USER") {
-        Ok(user) => user,
+        Ok(user) => (user, AgentIdSource::User),
         Err(_) => {
             eprintln!("warning: $USER not set, using 'default-user' as agent_id");
-            "default-user".to_string()
+            ("default-user".to_string(), AgentIdSource::Default)
         }
     }
 }
@@ -374,6 +469,28 @@ mod tests {
         (dir, paths, branch)
     }
 
+    #[test]
+    fn test_is_agent_worktree_needs_linked_worktree() {
+        let (dir, paths, _) = create_git_repo();
+        let main = &paths.worktree_root;
+        std::fs::create_dir_all(main.join(".braid")).unwrap();
+        std::fs::write(main.join(".braid/agent.toml"), "agent_id = \"me\"\n").unwrap();
+        // brd init writes agent.toml on main too
+        assert!(!is_agent_worktree(main));
+
+        let wt = dir.path().join("wt");
+        git_ok(
+            main,
+            &["worktree", "add", "-b", "agent-one", wt.to_str().unwrap()],
+        );
+        assert!(is_git_worktree(&wt));
+        assert!(!is_agent_worktree(&wt));
+
+        std::fs::create_dir_all(wt.join(".braid")).unwrap();
+        std::fs::write(wt.join(".braid/agent.toml"), "agent_id = \"agent-one\"\n").unwrap();
+        assert!(is_agent_worktree(&wt));
+    }
+
     #[test]
     fn test_repo_paths_methods() {
         let paths = RepoPaths {
@@ -102,8 +102,7 @@ impl Session {
     /// save the session to disk.
     pub fn save(&self, path: &Path) -> Result<()> {
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(path, content)?;
-        Ok(())
+        crate::atomic::write_atomic(path, content)
     }
 
     /// path to the session state file.
@@ -230,7 +229,7 @@ pub fn find_session(sessions_dir: &Path, id: &str) -> Result<Session> {
         1 => Ok(matches[0].clone()),
         _ => {
             let ids: Vec<_> = matches.iter().map(|s| s.session_id.as_str()).collect();
-            Err(BrdError::Other(format!(
+            Err(BrdError::InvalidInput(format!(
                 "ambiguous session ID '{}', matches: {}",
                 id,
                 ids.join(", ")
@@ -0,0 +1,198 @@
+//! issue templates stored in `.braid/templates/<name>.md`.
+//!
+//! a template is an issue file without the generated fields: optional YAML
+//! frontmatter with defaults, followed by a body skeleton.
+//!
+//! ```markdown
+//! ---
+//! description: something is broken
+//! priority: P1
+//! tags: [bug]
+//! acceptance:
+//!   - regression test added
+//! ---
+//! ## steps to reproduce
+//! ```
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{BrdError, Result};
+use crate::issue::{IssueType, Priority, split_frontmatter};
+
+/// defaults a template applies to new issues. unknown keys are rejected so a
+/// typo doesn't silently drop a default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateFrontmatter {
+    /// one-line summary shown by `brd templates`
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    #[serde(default, rename = "type")]
+    pub issue_type: Option<IssueType>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub acceptance: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Template {
+    pub name: String,
+    pub frontmatter: TemplateFrontmatter,
+    pub body: String,
+}
+
+impl Template {
+    /// parse a template. frontmatter is optional; without it the whole file is the body.
+    pub fn parse(name: &str, content: &str) -> Result<Self> {
+        let parse_err = |e: String| BrdError::ParseError(format!("template '{}'", name), e);
+        let (frontmatter, body) = if content.trim_start().starts_with("---") {
+            let (frontmatter_str, body) = split_frontmatter(content)?;
+            let frontmatter = if frontmatter_str.is_empty() {
+                TemplateFrontmatter::default()
+            } else {
+                serde_yaml::from_str(frontmatter_str).map_err(|e| parse_err(e.to_string()))?
+            };
+            (frontmatter, body)
+        } else {
+            (TemplateFrontmatter::default(), content)
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            frontmatter,
+            body: body.to_string(),
+        })
+    }
+
+    /// load the template called `name` from `templates_dir`. names are plain
+    /// file stems, so anything that could point outside the directory is refused.
+    pub fn load(templates_dir: &Path, name: &str) -> Result<Self> {
+        if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+            return Err(BrdError::InvalidInput(format!(
+                "invalid template name '{}' (use the file name without .md)",
+                name
+            )));
+        }
+        let path = templates_dir.join(format!("{}.md", name));
+        let content = std::fs::read_to_string(&path).map_err(|_| {
+            let available = list_templates(templates_dir)
+                .map(|templates| {
+                    templates
+                        .iter()
+                        .map(|t| t.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            let hint = if available.is_empty() {
+                format!("create {}", path.display())
+            } else {
+                format!("available: {}", available)
+            };
+            BrdError::InvalidInput(format!("template '{}' not found ({})", name, hint))
+        })?;
+        Self::parse(name, &content)
+    }
+}
+
+/// all templates in `templates_dir`, sorted by name. a missing directory
+/// means none; a template that fails to parse is skipped with a warning.
+pub fn list_templates(templates_dir: &Path) -> Result<Vec<Template>> {
+    let mut templates = Vec::new();
+    if !templates_dir.exists() {
+        return Ok(templates);
+    }
+    for entry in std::fs::read_dir(templates_dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|e| e != "md") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let content = std::fs::read_to_string(&path)?;
+        match Template::parse(name, &content) {
+            Ok(template) => templates.push(template),
+            Err(e) => eprintln!("warning: failed to load {}: {}", path.display(), e),
+        }
+    }
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_template_with_frontmatter() {
+        let template = Template::parse(
+            "bug",
+            "---\ndescription: something is broken\npriority: P1\ntags: [bug]\nacceptance:\n  - regression test\n---\n## steps to reproduce\n",
+        )
+        .unwrap();
+        assert_eq!(
+            template.frontmatter.description.as_deref(),
+            Some("something is broken")
+        );
+        assert_eq!(template.frontmatter.priority, Some(Priority::P1));
+        assert_eq!(template.frontmatter.tags, vec!["bug"]);
+        assert_eq!(template.frontmatter.acceptance, vec!["regression test"]);
+        assert_eq!(template.body, "## steps to reproduce\n");
+    }
+
+    #[test]
+    fn test_parse_template_body_only_and_unknown_keys() {
+        let template = Template::parse("note", "just a body\n").unwrap();
+        assert_eq!(template.frontmatter, TemplateFrontmatter::default());
+        assert_eq!(template.body, "just a body\n");
+
+        let err = Template::parse("bad", "---\nprioity: P1\n---\n").unwrap_err();
+        assert!(err.to_string().contains("prioity"));
+    }
+
+    #[test]
+    fn test_list_and_load_templates() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(
+            list_templates(&dir.path().join("missing"))
+                .unwrap()
+                .is_empty()
+        );
+
+        std::fs::write(dir.path().join("feature.md"), "body").unwrap();
+        std::fs::write(dir.path().join("bug.md"), "body").unwrap();
+        std::fs::write(dir.path().join("README.txt"), "ignored").unwrap();
+        std::fs::write(dir.path().join("broken.md"), "---\nprioity: P1\n---\n").unwrap();
+        let names: Vec<_> = list_templates(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["bug", "feature"]);
+
+        let err = Template::load(dir.path(), "chore").unwrap_err();
+        assert!(err.to_string().contains("available: bug, feature"));
+    }
+
+    #[test]
+    fn test_load_rejects_path_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let templates = dir.path().join("templates");
+        std::fs::create_dir(&templates).unwrap();
+        std::fs::write(dir.path().join("outside.md"), "body").unwrap();
+
+        for name in ["../outside", "sub/bug", "..\\outside", ".hidden", ""] {
+            let err = Template::load(&templates, name).unwrap_err();
+            assert!(
+                matches!(err, BrdError::InvalidInput(ref msg) if msg.contains("invalid template name")),
+                "{name}: {err}"
+            );
+        }
+    }
+}
@@ -57,6 +57,12 @@ impl TestRepoBuilder {
         self
     }
 
+    /// make `brd done` refuse unchecked acceptance items
+    pub fn with_required_acceptance(mut self) -> Self {
+        self.config.require_acceptance_on_done = true;
+        self
+    }
+
     /// build the test repo, creating all directories and files
     pub fn build(self) -> TestRepo {
         let dir = tempdir().unwrap();
@@ -96,6 +102,7 @@ pub struct IssueBuilder<'a> {
     owner: Option<String>,
     deps: Vec<String>,
     tags: Vec<String>,
+    acceptance: Vec<String>,
     scheduled_for: Option<OffsetDateTime>,
 }
 
@@ -111,6 +118,7 @@ impl<'a> IssueBuilder<'a> {
             owner: None,
             deps: vec![],
             tags: vec![],
+            acceptance: vec![],
             scheduled_for: None,
         }
     }
@@ -150,6 +158,11 @@ impl<'a> IssueBuilder<'a> {
         self
     }
 
+    pub fn acceptance(mut self, a: &[&str]) -> Self {
+        self.acceptance = a.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
     pub fn scheduled_for(mut self, dt: OffsetDateTime) -> Self {
         self.scheduled_for = Some(dt);
         self
@@ -167,6 +180,7 @@ impl<'a> IssueBuilder<'a> {
         issue.frontmatter.issue_type = self.issue_type;
         issue.frontmatter.owner = self.owner;
         issue.frontmatter.tags = self.tags;
+        issue.frontmatter.acceptance = self.acceptance;
         issue.frontmatter.scheduled_for = self.scheduled_for;
 
         let path = self
@@ -186,7 +200,7 @@ pub fn test_cli() -> Cli {
         repo: None,
         no_color: true,
         verbose: false,
-        command: Command::Doctor,
+        command: Command::Doctor { fix: false },
     }
 }
 
@@ -233,6 +247,6 @@ mod tests {
         repo.issue("brd-dep1").create();
         let issue = repo.issue("brd-child").deps(&["brd-dep1"]).create();
 
-        assert_eq!(issue.frontmatter.deps, vec!["brd-dep1".to_string()]);
+        assert_eq!(issue.frontmatter.deps.blocks, vec!["brd-dep1".to_string()]);
     }
 }
@@ -0,0 +1,105 @@
+//! display-width helpers shared by the CLI listings and the TUI.
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// shorten `s` to at most `max_width` terminal cells, ending in "…" when
+/// something was cut. wide chars (CJK, most emoji) take two cells.
+pub fn truncate(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > max_width - 1 {
+            break;
+        }
+        truncated.push(c);
+        used += w;
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// left-align `s` in `width` cells, like `{:<width}` but by display width.
+pub fn pad_right(s: &str, width: usize) -> String {
+    format!("{}{}", s, " ".repeat(width.saturating_sub(s.width())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_within_limit() {
+        assert_eq!(truncate("hello", 10), "hello");
+    }
+
+    #[test]
+    fn ascii_at_limit() {
+        assert_eq!(truncate("hello", 5), "hello");
+    }
+
+    #[test]
+    fn ascii_over_limit() {
+        assert_eq!(truncate("hello world", 5), "hell…");
+    }
+
+    #[test]
+    fn multibyte_em_dash() {
+        // '—' is 3 bytes in UTF-8; slicing at byte boundaries must not panic
+        let s = "add support for query — live";
+        assert_eq!(truncate(s, 26), "add support for query — l…");
+    }
+
+    #[test]
+    fn multibyte_emoji() {
+        // the emoji takes two cells
+        let s = "🎉 party time";
+        assert_eq!(truncate(s, 5), "🎉 p…");
+    }
+
+    #[test]
+    fn multibyte_every_length() {
+        let s = "naïve café — 日本語 🎉 ok";
+        let width = s.width();
+        for max in 0..=width + 2 {
+            let out = truncate(s, max);
+            assert!(out.width() <= max, "max {max}: {out}");
+            // a wide char at the cut point may leave one cell unused
+            assert!(out.width() + 1 >= max.min(width), "max {max}: {out}");
+            if max > 0 && max < width {
+                assert!(out.ends_with('…'));
+            }
+        }
+    }
+
+    #[test]
+    fn cjk_fits_cell_budget() {
+        // each ideograph is two cells wide
+        assert_eq!(truncate("日本語のタイトル", 8), "日本語…");
+        assert_eq!(truncate("日本語のタイトル", 7), "日本語…");
+        assert_eq!(truncate("日本語", 6), "日本語");
+        assert_eq!(pad_right("日本", 6), "日本  ");
+        assert_eq!(pad_right(&truncate("山田太郎さん", 10), 10).width(), 10);
+    }
+
+    #[test]
+    fn max_len_zero() {
+        assert_eq!(truncate("hello", 0), "");
+    }
+
+    #[test]
+    fn max_len_one() {
+        assert_eq!(truncate("hello", 1), "…");
+    }
+
+    #[test]
+    fn empty_string() {
+        assert_eq!(truncate("", 5), "");
+    }
+}
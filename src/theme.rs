@@ -0,0 +1,153 @@
+//! color theme for TUI and CLI output.
+//!
+//! colors are configured per semantic role in the `[theme]` section of
+//! `.braid/config.toml`. values are color names ("cyan", "dark gray",
+//! "light red"), 256-color indices ("208"), or hex ("#ff8800").
+
+use ratatui::style::Color;
+
+use crate::config::ThemeConfig;
+use crate::error::{BrdError, Result};
+
+/// resolved colors for each semantic role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// headers and accents (TUI title bar, tags)
+    pub header: Color,
+    /// selection, focus, in-progress work, and P1 priority
+    pub highlight: Color,
+    /// ready issues
+    pub ready: Color,
+    /// blocked issues
+    pub blocked: Color,
+    /// P0 priority and bug tags
+    pub urgent: Color,
+    /// completed issues
+    pub done: Color,
+    /// errors and missing references
+    pub error: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            header: Color::Cyan,
+            highlight: Color::Yellow,
+            ready: Color::Green,
+            blocked: Color::Red,
+            urgent: Color::Magenta,
+            done: Color::Green,
+            error: Color::Red,
+        }
+    }
+}
+
+impl Theme {
+    /// build a theme from config, falling back to defaults for unset or invalid roles.
+    /// call `ThemeConfig::validate` first to surface invalid colors as errors.
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let defaults = Self::default();
+        let pick = |value: &Option<String>, default: Color| {
+            value
+                .as_deref()
+                .and_then(|v| parse_color(v).ok())
+                .unwrap_or(default)
+        };
+        Self {
+            header: pick(&config.header, defaults.header),
+            highlight: pick(&config.highlight, defaults.highlight),
+            ready: pick(&config.ready, defaults.ready),
+            blocked: pick(&config.blocked, defaults.blocked),
+            urgent: pick(&config.urgent, defaults.urgent),
+            done: pick(&config.done, defaults.done),
+            error: pick(&config.error, defaults.error),
+        }
+    }
+}
+
+/// parse a color name, 256-color index, or hex value.
+pub fn parse_color(value: &str) -> Result<Color> {
+    value.parse::<Color>().map_err(|_| {
+        BrdError::ParseError(
+            "config".to_string(),
+            format!(
+                "invalid theme color '{}' (use a name like \"cyan\", an index like \"208\", or hex like \"#ff8800\")",
+                value
+            ),
+        )
+    })
+}
+
+/// convert a theme color to a crossterm color for CLI output.
+pub fn to_crossterm(color: Color) -> crossterm::style::Color {
+    use crossterm::style::Color as C;
+    match color {
+        Color::Reset => C::Reset,
+        Color::Black => C::Black,
+        // ratatui's base colors are the normal ANSI colors, which crossterm
+        // calls "dark"; the light variants are crossterm's plain names
+        Color::Red => C::DarkRed,
+        Color::LightRed => C::Red,
+        Color::Green => C::DarkGreen,
+        Color::LightGreen => C::Green,
+        Color::Yellow => C::DarkYellow,
+        Color::LightYellow => C::Yellow,
+        Color::Blue => C::DarkBlue,
+        Color::LightBlue => C::Blue,
+        Color::Magenta => C::DarkMagenta,
+        Color::LightMagenta => C::Magenta,
+        Color::Cyan => C::DarkCyan,
+        Color::LightCyan => C::Cyan,
+        Color::Gray => C::Grey,
+        Color::DarkGray => C::DarkGrey,
+        Color::White => C::White,
+        Color::Rgb(r, g, b) => C::Rgb { r, g, b },
+        Color::Indexed(i) => C::AnsiValue(i),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_formats() {
+        assert_eq!(parse_color("cyan").unwrap(), Color::Cyan);
+        assert_eq!(parse_color("dark gray").unwrap(), Color::DarkGray);
+        assert_eq!(parse_color("208").unwrap(), Color::Indexed(208));
+        assert_eq!(parse_color("#ff8800").unwrap(), Color::Rgb(255, 136, 0));
+        assert!(parse_color("not-a-color").is_err());
+    }
+
+    #[test]
+    fn test_theme_from_config_overrides_roles() {
+        let config = ThemeConfig {
+            highlight: Some("blue".to_string()),
+            ..Default::default()
+        };
+        let theme = Theme::from_config(&config);
+        assert_eq!(theme.highlight, Color::Blue);
+        assert_eq!(theme.header, Theme::default().header);
+    }
+
+    #[test]
+    fn test_default_urgent_differs_from_blocked() {
+        // P0 and bug markers must not read as blocked
+        let theme = Theme::default();
+        assert_ne!(theme.urgent, theme.blocked);
+    }
+
+    #[test]
+    fn test_to_crossterm_maps_colors_one_to_one() {
+        use crossterm::style::Color as C;
+        assert_eq!(to_crossterm(Color::Red), C::DarkRed);
+        assert_eq!(to_crossterm(Color::LightRed), C::Red);
+        assert_eq!(to_crossterm(Color::Cyan), C::DarkCyan);
+        assert_eq!(to_crossterm(Color::LightCyan), C::Cyan);
+        assert_eq!(to_crossterm(Color::DarkGray), C::DarkGrey);
+        assert_eq!(
+            to_crossterm(Color::Rgb(1, 2, 3)),
+            C::Rgb { r: 1, g: 2, b: 3 }
+        );
+    }
+}
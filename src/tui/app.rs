@@ -3,18 +3,23 @@
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+use ratatui::layout::Rect;
 use ratatui::text::Text;
+use serde::{Deserialize, Serialize};
 
+use crate::commands::claim_issue;
 use crate::config::Config;
 use crate::error::{BrdError, Result};
 use crate::graph::{DerivedState, compute_derived};
-use crate::issue::{Issue, IssueType, Priority, Status};
+use crate::issue::{Issue, IssueType, Priority, Status, find_issue_file, resolve_issue_id};
 use crate::lock::LockGuard;
 use crate::repo::RepoPaths;
 use crate::session::{Session, SessionStatus, load_all_sessions};
+use crate::theme::Theme;
 
 use super::diff_panel::DiffPanelState;
 use super::diff_render::DiffRendererType;
+use super::state::TuiState;
 
 /// Information about an agent worktree.
 #[derive(Debug, Clone)]
@@ -78,7 +83,8 @@ pub struct WorktreeDiff {
 }
 
 /// which view is currently active.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum View {
     /// dashboard with stats overview
     Dashboard,
@@ -89,6 +95,21 @@ pub enum View {
     Agents,
 }
 
+impl std::str::FromStr for View {
+    type Err = BrdError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "dashboard" => Ok(View::Dashboard),
+            "issues" => Ok(View::Issues),
+            "agents" => Ok(View::Agents),
+            _ => Err(BrdError::InvalidInput(format!(
+                "invalid view: {s} (expected dashboard, issues, or agents)"
+            ))),
+        }
+    }
+}
+
 /// which panel has focus in agents view.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AgentsFocus {
@@ -100,7 +121,8 @@ pub enum AgentsFocus {
 }
 
 /// which panel has focus in issues view.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum IssuesFocus {
     /// issue list (left panel)
     #[default]
@@ -109,6 +131,38 @@ pub enum IssuesFocus {
     Details,
 }
 
+/// backlog health counts shown in the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IssueCounts {
+    /// open issues with no open dependencies
+    pub ready: usize,
+    /// open issues waiting on dependencies
+    pub blocked: usize,
+    /// issues in progress
+    pub doing: usize,
+}
+
+impl IssueCounts {
+    pub fn from_issues(issues: &HashMap<String, Issue>) -> Self {
+        let mut counts = Self::default();
+        for issue in issues.values() {
+            match issue.status() {
+                Status::Doing => counts.doing += 1,
+                Status::Open => {
+                    let derived = compute_derived(issue, issues);
+                    if derived.is_ready {
+                        counts.ready += 1;
+                    } else if derived.is_blocked {
+                        counts.blocked += 1;
+                    }
+                }
+                Status::Done | Status::Skip => {}
+            }
+        }
+        counts
+    }
+}
+
 /// which section is active in the detail pane.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DetailSection {
@@ -144,6 +198,35 @@ pub enum InputMode {
     },
     /// filtering issues
     Filter(String),
+    /// entering an id (or id fragment) to jump to
+    JumpTo(String),
+    /// confirming start of an issue that still has open dependencies
+    ConfirmStart { id: String, open_deps: usize },
+    /// picking an action to apply to every selected issue
+    BulkAction { selected: usize },
+    /// entering a tag to add to every selected issue
+    BulkTag(String),
+    /// editing an issue's tags as a comma-separated list
+    Tags { id: String, input: String },
+}
+
+/// an action applied to all selected issues at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkAction {
+    Done,
+    Skip,
+    AddTag(String),
+}
+
+/// labels for the bulk action menu, in `InputMode::BulkAction` index order.
+pub const BULK_ACTIONS: [&str; 3] = ["mark done", "skip", "add tag"];
+
+/// initial view and filters given on the `brd tui` command line.
+#[derive(Debug, Clone, Default)]
+pub struct StartOptions {
+    pub view: Option<View>,
+    pub filter: Option<String>,
+    pub status: Vec<Status>,
 }
 
 /// TUI application state.
@@ -172,6 +255,8 @@ pub struct App {
     pub input_mode: InputMode,
     /// current config
     pub config: Config,
+    /// colors for each semantic role (from config `[theme]`)
+    pub theme: Theme,
     /// selected dependency index in detail pane
     pub detail_dep_selected: Option<usize>,
     /// which section is active in the detail pane (deps or dependents)
@@ -226,6 +311,19 @@ pub struct App {
     pub detail_scroll: usize,
     /// which panel has focus in issues view
     pub issues_focus: IssuesFocus,
+    /// system clipboard, opened on first copy and kept alive so the contents
+    /// survive on X11 (where the owning process must serve them)
+    clipboard: Option<arboard::Clipboard>,
+    /// inner area of the issue list as last drawn, for mouse hit-testing
+    pub list_area: Rect,
+    /// inner area of the detail pane as last drawn (empty when hidden)
+    pub detail_area: Rect,
+    /// `(wrapped row, issue id)` of each dep/dependent line in the detail pane
+    pub detail_links: Vec<(usize, String)>,
+    /// ready/blocked/doing counts, refreshed on every reload
+    pub counts: IssueCounts,
+    /// issues marked with space for a bulk action
+    pub selected_ids: HashSet<String>,
 }
 
 impl App {
@@ -261,6 +359,7 @@ impl App {
             message: None,
             show_help: false,
             input_mode: InputMode::Normal,
+            theme: Theme::from_config(&config.theme),
             config,
             detail_dep_selected: None,
             detail_section: DetailSection::default(),
@@ -289,13 +388,79 @@ impl App {
             logs_session_id: None,
             detail_scroll: 0,
             issues_focus: IssuesFocus::default(),
+            clipboard: None,
+            list_area: Rect::default(),
+            detail_area: Rect::default(),
+            detail_links: Vec::new(),
+            counts: IssueCounts::default(),
+            selected_ids: HashSet::new(),
         };
         app.reload_issues(paths)?;
+        app.restore_state(TuiState::load(&paths.tui_state_path()));
         app.reload_worktrees(paths);
         app.load_git_graph();
         Ok(app)
     }
 
+    /// order issues the way `brd ls` does and select `id`. used by the show pager.
+    pub fn use_listing_order(&mut self, id: &str) {
+        // the pager always walks the full list, whatever filter was saved
+        self.filter_query.clear();
+        self.status_filter.clear();
+        self.ready_filter = false;
+        let mut all: Vec<&Issue> = self.issues.values().collect();
+        all.sort_by(|a, b| a.cmp_for_listing(b));
+        self.sorted_issues = all.iter().map(|i| i.id().to_string()).collect();
+        self.filtered_issues.clear();
+        self.selected = self.sorted_issues.iter().position(|i| i == id).unwrap_or(0);
+        self.detail_scroll = 0;
+    }
+
+    /// apply state saved by a previous run.
+    fn restore_state(&mut self, state: TuiState) {
+        self.view = state.view;
+        self.issues_focus = state.issues_focus;
+        self.show_details = state.show_details;
+        self.filter_query = state.filter_query;
+        self.status_filter = state.status_filter.into_iter().collect();
+        self.ready_filter = state.ready_filter;
+        self.apply_filter();
+        if let Some(id) = state.selected_id {
+            self.select_issue_by_id(&id);
+        }
+    }
+
+    /// apply `brd tui` arguments on top of the restored state. any filter
+    /// argument replaces the saved filters rather than narrowing them.
+    pub fn apply_start_options(&mut self, opts: &StartOptions) {
+        if let Some(view) = opts.view {
+            self.view = view;
+        }
+        if opts.filter.is_some() || !opts.status.is_empty() {
+            self.filter_query = opts.filter.clone().unwrap_or_default();
+            self.status_filter = opts.status.iter().copied().collect();
+            self.ready_filter = false;
+            self.selected = 0;
+            self.offset = 0;
+            self.apply_filter();
+        }
+    }
+
+    /// snapshot of the state to restore on the next run.
+    pub fn session_state(&self) -> TuiState {
+        let mut status_filter: Vec<Status> = self.status_filter.iter().copied().collect();
+        status_filter.sort_by_key(|s| s.to_string());
+        TuiState {
+            view: self.view,
+            issues_focus: self.issues_focus,
+            show_details: self.show_details,
+            filter_query: self.filter_query.clone(),
+            status_filter,
+            ready_filter: self.ready_filter,
+            selected_id: self.selected_issue_id().map(|s| s.to_string()),
+        }
+    }
+
     /// reload issues from disk.
     pub fn reload_issues(&mut self, paths: &RepoPaths) -> Result<()> {
         self.reload_issues_with_message(paths, true)
@@ -308,6 +473,8 @@ impl App {
         show_message: bool,
     ) -> Result<()> {
         self.issues = load_all_issues(paths, &self.config)?;
+        self.counts = IssueCounts::from_issues(&self.issues);
+        self.selected_ids.retain(|id| self.issues.contains_key(id));
 
         // build sorted list: done/skip last, then by priority
         let mut all: Vec<&Issue> = self.issues.values().collect();
@@ -597,7 +764,7 @@ impl App {
 
         #[cfg(not(unix))]
         {
-            return Err(BrdError::Other(
+            return Err(BrdError::Unsupported(
                 "kill not implemented on this platform".to_string(),
             ));
         }
@@ -623,8 +790,7 @@ impl App {
         let issues_dir = paths.issues_dir(&config);
 
         // verify issue exists
-        let issue_path = issues_dir.join(format!("{}.md", issue_id));
-        if !issue_path.exists() {
+        if find_issue_file(&issues_dir, issue_id).is_none() {
             return Err(BrdError::IssueNotFound(issue_id.to_string()));
         }
 
@@ -668,7 +834,7 @@ impl App {
             .spawn()
             .map_err(|e| {
                 if e.kind() == std::io::ErrorKind::NotFound {
-                    BrdError::Other(
+                    BrdError::ToolNotFound(
                         "claude CLI not found - install from https://claude.ai/download"
                             .to_string(),
                     )
@@ -890,7 +1056,6 @@ impl App {
 
     /// apply the current filter to the issues list.
     pub fn apply_filter(&mut self) {
-        let query = self.filter_query.to_lowercase();
         self.filtered_issues = self
             .sorted_issues
             .iter()
@@ -910,14 +1075,7 @@ impl App {
                     return false;
                 }
                 // check query filter (matches title or id)
-                if !query.is_empty() {
-                    let matches_title = issue.title().to_lowercase().contains(&query);
-                    let matches_id = issue.id().to_lowercase().contains(&query);
-                    if !matches_title && !matches_id {
-                        return false;
-                    }
-                }
-                true
+                issue.matches_query(&self.filter_query)
             })
             .cloned()
             .collect();
@@ -998,6 +1156,22 @@ impl App {
         self.input_mode = InputMode::Normal;
     }
 
+    /// abandon whatever input is in flight, the same way Esc would.
+    pub fn cancel_input(&mut self) {
+        match self.input_mode {
+            InputMode::Normal => {}
+            InputMode::Filter(_) => self.cancel_filter(),
+            InputMode::JumpTo(_) => self.cancel_jump(),
+            InputMode::ConfirmStart { .. } => self.cancel_start(),
+            InputMode::BulkAction { .. } | InputMode::BulkTag(_) => self.cancel_bulk_action(),
+            InputMode::Tags { .. } => self.cancel_edit_tags(),
+            InputMode::Title(_)
+            | InputMode::Priority { .. }
+            | InputMode::Type { .. }
+            | InputMode::Deps { .. } => self.cancel_add_issue(),
+        }
+    }
+
     /// confirm filter input and return to normal mode.
     pub fn confirm_filter(&mut self) {
         if let InputMode::Filter(query) = &self.input_mode {
@@ -1320,12 +1494,372 @@ impl App {
         let _lock = LockGuard::acquire(&paths.lock_path())?;
 
         // save issue
-        let issue_path = issues_dir.join(format!("{}.md", id));
-        issue.save(&issue_path)?;
+        issue.save_in(&issues_dir, self.config.filename_scheme)?;
 
         self.input_mode = InputMode::Normal;
-        self.message = Some(format!("created {}", id));
-        self.reload_issues(paths)?;
+        self.reload_issues_with_message(paths, false)?;
+        if self.select_issue_by_id(&id) {
+            self.message = Some(format!("created {} · press e to edit", id));
+        } else {
+            self.message = Some(format!("created {}", id));
+        }
+        Ok(())
+    }
+
+    /// copy the selected issue's id (optionally with its title) to the clipboard.
+    /// failures are reported in the footer rather than propagated.
+    pub fn copy_selected(&mut self, with_title: bool) {
+        let Some(issue) = self.selected_issue() else {
+            self.message = Some("no issue selected".to_string());
+            return;
+        };
+        let text = clipboard_text(issue, with_title);
+
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    self.message = Some(format!("clipboard unavailable: {}", e));
+                    return;
+                }
+            }
+        }
+        let Some(clipboard) = self.clipboard.as_mut() else {
+            return;
+        };
+        self.message = Some(match clipboard.set_text(text.clone()) {
+            Ok(()) => format!("copied: {}", text),
+            Err(e) => format!("failed to copy: {}", e),
+        });
+    }
+
+    /// select the list row at screen row `y`, if there is one.
+    pub fn click_list_row(&mut self, y: u16) {
+        let area = self.list_area;
+        if y < area.y || y >= area.y + area.height {
+            return;
+        }
+        let idx = self.offset + (y - area.y) as usize;
+        if idx < self.visible_issues().len() {
+            self.selected = idx;
+            self.issues_focus = IssuesFocus::List;
+            self.detail_scroll = 0;
+            self.reset_dep_selection();
+            self.message = None;
+        }
+    }
+
+    /// follow the dep/dependent line at screen row `y` in the detail pane.
+    pub fn click_detail_row(&mut self, y: u16) {
+        let area = self.detail_area;
+        if y < area.y || y >= area.y + area.height {
+            return;
+        }
+        let row = self.detail_scroll + (y - area.y) as usize;
+        let Some(id) = self
+            .detail_links
+            .iter()
+            .find(|(r, _)| *r == row)
+            .map(|(_, id)| id.clone())
+        else {
+            return;
+        };
+        if self.select_issue_by_id(&id) {
+            self.detail_scroll = 0;
+            self.message = None;
+        } else {
+            self.message = Some(format!("{} is not in the current list", id));
+        }
+    }
+
+    /// open the jump-to-id prompt.
+    pub fn start_jump(&mut self) {
+        self.input_mode = InputMode::JumpTo(String::new());
+        self.message = None;
+    }
+
+    /// close the jump-to-id prompt without moving.
+    pub fn cancel_jump(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// select the issue matching the typed id, clearing filters if it is hidden.
+    pub fn confirm_jump(&mut self) {
+        let InputMode::JumpTo(query) = &self.input_mode else {
+            return;
+        };
+        let query = query.trim().to_string();
+        self.input_mode = InputMode::Normal;
+        if query.is_empty() {
+            return;
+        }
+
+        let id = match resolve_issue_id(&query, &self.issues) {
+            Ok(id) => id,
+            Err(BrdError::AmbiguousId(_, matches)) => {
+                let ids: Vec<_> = matches.into_iter().map(|(id, _)| id).collect();
+                self.message = Some(format!("'{}' matches {}", query, ids.join(", ")));
+                return;
+            }
+            Err(_) => {
+                self.message = Some(format!("no issue matching '{}'", query));
+                return;
+            }
+        };
+
+        if !self.visible_issues().contains(&id) {
+            self.filter_query.clear();
+            self.status_filter.clear();
+            self.ready_filter = false;
+            self.apply_filter();
+        }
+        self.view = View::Issues;
+        self.issues_focus = IssuesFocus::List;
+        self.select_issue_by_id(&id);
+        self.detail_scroll = 0;
+        self.message = None;
+    }
+
+    /// start the selected issue, asking for confirmation first if it is blocked.
+    pub fn start_selected(&mut self, paths: &RepoPaths) -> Result<()> {
+        let Some(issue) = self.selected_issue() else {
+            self.message = Some("no issue selected".to_string());
+            return Ok(());
+        };
+        let id = issue.id().to_string();
+        let derived = self.derived_state(issue);
+        if derived.is_blocked {
+            self.input_mode = InputMode::ConfirmStart {
+                id,
+                open_deps: derived.open_deps.len() + derived.missing_deps.len(),
+            };
+            return Ok(());
+        }
+        self.start_issue(paths, &id)
+    }
+
+    /// start the issue awaiting confirmation.
+    pub fn confirm_start(&mut self, paths: &RepoPaths) -> Result<()> {
+        let InputMode::ConfirmStart { id, .. } = &self.input_mode else {
+            return Ok(());
+        };
+        let id = id.clone();
+        self.input_mode = InputMode::Normal;
+        self.start_issue(paths, &id)
+    }
+
+    /// dismiss the start confirmation without changing anything.
+    pub fn cancel_start(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.message = Some("start cancelled".to_string());
+    }
+
+    /// mark the selected issue as done.
+    pub fn done_selected(&mut self, paths: &RepoPaths) -> Result<()> {
+        self.finish_selected(paths, Status::Done)
+    }
+
+    /// mark the selected issue as skipped (won't do).
+    pub fn skip_selected(&mut self, paths: &RepoPaths) -> Result<()> {
+        self.finish_selected(paths, Status::Skip)
+    }
+
+    /// close the selected issue with `status`, clearing its owner.
+    fn finish_selected(&mut self, paths: &RepoPaths, status: Status) -> Result<()> {
+        let Some(id) = self.selected_issue_id().map(|s| s.to_string()) else {
+            self.message = Some("no issue selected".to_string());
+            return Ok(());
+        };
+
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        let issues_dir = paths.issues_dir(&self.config);
+        let issue_path =
+            find_issue_file(&issues_dir, &id).ok_or_else(|| BrdError::IssueNotFound(id.clone()))?;
+        let mut issue = Issue::load(&issue_path)?;
+        issue.frontmatter.status = status;
+        issue.frontmatter.owner = None;
+        issue.mark_completed();
+        issue.save_in(&issues_dir, self.config.filename_scheme)?;
+
+        let verb = if status == Status::Skip {
+            "skipped"
+        } else {
+            "done"
+        };
+        self.reload_issues_with_message(paths, false)?;
+        self.message = Some(format!("{} {}", verb, id));
+        Ok(())
+    }
+
+    /// add or remove the highlighted issue from the bulk selection.
+    pub fn toggle_selection(&mut self) {
+        let Some(id) = self.selected_issue_id().map(|s| s.to_string()) else {
+            self.message = Some("no issue selected".to_string());
+            return;
+        };
+        if !self.selected_ids.remove(&id) {
+            self.selected_ids.insert(id);
+        }
+        self.message = Some(format!("{} selected", self.selected_ids.len()));
+    }
+
+    /// drop the bulk selection without changing any issue.
+    pub fn clear_selection(&mut self) {
+        self.selected_ids.clear();
+        self.message = Some("selection cleared".to_string());
+    }
+
+    /// open the bulk action menu for the selected issues.
+    pub fn start_bulk_action(&mut self) {
+        if self.selected_ids.is_empty() {
+            self.message = Some("no issues selected (space to select)".to_string());
+            return;
+        }
+        self.input_mode = InputMode::BulkAction { selected: 0 };
+        self.message = None;
+    }
+
+    /// run the action picked in the bulk menu, or ask for a tag first.
+    pub fn confirm_bulk_action(&mut self, paths: &RepoPaths) -> Result<()> {
+        let InputMode::BulkAction { selected } = self.input_mode else {
+            return Ok(());
+        };
+        match selected {
+            0 => self.apply_bulk_action(paths, BulkAction::Done),
+            1 => self.apply_bulk_action(paths, BulkAction::Skip),
+            _ => {
+                self.input_mode = InputMode::BulkTag(String::new());
+                Ok(())
+            }
+        }
+    }
+
+    /// add the entered tag to the selected issues.
+    pub fn confirm_bulk_tag(&mut self, paths: &RepoPaths) -> Result<()> {
+        let InputMode::BulkTag(tag) = &self.input_mode else {
+            return Ok(());
+        };
+        let tag = tag.trim().to_string();
+        if tag.is_empty() || !is_valid_tag(&tag) {
+            self.message = Some("tag cannot be empty or contain spaces or commas".to_string());
+            return Ok(());
+        }
+        self.apply_bulk_action(paths, BulkAction::AddTag(tag))
+    }
+
+    /// open the tag editor for the selected issue, prefilled with its tags.
+    pub fn start_edit_tags(&mut self) {
+        let Some(issue) = self.selected_issue() else {
+            self.message = Some("no issue selected".to_string());
+            return;
+        };
+        self.input_mode = InputMode::Tags {
+            id: issue.id().to_string(),
+            input: issue.tags().join(", "),
+        };
+        self.message = None;
+    }
+
+    /// save the edited tags. invalid input keeps the editor open.
+    pub fn confirm_edit_tags(&mut self, paths: &RepoPaths) -> Result<()> {
+        let InputMode::Tags { id, input } = &self.input_mode else {
+            return Ok(());
+        };
+        let tags = match parse_tags(input) {
+            Ok(tags) => tags,
+            Err(msg) => {
+                self.message = Some(msg);
+                return Ok(());
+            }
+        };
+        let id = id.clone();
+        self.input_mode = InputMode::Normal;
+
+        {
+            let _lock = LockGuard::acquire(&paths.lock_path())?;
+            let issues_dir = paths.issues_dir(&self.config);
+            let issue_path = find_issue_file(&issues_dir, &id)
+                .ok_or_else(|| BrdError::IssueNotFound(id.clone()))?;
+            // re-read from disk so we don't overwrite changes made outside the TUI
+            let mut issue = Issue::load(&issue_path)?;
+            issue.frontmatter.tags = tags;
+            issue.save_in(&issues_dir, self.config.filename_scheme)?;
+        }
+
+        self.reload_issues_with_message(paths, false)?;
+        self.message = Some(format!("updated tags on {}", id));
+        Ok(())
+    }
+
+    /// close the tag editor without saving.
+    pub fn cancel_edit_tags(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.message = None;
+    }
+
+    /// close the bulk menu, keeping the selection.
+    pub fn cancel_bulk_action(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.message = Some("bulk action cancelled".to_string());
+    }
+
+    /// apply `action` to every selected issue under one lock, then clear the
+    /// selection.
+    pub fn apply_bulk_action(&mut self, paths: &RepoPaths, action: BulkAction) -> Result<()> {
+        self.input_mode = InputMode::Normal;
+        let mut ids: Vec<String> = self.selected_ids.iter().cloned().collect();
+        ids.sort();
+
+        {
+            let _lock = LockGuard::acquire(&paths.lock_path())?;
+            let issues_dir = paths.issues_dir(&self.config);
+            for id in &ids {
+                let issue_path = find_issue_file(&issues_dir, id)
+                    .ok_or_else(|| BrdError::IssueNotFound(id.clone()))?;
+                // re-read from disk so we don't overwrite changes made outside the TUI
+                let mut issue = Issue::load(&issue_path)?;
+                match &action {
+                    BulkAction::Done | BulkAction::Skip => {
+                        issue.frontmatter.status = if action == BulkAction::Skip {
+                            Status::Skip
+                        } else {
+                            Status::Done
+                        };
+                        issue.frontmatter.owner = None;
+                        issue.mark_completed();
+                    }
+                    BulkAction::AddTag(tag) => {
+                        if issue.frontmatter.tags.contains(tag) {
+                            continue;
+                        }
+                        issue.frontmatter.tags.push(tag.clone());
+                    }
+                }
+                issue.save_in(&issues_dir, self.config.filename_scheme)?;
+            }
+        }
+
+        self.selected_ids.clear();
+        self.reload_issues_with_message(paths, false)?;
+        let noun = if ids.len() == 1 { "issue" } else { "issues" };
+        self.message = Some(match action {
+            BulkAction::Done => format!("done {} {}", ids.len(), noun),
+            BulkAction::Skip => format!("skipped {} {}", ids.len(), noun),
+            BulkAction::AddTag(tag) => format!("tagged {} {} #{}", ids.len(), noun, tag),
+        });
+        Ok(())
+    }
+
+    fn start_issue(&mut self, paths: &RepoPaths, id: &str) -> Result<()> {
+        let _lock = LockGuard::acquire(&paths.lock_path())?;
+        let issue_path = find_issue_file(&paths.issues_dir(&self.config), id)
+            .ok_or_else(|| BrdError::IssueNotFound(id.to_string()))?;
+        // re-read from disk so we don't overwrite changes made outside the TUI
+        let mut issue = Issue::load(&issue_path)?;
+        claim_issue(paths, &self.config, &mut issue, &self.agent_id, false)?;
+
+        self.reload_issues_with_message(paths, false)?;
+        self.message = Some(format!("started {}", id));
         Ok(())
     }
 
@@ -1335,10 +1869,7 @@ impl App {
             self.message = Some("no issue selected".to_string());
             return;
         };
-        let issue_path = paths
-            .issues_dir(&self.config)
-            .join(format!("{}.md", issue_id));
-        if issue_path.exists() {
+        if let Some(issue_path) = find_issue_file(&paths.issues_dir(&self.config), issue_id) {
             self.editor_file = Some(issue_path);
         } else {
             self.message = Some("issue file not found".to_string());
@@ -1423,7 +1954,7 @@ impl App {
         }
     }
 
-    fn select_issue_by_id(&mut self, issue_id: &str) -> bool {
+    pub(super) fn select_issue_by_id(&mut self, issue_id: &str) -> bool {
         if let Some(index) = self.visible_issues().iter().position(|id| id == issue_id) {
             self.selected = index;
             self.reset_dep_selection();
@@ -1433,6 +1964,35 @@ impl App {
     }
 }
 
+/// whether `tag` can be stored as-is: no whitespace and no commas.
+fn is_valid_tag(tag: &str) -> bool {
+    !tag.contains(|c: char| c == ',' || c.is_whitespace())
+}
+
+/// parse the tag editor's comma-separated input: entries are trimmed, empty
+/// ones dropped, and duplicates removed keeping the first occurrence.
+fn parse_tags(input: &str) -> std::result::Result<Vec<String>, String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !is_valid_tag(tag) {
+            return Err(format!("invalid tag '{}': tags cannot contain spaces", tag));
+        }
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    Ok(tags)
+}
+
+/// text placed on the clipboard by `y` (id) and `Y` (id and title).
+fn clipboard_text(issue: &Issue, with_title: bool) -> String {
+    if with_title {
+        format!("{} {}", issue.id(), issue.title())
+    } else {
+        issue.id().to_string()
+    }
+}
+
 /// format a log event from claude stream-json for display.
 fn format_log_event(event: &serde_json::Value) -> Option<String> {
     let event_type = event.get("type").and_then(|v| v.as_str())?;
@@ -1518,8 +2078,7 @@ fn generate_issue_id(config: &Config, issues_dir: &std::path::Path) -> Result<St
             })
             .collect();
         let id = format!("{}-{}", prefix, suffix);
-        let path = issues_dir.join(format!("{}.md", id));
-        if !path.exists() {
+        if find_issue_file(issues_dir, &id).is_none() {
             return Ok(id);
         }
     }
@@ -2157,7 +2716,7 @@ mod tests {
         // remove one dep from the issue file
         let issue_path = env.paths.issues_dir(&env.config).join("brd-main.md");
         let mut issue = app.issues.get("brd-main").unwrap().clone();
-        issue.frontmatter.deps = vec!["brd-dep1".to_string()];
+        issue.frontmatter.deps.blocks = vec!["brd-dep1".to_string()];
         issue.save(&issue_path).expect("failed to save");
 
         // reload - should clamp to max valid index (0)
@@ -2194,7 +2753,7 @@ mod tests {
         // remove one dependent by removing its dep on brd-main
         let issue_path = env.paths.issues_dir(&env.config).join("brd-dep2.md");
         let mut issue = app.issues.get("brd-dep2").unwrap().clone();
-        issue.frontmatter.deps = vec![];
+        issue.frontmatter.deps.blocks = vec![];
         issue.save(&issue_path).expect("failed to save");
 
         // reload - should clamp to max valid index (0)
@@ -2223,4 +2782,273 @@ mod tests {
         assert_eq!(app.detail_dep_selected, None);
         assert_eq!(app.detail_dependent_selected, Some(0));
     }
+
+    #[test]
+    fn test_clipboard_text() {
+        let issue = Issue::new(
+            "brd-aaaa".to_string(),
+            "fix the thing".to_string(),
+            Priority::P2,
+            vec![],
+        );
+        assert_eq!(clipboard_text(&issue, false), "brd-aaaa");
+        assert_eq!(clipboard_text(&issue, true), "brd-aaaa fix the thing");
+    }
+
+    #[test]
+    fn test_copy_without_selection_reports_message() {
+        let env = TestEnv::new();
+        let mut app = env.app();
+        app.copy_selected(false);
+        assert_eq!(app.message.as_deref(), Some("no issue selected"));
+    }
+
+    #[test]
+    fn test_issue_counts_on_reload() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "ready", Priority::P1, Status::Open);
+        env.add_issue("brd-bbbb", "doing", Priority::P1, Status::Doing);
+        env.add_issue("brd-cccc", "done", Priority::P1, Status::Done);
+        env.add_issue_with_deps(
+            "brd-dddd",
+            "blocked",
+            Priority::P1,
+            Status::Open,
+            vec!["brd-aaaa"],
+        );
+        let mut app = env.app();
+        assert_eq!(
+            app.counts,
+            IssueCounts {
+                ready: 1,
+                blocked: 1,
+                doing: 1
+            }
+        );
+
+        env.add_issue("brd-eeee", "another", Priority::P2, Status::Open);
+        app.reload_issues(&env.paths).unwrap();
+        assert_eq!(app.counts.ready, 2);
+    }
+
+    #[test]
+    fn test_session_state_restored_by_id() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "beta", Priority::P1, Status::Open);
+        env.add_issue("brd-cccc", "gamma", Priority::P2, Status::Open);
+
+        let mut app = env.app();
+        app.view = View::Dashboard;
+        app.show_details = false;
+        app.select_issue_by_id("brd-cccc");
+        app.session_state()
+            .save(&env.paths.tui_state_path())
+            .unwrap();
+
+        // a new issue shifts indices, but the id still wins
+        env.add_issue("brd-0000", "first", Priority::P0, Status::Open);
+        let app = env.app();
+        assert_eq!(app.view, View::Dashboard);
+        assert!(!app.show_details);
+        assert_eq!(app.selected_issue_id(), Some("brd-cccc"));
+    }
+
+    #[test]
+    fn test_session_state_ignores_vanished_selection() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+        let state = TuiState {
+            filter_query: "alp".to_string(),
+            selected_id: Some("brd-gone".to_string()),
+            ..TuiState::default()
+        };
+        state.save(&env.paths.tui_state_path()).unwrap();
+
+        let app = env.app();
+        assert_eq!(app.filter_query, "alp");
+        assert_eq!(app.selected_issue_id(), Some("brd-aaaa"));
+    }
+
+    #[test]
+    fn test_bulk_done_applies_to_all_selected() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "beta", Priority::P1, Status::Doing);
+        env.add_issue("brd-cccc", "gamma", Priority::P2, Status::Open);
+
+        let mut app = env.app();
+        app.select_issue_by_id("brd-aaaa");
+        app.toggle_selection();
+        app.select_issue_by_id("brd-bbbb");
+        app.toggle_selection();
+        assert_eq!(app.selected_ids.len(), 2);
+
+        app.apply_bulk_action(&env.paths, BulkAction::Done).unwrap();
+        assert!(app.selected_ids.is_empty());
+        assert_eq!(app.issues["brd-aaaa"].status(), Status::Done);
+        assert_eq!(app.issues["brd-bbbb"].status(), Status::Done);
+        assert!(app.issues["brd-bbbb"].frontmatter.owner.is_none());
+        assert_eq!(app.issues["brd-cccc"].status(), Status::Open);
+        assert_eq!(app.message.as_deref(), Some("done 2 issues"));
+    }
+
+    #[test]
+    fn test_bulk_tag_skips_issues_already_tagged() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "beta", Priority::P1, Status::Open);
+
+        let mut app = env.app();
+        app.selected_ids = ["brd-aaaa", "brd-bbbb"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        app.apply_bulk_action(&env.paths, BulkAction::AddTag("ui".to_string()))
+            .unwrap();
+        app.selected_ids.insert("brd-aaaa".to_string());
+        app.apply_bulk_action(&env.paths, BulkAction::AddTag("ui".to_string()))
+            .unwrap();
+
+        assert_eq!(app.issues["brd-aaaa"].tags(), ["ui"]);
+        assert_eq!(app.issues["brd-bbbb"].tags(), ["ui"]);
+    }
+
+    #[test]
+    fn test_bulk_tag_rejects_whitespace() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+
+        let mut app = env.app();
+        app.selected_ids.insert("brd-aaaa".to_string());
+        app.input_mode = InputMode::BulkTag("two words".to_string());
+        app.confirm_bulk_tag(&env.paths).unwrap();
+
+        assert!(matches!(app.input_mode, InputMode::BulkTag(_)));
+        assert!(app.issues["brd-aaaa"].tags().is_empty());
+        assert_eq!(app.selected_ids.len(), 1);
+    }
+
+    #[test]
+    fn test_reload_drops_vanished_selection() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+
+        let mut app = env.app();
+        app.selected_ids.insert("brd-aaaa".to_string());
+        app.selected_ids.insert("brd-gone".to_string());
+        app.reload_issues(&env.paths).unwrap();
+        assert_eq!(app.selected_ids.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(
+            parse_tags(" ui, bug ,,ui,docs ").unwrap(),
+            vec!["ui", "bug", "docs"]
+        );
+        assert!(parse_tags("").unwrap().is_empty());
+        assert!(parse_tags("good, two words").is_err());
+    }
+
+    #[test]
+    fn test_edit_tags_saves_parsed_tags() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+
+        let mut app = env.app();
+        app.start_edit_tags();
+        assert!(matches!(
+            app.input_mode,
+            InputMode::Tags { ref id, ref input } if id == "brd-aaaa" && input.is_empty()
+        ));
+
+        app.input_mode = InputMode::Tags {
+            id: "brd-aaaa".to_string(),
+            input: "bug, ui, bug".to_string(),
+        };
+        app.confirm_edit_tags(&env.paths).unwrap();
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert_eq!(app.issues["brd-aaaa"].tags(), ["bug", "ui"]);
+
+        // reopening prefills the current tags
+        app.start_edit_tags();
+        assert!(matches!(
+            app.input_mode,
+            InputMode::Tags { ref input, .. } if input == "bug, ui"
+        ));
+    }
+
+    #[test]
+    fn test_edit_tags_rejects_invalid_input() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+
+        let mut app = env.app();
+        app.input_mode = InputMode::Tags {
+            id: "brd-aaaa".to_string(),
+            input: "two words".to_string(),
+        };
+        app.confirm_edit_tags(&env.paths).unwrap();
+        assert!(matches!(app.input_mode, InputMode::Tags { .. }));
+        assert!(app.message.as_deref().unwrap().contains("two words"));
+        assert!(app.issues["brd-aaaa"].tags().is_empty());
+    }
+
+    #[test]
+    fn test_draw_survives_issue_vanishing_mid_refresh() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "beta", Priority::P1, Status::Open);
+
+        let mut app = env.app();
+        app.issues.remove("brd-aaaa");
+
+        let backend = ratatui::backend::TestBackend::new(100, 30);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| super::super::ui::draw(f, &mut app))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_start_options_override_saved_state() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "fix login", Priority::P0, Status::Doing);
+        env.add_issue("brd-bbbb", "fix logout", Priority::P1, Status::Open);
+        env.add_issue("brd-cccc", "write docs", Priority::P2, Status::Doing);
+        let state = TuiState {
+            view: View::Agents,
+            filter_query: "docs".to_string(),
+            ready_filter: true,
+            ..TuiState::default()
+        };
+        state.save(&env.paths.tui_state_path()).unwrap();
+
+        let mut app = env.app();
+        app.apply_start_options(&StartOptions {
+            view: Some(View::Dashboard),
+            filter: Some("fix".to_string()),
+            status: vec![Status::Doing],
+        });
+        assert_eq!(app.view, View::Dashboard);
+        assert!(!app.ready_filter);
+        assert_eq!(app.visible_issues(), &vec!["brd-aaaa".to_string()]);
+
+        // no arguments keep the saved state
+        let mut app = env.app();
+        app.apply_start_options(&StartOptions::default());
+        assert_eq!(app.view, View::Agents);
+        assert_eq!(app.filter_query, "docs");
+    }
+
+    #[test]
+    fn test_view_from_str() {
+        assert_eq!("agents".parse::<View>().unwrap(), View::Agents);
+        assert_eq!("Dashboard".parse::<View>().unwrap(), View::Dashboard);
+        assert!(matches!(
+            "kanban".parse::<View>(),
+            Err(BrdError::InvalidInput(_))
+        ));
+    }
 }
@@ -78,7 +78,7 @@ impl DiffRendererType {
             Self::Native => NativeRenderer.render(diff, width),
             Self::GitPager => {
                 let pager = get_git_pager().ok_or_else(|| {
-                    crate::error::BrdError::Other("no git pager configured".to_string())
+                    crate::error::BrdError::Config("no git pager configured".to_string())
                 })?;
                 ExternalRenderer::new(pager).render(diff, width)
             }
@@ -2,24 +2,64 @@
 
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 
-use super::app::{App, DetailSection, InputMode, IssuesFocus, View};
+use super::app::{App, BULK_ACTIONS, DetailSection, InputMode, IssuesFocus, View};
 use crate::error::Result;
 use crate::repo::RepoPaths;
 
 /// handle events. returns true if the app should quit.
 pub fn handle_events(app: &mut App, paths: &RepoPaths) -> Result<bool> {
     // poll with timeout to allow for refresh
-    if event::poll(Duration::from_millis(100))?
-        && let Event::Key(key) = event::read()?
-    {
-        return handle_key_event(app, paths, key);
+    if event::poll(Duration::from_millis(100))? {
+        match event::read()? {
+            Event::Key(key) => return handle_key_event(app, paths, key),
+            Event::Mouse(mouse) => handle_mouse_event(app, mouse),
+            _ => {}
+        }
     }
 
     Ok(false)
 }
 
+/// handle mouse events: clicks select list rows or follow dep lines, the wheel
+/// moves the selection (or scrolls the detail pane when over it).
+fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    let overlay_open =
+        app.show_logs_overlay || app.is_diff_visible() || app.show_help || app.show_detail_overlay;
+    if app.view != View::Issues || overlay_open || app.input_mode != InputMode::Normal {
+        return;
+    }
+
+    let over = |area: ratatui::layout::Rect| {
+        mouse.column >= area.x
+            && mouse.column < area.x + area.width
+            && mouse.row >= area.y
+            && mouse.row < area.y + area.height
+    };
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) if over(app.list_area) => {
+            app.click_list_row(mouse.row)
+        }
+        MouseEventKind::Down(MouseButton::Left) if over(app.detail_area) => {
+            app.click_detail_row(mouse.row)
+        }
+        MouseEventKind::ScrollUp if over(app.detail_area) => app.detail_scroll_up(3),
+        MouseEventKind::ScrollDown if over(app.detail_area) => {
+            app.detail_scroll_down(3, usize::MAX)
+        }
+        MouseEventKind::ScrollUp => app.move_up(),
+        MouseEventKind::ScrollDown => app.move_down(),
+        _ => {}
+    }
+}
+
+// the bounds checks stay inside the arms so a key at the edge is still
+// consumed by its arm rather than falling through to a later one
+#[allow(clippy::collapsible_match)]
 fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<bool> {
     // handle logs overlay mode
     if app.show_logs_overlay {
@@ -64,6 +104,16 @@ fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<b
         return Ok(false);
     }
 
+    // ctrl+c backs out of an input first; only a second press quits.
+    // every other key, including `q`, belongs to the input while one is open.
+    if key.code == KeyCode::Char('c')
+        && key.modifiers.contains(KeyModifiers::CONTROL)
+        && !matches!(app.input_mode, InputMode::Normal)
+    {
+        app.cancel_input();
+        return Ok(false);
+    }
+
     // handle input modes
     match &app.input_mode {
         InputMode::Title(current) => {
@@ -200,6 +250,108 @@ fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<b
             }
             return Ok(false);
         }
+        InputMode::JumpTo(current) => {
+            match key.code {
+                KeyCode::Esc => app.cancel_jump(),
+                KeyCode::Enter => app.confirm_jump(),
+                KeyCode::Backspace => {
+                    let mut s = current.clone();
+                    s.pop();
+                    app.input_mode = InputMode::JumpTo(s);
+                }
+                KeyCode::Char(c) => {
+                    let mut s = current.clone();
+                    s.push(c);
+                    app.input_mode = InputMode::JumpTo(s);
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+        InputMode::ConfirmStart { .. } => {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                    if let Err(e) = app.confirm_start(paths) {
+                        app.message = Some(format!("error: {}", e));
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.cancel_start(),
+                _ => {}
+            }
+            return Ok(false);
+        }
+        InputMode::BulkAction { selected } => {
+            match key.code {
+                KeyCode::Esc => app.cancel_bulk_action(),
+                KeyCode::Enter => {
+                    if let Err(e) = app.confirm_bulk_action(paths) {
+                        app.message = Some(format!("error: {}", e));
+                    }
+                }
+                KeyCode::Up | KeyCode::Char('k') if *selected > 0 => {
+                    app.input_mode = InputMode::BulkAction {
+                        selected: selected - 1,
+                    };
+                }
+                KeyCode::Down | KeyCode::Char('j') if *selected < BULK_ACTIONS.len() - 1 => {
+                    app.input_mode = InputMode::BulkAction {
+                        selected: selected + 1,
+                    };
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+        InputMode::Tags { id, input } => {
+            match key.code {
+                KeyCode::Esc => app.cancel_edit_tags(),
+                KeyCode::Enter => {
+                    if let Err(e) = app.confirm_edit_tags(paths) {
+                        app.message = Some(format!("error: {}", e));
+                    }
+                }
+                KeyCode::Backspace => {
+                    let mut s = input.clone();
+                    s.pop();
+                    app.input_mode = InputMode::Tags {
+                        id: id.clone(),
+                        input: s,
+                    };
+                }
+                KeyCode::Char(c) => {
+                    let mut s = input.clone();
+                    s.push(c);
+                    app.input_mode = InputMode::Tags {
+                        id: id.clone(),
+                        input: s,
+                    };
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
+        InputMode::BulkTag(current) => {
+            match key.code {
+                KeyCode::Esc => app.cancel_bulk_action(),
+                KeyCode::Enter => {
+                    if let Err(e) = app.confirm_bulk_tag(paths) {
+                        app.message = Some(format!("error: {}", e));
+                    }
+                }
+                KeyCode::Backspace => {
+                    let mut s = current.clone();
+                    s.pop();
+                    app.input_mode = InputMode::BulkTag(s);
+                }
+                KeyCode::Char(c) => {
+                    let mut s = current.clone();
+                    s.push(c);
+                    app.input_mode = InputMode::BulkTag(s);
+                }
+                _ => {}
+            }
+            return Ok(false);
+        }
         InputMode::Normal => {}
     }
 
@@ -223,10 +375,13 @@ fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<b
             app.issues_focus = IssuesFocus::List;
         }
 
-        // esc returns to list focus (when in details), otherwise clears filter
+        // esc returns to list focus (when in details), then clears the
+        // selection, then the filter
         KeyCode::Esc if app.view == View::Issues => {
             if app.issues_focus == IssuesFocus::Details {
                 app.issues_focus = IssuesFocus::List;
+            } else if !app.selected_ids.is_empty() {
+                app.clear_selection();
             } else if app.has_filter() {
                 app.clear_filter();
             }
@@ -287,6 +442,9 @@ fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<b
         },
         KeyCode::Char('g') => app.move_to_top(),
         KeyCode::Char('G') => app.move_to_bottom(),
+        KeyCode::Char(':') => app.start_jump(),
+        KeyCode::Char('y') if app.view == View::Issues => app.copy_selected(false),
+        KeyCode::Char('Y') if app.view == View::Issues => app.copy_selected(true),
         // half-page scroll (agents view, u without modifier)
         KeyCode::Char('u') if app.view == View::Agents => app.agents_half_page_up(),
         KeyCode::Left | KeyCode::Char('h') if app.view == View::Agents => {
@@ -322,6 +480,26 @@ fn handle_key_event(app: &mut App, paths: &RepoPaths, key: KeyEvent) -> Result<b
         // actions
         KeyCode::Char('a') | KeyCode::Char('n') => app.start_add_issue(),
         KeyCode::Char('e') => app.open_in_editor(paths),
+        KeyCode::Char('s') if app.view == View::Issues => {
+            if let Err(e) = app.start_selected(paths) {
+                app.message = Some(format!("error: {}", e));
+            }
+        }
+        KeyCode::Char('d') if app.view == View::Issues => {
+            if let Err(e) = app.done_selected(paths) {
+                app.message = Some(format!("error: {}", e));
+            }
+        }
+        KeyCode::Char('!') if app.view == View::Issues => {
+            if let Err(e) = app.skip_selected(paths) {
+                app.message = Some(format!("error: {}", e));
+            }
+        }
+        KeyCode::Char(' ') if app.view == View::Issues && app.issues_focus == IssuesFocus::List => {
+            app.toggle_selection();
+        }
+        KeyCode::Char('x') if app.view == View::Issues => app.start_bulk_action(),
+        KeyCode::Char('t') if app.view == View::Issues => app.start_edit_tags(),
         // half-page scroll (agents view)
         KeyCode::Char('d') if app.view == View::Agents => app.agents_half_page_down(),
         KeyCode::Char('r') => {
@@ -705,8 +883,13 @@ mod tests {
         // confirm deps (no deps selected) → create issue
         handle_key_event(&mut app, &env.paths, key(KeyCode::Enter)).expect("create issue failed");
         assert!(matches!(app.input_mode, InputMode::Normal));
-        assert_eq!(app.message.as_deref(), Some("refreshed"));
         assert_eq!(app.sorted_issues.len(), 1);
+        let new_id = app.sorted_issues[0].clone();
+        assert_eq!(app.selected_issue_id(), Some(new_id.as_str()));
+        assert_eq!(
+            app.message,
+            Some(format!("created {} · press e to edit", new_id))
+        );
 
         handle_key_event(&mut app, &env.paths, key(KeyCode::Down)).expect("move down failed");
         assert!(app.message.is_none());
@@ -807,6 +990,68 @@ mod tests {
         assert!(quit);
     }
 
+    #[test]
+    fn test_q_is_literal_in_title_input() {
+        let env = TestEnv::new();
+        let mut app = env.app();
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('a'))).expect("add failed");
+        for c in "quit".chars() {
+            let quit =
+                handle_key_event(&mut app, &env.paths, key(KeyCode::Char(c))).expect("type failed");
+            assert!(!quit);
+        }
+        assert!(matches!(app.input_mode, InputMode::Title(ref t) if t == "quit"));
+
+        // esc cancels the input, after which q quits
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Esc)).expect("esc failed");
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        let quit =
+            handle_key_event(&mut app, &env.paths, key(KeyCode::Char('q'))).expect("quit failed");
+        assert!(quit);
+    }
+
+    #[test]
+    fn test_q_is_literal_in_filter_and_selection_modes() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "alpha", Priority::P1, Status::Open);
+        let mut app = env.app();
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('/'))).expect("filter failed");
+        let quit =
+            handle_key_event(&mut app, &env.paths, key(KeyCode::Char('q'))).expect("q failed");
+        assert!(!quit);
+        assert!(matches!(app.input_mode, InputMode::Filter(ref q) if q == "q"));
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Esc)).expect("esc failed");
+
+        // q does nothing while picking a priority
+        app.input_mode = InputMode::Priority {
+            title: "t".to_string(),
+            selected: 2,
+        };
+        let quit =
+            handle_key_event(&mut app, &env.paths, key(KeyCode::Char('q'))).expect("q failed");
+        assert!(!quit);
+        assert!(matches!(app.input_mode, InputMode::Priority { .. }));
+    }
+
+    #[test]
+    fn test_ctrl_c_cancels_input_before_quitting() {
+        let env = TestEnv::new();
+        let mut app = env.app();
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('a'))).expect("add failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('x'))).expect("type failed");
+
+        let quit = handle_key_event(&mut app, &env.paths, ctrl_c).expect("ctrl-c failed");
+        assert!(!quit);
+        assert!(matches!(app.input_mode, InputMode::Normal));
+
+        let quit = handle_key_event(&mut app, &env.paths, ctrl_c).expect("ctrl-c failed");
+        assert!(quit);
+    }
+
     #[test]
     fn test_dependency_selection_and_open() {
         let env = TestEnv::new();
@@ -1011,4 +1256,232 @@ mod tests {
         // should have jumped to one of the dependents
         assert_ne!(app.selected_issue_id(), Some("brd-main"));
     }
+
+    #[test]
+    fn test_start_ready_issue_without_confirmation() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "ready", Priority::P1, Status::Open);
+        let mut app = env.app();
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('s'))).expect("start failed");
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert_eq!(app.issues["brd-aaaa"].status(), Status::Doing);
+        assert_eq!(
+            app.issues["brd-aaaa"].frontmatter.owner.as_deref(),
+            Some(app.agent_id.as_str())
+        );
+    }
+
+    #[test]
+    fn test_start_blocked_issue_asks_for_confirmation() {
+        let env = TestEnv::new();
+        env.add_issue_with_deps(
+            "brd-aaaa",
+            "blocked",
+            Priority::P0,
+            Status::Open,
+            vec!["brd-bbbb".to_string()],
+        );
+        env.add_issue("brd-bbbb", "blocker", Priority::P1, Status::Open);
+        let mut app = env.app();
+        assert_eq!(app.selected_issue_id(), Some("brd-aaaa"));
+
+        // declining leaves the issue untouched
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('s'))).expect("start failed");
+        assert!(matches!(
+            app.input_mode,
+            InputMode::ConfirmStart { ref id, open_deps: 1 } if id == "brd-aaaa"
+        ));
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('n'))).expect("cancel failed");
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert_eq!(app.issues["brd-aaaa"].status(), Status::Open);
+
+        // confirming starts it anyway
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('s'))).expect("start failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('y'))).expect("confirm failed");
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert_eq!(app.issues["brd-aaaa"].status(), Status::Doing);
+    }
+
+    fn type_keys(app: &mut App, env: &TestEnv, text: &str) {
+        for c in text.chars() {
+            handle_key_event(app, &env.paths, key(KeyCode::Char(c))).expect("key failed");
+        }
+    }
+
+    #[test]
+    fn test_jump_to_id_clears_hiding_filter() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "first", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "second", Priority::P1, Status::Open);
+        env.add_issue("brd-cccc", "third", Priority::P2, Status::Open);
+        let mut app = env.app();
+
+        // filter down to one issue, then jump to another
+        app.filter_query = "first".to_string();
+        app.apply_filter();
+        assert_eq!(app.visible_issues().len(), 1);
+
+        type_keys(&mut app, &env, ":ccc");
+        assert!(matches!(app.input_mode, InputMode::JumpTo(ref q) if q == "ccc"));
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Enter)).expect("jump failed");
+
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert!(!app.has_filter());
+        assert_eq!(app.selected_issue_id(), Some("brd-cccc"));
+    }
+
+    #[test]
+    fn test_jump_to_id_reports_ambiguous_and_missing() {
+        let env = TestEnv::new();
+        env.add_issue("brd-ab12", "first", Priority::P0, Status::Open);
+        env.add_issue("brd-ab34", "second", Priority::P1, Status::Open);
+        let mut app = env.app();
+
+        type_keys(&mut app, &env, ":ab");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Enter)).expect("jump failed");
+        assert_eq!(
+            app.message.as_deref(),
+            Some("'ab' matches brd-ab12, brd-ab34")
+        );
+        assert_eq!(app.selected_issue_id(), Some("brd-ab12"));
+
+        type_keys(&mut app, &env, ":zz");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Enter)).expect("jump failed");
+        assert_eq!(app.message.as_deref(), Some("no issue matching 'zz'"));
+    }
+
+    #[test]
+    fn test_done_and_skip_keys_close_issue() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "first", Priority::P0, Status::Doing);
+        env.add_issue("brd-bbbb", "second", Priority::P1, Status::Open);
+        let mut app = env.app();
+        app.select_issue_by_id("brd-aaaa");
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('d'))).expect("done failed");
+        let issue = &app.issues["brd-aaaa"];
+        assert_eq!(issue.status(), Status::Done);
+        assert!(issue.frontmatter.owner.is_none());
+        assert!(issue.frontmatter.completed_at.is_some());
+        assert_eq!(app.message.as_deref(), Some("done brd-aaaa"));
+
+        app.select_issue_by_id("brd-bbbb");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('!'))).expect("skip failed");
+        assert_eq!(app.issues["brd-bbbb"].status(), Status::Skip);
+        assert_eq!(app.message.as_deref(), Some("skipped brd-bbbb"));
+    }
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn test_mouse_click_selects_list_row() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "first", Priority::P0, Status::Open);
+        env.add_issue("brd-bbbb", "second", Priority::P1, Status::Open);
+        env.add_issue("brd-cccc", "third", Priority::P2, Status::Open);
+        let mut app = env.app();
+        app.list_area = ratatui::layout::Rect::new(1, 2, 40, 10);
+        app.offset = 1;
+
+        // second visible row, scrolled down by one
+        handle_mouse_event(&mut app, click(5, 3));
+        assert_eq!(app.selected_issue_id(), Some("brd-cccc"));
+
+        // clicks below the last issue are ignored
+        handle_mouse_event(&mut app, click(5, 9));
+        assert_eq!(app.selected_issue_id(), Some("brd-cccc"));
+
+        let scroll_up = MouseEvent {
+            kind: MouseEventKind::ScrollUp,
+            ..click(5, 3)
+        };
+        handle_mouse_event(&mut app, scroll_up);
+        assert_eq!(app.selected_issue_id(), Some("brd-bbbb"));
+    }
+
+    #[test]
+    fn test_mouse_click_follows_detail_link() {
+        let env = TestEnv::new();
+        env.add_issue_with_deps(
+            "brd-main",
+            "main",
+            Priority::P0,
+            Status::Open,
+            vec!["brd-dep1".to_string()],
+        );
+        env.add_issue("brd-dep1", "dep", Priority::P2, Status::Open);
+        let mut app = env.app();
+        assert_eq!(app.selected_issue_id(), Some("brd-main"));
+
+        app.detail_area = ratatui::layout::Rect::new(41, 2, 40, 20);
+        app.detail_links = vec![(7, "brd-dep1".to_string())];
+
+        // a non-link row does nothing
+        handle_mouse_event(&mut app, click(45, 3));
+        assert_eq!(app.selected_issue_id(), Some("brd-main"));
+
+        handle_mouse_event(&mut app, click(45, 9));
+        assert_eq!(app.selected_issue_id(), Some("brd-dep1"));
+    }
+
+    #[test]
+    fn test_bulk_selection_flow() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "first", Priority::P1, Status::Open);
+        env.add_issue("brd-bbbb", "second", Priority::P2, Status::Open);
+
+        let mut app = env.app();
+
+        // nothing selected yet
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('x'))).expect("bulk failed");
+        assert!(matches!(app.input_mode, InputMode::Normal));
+
+        // space toggles, so selecting twice leaves the issue out
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char(' '))).expect("space failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Down)).expect("down failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char(' '))).expect("space failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char(' '))).expect("space failed");
+        assert_eq!(app.selected_ids.len(), 1);
+        assert!(app.selected_ids.contains("brd-aaaa"));
+
+        // x → skip
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Char('x'))).expect("bulk failed");
+        assert!(matches!(
+            app.input_mode,
+            InputMode::BulkAction { selected: 0 }
+        ));
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Down)).expect("menu down failed");
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Enter)).expect("apply failed");
+
+        assert!(matches!(app.input_mode, InputMode::Normal));
+        assert!(app.selected_ids.is_empty());
+        assert_eq!(app.issues["brd-aaaa"].status(), Status::Skip);
+        assert_eq!(app.issues["brd-bbbb"].status(), Status::Open);
+    }
+
+    #[test]
+    fn test_esc_clears_selection_before_filter() {
+        let env = TestEnv::new();
+        env.add_issue("brd-aaaa", "first", Priority::P1, Status::Open);
+
+        let mut app = env.app();
+        app.filter_query = "first".to_string();
+        app.apply_filter();
+        app.toggle_selection();
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Esc)).expect("esc failed");
+        assert!(app.selected_ids.is_empty());
+        assert!(app.has_filter());
+
+        handle_key_event(&mut app, &env.paths, key(KeyCode::Esc)).expect("esc failed");
+        assert!(!app.has_filter());
+    }
 }
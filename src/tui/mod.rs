@@ -4,6 +4,7 @@ mod app;
 pub mod diff_panel;
 pub mod diff_render;
 mod event;
+mod state;
 mod ui;
 
 use std::io;
@@ -20,11 +21,13 @@ use crate::error::Result;
 use crate::repo::RepoPaths;
 
 use app::App;
+pub use app::{StartOptions, View};
 use event::handle_events;
 
-/// run the TUI application.
-pub fn run(paths: &RepoPaths) -> Result<()> {
+/// run the TUI application, opened in the view and filters given by `opts`.
+pub fn run(paths: &RepoPaths, opts: &StartOptions) -> Result<()> {
     // setup terminal
+    install_panic_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -33,9 +36,13 @@ pub fn run(paths: &RepoPaths) -> Result<()> {
 
     // create app state
     let mut app = App::new(paths)?;
+    app.apply_start_options(opts);
 
     // main loop
     let result = run_loop(&mut terminal, &mut app, paths);
+    let saved = app.session_state().save(&paths.tui_state_path());
+    // back to the default hook; the terminal is restored below
+    let _ = std::panic::take_hook();
 
     // restore terminal
     disable_raw_mode()?;
@@ -46,9 +53,82 @@ pub fn run(paths: &RepoPaths) -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    if let Err(e) = saved {
+        eprintln!("warning: failed to save TUI state: {}", e);
+    }
+    result
+}
+
+/// restore the terminal before the default panic message prints, so a panic
+/// mid-draw doesn't leave the shell in raw mode on the alternate screen.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            crossterm::cursor::Show
+        );
+        default_hook(info);
+    }));
+}
+
+/// run the show pager: one issue at a time in `brd ls` order, starting at `id`.
+pub fn run_pager(paths: &RepoPaths, id: &str) -> Result<()> {
+    install_panic_hook();
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = App::new(paths).and_then(|mut app| {
+        app.use_listing_order(id);
+        pager_loop(&mut terminal, &mut app)
+    });
+    let _ = std::panic::take_hook();
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
     result
 }
 
+fn pager_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+    loop {
+        terminal.draw(|f| ui::draw_pager(f, app))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let len = app.visible_issues().len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('n') | KeyCode::Right if app.selected + 1 < len => {
+                app.selected += 1;
+                app.detail_scroll = 0;
+            }
+            KeyCode::Char('p') | KeyCode::Left if app.selected > 0 => {
+                app.selected -= 1;
+                app.detail_scroll = 0;
+            }
+            KeyCode::Char('j') | KeyCode::Down => app.detail_scroll += 1,
+            KeyCode::Char('k') | KeyCode::Up => {
+                app.detail_scroll = app.detail_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+}
+
 fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     app: &mut App,
@@ -0,0 +1,97 @@
+//! TUI session state persisted between runs in `.git/brd/tui-state.json`.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::issue::Status;
+
+use super::app::{IssuesFocus, View};
+
+/// the parts of the TUI worth restoring on the next launch.
+/// selection is kept by issue id, not index, since the list changes between runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiState {
+    pub view: View,
+    pub issues_focus: IssuesFocus,
+    pub show_details: bool,
+    pub filter_query: String,
+    pub status_filter: Vec<Status>,
+    pub ready_filter: bool,
+    pub selected_id: Option<String>,
+}
+
+impl Default for TuiState {
+    fn default() -> Self {
+        Self {
+            view: View::default(),
+            issues_focus: IssuesFocus::default(),
+            show_details: true,
+            filter_query: String::new(),
+            status_filter: Vec::new(),
+            ready_filter: false,
+            selected_id: None,
+        }
+    }
+}
+
+impl TuiState {
+    /// load saved state. a missing or corrupt file yields the defaults; the
+    /// next save replaces it.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        crate::atomic::write_atomic(path, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tui-state.json");
+        let state = TuiState {
+            view: View::Agents,
+            issues_focus: IssuesFocus::Details,
+            show_details: false,
+            filter_query: "login".to_string(),
+            status_filter: vec![Status::Doing],
+            ready_filter: true,
+            selected_id: Some("brd-aaaa".to_string()),
+        };
+        state.save(&path).unwrap();
+        assert_eq!(TuiState::load(&path), state);
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_state_uses_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tui-state.json");
+        assert_eq!(TuiState::load(&path), TuiState::default());
+
+        fs::write(&path, "{not json").unwrap();
+        assert_eq!(TuiState::load(&path), TuiState::default());
+
+        // unknown views are treated as corrupt rather than failing startup
+        fs::write(&path, r#"{"view": "kanban"}"#).unwrap();
+        assert_eq!(TuiState::load(&path), TuiState::default());
+
+        // missing fields fall back individually
+        fs::write(&path, r#"{"filter_query": "x"}"#).unwrap();
+        let state = TuiState::load(&path);
+        assert_eq!(state.filter_query, "x");
+        assert!(state.show_details);
+    }
+}
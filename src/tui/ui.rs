@@ -11,12 +11,15 @@ use ratatui::{
     },
 };
 use time::{Duration as TimeDuration, OffsetDateTime};
+use unicode_width::UnicodeWidthStr;
 
-use crate::graph::{compute_derived, get_dependents};
+use crate::graph::{compute_derived, get_dependents, get_related};
 use crate::issue::{Priority, Status};
 use crate::session::SessionStatus;
+use crate::text::{pad_right, truncate};
+use crate::theme::Theme;
 
-use super::app::{App, DetailSection, InputMode, IssuesFocus, View};
+use super::app::{App, BULK_ACTIONS, DetailSection, InputMode, IssueCounts, IssuesFocus, View};
 use super::diff_panel::{DiffPanel, centered_overlay};
 
 /// draw the entire UI.
@@ -56,14 +59,29 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 }
 
 fn draw_header(f: &mut Frame, area: Rect, app: &App) {
-    let text = format!("brd tui — agent: {}", app.agent_id);
-    let header = Paragraph::new(text).style(Style::default().fg(Color::Cyan));
+    let text = header_text(&app.agent_id, &app.counts, area.width as usize);
+    let header = Paragraph::new(text).style(Style::default().fg(app.theme.header));
     f.render_widget(header, area);
 }
 
+/// header line with the agent on the left and ready/blocked/doing counts on
+/// the right. the agent part gives way first on narrow terminals.
+fn header_text(agent_id: &str, counts: &IssueCounts, width: usize) -> String {
+    let left = format!("brd tui — agent: {}", agent_id);
+    let right = format!("R:{} B:{} →:{}", counts.ready, counts.blocked, counts.doing);
+    let right_len = right.width();
+
+    if right_len >= width {
+        return truncate(&right, width);
+    }
+    let left = truncate(&left, width.saturating_sub(right_len + 1));
+    let gap = width.saturating_sub(right_len + left.width());
+    format!("{}{}{}", left, " ".repeat(gap), right)
+}
+
 fn draw_footer(f: &mut Frame, area: Rect, app: &App) {
     let msg = app.message.as_deref().unwrap_or("");
-    let help = "[1]dashboard [2]issues [3]agents [Tab]focus [\\]toggle details [a]dd [e]dit [s]tart [d]one [/]filter [?]help [q]uit";
+    let help = "[1]dashboard [2]issues [3]agents [Tab]focus [\\]toggle details [a]dd [e]dit [s]tart [d]one [!]skip [/]filter [?]help [q]uit";
     let text = if msg.is_empty() {
         help.to_string()
     } else {
@@ -165,7 +183,7 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
             )
         })
         .collect();
-    active_agents.sort_by(|a, b| a.0.cmp(b.0));
+    active_agents.sort_by(|a, b| a.0.cmp(b.0).then_with(|| a.1.cmp(b.1)));
 
     // velocity: 7-day completion and creation data
     let week_ago = now - TimeDuration::days(7);
@@ -287,8 +305,8 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
         bar_width,
         total_count,
         &[
-            (done_count, Color::Green),
-            (doing_count, Color::Yellow),
+            (done_count, app.theme.done),
+            (doing_count, app.theme.highlight),
             (open_count, Color::White),
             (skip_count, Color::DarkGray),
         ],
@@ -299,17 +317,17 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
         Line::from(vec![
             Span::styled("open ", Style::default()),
             Span::styled(format!("{:>3}", open_count), Style::default()),
-            Span::styled("  doing ", Style::default().fg(Color::Yellow)),
+            Span::styled("  doing ", Style::default().fg(app.theme.highlight)),
             Span::styled(
                 format!("{:>3}", doing_count),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app.theme.highlight),
             ),
         ]),
         Line::from(vec![
-            Span::styled("done ", Style::default().fg(Color::Green)),
+            Span::styled("done ", Style::default().fg(app.theme.done)),
             Span::styled(
                 format!("{:>3}", done_count),
-                Style::default().fg(Color::Green),
+                Style::default().fg(app.theme.done),
             ),
             Span::styled("  skip  ", Style::default().fg(Color::DarkGray)),
             Span::styled(
@@ -332,8 +350,8 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
         bar_width,
         active_total,
         &[
-            (p0_count, Color::Red),
-            (p1_count, Color::Yellow),
+            (p0_count, app.theme.urgent),
+            (p1_count, app.theme.highlight),
             (p2_count, Color::White),
             (p3_count, Color::DarkGray),
         ],
@@ -342,12 +360,15 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
         priority_bar,
         Line::from(""),
         Line::from(vec![
-            Span::styled("P0 ", Style::default().fg(Color::Red)),
-            Span::styled(format!("{:>3}", p0_count), Style::default().fg(Color::Red)),
-            Span::styled("  P1 ", Style::default().fg(Color::Yellow)),
+            Span::styled("P0 ", Style::default().fg(app.theme.urgent)),
+            Span::styled(
+                format!("{:>3}", p0_count),
+                Style::default().fg(app.theme.urgent),
+            ),
+            Span::styled("  P1 ", Style::default().fg(app.theme.highlight)),
             Span::styled(
                 format!("{:>3}", p1_count),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app.theme.highlight),
             ),
         ]),
         Line::from(vec![
@@ -373,35 +394,41 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
     let health_bar = make_stacked_bar(
         bar_width,
         ready_count + blocked_count,
-        &[(ready_count, Color::Green), (blocked_count, Color::Yellow)],
+        &[
+            (ready_count, app.theme.ready),
+            (blocked_count, app.theme.blocked),
+        ],
     );
     let mut health_lines = vec![
         health_bar,
         Line::from(""),
         Line::from(vec![
-            Span::styled("ready   ", Style::default().fg(Color::Green)),
+            Span::styled("ready   ", Style::default().fg(app.theme.ready)),
             Span::styled(
                 format!("{:>3}", ready_count),
-                Style::default().fg(Color::Green),
+                Style::default().fg(app.theme.ready),
             ),
         ]),
         Line::from(vec![
-            Span::styled("blocked ", Style::default().fg(Color::Yellow)),
+            Span::styled("blocked ", Style::default().fg(app.theme.blocked)),
             Span::styled(
                 format!("{:>3}", blocked_count),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app.theme.blocked),
             ),
         ]),
     ];
     if stale_count > 0 {
         health_lines[3] = Line::from(vec![
-            Span::styled("blocked ", Style::default().fg(Color::Yellow)),
+            Span::styled("blocked ", Style::default().fg(app.theme.blocked)),
             Span::styled(
                 format!("{:>3}", blocked_count),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app.theme.blocked),
+            ),
+            Span::styled("  stale ", Style::default().fg(app.theme.highlight)),
+            Span::styled(
+                format!("{}", stale_count),
+                Style::default().fg(app.theme.highlight),
             ),
-            Span::styled("  stale ", Style::default().fg(Color::Red)),
-            Span::styled(format!("{}", stale_count), Style::default().fg(Color::Red)),
         ]);
     }
     f.render_widget(Paragraph::new(health_lines), health_inner);
@@ -429,9 +456,9 @@ fn draw_dashboard(f: &mut Frame, area: Rect, app: &App) {
             .take(max_show)
             .map(|(owner, id, title)| {
                 let max_title = agents_inner.width.saturating_sub(25) as usize;
-                let truncated_title: String = title.chars().take(max_title).collect();
+                let truncated_title = truncate(title, max_title);
                 Line::from(vec![
-                    Span::styled(format!("{:<12}", owner), Style::default().fg(Color::Cyan)),
+                    Span::styled(pad_right(owner, 12), Style::default().fg(Color::Cyan)),
                     Span::styled(" → ", Style::default().fg(Color::DarkGray)),
                     Span::raw(format!("{} ", id)),
                     Span::styled(truncated_title, Style::default().fg(Color::DarkGray)),
@@ -586,7 +613,7 @@ fn draw_worktree_list(f: &mut Frame, area: Rect, app: &App) {
 
     let is_focused = app.agents_focus == AgentsFocus::Worktrees;
     let border_color = if is_focused {
-        Color::Yellow
+        app.theme.highlight
     } else {
         Color::DarkGray
     };
@@ -627,7 +654,7 @@ fn draw_worktree_list(f: &mut Frame, area: Rect, app: &App) {
 
             // selection indicator (only show arrow when focused)
             if is_selected && is_focused {
-                spans.push(Span::styled("▶ ", Style::default().fg(Color::Yellow)));
+                spans.push(Span::styled("▶ ", Style::default().fg(app.theme.highlight)));
             } else if is_selected {
                 spans.push(Span::styled("› ", Style::default().fg(Color::DarkGray)));
             } else {
@@ -719,7 +746,7 @@ fn draw_worktree_files(f: &mut Frame, area: Rect, app: &App) {
 
     let is_focused = app.agents_focus == AgentsFocus::Files;
     let border_color = if is_focused {
-        Color::Yellow
+        app.theme.highlight
     } else {
         Color::DarkGray
     };
@@ -776,7 +803,7 @@ fn draw_worktree_files(f: &mut Frame, area: Rect, app: &App) {
 
             // selection indicator (only show arrow when focused)
             if is_selected && is_focused {
-                spans.push(Span::styled("▶ ", Style::default().fg(Color::Yellow)));
+                spans.push(Span::styled("▶ ", Style::default().fg(app.theme.highlight)));
             } else if is_selected {
                 spans.push(Span::styled("› ", Style::default().fg(Color::DarkGray)));
             } else {
@@ -1081,6 +1108,8 @@ fn draw_issues_view(f: &mut Frame, area: Rect, app: &mut App) {
     } else {
         // full-width list only
         draw_issue_list(f, area, app);
+        app.detail_area = Rect::default();
+        app.detail_links.clear();
     }
 
     // draw detail overlay on top if active
@@ -1092,7 +1121,7 @@ fn draw_issues_view(f: &mut Frame, area: Rect, app: &mut App) {
 fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
     let is_focused = app.issues_focus == IssuesFocus::List;
     let border_color = if is_focused {
-        Color::Yellow
+        app.theme.highlight
     } else {
         Color::DarkGray
     };
@@ -1137,6 +1166,11 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
     } else {
         format!(" Issues ({}) ", app.sorted_issues.len())
     };
+    let title = if app.selected_ids.is_empty() {
+        title
+    } else {
+        format!("{}[{} selected] ", title, app.selected_ids.len())
+    };
 
     let block = Block::default()
         .title(title)
@@ -1156,11 +1190,20 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
         .iter()
         .enumerate()
         .map(|(i, id)| {
-            let issue = app.issues.get(id).unwrap();
+            let Some(issue) = app.issues.get(id) else {
+                // the issue vanished mid-refresh; keep its row so indices line up
+                return ListItem::new(Span::styled(
+                    format!("    {} (missing)", id),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            };
             let derived = compute_derived(issue, &app.issues);
             let is_blocker = blockers.contains(id);
-            // show ! for blockers, → for doing, otherwise space
-            let status_prefix = if issue.status() == Status::Doing {
+            let is_marked = app.selected_ids.contains(id);
+            // show ● for bulk-selected, ! for blockers, → for doing, otherwise space
+            let status_prefix = if is_marked {
+                "● "
+            } else if issue.status() == Status::Doing {
                 "→ "
             } else if is_blocker {
                 "! "
@@ -1186,7 +1229,7 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
             let is_blocked = derived.is_blocked;
             let base_style = if is_selected {
                 Style::default()
-                    .bg(Color::Yellow)
+                    .bg(app.theme.highlight)
                     .fg(Color::Black)
                     .add_modifier(Modifier::BOLD)
             } else {
@@ -1224,21 +1267,25 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
                 type_badge,
                 Span::styled(
                     format!(
-                        "{} {} {:>4} {:<10} {}",
+                        "{} {} {:>4} {} {}",
                         id,
                         issue.priority(),
                         age,
-                        owner,
+                        pad_right(&owner, 10),
                         title_part
                     ),
                     style,
                 ),
             ];
-            push_colored_tags(&mut rest_spans, &issue.frontmatter.tags, style);
+            push_colored_tags(&mut rest_spans, &issue.frontmatter.tags, style, &app.theme);
 
-            let line = if is_blocker && !is_selected {
+            let line = if is_marked && !is_selected {
+                let mut spans = vec![Span::styled("● ", Style::default().fg(app.theme.highlight))];
+                spans.extend(rest_spans);
+                Line::from(spans)
+            } else if is_blocker && !is_selected {
                 // show red "!" prefix for blockers (but not when selected, as bg is yellow)
-                let mut spans = vec![Span::styled("! ", Style::default().fg(Color::Red))];
+                let mut spans = vec![Span::styled("! ", Style::default().fg(app.theme.blocked))];
                 spans.extend(rest_spans);
                 Line::from(spans)
             } else {
@@ -1258,6 +1305,7 @@ fn draw_issue_list(f: &mut Frame, area: Rect, app: &mut App) {
         Some(app.selected)
     };
     update_offset(&mut app.offset, selected, visible_len, view_height);
+    app.list_area = block.inner(area);
     let mut state = ListState::default()
         .with_selected(selected)
         .with_offset(app.offset);
@@ -1293,10 +1341,10 @@ fn issue_tags_width(tags: &[String]) -> usize {
         return 0;
     }
     // each tag contributes "#" + tag plus one separator between tags.
-    tags.iter().map(|tag| tag.len() + 1).sum::<usize>() + tags.len() - 1
+    tags.iter().map(|tag| tag.width() + 1).sum::<usize>() + tags.len() - 1
 }
 
-fn push_colored_tags(spans: &mut Vec<Span<'static>>, tags: &[String], style: Style) {
+fn push_colored_tags(spans: &mut Vec<Span<'static>>, tags: &[String], style: Style, theme: &Theme) {
     if tags.is_empty() {
         return;
     }
@@ -1307,9 +1355,9 @@ fn push_colored_tags(spans: &mut Vec<Span<'static>>, tags: &[String], style: Sty
             spans.push(Span::styled(" ", style));
         }
         let color = if tag == "bug" {
-            Color::Red
+            theme.urgent
         } else {
-            Color::Cyan
+            theme.header
         };
         let tag_style = style.patch(Style::default().fg(color));
         spans.push(Span::styled(format!("#{}", tag), tag_style));
@@ -1321,7 +1369,7 @@ fn draw_detail(f: &mut Frame, area: Rect, app: &mut App) {
 
     let is_focused = app.issues_focus == IssuesFocus::Details;
     let border_color = if is_focused {
-        Color::Yellow
+        app.theme.highlight
     } else {
         Color::DarkGray
     };
@@ -1330,13 +1378,21 @@ fn draw_detail(f: &mut Frame, area: Rect, app: &mut App) {
         .border_style(Style::default().fg(border_color));
 
     // build the content lines first, collecting all needed data
-    let lines = build_detail_lines(
+    let (lines, links) = build_detail_lines(
         app,
         app.detail_dep_selected,
         app.detail_section,
         app.detail_dependent_selected,
     );
 
+    // remember where clickable dep/dependent lines land for mouse handling
+    app.detail_area = block.inner(area);
+    let row_starts = wrapped_row_starts(&lines, app.detail_area.width);
+    app.detail_links = links
+        .into_iter()
+        .map(|(line_idx, id)| (row_starts[line_idx], id))
+        .collect();
+
     if lines.is_empty() {
         let text = Paragraph::new("no issue selected").block(block.clone().title(" Detail "));
         f.render_widget(text, area);
@@ -1390,7 +1446,22 @@ fn draw_detail(f: &mut Frame, area: Rect, app: &mut App) {
     }
 }
 
+/// the wrapped row each line starts on when rendered `width` columns wide.
+fn wrapped_row_starts(lines: &[Line<'static>], width: u16) -> Vec<usize> {
+    let mut starts = Vec::with_capacity(lines.len());
+    let mut row = 0;
+    for line in lines {
+        starts.push(row);
+        row += Paragraph::new(line.clone())
+            .wrap(Wrap { trim: false })
+            .line_count(width)
+            .max(1);
+    }
+    starts
+}
+
 /// build content lines for the detail pane, returning empty vec if no issue selected.
+/// also returns `(line index, issue id)` for each dep/dependent line, so clicks can follow them.
 /// `selected_dep` enables numbered prefixes and preview for the detail pane; pass None for overlay.
 /// `detail_section` controls which section (deps/dependents) shows numbered items.
 /// `selected_dependent` tracks selected dependent index.
@@ -1399,10 +1470,11 @@ fn build_detail_lines(
     selected_dep: Option<usize>,
     detail_section: DetailSection,
     selected_dependent: Option<usize>,
-) -> Vec<Line<'static>> {
+) -> (Vec<Line<'static>>, Vec<(usize, String)>) {
     let Some(issue) = app.selected_issue() else {
-        return Vec::new();
+        return (Vec::new(), Vec::new());
     };
+    let mut links = Vec::new();
 
     let derived = app.derived_state(issue);
 
@@ -1424,8 +1496,8 @@ fn build_detail_lines(
             Span::styled(
                 issue.status().to_string(),
                 match issue.status() {
-                    crate::issue::Status::Done => Style::default().fg(Color::Green),
-                    crate::issue::Status::Doing => Style::default().fg(Color::Yellow),
+                    crate::issue::Status::Done => Style::default().fg(app.theme.done),
+                    crate::issue::Status::Doing => Style::default().fg(app.theme.highlight),
                     crate::issue::Status::Open => Style::default(),
                     crate::issue::Status::Skip => Style::default().fg(Color::DarkGray),
                 },
@@ -1462,15 +1534,15 @@ fn build_detail_lines(
             .join(" ");
         lines.push(Line::from(vec![
             Span::styled("Tags:     ", Style::default().fg(Color::DarkGray)),
-            Span::styled(tags, Style::default().fg(Color::Cyan)),
+            Span::styled(tags, Style::default().fg(app.theme.header)),
         ]));
     }
 
     // state
     let state_text = if derived.is_ready {
-        Span::styled("READY", Style::default().fg(Color::Green))
+        Span::styled("READY", Style::default().fg(app.theme.ready))
     } else if derived.is_blocked {
-        Span::styled("BLOCKED", Style::default().fg(Color::Red))
+        Span::styled("BLOCKED", Style::default().fg(app.theme.blocked))
     } else {
         Span::raw("")
     };
@@ -1525,9 +1597,9 @@ fn build_detail_lines(
                         t.to_string()
                     };
                     match dep_issue.status() {
-                        Status::Done => ("✓", "done", Color::Green, truncated),
+                        Status::Done => ("✓", "done", app.theme.done, truncated),
                         Status::Skip => ("⊘", "skip", Color::DarkGray, truncated),
-                        Status::Doing => ("→", "doing", Color::Yellow, truncated),
+                        Status::Doing => ("→", "doing", app.theme.highlight, truncated),
                         Status::Open => ("○", "open", Color::White, truncated),
                     }
                 } else {
@@ -1548,6 +1620,7 @@ fn build_detail_lines(
                 )
             };
 
+            links.push((lines.len(), dep_id.clone()));
             lines.push(Line::from(Span::styled(text, style)));
         }
 
@@ -1564,8 +1637,8 @@ fn build_detail_lines(
             )));
             if let Some(dep_issue) = app.issues.get(dep_id) {
                 let status_style = match dep_issue.status() {
-                    crate::issue::Status::Done => Style::default().fg(Color::Green),
-                    crate::issue::Status::Doing => Style::default().fg(Color::Yellow),
+                    crate::issue::Status::Done => Style::default().fg(app.theme.done),
+                    crate::issue::Status::Doing => Style::default().fg(app.theme.highlight),
                     crate::issue::Status::Open => Style::default(),
                     crate::issue::Status::Skip => Style::default().fg(Color::DarkGray),
                 };
@@ -1632,9 +1705,9 @@ fn build_detail_lines(
                         t.to_string()
                     };
                     match dep_issue.status() {
-                        Status::Done => ("✓", "done", Color::Green, truncated),
+                        Status::Done => ("✓", "done", app.theme.done, truncated),
                         Status::Skip => ("⊘", "skip", Color::DarkGray, truncated),
-                        Status::Doing => ("→", "doing", Color::Yellow, truncated),
+                        Status::Doing => ("→", "doing", app.theme.highlight, truncated),
                         Status::Open => ("○", "open", Color::White, truncated),
                     }
                 } else {
@@ -1655,6 +1728,7 @@ fn build_detail_lines(
                 )
             };
 
+            links.push((lines.len(), dep_id.clone()));
             lines.push(Line::from(Span::styled(text, style)));
         }
 
@@ -1671,8 +1745,8 @@ fn build_detail_lines(
             )));
             if let Some(dep_issue) = app.issues.get(dep_id) {
                 let status_style = match dep_issue.status() {
-                    crate::issue::Status::Done => Style::default().fg(Color::Green),
-                    crate::issue::Status::Doing => Style::default().fg(Color::Yellow),
+                    crate::issue::Status::Done => Style::default().fg(app.theme.done),
+                    crate::issue::Status::Doing => Style::default().fg(app.theme.highlight),
                     crate::issue::Status::Open => Style::default(),
                     crate::issue::Status::Skip => Style::default().fg(Color::DarkGray),
                 };
@@ -1697,6 +1771,44 @@ fn build_detail_lines(
         }
     }
 
+    // related (soft links) — informational only, so not a selectable section
+    let related = get_related(issue.id(), &app.issues);
+    if !related.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Related:",
+            Style::default().fg(Color::DarkGray),
+        )));
+        for rel_id in &related {
+            let (symbol, status_text, color, title) = match app.issues.get(rel_id) {
+                Some(rel_issue) => {
+                    let (symbol, color) = match rel_issue.status() {
+                        Status::Done => ("✓", app.theme.done),
+                        Status::Skip => ("⊘", Color::DarkGray),
+                        Status::Doing => ("→", app.theme.highlight),
+                        Status::Open => ("○", Color::White),
+                    };
+                    let t = rel_issue.title();
+                    let title = if t.chars().count() > 60 {
+                        let mut s: String = t.chars().take(59).collect();
+                        s.push('…');
+                        s
+                    } else {
+                        t.to_string()
+                    };
+                    (symbol, rel_issue.status().to_string(), color, title)
+                }
+                None => ("?", "missing".to_string(), Color::Red, String::new()),
+            };
+            let text = format!("  {} {} ({})  {}", symbol, rel_id, status_text, title);
+            links.push((lines.len(), rel_id.clone()));
+            lines.push(Line::from(Span::styled(
+                text.trim_end().to_string(),
+                Style::default().fg(color),
+            )));
+        }
+    }
+
     // acceptance
     if !issue.frontmatter.acceptance.is_empty() {
         lines.push(Line::from(""));
@@ -1709,6 +1821,18 @@ fn build_detail_lines(
         }
     }
 
+    // attachments
+    if !issue.frontmatter.attachments.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Attachments:",
+            Style::default().fg(Color::DarkGray),
+        )));
+        for path in &issue.frontmatter.attachments {
+            lines.push(Line::from(format!("  {}", path)));
+        }
+    }
+
     // body
     if !issue.body.is_empty() {
         lines.push(Line::from(""));
@@ -1721,7 +1845,7 @@ fn build_detail_lines(
         }
     }
 
-    lines
+    (lines, links)
 }
 
 fn draw_detail_overlay(f: &mut Frame, area: Rect, app: &mut App) {
@@ -1737,7 +1861,7 @@ fn draw_detail_overlay(f: &mut Frame, area: Rect, app: &mut App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Yellow));
 
-    let lines = build_detail_lines(app, None, DetailSection::Deps, None);
+    let (lines, _) = build_detail_lines(app, None, DetailSection::Deps, None);
     if lines.is_empty() {
         let text = Paragraph::new("no issue selected").block(block);
         f.render_widget(text, overlay_area);
@@ -1759,6 +1883,42 @@ fn draw_detail_overlay(f: &mut Frame, area: Rect, app: &mut App) {
     f.render_widget(paragraph, overlay_area);
 }
 
+/// draw the single-issue pager used by `brd show --pager`.
+pub fn draw_pager(f: &mut Frame, app: &mut App) {
+    let area = f.area();
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let position = format!(
+        " {}/{} · n next · p prev · j/k scroll · q quit ",
+        app.selected + 1,
+        app.visible_issues().len()
+    );
+    let block = Block::default()
+        .title(position)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.theme.highlight));
+
+    let (lines, _) = build_detail_lines(app, None, DetailSection::Deps, None);
+    if lines.is_empty() {
+        f.render_widget(Paragraph::new("no issues").block(block), area);
+        return;
+    }
+
+    let paragraph = Paragraph::new(Text::from(lines))
+        .block(block)
+        .wrap(Wrap { trim: false });
+
+    // use wrapped line count so scroll accounts for word-wrapped lines
+    let max_scroll = paragraph
+        .line_count(area.width)
+        .saturating_sub(inner_height);
+    if app.detail_scroll > max_scroll {
+        app.detail_scroll = max_scroll;
+    }
+
+    let paragraph = paragraph.scroll((app.detail_scroll as u16, 0));
+    f.render_widget(paragraph, area);
+}
+
 fn draw_help(f: &mut Frame, area: Rect) {
     let block = Block::default()
         .title(" help (press ? to close) ")
@@ -1775,9 +1935,11 @@ fn draw_help(f: &mut Frame, area: Rect) {
         Line::from("  ↓ / j      move down"),
         Line::from("  g          go to top"),
         Line::from("  G          go to bottom"),
+        Line::from("  :          jump to issue by ID"),
         Line::from("  Ctrl+u/d   half-page scroll"),
         Line::from("  Tab        switch focus to detail pane"),
         Line::from("  Enter      switch focus to detail pane"),
+        Line::from("  Space      select issue for a bulk action"),
         Line::from(""),
         Line::from(Span::styled(
             "issues view - detail focused",
@@ -1800,6 +1962,10 @@ fn draw_help(f: &mut Frame, area: Rect) {
         Line::from("  e          edit selected issue"),
         Line::from("  s          start selected issue"),
         Line::from("  d          mark selected issue as done"),
+        Line::from("  !          mark selected issue as skipped"),
+        Line::from("  x          bulk action on selected issues"),
+        Line::from("  t          edit tags of selected issue"),
+        Line::from("  y / Y      copy ID / ID and title to clipboard"),
         Line::from("  r          refresh issues from disk"),
         Line::from("  S          spawn agent for issue"),
         Line::from(""),
@@ -1826,7 +1992,7 @@ fn draw_help(f: &mut Frame, area: Rect) {
         Line::from("  /          enter filter mode"),
         Line::from("  R          toggle ready filter"),
         Line::from("  enter      confirm filter"),
-        Line::from("  esc        clear filter / unfocus detail"),
+        Line::from("  esc        clear selection / filter, unfocus detail"),
         Line::from(""),
         Line::from(Span::styled(
             "other",
@@ -1841,22 +2007,6 @@ fn draw_help(f: &mut Frame, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-fn truncate(s: &str, max_len: usize) -> String {
-    if max_len == 0 {
-        return String::new();
-    }
-    if max_len == 1 {
-        return "…".to_string();
-    }
-    if s.chars().count() <= max_len {
-        s.to_string()
-    } else {
-        let mut truncated: String = s.chars().take(max_len - 1).collect();
-        truncated.push('…');
-        truncated
-    }
-}
-
 fn format_age(timestamp: OffsetDateTime) -> String {
     let now = OffsetDateTime::now_utc();
     let duration = now - timestamp;
@@ -1896,6 +2046,11 @@ fn draw_input_dialog(f: &mut Frame, app: &App) {
         InputMode::Priority { .. } => 7, // title + 4 options
         InputMode::Type { .. } => 7,     // title + pri + 3 options
         InputMode::Deps { .. } => 12.min(app.sorted_issues.len() as u16 + 5),
+        InputMode::BulkAction { .. } => BULK_ACTIONS.len() as u16 + 2,
+        InputMode::JumpTo(_)
+        | InputMode::ConfirmStart { .. }
+        | InputMode::BulkTag(_)
+        | InputMode::Tags { .. } => 3,
         InputMode::Filter(_) | InputMode::Normal => return,
     };
 
@@ -2076,6 +2231,93 @@ fn draw_input_dialog(f: &mut Frame, app: &App) {
                 f.render_widget(list, chunks[3]);
             }
         }
+        InputMode::JumpTo(query) => {
+            let block = Block::default()
+                .title(" Jump to ID (Enter, Esc) ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.header));
+
+            let input = Paragraph::new(format!("{}_", query))
+                .block(block)
+                .style(Style::default().fg(Color::White));
+
+            f.render_widget(input, area);
+        }
+        InputMode::ConfirmStart { id, open_deps } => {
+            let block = Block::default()
+                .title(format!(" Start {} (y/n) ", id))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.blocked));
+
+            let noun = if *open_deps == 1 {
+                "dependency"
+            } else {
+                "dependencies"
+            };
+            let prompt = Paragraph::new(format!(
+                "this issue has {} open {}, start anyway?",
+                open_deps, noun
+            ))
+            .block(block)
+            .style(Style::default().fg(Color::White));
+
+            f.render_widget(prompt, area);
+        }
+        InputMode::BulkAction { selected } => {
+            let block = Block::default()
+                .title(format!(
+                    " {} selected (Enter, Esc) ",
+                    app.selected_ids.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow));
+
+            let items: Vec<ListItem> = BULK_ACTIONS
+                .iter()
+                .enumerate()
+                .map(|(i, action)| {
+                    let style = if i == *selected {
+                        Style::default()
+                            .bg(Color::Yellow)
+                            .fg(Color::Black)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    ListItem::new(format!("  {}", action)).style(style)
+                })
+                .collect();
+
+            let list = List::new(items).block(block);
+            f.render_widget(list, area);
+        }
+        InputMode::Tags { id, input } => {
+            let block = Block::default()
+                .title(format!(" Tags for {} - comma-separated (Enter, Esc) ", id))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow));
+
+            let input = Paragraph::new(format!("{}_", input))
+                .block(block)
+                .style(Style::default().fg(Color::White));
+
+            f.render_widget(input, area);
+        }
+        InputMode::BulkTag(tag) => {
+            let block = Block::default()
+                .title(format!(
+                    " Tag {} issues (Enter, Esc) ",
+                    app.selected_ids.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow));
+
+            let input = Paragraph::new(format!("{}_", tag))
+                .block(block)
+                .style(Style::default().fg(Color::White));
+
+            f.render_widget(input, area);
+        }
         InputMode::Filter(_) | InputMode::Normal => {}
     }
 }
@@ -2205,48 +2447,27 @@ fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
 
 #[cfg(test)]
 mod tests {
-    use super::truncate;
-
-    #[test]
-    fn ascii_within_limit() {
-        assert_eq!(truncate("hello", 10), "hello");
-    }
-
-    #[test]
-    fn ascii_at_limit() {
-        assert_eq!(truncate("hello", 5), "hello");
-    }
-
-    #[test]
-    fn ascii_over_limit() {
-        assert_eq!(truncate("hello world", 5), "hell…");
-    }
-
-    #[test]
-    fn multibyte_em_dash() {
-        // '—' is 3 bytes in UTF-8; slicing at byte boundaries must not panic
-        let s = "add support for query — live";
-        assert_eq!(truncate(s, 26), "add support for query — l…");
-    }
-
-    #[test]
-    fn multibyte_emoji() {
-        let s = "🎉 party time";
-        assert_eq!(truncate(s, 5), "🎉 pa…");
-    }
+    use super::{IssueCounts, header_text};
 
     #[test]
-    fn max_len_zero() {
-        assert_eq!(truncate("hello", 0), "");
-    }
-
-    #[test]
-    fn max_len_one() {
-        assert_eq!(truncate("hello", 1), "…");
+    fn header_right_aligns_counts() {
+        let counts = IssueCounts {
+            ready: 12,
+            blocked: 4,
+            doing: 3,
+        };
+        let text = header_text("alice", &counts, 40);
+        assert_eq!(text.chars().count(), 40);
+        assert!(text.starts_with("brd tui — agent: alice"));
+        assert!(text.ends_with("R:12 B:4 →:3"));
     }
 
     #[test]
-    fn empty_string() {
-        assert_eq!(truncate("", 5), "");
+    fn header_truncates_agent_before_counts() {
+        let counts = IssueCounts::default();
+        let text = header_text("a-very-long-agent-name", &counts, 20);
+        assert_eq!(text, "brd tui… R:0 B:0 →:0");
+        assert!(text.ends_with("R:0 B:0 →:0"));
+        assert_eq!(header_text("x", &counts, 5), "R:0 …");
     }
 }
@@ -1,7 +1,8 @@
 //! integration tests for the brd CLI.
 
+use std::io::Write;
 use std::path::PathBuf;
-use std::process::{Command, Output};
+use std::process::{Command, Output, Stdio};
 
 /// a temporary test environment with a git repo and braid initialized.
 struct TestEnv {
@@ -97,6 +98,25 @@ impl TestEnv {
             .expect("failed to run brd")
     }
 
+    /// run brd with args, piping `input` to its stdin.
+    fn brd_with_stdin(&self, args: &[&str], input: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_brd"))
+            .args(args)
+            .current_dir(self.path())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to run brd");
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        child.wait_with_output().expect("failed to run brd")
+    }
+
     /// get stdout as string.
     fn stdout(output: &Output) -> String {
         String::from_utf8_lossy(&output.stdout).to_string()
@@ -210,6 +230,30 @@ fn test_ls_shows_tags() {
     assert!(stdout.contains("#urgent"));
 }
 
+#[test]
+fn test_ls_mine_filters_by_resolved_agent() {
+    let env = TestEnv::new();
+
+    let output = env.brd_json(&["add", "my issue"]);
+    let mine = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+    let output = env.brd_json(&["add", "their issue"]);
+    let theirs = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    env.brd(&["set", &mine, "owner", "me"]);
+    env.brd(&["set", &theirs, "owner", "someone-else"]);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_brd"))
+        .args(["ls", "--mine"])
+        .env("BRD_AGENT_ID", "me")
+        .current_dir(env.path())
+        .output()
+        .expect("failed to run brd");
+    assert!(output.status.success());
+    let stdout = TestEnv::stdout(&output);
+    assert!(stdout.contains(&mine));
+    assert!(!stdout.contains(&theirs));
+}
+
 #[test]
 fn test_start_picks_highest_priority() {
     let env = TestEnv::new();
@@ -340,6 +384,41 @@ fn test_json_output_ls() {
     assert_eq!(json[0]["priority"], "P1");
 }
 
+#[test]
+fn test_json_output_ls_with_limit() {
+    let env = TestEnv::new();
+    env.brd(&["add", "first"]);
+    env.brd(&["add", "second"]);
+    env.brd(&["add", "third"]);
+
+    let output = env.brd_json(&["ls", "--limit", "2"]);
+    assert!(output.status.success());
+
+    let json = TestEnv::json(&output);
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["items"].as_array().unwrap().len(), 2);
+
+    let output = env.brd_json(&["ls", "--limit", "2", "--offset", "2"]);
+    let json = TestEnv::json(&output);
+    assert_eq!(json["total"], 3);
+    assert_eq!(json["items"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_ls_output_writes_to_file() {
+    let env = TestEnv::new();
+    env.brd(&["add", "exported issue", "--tag", "bug"]);
+
+    let out_path = env.path().join("issues.txt");
+    let output = env.brd(&["ls", "--output", out_path.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert!(TestEnv::stdout(&output).is_empty());
+
+    let written = std::fs::read_to_string(&out_path).unwrap();
+    assert!(written.contains("exported issue #bug"));
+    assert!(!written.contains('\x1b'));
+}
+
 #[test]
 fn test_json_output_show() {
     let env = TestEnv::new();
@@ -436,10 +515,127 @@ fn test_json_output_doctor() {
     assert!(json["errors"].as_array().unwrap().is_empty());
 }
 
+#[test]
+fn test_version_reports_repo_schema() {
+    let env = TestEnv::new();
+
+    let output = env.brd_json(&["version"]);
+    assert!(output.status.success());
+    let json = TestEnv::json(&output);
+    assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(json["repo"]["schema"], json["schema"]);
+    assert_eq!(json["repo"]["status"], "ok");
+
+    // outside a repo only the binary info is shown
+    let outside = tempfile::tempdir().unwrap();
+    let output = TestEnv::run_brd_in(&outside.path().to_path_buf(), &["--json", "version"]);
+    assert!(output.status.success());
+    assert!(TestEnv::json(&output)["repo"].is_null());
+}
+
+#[test]
+fn test_doctor_diagnoses_newer_repo_schema() {
+    let env = TestEnv::new();
+
+    let config_path = env.path().join(".braid/config.toml");
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    let config = config
+        .lines()
+        .map(|line| {
+            if line.starts_with("schema_version") {
+                "schema_version = 999"
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    std::fs::write(&config_path, config).unwrap();
+
+    // regular commands refuse to touch the repo
+    let output = env.brd(&["ls"]);
+    assert_eq!(output.status.code(), Some(20));
+    assert!(TestEnv::stderr(&output).contains("schema v999"));
+    let output = env.brd_json(&["ls"]);
+    assert_eq!(TestEnv::json(&output)["code"], "schema_mismatch");
+
+    // doctor still runs and says what's wrong. its report comes first on
+    // stdout, followed by the generic error object.
+    let output = env.brd_json(&["doctor"]);
+    assert!(!output.status.success());
+    let stdout = TestEnv::stdout(&output);
+    let json: serde_json::Value = serde_json::Deserializer::from_str(&stdout)
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+    let check = json["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "config_schema")
+        .expect("config_schema check");
+    assert_eq!(check["passed"], false);
+    assert!(check["description"].as_str().unwrap().contains("v999"));
+    assert_eq!(json["errors"][0]["code"], "schema_unsupported");
+}
+
 // =============================================================================
 // error case tests
 // =============================================================================
 
+#[test]
+fn test_errors_lists_codes_outside_a_repo() {
+    let outside = tempfile::tempdir().unwrap();
+    let output = TestEnv::run_brd_in(&outside.path().to_path_buf(), &["--json", "errors"]);
+    assert!(output.status.success());
+    let codes = TestEnv::json(&output);
+    let find = |code: &str| {
+        codes
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["code"] == code)
+            .unwrap_or_else(|| panic!("missing {}", code))["exit"]
+            .clone()
+    };
+    assert_eq!(find("issue_not_found"), 12);
+    assert_eq!(find("lock_held"), 22);
+
+    // and the exit status of a real failure matches the listing
+    let env = TestEnv::new();
+    let output = env.brd_json(&["show", "zzzz"]);
+    assert_eq!(output.status.code(), Some(12));
+    assert_eq!(TestEnv::json(&output)["exit"], find("issue_not_found"));
+}
+
+#[test]
+fn test_ndjson_reports_errors_as_one_json_line() {
+    let env = TestEnv::new();
+    let output = env.brd(&["ls", "--ndjson", "--blocked-by", "zzzz"]);
+    assert_eq!(output.status.code(), Some(12));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["code"], "issue_not_found");
+    assert!(TestEnv::stderr(&output).is_empty());
+}
+
+#[test]
+fn test_show_several_ids_json_is_one_document() {
+    let env = TestEnv::new();
+    let output = env.brd_json(&["add", "real"]);
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    let output = env.brd_json(&["show", &id, "zzzz"]);
+    assert_eq!(output.status.code(), Some(12));
+    // a second document after the first would fail to parse
+    let json = TestEnv::json(&output);
+    assert_eq!(json["issues"][0]["id"], id.as_str());
+    assert_eq!(json["errors"][0]["id"], "zzzz");
+    assert_eq!(json["errors"][0]["code"], "issue_not_found");
+}
+
 #[test]
 fn test_error_issue_not_found() {
     let env = TestEnv::new();
@@ -700,3 +896,485 @@ fn test_done_clears_owner() {
     assert_eq!(json["status"], "done");
     assert!(json["owner"].is_null());
 }
+
+// =============================================================================
+// auto-sync tests
+// =============================================================================
+
+impl TestEnv {
+    /// create a test environment with a bare `origin` remote and the given auto-sync settings.
+    fn with_remote(auto_pull: bool, auto_push: bool) -> (Self, tempfile::TempDir) {
+        let env = Self::new();
+        let remote = tempfile::tempdir().expect("failed to create temp remote");
+
+        Command::new("git")
+            .args(["init", "--bare"])
+            .current_dir(remote.path())
+            .output()
+            .expect("failed to init bare remote");
+        Command::new("git")
+            .args(["remote", "add", "origin", &remote.path().to_string_lossy()])
+            .current_dir(env.path())
+            .output()
+            .expect("failed to add remote");
+
+        let config_path = env.path().join(".braid/config.toml");
+        let config = std::fs::read_to_string(&config_path).expect("failed to read config");
+        let config = config
+            .replace("auto_pull = false", &format!("auto_pull = {}", auto_pull))
+            .replace("auto_push = false", &format!("auto_push = {}", auto_push));
+        std::fs::write(&config_path, config).expect("failed to write config");
+
+        (env, remote)
+    }
+
+    /// list issue files on the remote's issues branch.
+    fn remote_issue_files(remote: &tempfile::TempDir) -> String {
+        let output = Command::new("git")
+            .args(["ls-tree", "-r", "--name-only", "braid-issues"])
+            .current_dir(remote.path())
+            .output()
+            .expect("failed to list remote tree");
+        Self::stdout(&output)
+    }
+}
+
+#[test]
+fn test_add_pushes_when_auto_push_enabled() {
+    let (env, remote) = TestEnv::with_remote(false, true);
+
+    let output = env.brd_json(&["add", "pushed issue"]);
+    assert!(
+        output.status.success(),
+        "add failed: {}",
+        TestEnv::stderr(&output)
+    );
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    assert!(TestEnv::remote_issue_files(&remote).contains(&id));
+}
+
+#[test]
+fn test_doctor_reports_divergence_from_upstream() {
+    let (env, _remote) = TestEnv::with_remote(false, true);
+    env.brd(&["add", "pushed issue"]);
+    Command::new("git")
+        .args(["commit", "--allow-empty", "-m", "local issue change"])
+        .current_dir(env.path().join(".git/brd/issues"))
+        .output()
+        .expect("failed to commit in issues worktree");
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env.path())
+            .output()
+            .expect("failed to run git")
+    };
+    git(&["branch", "-M", "main"]);
+    git(&["commit", "--allow-empty", "-m", "pushed"]);
+    git(&["push", "-u", "origin", "main"]);
+    git(&["commit", "--allow-empty", "-m", "local"]);
+    git(&["commit", "--allow-empty", "-m", "local 2"]);
+
+    let output = env.brd_json(&["doctor"]);
+    let stdout = TestEnv::stdout(&output);
+    let json: serde_json::Value = serde_json::Deserializer::from_str(&stdout)
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+    let divergence = &json["divergence"];
+    assert_eq!(divergence["issues_branch"]["ahead"], 1, "{}", stdout);
+    assert_eq!(divergence["issues_branch"]["behind"], 0);
+    assert_eq!(divergence["main"]["upstream"], "origin/main");
+    assert_eq!(divergence["main"]["ahead"], 2);
+    assert_eq!(divergence["sync_recommended"], true);
+    // the issues branch was pushed without --set-upstream
+    assert_eq!(divergence["sync_command"], "brd sync --push");
+
+    let output = env.brd(&["doctor"]);
+    let stdout = TestEnv::stdout(&output);
+    assert!(stdout.contains("main is 2 ahead origin/main"), "{}", stdout);
+    assert!(TestEnv::stderr(&output).contains("run `brd sync --push`"));
+
+    env.brd(&["sync", "--push"]);
+    let output = env.brd_json(&["doctor"]);
+    let stdout = TestEnv::stdout(&output);
+    let json: serde_json::Value = serde_json::Deserializer::from_str(&stdout)
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(json["divergence"]["issues_branch"]["ahead"], 0);
+    // main diverging doesn't need `brd sync` in local-sync mode
+    assert_eq!(json["divergence"]["sync_recommended"], false);
+}
+
+#[test]
+fn test_add_does_not_push_when_auto_push_disabled() {
+    let (env, remote) = TestEnv::with_remote(false, false);
+
+    let output = env.brd_json(&["add", "local issue"]);
+    assert!(output.status.success());
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    assert!(!TestEnv::remote_issue_files(&remote).contains(&id));
+}
+
+#[test]
+fn test_add_no_push_flag_skips_push() {
+    let (env, remote) = TestEnv::with_remote(false, true);
+
+    let output = env.brd_json(&["add", "local issue", "--no-push"]);
+    assert!(output.status.success());
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    assert!(!TestEnv::remote_issue_files(&remote).contains(&id));
+}
+
+#[test]
+fn test_done_pushes_when_auto_push_enabled() {
+    let (env, remote) = TestEnv::with_remote(false, true);
+
+    let output = env.brd_json(&["add", "test issue"]);
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+    env.brd(&["start", &id]);
+    let output = env.brd(&["done", &id]);
+    assert!(
+        output.status.success(),
+        "done failed: {}",
+        TestEnv::stderr(&output)
+    );
+
+    let output = Command::new("git")
+        .args(["log", "--format=%s", "braid-issues"])
+        .current_dir(remote.path())
+        .output()
+        .expect("failed to read remote log");
+    assert!(TestEnv::stdout(&output).contains(&format!("chore(braid): done {}", id)));
+}
+
+/// push a new issue to the remote from a separate clone, returning its id.
+fn push_issue_from_other_clone(env: &TestEnv, remote: &tempfile::TempDir) -> String {
+    // publish the issues branch so the other clone can see it
+    let output = env.brd(&["sync", "--push"]);
+    assert!(
+        output.status.success(),
+        "sync failed: {}",
+        TestEnv::stderr(&output)
+    );
+
+    let other = tempfile::tempdir().expect("failed to create temp clone");
+    Command::new("git")
+        .args(["clone", "--branch", "braid-issues"])
+        .arg(remote.path())
+        .arg(other.path())
+        .output()
+        .expect("failed to clone remote");
+    for args in [
+        ["config", "user.email", "other@test.com"],
+        ["config", "user.name", "Other User"],
+        ["config", "commit.gpgsign", "false"],
+    ] {
+        Command::new("git")
+            .args(args)
+            .current_dir(other.path())
+            .output()
+            .expect("failed to configure clone");
+    }
+
+    let id = "rmt-abcd".to_string();
+    let issues_dir = other.path().join(".braid/issues");
+    std::fs::create_dir_all(&issues_dir).expect("failed to create issues dir");
+    std::fs::write(
+        issues_dir.join(format!("{}.md", id)),
+        format!(
+            "---\nschema_version: 6\nid: {}\ntitle: remote issue\npriority: P1\nstatus: open\ndeps: []\ncreated_at: 2025-01-01T00:00:00Z\n---\n",
+            id
+        ),
+    )
+    .expect("failed to write issue");
+    for args in [
+        vec!["add", "."],
+        vec!["commit", "-m", "add remote issue"],
+        vec!["push", "origin", "braid-issues"],
+    ] {
+        let output = Command::new("git")
+            .args(&args)
+            .current_dir(other.path())
+            .output()
+            .expect("failed to run git in clone");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    id
+}
+
+#[test]
+fn test_start_pulls_when_auto_pull_enabled() {
+    let (env, remote) = TestEnv::with_remote(true, false);
+    let id = push_issue_from_other_clone(&env, &remote);
+
+    let output = env.brd(&["start", &id]);
+    assert!(
+        output.status.success(),
+        "start failed: {}",
+        TestEnv::stderr(&output)
+    );
+}
+
+#[test]
+fn test_add_does_not_pull_when_auto_pull_disabled() {
+    let (env, remote) = TestEnv::with_remote(false, false);
+    let id = push_issue_from_other_clone(&env, &remote);
+
+    env.brd(&["add", "local issue"]);
+
+    let output = env.brd(&["show", &id]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_add_no_sync_flag_skips_pull() {
+    let (env, remote) = TestEnv::with_remote(true, false);
+    let id = push_issue_from_other_clone(&env, &remote);
+
+    env.brd(&["add", "local issue", "--no-sync"]);
+
+    let output = env.brd(&["show", &id]);
+    assert!(!output.status.success());
+
+    // a regular add pulls the remote issue in
+    env.brd(&["add", "another issue"]);
+    let output = env.brd(&["show", &id]);
+    assert!(
+        output.status.success(),
+        "show failed: {}",
+        TestEnv::stderr(&output)
+    );
+}
+
+#[test]
+fn test_tui_rejects_invalid_start_options() {
+    let env = TestEnv::new();
+
+    let output = env.brd(&["tui", "--force", "--status", "bogus"]);
+    assert!(!output.status.success());
+    assert!(TestEnv::stderr(&output).contains("invalid status: bogus"));
+
+    let output = env.brd(&["tui", "--force", "--view", "kanban"]);
+    assert!(!output.status.success());
+    assert!(TestEnv::stderr(&output).contains("invalid view: kanban"));
+}
+
+#[test]
+fn test_add_in_repo_without_commits_explains() {
+    let dir = tempfile::tempdir().expect("failed to create temp dir");
+    let path = dir.path().to_path_buf();
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(&path)
+            .output()
+            .expect("failed to run git")
+    };
+    git(&["init"]);
+    git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://example.invalid/repo.git",
+    ]);
+
+    // init refuses up front instead of leaving a half-built .braid
+    let output = TestEnv::run_brd_in(&path, &["init", "-y"]);
+    assert!(!output.status.success());
+    assert!(TestEnv::stderr(&output).contains("no commits"));
+    assert!(!path.join(".braid").exists());
+
+    // a git-native setup with auto-sync fails before pulling, not mid-push
+    std::fs::create_dir_all(path.join(".braid/issues")).unwrap();
+    let config = format!(
+        "schema_version = {}\nid_prefix = \"tst\"\nid_len = 4\nauto_pull = true\nauto_push = true\n",
+        braid::migrate::CURRENT_SCHEMA
+    );
+    std::fs::write(path.join(".braid/config.toml"), config).unwrap();
+    let output = TestEnv::run_brd_in(&path, &["add", "first issue"]);
+    assert!(!output.status.success());
+    let stderr = TestEnv::stderr(&output);
+    assert!(stderr.contains("no commits yet"), "stderr: {}", stderr);
+    assert!(stderr.contains("git commit --allow-empty"));
+}
+
+#[test]
+fn test_agent_worktree_with_outdated_issues_refuses_mutations() {
+    let env = TestEnv::new();
+    let output = env.brd_json(&["add", "older issue"]);
+    assert!(output.status.success());
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    let git = |cwd: &PathBuf, args: &[&str]| {
+        let out = Command::new("git")
+            .args(args)
+            .current_dir(cwd)
+            .output()
+            .expect("failed to run git");
+        assert!(
+            out.status.success(),
+            "git {:?}: {}",
+            args,
+            TestEnv::stderr(&out)
+        );
+    };
+    git(&env.path(), &["add", "-A"]);
+    git(&env.path(), &["commit", "-m", "add braid"]);
+
+    let wt_parent = tempfile::tempdir().unwrap();
+    let wt = wt_parent.path().join("agent-one");
+    git(
+        &env.path(),
+        &["worktree", "add", "-b", "agent-one", &wt.to_string_lossy()],
+    );
+    std::fs::write(wt.join(".braid/agent.toml"), "agent_id = \"agent-one\"\n").unwrap();
+
+    // the worktree's copy of the issue predates the current schema
+    let output = TestEnv::run_brd_in(&wt, &["path", &id]);
+    assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+    let issue_path = PathBuf::from(TestEnv::stdout(&output).trim());
+    let content = std::fs::read_to_string(&issue_path).unwrap();
+    let current = format!("schema_version: {}", braid::migrate::CURRENT_SCHEMA);
+    let older = format!("schema_version: {}", braid::migrate::CURRENT_SCHEMA - 1);
+    std::fs::write(&issue_path, content.replace(&current, &older)).unwrap();
+
+    // mutating commands stop with worktree guidance before touching anything
+    let output = TestEnv::run_brd_in(&wt, &["add", "new issue"]);
+    assert!(!output.status.success());
+    let stderr = TestEnv::stderr(&output);
+    assert!(stderr.contains("older schema"), "stderr: {}", stderr);
+    assert!(stderr.contains("git rebase origin/main"));
+    assert!(stderr.contains("brd migrate"));
+    let output = TestEnv::run_brd_in(&wt, &["set", &id, "priority", "P0"]);
+    assert!(!output.status.success());
+
+    // reading still works, and migrating unblocks it
+    assert!(TestEnv::run_brd_in(&wt, &["ls"]).status.success());
+    assert!(TestEnv::run_brd_in(&wt, &["migrate"]).status.success());
+    let output = TestEnv::run_brd_in(&wt, &["set", &id, "priority", "P0"]);
+    assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+}
+
+#[test]
+fn test_detached_head_is_reported_and_refused() {
+    let env = TestEnv::new();
+    env.brd(&["add", "some issue"]);
+    Command::new("git")
+        .args(["checkout", "--detach"])
+        .current_dir(env.path())
+        .output()
+        .expect("failed to detach HEAD");
+
+    let output = env.brd_json(&["doctor"]);
+    assert!(!output.status.success());
+    let stdout = TestEnv::stdout(&output);
+    let json: serde_json::Value = serde_json::Deserializer::from_str(&stdout)
+        .into_iter()
+        .next()
+        .unwrap()
+        .unwrap();
+    let errors = json["errors"].as_array().unwrap();
+    assert!(errors.iter().any(|e| e["code"] == "detached_head"));
+
+    let output = env.brd(&["commit"]);
+    assert!(!output.status.success());
+    let stderr = TestEnv::stderr(&output);
+    assert!(stderr.contains("HEAD is detached"), "stderr: {}", stderr);
+    assert!(stderr.contains("git switch"));
+}
+
+#[test]
+fn test_id_slug_filename_scheme() {
+    let env = TestEnv::new();
+    let config_path = env.path().join(".braid/config.toml");
+    let mut config = std::fs::read_to_string(&config_path).unwrap();
+    config.push_str("filename_scheme = \"id-slug\"\n");
+    std::fs::write(&config_path, config).unwrap();
+
+    let output = env.brd_json(&["add", "Fix login bug"]);
+    assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    let output = env.brd(&["path", &id]);
+    let path = TestEnv::stdout(&output).trim().to_string();
+    assert!(
+        path.ends_with(&format!("{}-fix-login-bug.md", id)),
+        "{}",
+        path
+    );
+
+    // retitling renames the file; partial ids still resolve
+    let output = env.brd(&["set", &id, "title", "Fix logout bug"]);
+    assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+    assert!(!std::path::Path::new(&path).exists());
+    let output = env.brd(&["path", &id[id.len() - 4..]]);
+    let renamed = TestEnv::stdout(&output).trim().to_string();
+    assert!(
+        renamed.ends_with(&format!("{}-fix-logout-bug.md", id)),
+        "{}",
+        renamed
+    );
+
+    let output = env.brd_json(&["show", &id]);
+    assert_eq!(TestEnv::json(&output)["title"], "Fix logout bug");
+}
+
+#[test]
+fn test_add_reads_body_from_stdin() {
+    let env = TestEnv::new();
+    let body_of = |output: &Output| {
+        assert!(output.status.success(), "{}", TestEnv::stderr(output));
+        let id = TestEnv::json(output)["id"].as_str().unwrap().to_string();
+        let path = TestEnv::stdout(&env.brd(&["path", &id]));
+        let content = std::fs::read_to_string(path.trim()).unwrap();
+        content.split("---\n").nth(2).unwrap().trim().to_string()
+    };
+
+    let output = env.brd_with_stdin(
+        &["--json", "add", "--title", "From a script", "-"],
+        "line one\nline two\n",
+    );
+    assert_eq!(body_of(&output), "line one\nline two");
+
+    // without `-`, piped input is ignored
+    let output = env.brd_with_stdin(&["--json", "add", "Piped"], "piped body\n");
+    assert_eq!(body_of(&output), "");
+    let output = env.brd_with_stdin(&["--json", "add", "Flag", "-b", "flag body"], "ignored");
+    assert_eq!(body_of(&output), "flag body");
+
+    // `-` needs --title
+    let output = env.brd_with_stdin(&["add", "-"], "body");
+    assert!(!output.status.success());
+    assert!(TestEnv::stderr(&output).contains("--title"));
+}
+
+#[test]
+fn test_auto_sync_without_origin_remote() {
+    let env = TestEnv::new();
+    let config_path = env.path().join(".braid/config.toml");
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    let config = config
+        .replace("auto_pull = false", "auto_pull = true")
+        .replace("auto_push = false", "auto_push = true");
+    std::fs::write(&config_path, config).unwrap();
+
+    let output = env.brd_json(&["add", "no remote here"]);
+    assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+    let id = TestEnv::json(&output)["id"].as_str().unwrap().to_string();
+
+    for args in [["start", id.as_str()], ["done", id.as_str()]] {
+        let output = env.brd(&args);
+        assert!(output.status.success(), "{}", TestEnv::stderr(&output));
+    }
+    let output = env.brd_json(&["show", &id]);
+    assert_eq!(TestEnv::json(&output)["status"], "done");
+}